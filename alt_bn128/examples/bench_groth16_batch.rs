@@ -0,0 +1,36 @@
+use alt_bn128::gnark;
+use alt_bn128::groth16::{verify, verify_batch, Instance, Proof, Transcript, VerifyingKey};
+use alt_bn128::Fr;
+
+fn main() {
+    let vk = gnark::VerifyingKey::from_bytes(include_bytes!("../fixtures/gnark/vk.bin")).unwrap();
+    let vk = VerifyingKey::from_gnark(&vk).unwrap();
+    let proof =
+        gnark::Proof::from_bytes(include_bytes!("../fixtures/gnark/proof_raw.bin")).unwrap();
+    // The public inputs of the SP1 proof, see fixtures/gnark/fetch.sh.
+    let inputs = [
+        Fr::from_str("406432647295647673015241033990011138953233847343093038612820599671924330193")
+            .unwrap(),
+        Fr::from_str(
+            "6265467995298269824615662043982564374977105610814771179903635896347370323221",
+        )
+        .unwrap(),
+    ];
+    let instances = [Instance {
+        proof: Proof::from(&proof),
+        inputs: &inputs,
+    }; 64];
+
+    for n in [1, 4, 16, 64].iter().cloned() {
+        let a = std::time::SystemTime::now();
+        for instance in instances[..n].iter() {
+            assert!(verify(&vk, &instance.proof, instance.inputs).unwrap());
+        }
+        let b = a.elapsed().unwrap().as_nanos();
+        let a = std::time::SystemTime::now();
+        let mut transcript = Transcript::new(&instances[..n]);
+        assert!(verify_batch(&vk, &instances[..n], &mut transcript).unwrap());
+        let c = a.elapsed().unwrap().as_nanos();
+        println!("{:3} proofs {:12} ns one by one {:12} ns batched", n, b, c);
+    }
+}
@@ -4,7 +4,7 @@ use alt_bn128::ethereum::ut::{hex2bin, ALT_BN128_PAIRING_CASE};
 fn main() {
     let mut buf0 = [0x00; 4096];
     let mut buf1 = [0x00; 32];
-    for (_, (inputs, expect)) in ALT_BN128_PAIRING_CASE.iter().enumerate() {
+    for (inputs, expect) in ALT_BN128_PAIRING_CASE.iter() {
         let a = std::time::SystemTime::now();
         for _ in 0..20 {
             hex2bin(inputs, &mut buf0[..]);
@@ -1,8 +1,28 @@
 fn main() {
     alt_bn128::ethereum::ut::test_alt_bn128_add();
-    println!("test_alt_bn128_add     pass");
+    println!("test_alt_bn128_add       pass");
     alt_bn128::ethereum::ut::test_alt_bn128_mul();
-    println!("test_alt_bn128_mul     pass");
+    println!("test_alt_bn128_mul       pass");
     alt_bn128::ethereum::ut::test_alt_bn128_pairing();
-    println!("test_alt_bn128_pairing pass");
+    println!("test_alt_bn128_pairing   pass");
+    alt_bn128::ethereum::ut::test_ecrecover();
+    println!("test_ecrecover           pass");
+    alt_bn128::ethereum::ut::test_modexp();
+    println!("test_modexp              pass");
+    alt_bn128::ethereum::ut::test_blake2f();
+    println!("test_blake2f             pass");
+    alt_bn128::ethereum::ut::test_bls12_g1add();
+    println!("test_bls12_g1add         pass");
+    alt_bn128::ethereum::ut::test_bls12_g1msm();
+    println!("test_bls12_g1msm         pass");
+    alt_bn128::ethereum::ut::test_bls12_g2add();
+    println!("test_bls12_g2add         pass");
+    alt_bn128::ethereum::ut::test_bls12_g2msm();
+    println!("test_bls12_g2msm         pass");
+    alt_bn128::ethereum::ut::test_bls12_pairing_check();
+    println!("test_bls12_pairing_check pass");
+    alt_bn128::ethereum::ut::test_bls12_map_fp_to_g1();
+    println!("test_bls12_map_fp_to_g1  pass");
+    alt_bn128::ethereum::ut::test_bls12_map_fp2_to_g2();
+    println!("test_bls12_map_fp2_to_g2 pass");
 }
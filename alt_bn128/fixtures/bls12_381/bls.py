@@ -0,0 +1,323 @@
+# A plain reference of BLS12-381 for the tests of bls12_381: the tower, both groups, the
+# optimal ate pairing and the hash-to-curve maps of RFC 9380.
+import hashlib
+
+P = 0x1A0111EA397FE69A4B1BA7B6434BACD764774B84F38512BF6730D2A0F6B0F6241EABFFFEB153FFFFB9FEFFFFFFFFAAAB
+R = 0x73EDA753299D7D483339D80809A1D80553BDA402FFFE5BFEFFFFFFFF00000001
+X = -0xD201000000010000
+
+
+class Fp2:
+    def __init__(self, a, b=0):
+        self.a, self.b = a % P, b % P
+
+    def __add__(s, o):
+        o = lift(o)
+        return Fp2(s.a + o.a, s.b + o.b)
+
+    __radd__ = __add__
+
+    def __sub__(s, o):
+        o = lift(o)
+        return Fp2(s.a - o.a, s.b - o.b)
+
+    def __rsub__(s, o):
+        return lift(o) - s
+
+    def __neg__(s):
+        return Fp2(-s.a, -s.b)
+
+    def __mul__(s, o):
+        if isinstance(o, int):
+            return Fp2(s.a * o, s.b * o)
+        return Fp2(s.a * o.a - s.b * o.b, s.a * o.b + s.b * o.a)
+
+    __rmul__ = __mul__
+
+    def __eq__(s, o):
+        o = lift(o)
+        return s.a == o.a and s.b == o.b
+
+    def __hash__(s):
+        return hash((s.a, s.b))
+
+    def conj(s):
+        return Fp2(s.a, -s.b)
+
+    def inv(s):
+        n = pow(s.a * s.a + s.b * s.b, P - 2, P)
+        return Fp2(s.a * n, -s.b * n)
+
+    def __truediv__(s, o):
+        return s * lift(o).inv()
+
+    def __pow__(s, e):
+        r, b = Fp2(1), s
+        while e:
+            if e & 1:
+                r = r * b
+            b = b * b
+            e >>= 1
+        return r
+
+    def is_zero(s):
+        return s.a == 0 and s.b == 0
+
+    def __repr__(s):
+        return "Fp2(%#x, %#x)" % (s.a, s.b)
+
+
+def lift(o):
+    return Fp2(o) if isinstance(o, int) else o
+
+
+XI = Fp2(1, 1)
+
+
+# Fp12 as polynomials of degree < 12 over Fp2 in w with w^6 = XI; v = w^2.
+class Fp12:
+    def __init__(self, c):
+        self.c = [lift(x) for x in c] + [Fp2(0)] * (6 - len(c))
+
+    @staticmethod
+    def one():
+        return Fp12([1])
+
+    def __mul__(s, o):
+        t = [Fp2(0)] * 11
+        for i in range(6):
+            if s.c[i].is_zero():
+                continue
+            for j in range(6):
+                t[i + j] = t[i + j] + s.c[i] * o.c[j]
+        for i in range(10, 5, -1):
+            t[i - 6] = t[i - 6] + t[i] * XI
+        return Fp12(t[:6])
+
+    def __eq__(s, o):
+        return all(a == b for a, b in zip(s.c, o.c))
+
+    def __pow__(s, e):
+        r, b = Fp12.one(), s
+        while e:
+            if e & 1:
+                r = r * b
+            b = b * b
+            e >>= 1
+        return r
+
+    def conj(s):
+        # w -> -w, the p^6 power
+        return Fp12([c if i % 2 == 0 else -c for i, c in enumerate(s.c)])
+
+    def frob(s):
+        # (sum c_i w^i)^p = sum conj(c_i) w^(ip) and w^p = XI^((p-1)/6) w
+        g = XI ** ((P - 1) // 6)
+        return Fp12([s.c[i].conj() * g**i for i in range(6)])
+
+    def inv(s):
+        # s^-1 = s^(p^12 - 2) is slow; use the norm to Fp6 = Fp2[v]: (a + b w)(a - b w) = a^2 - b^2 v
+        a = [s.c[0], s.c[2], s.c[4]]
+        b = [s.c[1], s.c[3], s.c[5]]
+        n = fp6_sub(fp6_mul(a, a), fp6_mul_v(fp6_mul(b, b)))
+        ni = fp6_inv(n)
+        ra, rb = fp6_mul(a, ni), fp6_mul([-x for x in b], ni)
+        return Fp12([ra[0], rb[0], ra[1], rb[1], ra[2], rb[2]])
+
+
+def fp6_mul(a, b):
+    t = [Fp2(0)] * 5
+    for i in range(3):
+        for j in range(3):
+            t[i + j] = t[i + j] + a[i] * b[j]
+    return [t[0] + t[3] * XI, t[1] + t[4] * XI, t[2]]
+
+
+def fp6_sub(a, b):
+    return [x - y for x, y in zip(a, b)]
+
+
+def fp6_mul_v(a):
+    return [a[2] * XI, a[0], a[1]]
+
+
+def fp6_inv(a):
+    c0 = a[0] * a[0] - a[1] * a[2] * XI
+    c1 = a[2] * a[2] * XI - a[0] * a[1]
+    c2 = a[1] * a[1] - a[0] * a[2]
+    t = a[0] * c0 + (a[2] * c1 + a[1] * c2) * XI
+    ti = t.inv()
+    return [c0 * ti, c1 * ti, c2 * ti]
+
+
+# Curves y^2 = x^3 + B over Fp (ints) and Fp2, affine points or None.
+B1 = 4
+B2 = Fp2(4, 4)
+
+
+def add(p, q, field=None):
+    if p is None:
+        return q
+    if q is None:
+        return p
+    x1, y1 = p
+    x2, y2 = q
+    if x1 == x2:
+        if y1 == -y2 or (isinstance(y1, int) and (y1 + y2) % P == 0):
+            return None
+        l = div(3 * x1 * x1, 2 * y1)
+    else:
+        l = div(y2 - y1, x2 - x1)
+    x3 = md(l * l - x1 - x2)
+    return x3, md(l * (x1 - x3) - y1)
+
+
+def md(a):
+    return a % P if isinstance(a, int) else a
+
+
+def div(a, b):
+    if isinstance(a, int) and isinstance(b, int):
+        return a * pow(b, P - 2, P) % P
+    return lift(a) / lift(b)
+
+
+def neg(p):
+    return None if p is None else (p[0], md(-p[1]))
+
+
+def mul(k, p):
+    if k < 0:
+        return mul(-k, neg(p))
+    r = None
+    for bit in bin(k)[2:]:
+        r = add(r, r)
+        if bit == "1":
+            r = add(r, p)
+    return r
+
+
+G1 = (
+    0x17F1D3A73197D7942695638C4FA9AC0FC3688C4F9774B905A14E3A3F171BAC586C55E83FF97A1AEFFB3AF00ADB22C6BB,
+    0x08B3F481E3AAA0F1A09E30ED741D8AE4FCF5E095D5D00AF600DB18CB2C04B3EDD03CC744A2888AE40CAA232946C5E7E1,
+)
+G2 = (
+    Fp2(
+        0x024AA2B2F08F0A91260805272DC51051C6E47AD4FA403B02B4510B647AE3D1770BAC0326A805BBEFD48056C8C121BDB8,
+        0x13E02B6052719F607DACD3A088274F65596BD0D09920B61AB5DA61BBDC7F5049334CF11213945D57E5AC7D055D042B7E,
+    ),
+    Fp2(
+        0x0CE5D527727D6E118CC9CDC6DA2E351AADFD9BAA8CBDD3A76D429A695160D12C923AC9CC3BACA289E193548608B82801,
+        0x0606C4A02EA734CC32ACD2B02BC28B99CB3E287E85A763AF267492AB572E99AB3F370D275CEC1DA1AAA9075FF05F79BE,
+    ),
+)
+
+
+def on_curve(p, b):
+    if p is None:
+        return True
+    x, y = p
+    return md(y * y - x * x * x - b) == (0 if isinstance(x, int) else Fp2(0))
+
+
+# Miller loop of the optimal ate pairing f_{|x|,Q}(P), conjugated as x < 0, with lines scaled
+# into the sparse form c0 + c1 v + c4 w v (coefficients 0, 1 and 4 of the w-basis).
+def line(t, q, p):
+    x, y = t
+    if q is None or (t[0] == q[0] and t[1] == q[1]):
+        l = (3 * x * x) / (2 * y)
+    else:
+        l = (q[1] - y) / (q[0] - x)
+    # y_P w^3 - l x_P w^2 + (l x_T - y_T)
+    c = [l * x - y, Fp2(0), -l * p[0], Fp2(p[1]), Fp2(0), Fp2(0)]
+    # indices in w: 0, 2, 3
+    return Fp12([c[0], Fp2(0), c[2], c[3], Fp2(0), Fp2(0)])
+
+
+def miller(p, q):
+    f, t = Fp12.one(), q
+    for bit in bin(-X)[3:]:
+        f = f * f * line(t, None, p)
+        t = add(t, t)
+        if bit == "1":
+            f = f * line(t, q, p)
+            t = add(t, q)
+    return f.conj()
+
+
+def final_exp(f):
+    return f ** ((P**12 - 1) // R)
+
+
+def pairing(p, q):
+    return final_exp(miller(p, q))
+
+
+# RFC 9380
+def sgn0(a):
+    if isinstance(a, int):
+        return a % 2
+    return (a.a % 2) | ((a.a == 0) & (a.b % 2))
+
+
+def is_square(a):
+    if isinstance(a, int):
+        return pow(a, (P - 1) // 2, P) <= 1
+    return pow(a.a * a.a + a.b * a.b, (P - 1) // 2, P) <= 1
+
+
+def sqrt_fp(a):
+    r = pow(a, (P + 1) // 4, P)
+    return r if r * r % P == a % P else None
+
+
+def sqrt(a):
+    if isinstance(a, int):
+        return sqrt_fp(a)
+    if a.b == 0:
+        r = sqrt_fp(a.a)
+        return Fp2(r) if r is not None else Fp2(0, sqrt_fp(-a.a))
+    alpha = sqrt_fp((a.a * a.a + a.b * a.b) % P)
+    delta = (a.a + alpha) * pow(2, P - 2, P) % P
+    if not is_square(delta):
+        delta = (a.a - alpha) * pow(2, P - 2, P) % P
+    x0 = sqrt_fp(delta)
+    x1 = a.b * pow(2 * x0, P - 2, P) % P
+    return Fp2(x0, x1)
+
+
+def sswu(u, a, b, z):
+    tv1 = z * z * u**4 + z * u * u if isinstance(u, int) else z * z * u * u * u * u + z * u * u
+    tv1 = md(tv1)
+    if tv1 == 0 or tv1 == Fp2(0):
+        x1 = div(b, md(z * a))
+    else:
+        x1 = md(div(md(-b), a) * (1 + div(1, tv1)))
+    gx1 = md(x1 * x1 * x1 + a * x1 + b)
+    if is_square(gx1):
+        x, y = x1, sqrt(gx1)
+    else:
+        x = md(z * u * u * x1)
+        y = sqrt(md(x * x * x + a * x + b))
+    if sgn0(u) != sgn0(y):
+        y = md(-y)
+    return x, y
+
+
+def expand_message_xmd(msg, dst, n):
+    ell = (n + 31) // 32
+    dst_prime = dst + bytes([len(dst)])
+    b0 = hashlib.sha256(bytes(64) + msg + n.to_bytes(2, "big") + b"\0" + dst_prime).digest()
+    b = [hashlib.sha256(b0 + b"\1" + dst_prime).digest()]
+    for i in range(2, ell + 1):
+        b.append(hashlib.sha256(bytes(x ^ y for x, y in zip(b0, b[-1])) + bytes([i]) + dst_prime).digest())
+    return b"".join(b)[:n]
+
+
+def hash_to_field(msg, dst, count, m):
+    data = expand_message_xmd(msg, dst, count * m * 64)
+    out = []
+    for i in range(count):
+        e = [int.from_bytes(data[64 * (i * m + j) : 64 * (i * m + j + 1)], "big") % P for j in range(m)]
+        out.append(e[0] if m == 1 else Fp2(*e))
+    return out
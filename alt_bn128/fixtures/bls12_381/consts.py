@@ -0,0 +1,88 @@
+# Prints the constants of src/bls12_381 in Montgomery form, R = 2^384.
+import sys
+from bls import P, R, X, XI, Fp2, G1, G2
+import derive
+
+RM = 1 << 384
+
+
+def limbs(v):
+    return [(v >> (64 * i)) & (2**64 - 1) for i in range(6)]
+
+
+def fp(v, ind=""):
+    ls = limbs(v * RM % P)
+    return "const_fp([\n" + "".join("%s    0x%016x,\n" % (ind, l) for l in ls) + ind + "])"
+
+
+def fp2(v, ind=""):
+    return "Fp2::new(\n%s    %s,\n%s    %s,\n%s)" % (ind, fp(v.a, ind + "    "), ind, fp(v.b, ind + "    "), ind)
+
+
+def raw(v):
+    return "[" + ", ".join("0x%016x" % l for l in limbs(v)) + "]"
+
+
+if __name__ == "__main__":
+    what = sys.argv[1]
+    if what == "field":
+        print("MODULUS", raw(P))
+        print("INV", hex((-pow(P, -1, 2**64)) % 2**64))
+        print("R", raw(RM % P))
+        print("R2", raw(RM * RM % P))
+        print("P-2", raw(P - 2))
+        print("(P+1)/4", raw((P + 1) // 4))
+        print("(P-1)/2", raw((P - 1) // 2))
+    elif what == "frob":
+        for k in range(4):
+            print("fp6 c1", k, fp2(XI ** (((P**k) - 1) // 3)))
+            print("fp6 c2", k, fp2(XI ** (2 * ((P**k) - 1) // 3)))
+            print("fp12 c1", k, fp2(XI ** (((P**k) - 1) // 6)))
+    elif what == "groups":
+        print("g1", fp(G1[0]), fp(G1[1]))
+        print("g2", fp2(G2[0]), fp2(G2[1]))
+        print("b", fp(4), fp2(Fp2(4, 4)))
+        print("psi", fp2((XI ** ((P - 1) // 3)).inv()), fp2((XI ** ((P - 1) // 2)).inv()))
+    elif what == "iso":
+        print(fp(derive.G1_A), fp(derive.G1_B), fp(11))
+        print(fp2(derive.G2_A), fp2(derive.G2_B), fp2(derive.G2_Z))
+
+
+def coeff_table(name, n, values):
+    # a match on power % n as in src/fields
+    out = "fn %s(power: usize) -> Fp2 {\n    match power %% %d {\n        0 => Fp2::one(),\n" % (name, n)
+    for k, v in enumerate(values, 1):
+        if v.b == 0:
+            body = "Fp2::new(\n            %s,\n            Fp::zero(),\n        )" % fp(v.a, "            ")
+        elif v.a == 0:
+            body = "Fp2::new(\n            Fp::zero(),\n            %s,\n        )" % fp(v.b, "            ")
+        else:
+            body = fp2(v, "        ")
+        out += "        %d => %s,\n" % (k, body)
+    out += "        _ => unimplemented!(),\n    }\n}\n"
+    return out
+
+
+if __name__ == "__main__" and sys.argv[1] == "tables":
+    print(coeff_table("frobenius_coeffs_c1", 6, [XI ** (((P**k) - 1) // 3) for k in (1, 2, 3)]))
+    print(coeff_table("frobenius_coeffs_c2", 6, [XI ** (2 * ((P**k) - 1) // 3) for k in (1, 2, 3)]))
+    print(coeff_table("frobenius_coeffs_c1", 12, [XI ** (((P**k) - 1) // 6) for k in (1, 2, 3)]))
+
+
+def const_array(name, ty, values, conv):
+    return "const %s: [%s; %d] = [\n%s];\n" % (
+        name,
+        ty,
+        len(values),
+        "".join("    %s,\n" % conv(v, "    ") for v in values),
+    )
+
+
+if __name__ == "__main__" and sys.argv[1] == "isogenies":
+    g1, g2 = derive.isogenies()
+    out = []
+    for name, f in zip(("G1_X_NUM", "G1_X_DEN", "G1_Y_NUM", "G1_Y_DEN"), g1):
+        out.append(const_array(name, "Fp", f, fp))
+    for name, f in zip(("G2_X_NUM", "G2_X_DEN", "G2_Y_NUM", "G2_Y_DEN"), g2):
+        out.append(const_array(name, "Fp2", f, fp2))
+    print("\n".join(out))
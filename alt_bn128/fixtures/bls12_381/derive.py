@@ -0,0 +1,311 @@
+# Derives the isogeny maps of the SSWU curves E1' and E2' to E1 and E2 for the
+# hash-to-curve code of bls12_381: the kernel is a factor of the l-division polynomial of
+# E', Velu's formulas give the map to a curve with A = 0, and of the isomorphisms of that
+# curve to E the one of RFC 9380 is told apart by its test vectors. Prints the
+# coefficients of x_num, x_den, y_num and y_den, lowest degree first.
+import random
+from bls import P, Fp2, X, hash_to_field, sswu
+
+
+class Fq:
+    def __init__(self, a):
+        self.a = a % P
+
+    def __add__(s, o):
+        return Fq(s.a + o.a)
+
+    def __sub__(s, o):
+        return Fq(s.a - o.a)
+
+    def __neg__(s):
+        return Fq(-s.a)
+
+    def __mul__(s, o):
+        return Fq(s.a * (o.a if isinstance(o, Fq) else o))
+
+    def __eq__(s, o):
+        return s.a == o.a
+
+    def is_zero(s):
+        return s.a == 0
+
+    def inv(s):
+        return Fq(pow(s.a, P - 2, P))
+
+    def __pow__(s, e):
+        return Fq(pow(s.a, e, P))
+
+
+def trim(f):
+    while f and f[-1].is_zero():
+        f.pop()
+    return f
+
+
+def padd(f, g, z):
+    n = max(len(f), len(g))
+    return trim([(f[i] if i < len(f) else z) + (g[i] if i < len(g) else z) for i in range(n)])
+
+
+def pneg(f):
+    return [-c for c in f]
+
+
+def psub(f, g, z):
+    return padd(f, pneg(g), z)
+
+
+def pmul(f, g, z):
+    if not f or not g:
+        return []
+    out = [z] * (len(f) + len(g) - 1)
+    for i, a in enumerate(f):
+        if a.is_zero():
+            continue
+        for j, b in enumerate(g):
+            out[i + j] = out[i + j] + a * b
+    return trim(out)
+
+
+def pscale(f, c):
+    return trim([a * c for a in f])
+
+
+def pdivmod(f, g, z):
+    f = list(f)
+    q = [z] * max(len(f) - len(g) + 1, 0)
+    inv = g[-1].inv()
+    while len(f) >= len(g):
+        c = f[-1] * inv
+        d = len(f) - len(g)
+        q[d] = c
+        for i, b in enumerate(g):
+            f[i + d] = f[i + d] - c * b
+        f.pop()
+        trim(f)
+    return trim(q), trim(f)
+
+
+def pmod(f, g, z):
+    return pdivmod(f, g, z)[1]
+
+
+def monic(f):
+    inv = f[-1].inv()
+    return [c * inv for c in f]
+
+
+def pgcd(f, g, z):
+    while g:
+        f, g = g, pmod(f, g, z)
+    return monic(f)
+
+
+def ppowmod(f, e, m, z, one):
+    r, b = [one], pmod(f, m, z)
+    while e:
+        if e & 1:
+            r = pmod(pmul(r, b, z), m, z)
+        b = pmod(pmul(b, b, z), m, z)
+        e >>= 1
+    return r
+
+
+def deriv(f, z):
+    return trim([c * i for i, c in enumerate(f)][1:]) if f else []
+
+
+def division_polynomial(l, a, b, z, one):
+    # g_n with psi_n = g_n for odd n and psi_n = 2y g_n for even n.
+    c = lambda k: one * k
+    F = [b * 4, a * 4, z, c(4)]
+    F2 = pmul(F, F, z)
+    g = {
+        0: [],
+        1: [one],
+        2: [one],
+        3: [a * a * (-1), b * 12, a * 6, z, c(3)],
+        4: pscale([a * a * a * (-1) - b * b * 8, a * b * (-4), a * a * (-5), b * 20, a * 5, z, one], c(2)),
+    }
+
+    def get(n):
+        if n in g:
+            return g[n]
+        m = n // 2
+        if n % 2:
+            if m % 2 == 0:
+                r = psub(pmul(F2, pmul(get(m + 2), pmul(get(m), pmul(get(m), get(m), z), z), z), z),
+                         pmul(get(m - 1), pmul(get(m + 1), pmul(get(m + 1), get(m + 1), z), z), z), z)
+            else:
+                r = psub(pmul(get(m + 2), pmul(get(m), pmul(get(m), get(m), z), z), z),
+                         pmul(F2, pmul(get(m - 1), pmul(get(m + 1), pmul(get(m + 1), get(m + 1), z), z), z), z), z)
+        else:
+            r = pmul(get(m), psub(pmul(get(m + 2), pmul(get(m - 1), get(m - 1), z), z),
+                                  pmul(get(m - 2), pmul(get(m + 1), get(m + 1), z), z), z), z)
+        g[n] = r
+        return r
+
+    return get(l)
+
+
+def kernel_factors(psi, q, z, one):
+    # the monic irreducible factors of degree 1 and (l-1)/2 of a squarefree psi
+    x = [z, one]
+    xq = ppowmod(x, q, psi, z, one)
+    lin = pgcd(psi, psub(xq, x, z), z)
+    return lin, xq
+
+
+def compose(f, g, m, z, one):
+    # f(g) mod m
+    r = []
+    for c in reversed(f):
+        r = pmod(padd(pmul(r, g, z), [c], z), m, z)
+    return r
+
+
+def velu(k, a, b, z, one):
+    d = len(k) - 1
+    s1 = -k[d - 1]
+    s2 = k[d - 2] if d >= 2 else z
+    s3 = -k[d - 3] if d >= 3 else z
+    t = (s1 * s1 - s2 * 2) * 6 + a * (2 * d)
+    w = (s1 * s1 * s1 - s1 * s2 * 3 + s3 * 3) * 10 + a * s1 * 6 + b * (4 * d)
+    a2, b2 = a - t * 5, b - w * 7
+    x = [z, one]
+    f = [b, a, z, one]
+    k1, k2 = deriv(k, z), deriv(deriv(k, z), z)
+    kk = pmul(k, k, z)
+    n = pmul(x, kk, z)
+    n = padd(n, pscale(pmul(pmul(x, x, z), pmul(k1, k, z), z), one * 6), z)
+    n = psub(n, pscale(pmul([s1, one * d], kk, z), one * 6), z)
+    n = padd(n, pscale(pmul(k1, k, z), a * 2), z)
+    inner = padd(pmul(deriv(f, z), pmul(k1, k, z), z), pmul(f, psub(pmul(k2, k, z), pmul(k1, k1, z), z), z), z)
+    inner = psub(inner, pmul([s1, one * (2 * d)], kk, z), z)
+    n = psub(n, pscale(inner, one * 4), z)
+    # y = y * X' = y * (N' K - 2 N K') / K^3
+    yn = psub(pmul(deriv(n, z), k, z), pscale(pmul(n, k1, z), one * 2), z)
+    return a2, b2, n, kk, yn, pmul(kk, k, z)
+
+
+def roots(f, q, z, one, rnd):
+    # the roots in the field of a squarefree polynomial
+    f = pgcd(f, psub(ppowmod([z, one], q, f, z, one), [z, one], z), z)
+    return split(f, q, z, one, rnd)
+
+
+def split(f, q, z, one, rnd):
+    if len(f) == 1:
+        return []
+    if len(f) == 2:
+        return [-f[0]]
+    while True:
+        h = ppowmod([rnd(), one], (q - 1) // 2, f, z, one)
+        g = pgcd(f, psub(h, [one], z), z) if len(h) > 1 or not (h and h[0] == one) else []
+        if g and 1 < len(g) < len(f):
+            return split(g, q, z, one, rnd) + split(monic(pdivmod(f, g, z)[0]), q, z, one, rnd)
+
+
+def ev(f, x, z):
+    r = z
+    for c in reversed(f):
+        r = r * x + c
+    return r
+
+
+def derive(l, a, b, q, target_b, z, one, rnd, check):
+    psi = monic(division_polynomial(l, a, b, z, one))
+    d = (l - 1) // 2
+    lin, xq = kernel_factors(psi, q, z, one)
+    cands = []
+    if len(lin) - 1 == d:
+        # the x-coordinates of the kernel are all in the field
+        cands = [lin]
+    else:
+        # an irreducible factor of degree d
+        xqd = xq
+        for _ in range(d - 1):
+            xqd = compose(xqd, xq, psi, z, one)
+        g = pgcd(psi, psub(xqd, [z, one], z), z)
+        cands = [g]
+    for c in cands:
+        k = c if isinstance(c, list) and len(c) == d + 1 else None
+        if k is None:
+            continue
+        a2, b2, xn, xd, yn, yd = velu(k, a, b, z, one)
+        if not a2.is_zero():
+            continue
+        for u2, u3 in isomorphisms(b2, target_b, q, z, one, rnd):
+            maps = (pscale(xn, u2), xd, pscale(yn, u3), yd)
+            if check(maps):
+                return maps
+    return None
+
+
+def isomorphisms(b2, target, q, z, one, rnd):
+    # (u^2, u^3) with u^6 = target / b2
+    c = target * b2.inv()
+    out = []
+    for u in roots([-c, z, z, z, z, z, one], q, z, one, rnd):
+        out.append((u * u, u * u * u))
+    return out
+
+
+def apply(maps, p, conv):
+    xn, xd, yn, yd = maps
+    x, y = conv(p[0]), conv(p[1])
+    e = lambda f: ev(f, x, f[0] * 0)
+    rx = e(xn) * e(xd).inv()
+    ry = y * e(yn) * e(yd).inv()
+    return rx, ry
+
+
+# The sixth root of unity fixing the isomorphism is the one reproducing P of the first
+# BLS12381G1_XMD:SHA-256_SSWU_NU_ and BLS12381G2_XMD:SHA-256_SSWU_NU_ vectors of
+# RFC 9380, appendix J (msg = "").
+G1_A = 0x144698A3B8E9433D693A02C96D4982B0EA985383EE66A8D8E8981AEFD881AC98936F8DA0E0F97F5CF428082D584C1D
+G1_B = 0x12E2908D11688030018B12E8753EEE3B2016C1F0F24F4070A0B9C14FCEF35EF55A23215A316CEAA5D1CC48E98E172BE0
+G2_A = Fp2(0, 240)
+G2_B = Fp2(1012, 1012)
+G2_Z = Fp2(-2, -1)
+G2_H_EFF = 0xBC69F08F2EE75B3584C6A0EA91B352888E2A8E9145AD7689986FF031508FFE1329C2F178731DB956D82BF015D1212B02EC0EC69D7477C1AE954CBC06689F6A359894C0ADEBBF6B4E8020005AAA95551
+RFC_G1_NU_X = 0x184BB665C37FF561A89EC2122DD343F20E0F4CBCAEC84E3C3052EA81D1834E192C426074B02ED3DCA4E7676CE4CE48BA
+RFC_G1_NU_Y = 0x04407B8D35AF4DACC809927071FC0405218F1401A6D15AF775810E4E460064BCC9468BEEBA82FDC751BE70476C888BF3
+RFC_G2_NU_X = Fp2(
+    0x00E7F4568A82B4B7DC1F14C6AAA055EDF51502319C723C4DC2688C7FE5944C213F510328082396515734B6612C4E7BB7,
+    0x126B855E9E69B1F691F816E48AC6977664D24D99F8724868A184186469DDFD4617367E94527D4B74FC86413483AFB35B,
+)
+RFC_G2_NU_Y = Fp2(
+    0x0CAEAD0FD7B6176C01436833C79D305C78BE307DA5F6AF6C133C47311DEF6FF1E0BABF57A0FB5539FCE7EE12407B0A42,
+    0x1498AADCF7AE2B345243E281AE076DF6DE84455D766AB6FCDAAD71FAB60ABB2E8B980A440043CD305DB09D283C895E3D,
+)
+
+
+def isogenies():
+    from bls import mul
+
+    u1 = hash_to_field(b"", b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_NU_", 1, 1)[0]
+    u2 = hash_to_field(b"", b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_NU_", 1, 2)[0]
+
+    def check1(maps):
+        q = apply(maps, sswu(u1, G1_A, G1_B, 11), Fq)
+        return mul(1 - X, (q[0].a, q[1].a)) == (RFC_G1_NU_X, RFC_G1_NU_Y)
+
+    def check2(maps):
+        q = apply(maps, sswu(u2, G2_A, G2_B, G2_Z), lambda v: v)
+        return mul(G2_H_EFF, q) == (RFC_G2_NU_X, RFC_G2_NU_Y)
+
+    rnd1 = lambda: Fq(random.randrange(P))
+    rnd2 = lambda: Fp2(random.randrange(P), random.randrange(P))
+    g1 = derive(11, Fq(G1_A), Fq(G1_B), P, Fq(4), Fq(0), Fq(1), rnd1, check1)
+    g2 = derive(3, G2_A, G2_B, P * P, Fp2(4, 4), Fp2(0), Fp2(1), rnd2, check2)
+    assert g1 and g2
+    return [[c.a for c in f] for f in g1], g2
+
+
+if __name__ == "__main__":
+    g1, g2 = isogenies()
+    for name, f in zip(("x_num", "x_den", "y_num", "y_den"), g1):
+        print("G1", name, [hex(c) for c in f])
+    for name, f in zip(("x_num", "x_den", "y_num", "y_den"), g2):
+        print("G2", name, f)
@@ -0,0 +1,167 @@
+# Vectors of the EIP-2537 precompiles for ethereum::ut: inputs and outputs in the padded
+# encodings, the gas of MSM and pairing and the error cases, printed as Rust tuples. An
+# empty output is an error. Takes a while for the isogenies and final exponentiations.
+import derive
+from bls import P, R, X, B1, B2, G1, G2, Fp2, Fp12, add, mul, neg, miller, final_exp, on_curve, sswu, sqrt, is_square
+
+G1_DISCOUNT = [1000, 949, 848, 797, 764, 750, 738, 728, 719, 712, 705, 698, 692, 687, 682, 677, 673, 669, 665, 661, 658, 654, 651, 648, 645, 642, 640, 637, 635, 632, 630, 627, 625, 623, 621, 619, 617, 615, 613, 611, 609, 608, 606, 604, 603, 601, 599, 598, 596, 595, 593, 592, 591, 589, 588, 586, 585, 584, 582, 581, 580, 579, 577, 576, 575, 574, 573, 572, 570, 569, 568, 567, 566, 565, 564, 563, 562, 561, 560, 559, 558, 557, 556, 555, 554, 553, 552, 551, 550, 549, 548, 547, 547, 546, 545, 544, 543, 542, 541, 540, 540, 539, 538, 537, 536, 536, 535, 534, 533, 532, 532, 531, 530, 529, 528, 528, 527, 526, 525, 525, 524, 523, 522, 522, 521, 520, 520, 519]
+G2_DISCOUNT = [1000, 1000, 923, 884, 855, 832, 812, 796, 782, 770, 759, 749, 740, 732, 724, 717, 711, 704, 699, 693, 688, 683, 679, 674, 670, 666, 663, 659, 655, 652, 649, 646, 643, 640, 637, 634, 632, 629, 627, 624, 622, 620, 618, 615, 613, 611, 609, 607, 606, 604, 602, 600, 598, 597, 595, 593, 592, 590, 589, 587, 586, 584, 583, 582, 580, 579, 578, 576, 575, 574, 573, 571, 570, 569, 568, 567, 566, 565, 563, 562, 561, 560, 559, 558, 557, 556, 555, 554, 553, 552, 552, 551, 550, 549, 548, 547, 546, 545, 545, 544, 543, 542, 541, 541, 540, 539, 538, 537, 537, 536, 535, 535, 534, 533, 532, 532, 531, 530, 530, 529, 528, 528, 527, 526, 526, 525, 524, 524]
+assert len(G1_DISCOUNT) == len(G2_DISCOUNT) == 128
+
+
+def msm_gas(k, base, discount):
+    return 0 if k == 0 else k * base * discount[min(k, 128) - 1] // 1000
+
+
+def enc_fp(v):
+    return v.to_bytes(64, "big")
+
+
+def enc1(p):
+    return bytes(128) if p is None else enc_fp(p[0]) + enc_fp(p[1])
+
+
+def enc2(p):
+    if p is None:
+        return bytes(256)
+    x, y = p
+    return enc_fp(x.a) + enc_fp(x.b) + enc_fp(y.a) + enc_fp(y.b)
+
+
+def scalar(k):
+    return k.to_bytes(32, "big")
+
+
+# Points on the curves outside the subgroups, with the smallest x that works.
+def off_subgroup(b, lift):
+    x = 1
+    while True:
+        gx = lift(x) * lift(x) * lift(x) + b
+        if is_square(gx):
+            p = (lift(x), sqrt(gx))
+            assert on_curve(p, b) and mul(R, p) is not None
+            return p
+        x += 1
+
+
+def is_one(pairs):
+    f = None
+    for p, q in pairs:
+        if p is not None and q is not None:
+            m = miller(p, q)
+            f = m if f is None else f * m
+    return f is None or final_exp(f) == Fp12.one()
+
+
+if __name__ == "__main__":
+    g1maps, g2maps = derive.isogenies()
+
+    def map_g1(u):
+        q = derive.apply([[derive.Fq(c) for c in f] for f in g1maps], sswu(u, derive.G1_A, derive.G1_B, 11), derive.Fq)
+        return mul(1 - X, (q[0].a, q[1].a))
+
+    def map_g2(u):
+        q = derive.apply(g2maps, sswu(u, derive.G2_A, derive.G2_B, derive.G2_Z), lambda v: v)
+        return mul(derive.G2_H_EFF, q)
+
+    bad1 = off_subgroup(B1, lambda v: v)
+    bad2 = off_subgroup(B2, lambda v: Fp2(v, 1))
+    p2, p3 = mul(2, G1), mul(3, G1)
+    q2, q3 = mul(2, G2), mul(3, G2)
+    padded = b"\x01" + enc1(G1)[1:]
+    big_x = (P + G1[0]).to_bytes(64, "big") + enc_fp(G1[1])
+    off_curve1 = enc_fp(G1[0]) + enc_fp(G1[0])
+    off_curve2 = enc2(G2)[:128] + enc2(G2)[:128]
+
+    print("// G1ADD")
+    for data, out in [
+        (enc1(G1) + enc1(p2), enc1(p3)),
+        (enc1(p2) + enc1(p2), enc1(mul(4, G1))),
+        (enc1(G1) + enc1(neg(G1)), enc1(None)),
+        (enc1(None) + enc1(p3), enc1(p3)),
+        (enc1(None) + enc1(None), enc1(None)),
+        (enc1(bad1) + enc1(G1), enc1(add(bad1, G1))),
+        (b"", b""),
+        (enc1(G1) + enc1(p2)[:-1], b""),
+        (enc1(G1) + padded, b""),
+        (enc1(G1) + big_x, b""),
+        (enc1(G1) + off_curve1, b""),
+    ]:
+        print('("%s", "%s"),' % (data.hex(), out.hex()))
+
+    print("// G2ADD")
+    for data, out in [
+        (enc2(G2) + enc2(q2), enc2(q3)),
+        (enc2(q2) + enc2(q2), enc2(mul(4, G2))),
+        (enc2(G2) + enc2(neg(G2)), enc2(None)),
+        (enc2(None) + enc2(None), enc2(None)),
+        (enc2(bad2) + enc2(G2), enc2(add(bad2, G2))),
+        (b"", b""),
+        (enc2(G2) + b"\x01" + enc2(q2)[1:], b""),
+        (enc2(G2) + off_curve2, b""),
+    ]:
+        print('("%s", "%s"),' % (data.hex(), out.hex()))
+
+    print("// G1MSM")
+    for pairs in [
+        [(G1, 5)],
+        [(G1, 0)],
+        [(G1, R)],
+        [(G1, 2**256 - 1)],
+        [(None, 7)],
+        [(G1, 3), (p2, R - 1), (None, 9)],
+        [(p3, 2**255 + 1), (mul(5, G1), 12345)],
+    ]:
+        data = b"".join(enc1(p) + scalar(k) for p, k in pairs)
+        out = None
+        for p, k in pairs:
+            out = add(out, mul(k, p) if p is not None else None)
+        print('("%s", "%s", %d),' % (data.hex(), enc1(out).hex(), msm_gas(len(pairs), 12000, G1_DISCOUNT)))
+    for data in [b"", enc1(G1) + scalar(1)[:-1], enc1(bad1) + scalar(1), off_curve1 + scalar(1)]:
+        print('("%s", "", %d),' % (data.hex(), msm_gas(len(data) // 160, 12000, G1_DISCOUNT)))
+
+    print("// G2MSM")
+    for pairs in [
+        [(G2, 5)],
+        [(G2, R)],
+        [(G2, 2**256 - 1), (q2, 1), (None, 1)],
+    ]:
+        data = b"".join(enc2(p) + scalar(k) for p, k in pairs)
+        out = None
+        for p, k in pairs:
+            out = add(out, mul(k, p) if p is not None else None)
+        print('("%s", "%s", %d),' % (data.hex(), enc2(out).hex(), msm_gas(len(pairs), 22500, G2_DISCOUNT)))
+    for data in [b"", enc2(bad2) + scalar(1), off_curve2 + scalar(1)]:
+        print('("%s", "", %d),' % (data.hex(), msm_gas(len(data) // 288, 22500, G2_DISCOUNT)))
+
+    print("// PAIRING")
+    for pairs in [
+        [(G1, G2), (neg(G1), G2)],
+        [(G1, G2)],
+        [(None, G2)],
+        [(p2, q3), (neg(mul(6, G1)), G2)],
+        [(p2, q3), (neg(mul(5, G1)), G2)],
+        [(G1, None), (p3, q2), (neg(G1), mul(6, G2))],
+    ]:
+        data = b"".join(enc1(p) + enc2(q) for p, q in pairs)
+        out = (1 if is_one(pairs) else 0).to_bytes(32, "big")
+        print('("%s", "%s", %d),' % (data.hex(), out.hex(), 32600 * len(pairs) + 37700))
+    for data in [b"", enc1(bad1) + enc2(G2), enc1(G1) + enc2(bad2), enc1(G1) + enc2(G2)[:-1]]:
+        print('("%s", "", %d),' % (data.hex(), 32600 * (len(data) // 384) + 37700))
+
+    print("// MAP_FP_TO_G1")
+    rfc = 0x156C8A6A2C184569D69A76BE144B5CDC5141D2D2CA4FE341F011E25E3969C55AD9E9B9CE2EB833C81A908E5FA4AC5F03
+    for u in [rfc, 0, 1, P - 1]:
+        print('("%s", "%s"),' % (enc_fp(u).hex(), enc1(map_g1(u)).hex()))
+    for data in [b"", enc_fp(P), b"\x01" + enc_fp(1)[1:], enc_fp(1)[1:]]:
+        print('("%s", ""),' % data.hex())
+
+    print("// MAP_FP2_TO_G2")
+    rfc = Fp2(
+        0x07355D25CAF6E7F2F0CB2812CA0E513BD026ED09DDA65B177500FA31714E09EA0DED3A078B526BED3307F804D4B93B04,
+        0x02829CE3C021339CCB5CAF3E187F6370E1E2A311DEC9B75363117063AB2015603FF52C3D3B98F19C2F65575E99E8B78C,
+    )
+    for u in [rfc, Fp2(0), Fp2(1, P - 1)]:
+        print('("%s", "%s"),' % ((enc_fp(u.a) + enc_fp(u.b)).hex(), enc2(map_g2(u)).hex()))
+    for data in [b"", enc_fp(0) + enc_fp(P), enc_fp(1)]:
+        print('("%s", ""),' % data.hex())
@@ -0,0 +1,124 @@
+# Minimal reference arithmetic for alt_bn128 used to synthesize test fixtures.
+
+P = 0x30644E72E131A029B85045B68181585D97816A916871CA8D3C208C16D87CFD47
+R = 0x30644E72E131A029B85045B68181585D2833E84879B9709143E1F593F0000001
+
+
+def fq2_add(a, b):
+    return ((a[0] + b[0]) % P, (a[1] + b[1]) % P)
+
+
+def fq2_sub(a, b):
+    return ((a[0] - b[0]) % P, (a[1] - b[1]) % P)
+
+
+def fq2_mul(a, b):
+    return ((a[0] * b[0] - a[1] * b[1]) % P, (a[0] * b[1] + a[1] * b[0]) % P)
+
+
+def fq2_inv(a):
+    t = pow(a[0] * a[0] + a[1] * a[1], P - 2, P)
+    return (a[0] * t % P, -a[1] * t % P)
+
+
+class Fq1:
+    zero = 0
+    one = 1
+    add = staticmethod(lambda a, b: (a + b) % P)
+    sub = staticmethod(lambda a, b: (a - b) % P)
+    mul = staticmethod(lambda a, b: a * b % P)
+    inv = staticmethod(lambda a: pow(a, P - 2, P))
+
+
+class Fq2:
+    zero = (0, 0)
+    one = (1, 0)
+    add = staticmethod(fq2_add)
+    sub = staticmethod(fq2_sub)
+    mul = staticmethod(fq2_mul)
+    inv = staticmethod(fq2_inv)
+
+
+def ec_add(F, p, q):
+    if p is None:
+        return q
+    if q is None:
+        return p
+    if p[0] == q[0]:
+        if F.add(p[1], q[1]) == F.zero:
+            return None
+        l = F.mul(F.mul(F.mul(p[0], p[0]), (3, 0) if F is Fq2 else 3), F.inv(F.add(p[1], p[1])))
+    else:
+        l = F.mul(F.sub(q[1], p[1]), F.inv(F.sub(q[0], p[0])))
+    x = F.sub(F.sub(F.mul(l, l), p[0]), q[0])
+    y = F.sub(F.mul(l, F.sub(p[0], x)), p[1])
+    return (x, y)
+
+
+def ec_neg(F, p):
+    return None if p is None else (p[0], F.sub(F.zero, p[1]))
+
+
+def ec_mul(F, p, k):
+    r = None
+    while k:
+        if k & 1:
+            r = ec_add(F, r, p)
+        p = ec_add(F, p, p)
+        k >>= 1
+    return r
+
+
+G1 = (1, 2)
+G2 = (
+    (
+        0x1800DEEF121F1E76426A00665E5C4479674322D4F75EDADD46DEBD5CD992F6ED,
+        0x198E9393920D483A7260BFB731FB5D25F1AA493335A9E71297E485B7AEF312C2,
+    ),
+    (
+        0x12C85EA5DB8C6DEB4AAB71808DCB408FE3D1E7690C43D37B4CE6CC0166FA7DAA,
+        0x090689D0585FF075EC9E99AD690C3395BC4B313370B38EF355ACDADCD122975B,
+    ),
+)
+
+
+def g1_mul(k, p=G1):
+    return ec_mul(Fq1, p, k % R)
+
+
+def g2_mul(k, p=G2):
+    return ec_mul(Fq2, p, k % R)
+
+
+def g1_add(p, q):
+    return ec_add(Fq1, p, q)
+
+
+def g2_add(p, q):
+    return ec_add(Fq2, p, q)
+
+
+def be32(x):
+    return x.to_bytes(32, "big")
+
+
+def fq_sqrt(a):
+    r = pow(a, (P + 1) // 4, P)
+    return r if r * r % P == a % P else None
+
+
+def fq2_sqrt(a):
+    n = fq_sqrt((a[0] * a[0] + a[1] * a[1]) % P)
+    if n is None:
+        return None
+    half = pow(2, P - 2, P)
+    for s in (n, P - n):
+        x0 = fq_sqrt((a[0] + s) * half % P)
+        if x0 is not None and x0 != 0:
+            x1 = a[1] * pow(2 * x0, P - 2, P) % P
+            if fq2_mul((x0, x1), (x0, x1)) == (a[0] % P, a[1] % P):
+                return (x0, x1)
+    return None
+
+
+B2 = fq2_mul((3, 0), fq2_inv((9, 1)))
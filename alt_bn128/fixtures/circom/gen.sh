@@ -0,0 +1,10 @@
+#!/bin/sh
+# The circuit of ark-circom's test vectors, compiled by circom and set up by snarkjs from
+# the power 17 file of the Perpetual Powers of Tau, with no phase 2 contribution. The
+# committed files are the mycircuit.r1cs and test.zkey that ark-circom 0.6.0 ships.
+set -e
+cd "$(dirname "$0")"
+circom mycircuit.circom --r1cs
+f=powersOfTau28_hez_final_17.ptau
+[ -f $f ] || curl -O https://storage.googleapis.com/zkevm/ptau/$f
+snarkjs zkey new mycircuit.r1cs $f mycircuit.zkey
@@ -0,0 +1,16 @@
+#!/bin/sh
+# A BN254 Groth16 verifying key and proof written by gnark, from SP1 v3.2.0: vk.bin is the key
+# the sp1-verifier crate embeds, in the compressed WriteTo encoding, and proof_raw.bin the
+# WriteRawTo encoding of the proof in its fibonacci test data. The proof has no commitment and
+# its two public inputs, the program's key hash and the digest of its public values, are
+#
+#   406432647295647673015241033990011138953233847343093038612820599671924330193
+#   6265467995298269824615662043982564374977105610814771179903635896347370323221
+set -e
+cd "$(dirname "$0")"
+curl -L https://static.crates.io/crates/sp1-verifier/sp1-verifier-3.2.0.crate | tar xz
+cp sp1-verifier-3.2.0/bn254-vk/groth16_vk.bin vk.bin
+# The test proof is bincode, with the raw proof as 648 hex digits from byte 699.
+dd if=sp1-verifier-3.2.0/test_binaries/fibonacci-groth16.bin bs=1 skip=699 count=648 |
+    xxd -r -p >proof_raw.bin
+rm -r sp1-verifier-3.2.0
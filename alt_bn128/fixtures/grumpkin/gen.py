@@ -0,0 +1,124 @@
+# Prints the Grumpkin generators checked in grumpkin's test, following Barretenberg's
+# affine_element::hash_to_curve and derive_generators, with BLAKE3 for blake3s.
+
+R = 0x30644E72E131A029B85045B68181585D2833E84879B9709143E1F593F0000001
+B = R - 17
+
+IV = [0x6A09E667, 0xBB67AE85, 0x3C6EF372, 0xA54FF53A, 0x510E527F, 0x9B05688C, 0x1F83D9AB, 0x5BE0CD19]
+PERM = [2, 6, 3, 10, 7, 0, 4, 13, 1, 11, 12, 5, 9, 14, 15, 8]
+M32 = 2**32 - 1
+CHUNK_START, CHUNK_END, PARENT, ROOT = 1, 2, 4, 8
+
+
+def rotr(x, n):
+    return ((x >> n) | (x << (32 - n))) & M32
+
+
+def compress(cv, block, counter, length, flags):
+    m = [int.from_bytes(block[4 * i : 4 * i + 4], "little") for i in range(16)]
+    v = cv + IV[:4] + [counter & M32, counter >> 32, length, flags]
+
+    def g(a, b, c, d, x, y):
+        v[a] = (v[a] + v[b] + x) & M32
+        v[d] = rotr(v[d] ^ v[a], 16)
+        v[c] = (v[c] + v[d]) & M32
+        v[b] = rotr(v[b] ^ v[c], 12)
+        v[a] = (v[a] + v[b] + y) & M32
+        v[d] = rotr(v[d] ^ v[a], 8)
+        v[c] = (v[c] + v[d]) & M32
+        v[b] = rotr(v[b] ^ v[c], 7)
+
+    for _ in range(7):
+        g(0, 4, 8, 12, m[0], m[1])
+        g(1, 5, 9, 13, m[2], m[3])
+        g(2, 6, 10, 14, m[4], m[5])
+        g(3, 7, 11, 15, m[6], m[7])
+        g(0, 5, 10, 15, m[8], m[9])
+        g(1, 6, 11, 12, m[10], m[11])
+        g(2, 7, 8, 13, m[12], m[13])
+        g(3, 4, 9, 14, m[14], m[15])
+        m = [m[i] for i in PERM]
+    return [v[i] ^ v[i + 8] for i in range(8)]
+
+
+# (cv, block, counter, length, flags) of the last compression of a node.
+def chunk_output(chunk, counter):
+    cv = IV[:]
+    blocks = [chunk[i : i + 64] for i in range(0, len(chunk), 64)] or [b""]
+    for i, b in enumerate(blocks[:-1]):
+        cv = compress(cv, b, counter, 64, CHUNK_START if i == 0 else 0)
+    last = blocks[-1]
+    flags = (CHUNK_START if len(blocks) == 1 else 0) | CHUNK_END
+    return cv, last.ljust(64, b"\0"), counter, len(last), flags
+
+
+def parent_output(left, right):
+    block = b"".join(w.to_bytes(4, "little") for w in left + right)
+    return IV[:], block, 0, 64, PARENT
+
+
+def chaining_value(out, root=False):
+    cv, block, counter, length, flags = out
+    if root:
+        return compress(cv, block, 0, length, flags | ROOT)
+    return compress(cv, block, counter, length, flags)
+
+
+def blake3(data):
+    chunks = [data[i : i + 1024] for i in range(0, len(data), 1024)] or [b""]
+    stack = []
+    for i, c in enumerate(chunks[:-1]):
+        cv = chaining_value(chunk_output(c, i))
+        total = i + 1
+        while total & 1 == 0:
+            cv = chaining_value(parent_output(stack.pop(), cv))
+            total >>= 1
+        stack.append(cv)
+    out = chunk_output(chunks[-1], len(chunks) - 1)
+    while stack:
+        out = parent_output(stack.pop(), chaining_value(out))
+    return b"".join(w.to_bytes(4, "little") for w in chaining_value(out, True))
+
+
+def sqrt(a):
+    if pow(a, (R - 1) // 2, R) != 1:
+        return None
+    s, q = 0, R - 1
+    while q % 2 == 0:
+        q //= 2
+        s += 1
+    # 5 is not a square modulo r.
+    m, c, t, r = s, pow(5, q, R), pow(a, q, R), pow(a, (q + 1) // 2, R)
+    while t != 1:
+        i, tt = 0, t
+        while tt != 1:
+            tt = tt * tt % R
+            i += 1
+        b = pow(c, 1 << (m - i - 1), R)
+        m, c, t, r = i, b * b % R, t * b * b % R, r * b % R
+    return r
+
+
+def hash_to_curve(seed):
+    attempt = 0
+    while True:
+        hi = blake3(seed + bytes([attempt, 0]))
+        lo = blake3(seed + bytes([attempt, 1]))
+        x = int.from_bytes(hi + lo, "big") % R
+        y = sqrt((x**3 + B) % R)
+        if y is not None:
+            if (y & 1) != (hi[0] > 127):
+                y = R - y
+            return x, y
+        attempt += 1
+
+
+def derive_generators(domain, n, start=0):
+    h = blake3(domain)
+    return [hash_to_curve(h + i.to_bytes(4, "big")) for i in range(start, start + n)]
+
+
+if __name__ == "__main__":
+    assert blake3(b"").hex() == "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262"
+    for x, y in derive_generators(b"DEFAULT_DOMAIN_SEPARATOR", 2):
+        print("%064x %064x" % (x, y))
@@ -0,0 +1,86 @@
+# Prints the MODEXP cases of ethereum::ut as (input, output, EIP-2565 gas): the two examples
+# of EIP-198 and numbers in the shapes of go-ethereum's nagydani cases, drawn from keccak.
+import os
+import sys
+
+sys.path.insert(0, os.path.join(os.path.dirname(__file__), ".."))
+from keccak import keccak256
+
+
+def rand(seed, n):
+    out = b""
+    while len(out) < n:
+        out += keccak256(seed + len(out).to_bytes(4, "big"))
+    return out[:n]
+
+
+def word(x):
+    return x.to_bytes(32, "big")
+
+
+def read(data, offset, n):
+    return data[offset : offset + n].ljust(n, b"\0")
+
+
+def modexp(data):
+    b, e, m = (int.from_bytes(read(data, i, 32), "big") for i in (0, 32, 64))
+    base = int.from_bytes(read(data, 96, b), "big")
+    exp = int.from_bytes(read(data, 96 + b, e), "big")
+    mod = read(data, 96 + b + e, m)
+    if m == 0:
+        return b""
+    v = int.from_bytes(mod, "big")
+    return (pow(base, exp, v) if v else 0).to_bytes(m, "big")
+
+
+def gas(data):
+    b, e, m = (int.from_bytes(read(data, i, 32), "big") for i in (0, 32, 64))
+    words = (max(b, m) + 7) // 8
+    head = int.from_bytes(read(data, 96 + b, min(e, 32)), "big")
+    it = max(head.bit_length() - 1, 0)
+    if e > 32:
+        it += 8 * (e - 32)
+    return max(200, words * words * max(it, 1) // 3)
+
+
+def case(base, exp, mod):
+    return word(len(base)) + word(len(exp)) + word(len(mod)) + base + exp + mod
+
+
+def cases():
+    p = bytes.fromhex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f")
+    pm2 = bytes.fromhex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2e")
+    yield case(b"\x03", pm2, p)
+    yield word(0) + word(32) + word(32) + pm2 + p
+    shapes = [
+        (64, [b"\x02", b"\x03", b"\x01\x00\x01"]),
+        (128, [b"\x01\x00\x01"]),
+        (256, [b"\x02"]),
+        (512, [b"\x03"]),
+        (1024, [b"\x01\x00\x01"]),
+    ]
+    for n, exps in shapes:
+        for exp in exps:
+            seed = b"nagydani" + n.to_bytes(2, "big") + exp
+            yield case(rand(seed + b"b", n), exp, rand(seed + b"m", n))
+    # Odd and even moduli, a power of two, leading zeros and an exponent above 32 bytes.
+    yield case(rand(b"odd", 32), rand(b"odd-e", 40), rand(b"odd-m", 31) + b"\x01")
+    yield case(rand(b"even", 48), rand(b"even-e", 16), rand(b"even-m", 47) + b"\x40")
+    yield case(rand(b"even2", 72), rand(b"even2-e", 8), rand(b"even2-m", 64) + b"\x00" * 8)
+    yield case(rand(b"pow2", 40), rand(b"pow2-e", 20), b"\x10" + b"\x00" * 39)
+    yield case(rand(b"lead", 32), b"\x01\x00\x01", b"\x00" * 20 + rand(b"lead-m", 12))
+    # 0^0, modulus 1, modulus 0, a zero exponent and an empty modulus.
+    yield case(b"", b"", b"\x00\x07")
+    yield case(b"\x05", b"\x09", b"\x00\x01")
+    yield case(b"\x05", b"\x09", b"\x00\x00")
+    yield case(rand(b"zero", 32), b"\x00" * 32, rand(b"zero-m", 32))
+    yield case(b"\x05", b"\x09", b"")
+    # Truncated: the modulus and part of the base are zero-padded.
+    yield case(rand(b"cut", 64), b"\x03", rand(b"cut-m", 64))[: 96 + 64 + 1 + 30]
+    yield word(8) + word(1) + word(8) + b"\x02\x03"
+
+
+if __name__ == "__main__":
+    print("# eip_example1:", modexp(next(cases())).hex(), gas(next(cases())))
+    for data in cases():
+        print('("%s", "%s", %d),' % (data.hex(), modexp(data).hex(), gas(data)))
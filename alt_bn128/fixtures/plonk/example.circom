@@ -0,0 +1,17 @@
+pragma circom 2.0.0;
+
+// out <== p * q + x with x and out public and p constrained to 3, the circuit gen.py
+// synthesizes a proof for.
+template Example() {
+    signal input p;
+    signal input q;
+    signal input x;
+    signal output out;
+    signal m;
+
+    p === 3;
+    m <== p * q;
+    out <== m + x;
+}
+
+component main {public [x]} = Example();
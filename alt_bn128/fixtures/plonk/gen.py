@@ -0,0 +1,245 @@
+# Synthesizes a snarkjs PLONK verification key, proof and public signals for the circuit
+# of example.circom and input.json,
+#
+#   out <== p * q + x    (x and out public, p fixed to 3 by a constant gate)
+#
+# Commitments are computed from the trapdoor as p(tau) * G1, otherwise the proof follows
+# the snarkjs prover (without blinding) and its Keccak transcript, so the quotient and
+# linearization identities genuinely hold. gen.sh writes the same files with circom and
+# snarkjs themselves; the tests take the output of either.
+import json
+import os
+import random
+import sys
+
+sys.path.insert(0, os.path.join(os.path.dirname(__file__), ".."))
+from bn254 import *  # noqa
+from keccak import keccak256
+
+POWER = 3
+N = 2**POWER
+K1, K2 = 2, 3
+W = pow(pow(5, (R - 1) >> 28, R), 2 ** (28 - POWER), R)
+
+# Rows: (a, b, c, qm, ql, qr, qo, qc) with wires named by the signal they carry.
+p, q, x = 3, 11, 5
+m = p * q
+out = m + x
+ROWS = [
+    ("out", None, None, 0, 1, 0, 0, 0),
+    ("x", None, None, 0, 1, 0, 0, 0),
+    ("p", "q", "m", 1, 0, 0, R - 1, 0),
+    ("m", "x", "out", 0, 1, 1, R - 1, 0),
+    ("p", None, None, 0, 1, 0, 0, R - 3),
+]
+VALUES = {"out": out, "x": x, "p": p, "q": q, "m": m, None: 0}
+PUBLIC = [out, x]
+ROWS += [(None, None, None, 0, 0, 0, 0, 0)] * (N - len(ROWS))
+
+
+def inv(a):
+    return pow(a, R - 2, R)
+
+
+def padd(a, b):
+    n = max(len(a), len(b))
+    return [((a[i] if i < len(a) else 0) + (b[i] if i < len(b) else 0)) % R for i in range(n)]
+
+
+def pscale(a, k):
+    return [c * k % R for c in a]
+
+
+def pmul(a, b):
+    r = [0] * (len(a) + len(b) - 1)
+    for i, x in enumerate(a):
+        for j, y in enumerate(b):
+            r[i + j] = (r[i + j] + x * y) % R
+    return r
+
+
+def peval(a, z):
+    r = 0
+    for c in reversed(a):
+        r = (r * z + c) % R
+    return r
+
+
+def pdiv(a, b):
+    """Exact division, asserts there is no remainder."""
+    a = list(a)
+    out = [0] * max(len(a) - len(b) + 1, 1)
+    lead = inv(b[-1])
+    for i in range(len(a) - len(b), -1, -1):
+        f = a[i + len(b) - 1] * lead % R
+        out[i] = f
+        for j, c in enumerate(b):
+            a[i + j] = (a[i + j] - f * c) % R
+    assert all(c == 0 for c in a), "division has a remainder"
+    return out
+
+
+def interpolate(evals):
+    """Coefficients of the polynomial taking evals[i] at W^i."""
+    n = len(evals)
+    n_inv = inv(n)
+    return [sum(e * pow(W, (R - 1 - i * k) % (R - 1), R) for i, e in enumerate(evals)) * n_inv % R for k in range(n)]
+
+
+def transcript(items):
+    buf = b""
+    for it in items:
+        if isinstance(it, tuple) or it is None:
+            buf += (bytes([0x40]) + bytes(63)) if it is None else be32(it[0]) + be32(it[1])
+        else:
+            buf += be32(it)
+    return int.from_bytes(keccak256(buf), "big") % R
+
+
+def g1_json(p):
+    return [str(p[0]), str(p[1]), "1"]
+
+
+def g2_json(p):
+    return [[str(p[0][0]), str(p[0][1])], [str(p[1][0]), str(p[1][1])], ["1", "0"]]
+
+
+rng = random.Random(0x706C6F6E6B)
+tau = rng.randrange(1, R)
+
+
+def commit(poly):
+    return g1_mul(peval(poly, tau))
+
+
+# Selectors and permutation.
+cols = [[VALUES[r[c]] for r in ROWS] for c in range(3)]
+qm, ql, qr, qo, qc = (interpolate([r[3 + i] for r in ROWS]) for i in range(5))
+ident = [[k * pow(W, i, R) % R for i in range(N)] for k in (1, K1, K2)]
+positions = {}
+for i, r in enumerate(ROWS):
+    for c in range(3):
+        if r[c] is not None:
+            positions.setdefault(r[c], []).append((c, i))
+sigma = [list(col) for col in ident]
+for cycle in positions.values():
+    for j, (c, i) in enumerate(cycle):
+        c2, i2 = cycle[(j + 1) % len(cycle)]
+        sigma[c][i] = ident[c2][i2]
+s1, s2, s3 = (interpolate(s) for s in sigma)
+
+vk = {
+    "protocol": "plonk",
+    "curve": "bn128",
+    "nPublic": len(PUBLIC),
+    "power": POWER,
+    "k1": str(K1),
+    "k2": str(K2),
+    "Qm": g1_json(commit(qm)),
+    "Ql": g1_json(commit(ql)),
+    "Qr": g1_json(commit(qr)),
+    "Qo": g1_json(commit(qo)),
+    "Qc": g1_json(commit(qc)),
+    "S1": g1_json(commit(s1)),
+    "S2": g1_json(commit(s2)),
+    "S3": g1_json(commit(s3)),
+    "X_2": g2_json(g2_mul(tau)),
+    "w": str(W),
+}
+vk_points = [commit(qm), commit(ql), commit(qr), commit(qo), commit(qc), commit(s1), commit(s2), commit(s3)]
+
+# Round 1: wire polynomials.
+a, b, c = (interpolate(col) for col in cols)
+A, B, C = commit(a), commit(b), commit(c)
+beta = transcript(vk_points + PUBLIC + [A, B, C])
+gamma = transcript([beta])
+
+# Round 2: permutation accumulator.
+z_evals = [1]
+for i in range(N - 1):
+    num, den = 1, 1
+    for col in range(3):
+        num = num * (cols[col][i] + beta * ident[col][i] + gamma) % R
+        den = den * (cols[col][i] + beta * sigma[col][i] + gamma) % R
+    z_evals.append(z_evals[-1] * num * inv(den) % R)
+z = interpolate(z_evals)
+Z = commit(z)
+alpha = transcript([beta, gamma, Z])
+
+# Round 3: quotient.
+zh = [R - 1] + [0] * (N - 1) + [1]
+l1 = interpolate([1] + [0] * (N - 1))
+pi = interpolate([(R - PUBLIC[i]) % R if i < len(PUBLIC) else 0 for i in range(N)])
+zw = [c * pow(W, i, R) % R for i, c in enumerate(z)]
+X = [0, 1]
+gate = padd(padd(padd(pmul(pmul(a, b), qm), pmul(a, ql)), padd(pmul(b, qr), pmul(c, qo))), padd(qc, pi))
+perm1 = pmul(pmul(pmul(padd(a, [gamma, beta]), padd(b, [gamma, beta * K1])), padd(c, [gamma, beta * K2])), z)
+perm2 = pmul(
+    pmul(pmul(padd(a, padd([gamma], pscale(s1, beta))), padd(b, padd([gamma], pscale(s2, beta)))), padd(c, padd([gamma], pscale(s3, beta)))),
+    zw,
+)
+perm = padd(perm1, pscale(perm2, R - 1))
+init = pmul(padd(z, [R - 1]), l1)
+t = pdiv(padd(padd(gate, pscale(perm, alpha)), pscale(init, alpha * alpha % R)), zh)
+t += [0] * (3 * N - len(t))
+t1, t2, t3 = t[:N], t[N : 2 * N], t[2 * N :]
+T1, T2, T3 = commit(t1), commit(t2), commit(t3)
+xi = transcript([alpha, T1, T2, T3])
+
+# Round 4: evaluations.
+ea, eb, ec = peval(a, xi), peval(b, xi), peval(c, xi)
+es1, es2 = peval(s1, xi), peval(s2, xi)
+ezw = peval(z, xi * W % R)
+v1 = transcript([xi, ea, eb, ec, es1, es2, ezw])
+v = [None, v1] + [pow(v1, i, R) for i in range(2, 6)]
+
+# Round 5: openings, with the linearization of the snarkjs verifier.
+xin = pow(xi, N, R)
+zh_xi = (xin - 1) % R
+l1_xi = peval(l1, xi)
+pi_xi = peval(pi, xi)
+e3 = (ea + beta * es1 + gamma) * (eb + beta * es2 + gamma) % R * (ec + gamma) % R * ezw % R * alpha % R
+r0 = (pi_xi - l1_xi * alpha * alpha - e3) % R
+betaxi = beta * xi % R
+d2 = ((ea + betaxi + gamma) * (eb + betaxi * K1 + gamma) % R * (ec + betaxi * K2 + gamma) % R * alpha + l1_xi * alpha * alpha) % R
+d3 = (ea + beta * es1 + gamma) * (eb + beta * es2 + gamma) % R * alpha % R * beta % R * ezw % R
+d = padd(padd(pscale(qm, ea * eb % R), pscale(ql, ea)), padd(pscale(qr, eb), pscale(qo, ec)))
+d = padd(padd(d, qc), pscale(z, d2))
+d = padd(d, pscale(s3, R - d3))
+d = padd(d, pscale(padd(padd(t1, pscale(t2, xin)), pscale(t3, xin * xin % R)), R - zh_xi))
+assert (peval(d, xi) + r0) % R == 0, "linearization does not vanish"
+wxi = padd(d, [r0])
+for vi, poly, e in ((v[1], a, ea), (v[2], b, eb), (v[3], c, ec), (v[4], s1, es1), (v[5], s2, es2)):
+    wxi = padd(wxi, pscale(padd(poly, [R - e]), vi))
+wxi = pdiv(wxi, [(R - xi) % R, 1])
+wxiw = pdiv(padd(z, [R - ezw]), [(R - xi * W) % R, 1])
+
+proof = {
+    "A": g1_json(A),
+    "B": g1_json(B),
+    "C": g1_json(C),
+    "Z": g1_json(Z),
+    "T1": g1_json(T1),
+    "T2": g1_json(T2),
+    "T3": g1_json(T3),
+    "Wxi": g1_json(commit(wxi)),
+    "Wxiw": g1_json(commit(wxiw)),
+    "eval_a": str(ea),
+    "eval_b": str(eb),
+    "eval_c": str(ec),
+    "eval_s1": str(es1),
+    "eval_s2": str(es2),
+    "eval_zw": str(ezw),
+    "protocol": "plonk",
+    "curve": "bn128",
+}
+
+
+def write(name, obj):
+    with open(os.path.join(os.path.dirname(__file__), name), "w") as f:
+        f.write(json.dumps(obj, indent=1))
+
+
+write("verification_key.json", vk)
+write("proof.json", proof)
+write("public.json", [str(s) for s in PUBLIC])
@@ -0,0 +1,24 @@
+#!/bin/sh
+# Writes verification_key.json, proof.json and public.json with circom and snarkjs, a
+# PLONK setup and proof of example.circom for input.json, in place of the files gen.py
+# synthesizes. The powers of tau are a fresh ceremony with one contribution.
+#
+#	npm install -g snarkjs@0.7.4
+#	git clone -b v2.1.9 https://github.com/iden3/circom && cargo install --path circom/circom
+#	./gen.sh
+#
+# plonk.rs and the plonk_verify example of alt_bn128_rv read the outputs as they are.
+set -e
+cd "$(dirname "$0")"
+tmp=$(mktemp -d)
+trap 'rm -rf "$tmp"' EXIT
+
+circom example.circom --r1cs --wasm -o "$tmp"
+snarkjs powersoftau new bn128 8 "$tmp/pot_0.ptau"
+snarkjs powersoftau contribute "$tmp/pot_0.ptau" "$tmp/pot_1.ptau" --name=fixture -e=fixture
+snarkjs powersoftau prepare phase2 "$tmp/pot_1.ptau" "$tmp/pot.ptau"
+snarkjs plonk setup "$tmp/example.r1cs" "$tmp/pot.ptau" "$tmp/example.zkey"
+snarkjs zkey export verificationkey "$tmp/example.zkey" verification_key.json
+snarkjs wtns calculate "$tmp/example_js/example.wasm" input.json "$tmp/witness.wtns"
+snarkjs plonk prove "$tmp/example.zkey" "$tmp/witness.wtns" proof.json public.json
+snarkjs plonk verify verification_key.json public.json proof.json
@@ -0,0 +1,9 @@
+#!/bin/sh
+# The power 8 file of the Perpetual Powers of Tau ceremony, 54 contributions and the Hermez
+# beacon, prepared for phase 2 by `snarkjs powersoftau prepare phase2`, as the snarkjs README
+# links it. The ppot-rs crate ships the same file as 8.ptau.
+set -e
+cd "$(dirname "$0")"
+f=powersOfTau28_hez_final_08.ptau
+curl -L -o $f https://storage.googleapis.com/zkevm/ptau/$f
+echo "d6a8fb3a04feb600096c3b791f936a578c4e664d262e4aa24beed1b7a9a96aa5eb72864d628db247e9293384b74b36ffb52ca8d148d6e1b8b51e279fdf57b583  $f" | b2sum -c
@@ -0,0 +1,90 @@
+# Reference for the secp256k1 and ecrecover tests: public-key recovery as in go-ethereum's
+# precompile 0x01, and signatures with fixed nonces to build more cases from.
+import os
+import sys
+
+sys.path.insert(0, os.path.join(os.path.dirname(__file__), ".."))
+from keccak import keccak256
+
+P = 2**256 - 2**32 - 977
+N = 0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141
+G = (
+    0x79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798,
+    0x483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8,
+)
+
+
+def add(a, b):
+    if a is None:
+        return b
+    if b is None:
+        return a
+    if a[0] == b[0]:
+        if (a[1] + b[1]) % P == 0:
+            return None
+        l = 3 * a[0] * a[0] * pow(2 * a[1], -1, P)
+    else:
+        l = (b[1] - a[1]) * pow(b[0] - a[0], -1, P)
+    x = (l * l - a[0] - b[0]) % P
+    return x, (l * (a[0] - x) - a[1]) % P
+
+
+def mul(k, p):
+    r = None
+    for bit in bin(k)[2:]:
+        r = add(r, r)
+        if bit == "1":
+            r = add(r, p)
+    return r
+
+
+def address(q):
+    return keccak256(q[0].to_bytes(32, "big") + q[1].to_bytes(32, "big"))[12:]
+
+
+def ecrecover(data):
+    data = data.ljust(128, b"\0")[:128]
+    h, v, r, s = (int.from_bytes(data[i : i + 32], "big") for i in range(0, 128, 32))
+    if v not in (27, 28) or not 0 < r < N or not 0 < s < N:
+        return b""
+    y = pow(r**3 + 7, (P + 1) // 4, P)
+    if y * y % P != (r**3 + 7) % P:
+        return b""
+    if y & 1 != v - 27:
+        y = P - y
+    q = mul(pow(r, -1, N), add(mul(s, (r, y)), mul(N - h % N, G)))
+    return b"" if q is None else address(q).rjust(32, b"\0")
+
+
+def sign(h, d, k):
+    r, y = mul(k, G)
+    s = pow(k, -1, N) * (h + r * d) % N
+    return r % N, s, 27 + (y & 1)
+
+
+if __name__ == "__main__":
+    valid = bytes.fromhex(
+        "18c547e4f7b0f325ad1e56f57e26c745b09a3e503d86e00e5255ff7f715d3d1c"
+        "000000000000000000000000000000000000000000000000000000000000001c"
+        "73b1693892219d736caba55bdb67216e485557ea6b6af75f37096c9aa6a5a75f"
+        "eeb940b1d03b21e36b0e47e79769f095fe2ab855bd91e3a38756b7d75a9c4549"
+    )
+    assert ecrecover(valid).hex() == "000000000000000000000000a94f5374fce5edbc8e2a8697c15331677e6ebf0b"
+    # The ecrecover example of ethereumjs-util.
+    other = bytes.fromhex(
+        "456e9aea5e197a1f1af7a3e85a3212fa4049a3ba34c2289b4c860fc0b0c64ef3"
+        "000000000000000000000000000000000000000000000000000000000000001c"
+        "9242685bf161793cc25603c231bc2f568eb630ea16aa137d2664ac8038825608"
+        "4f8ae3bd7535248d0bd448298cc2e2071e56992d0774dc340c368ae950852ada"
+    )
+    assert ecrecover(other).hex() == "0000000000000000000000007156526fbd7a3c72969b54f64e42c10fbb768c8a"
+    assert address(mul(1, G)).hex() == "7e5f4552091a69125d5dfcb7b8c2659029395bdf"
+
+    h = int.from_bytes(keccak256(b"hello"), "big")
+    for d, k in [(1, 2), (0x45A915E4D060149EB4365960E6A7A45F334393093061116B197E3240065FF2D8, 7)]:
+        r, s, v = sign(h, d, k)
+        for s, v in [(s, v), (N - s, 55 - v)]:
+            data = b"".join(x.to_bytes(32, "big") for x in (h, v, r, s))
+            out = ecrecover(data)
+            assert out[12:] == address(mul(d, G))
+            print(data.hex(), out.hex())
@@ -0,0 +1,8 @@
+#!/bin/sh
+# A Groth16 verification key, proof and public input written by snarkjs, from the test data
+# of the risc0-groth16 crate. verification_key.rs is what `codegen::snarkjs_groth16_vk` makes
+# of the key, which its snapshot test compares.
+set -e
+cd "$(dirname "$0")"
+curl -L https://static.crates.io/crates/risc0-groth16/risc0-groth16-3.0.5.crate |
+    tar xz --strip-components=3 risc0-groth16-3.0.5/tests/data
@@ -0,0 +1,350 @@
+// Generated by alt_bn128::codegen. Do not edit.
+
+pub const VK_ALPHA_G1: alt_bn128::AffineG1 = alt_bn128::AffineG1::new_unchecked(
+    alt_bn128::Fq::from_montgomery([0x926b539a5c7416fa, 0x7911df7ce2351041, 0xab2c4a80a51e4dea, 0x2402357a9dc53c13]),
+    alt_bn128::Fq::from_montgomery([0xb53b5004d3e3f6db, 0x5fa6651005292bf5, 0x850b917701de663e, 0x1d63404adf606443]),
+);
+
+pub const VK_BETA_G2: alt_bn128::G2Prepared = alt_bn128::G2Prepared::from_coeffs(
+alt_bn128::AffineG2::new_unchecked(
+    alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x8b9d1ebd18389201, 0xbd4f56b2ed4dea13, 0x5921083dba8cfabe, 0x14cfdb53b6662436]), alt_bn128::Fq::from_montgomery([0x2c41556838891b7f, 0x7d0e89501435479c, 0xe20878f47d6d79e6, 0x22ba0ccbb1ce2c25])),
+    alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xd26737e3a39bc598, 0x80d4cdbf059b4028, 0xf82a910064fe320f, 0x008129f641cd6265]), alt_bn128::Fq::from_montgomery([0x372bb58176e3aa0a, 0x6367b68e4d815fe8, 0xd2d7568e2887894f, 0x12806635b034df8f])),
+),
+[
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x626675fb0afb231a, 0x442cb4cac31bcfb6, 0x39763cc8f29fde88, 0x0364adb086dbde98]), alt_bn128::Fq::from_montgomery([0xccfa64dc58d2cdc0, 0xbf5e97b4e28bff46, 0x49a619c5e2b6b7d6, 0x148ea976b3463b09])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x97521c4f91457217, 0x95d7cf135d3b4a3b, 0xc7fb23b5b784f43e, 0x2f61fa865d96db5d]), alt_bn128::Fq::from_montgomery([0xcdc92113eab5a933, 0xd0b1fd74cd6f0abc, 0x12a1989a307245be, 0x0b63820780c7e10a])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x5a5ae13dcd5d6e59, 0xae2c3be81be300c6, 0x8a4cb19c7bd69d63, 0x09016c141eeeafc9]), alt_bn128::Fq::from_montgomery([0xb0339e52b88bb703, 0xe8a6069cd344bb2c, 0xa80a645fc5567fe2, 0x0f468e9903fc62e3]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xbab4a0d31a05dbf9, 0x3d050dab6cb77ccb, 0x2a8016b79342e082, 0x239d96d153e1140c]), alt_bn128::Fq::from_montgomery([0x4adbec37b72ca801, 0x8db69136fe723b96, 0x41ed6008527e5235, 0x1cf0a41add4fbdc1])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x65f8e7799c4b2aad, 0xcd69f73d4a7a0104, 0xd7438107a6582b8b, 0x00577d7f82c2a6f6]), alt_bn128::Fq::from_montgomery([0x8202777d8d025cd4, 0xc3f0cfc8c1a34484, 0x989534581e9ebb67, 0x0801bce6dbaae7d0])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x86e0531f76c632a6, 0xad9b8fe8f87f49c1, 0x2af852695377c1c4, 0x0c29a01209c4895b]), alt_bn128::Fq::from_montgomery([0x7778fafa053da319, 0x8d19652c29a7d694, 0xfd07546d4cb900bc, 0x2708e4e7195a081d]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x3ab34072bbd2bbde, 0xd1cefb6fbcfee59c, 0x96b6e6dd31f34366, 0x2a4a05eff1a6aef4]), alt_bn128::Fq::from_montgomery([0x6d751577f0f14c07, 0x0cb89819e51bcaa1, 0x5bbeaf6cc8b57cdb, 0x13b5ad3f8f9b5f00])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x1eca7630bf91da77, 0x1a0c3db4efd62fe5, 0xf2376e571674a4a8, 0x225970c46743e526]), alt_bn128::Fq::from_montgomery([0xee188365b4a12366, 0x55f89a3c53f6ac55, 0x2e86fa802ceff5ab, 0x2a93ac8f1c709a78])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x6a283771bc78f48a, 0x60fef991a48727f9, 0xb1d67e6fcc979459, 0x17b9617d860124e2]), alt_bn128::Fq::from_montgomery([0xa3e17635bc3b354f, 0xf9eb63061c6cb2b6, 0x2ae3bb5acafeb885, 0x03f2a3724ad1c164]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x4614ad5c2b88497b, 0xa9f87a470490861b, 0x7acdaa1406f4c066, 0x053b67207394c92b]), alt_bn128::Fq::from_montgomery([0x3b378833dc4ad6be, 0xceeb3fdda39171e0, 0x46c71149a7a7e78a, 0x1193c8fdd5da1c77])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x75c562dd181a0c92, 0x96211d6138858c66, 0xa3c9adb12e565e45, 0x0cff319e39d8c87a]), alt_bn128::Fq::from_montgomery([0x42edec0709add765, 0xd2a95c115ecf3f7f, 0xba5be857092fd987, 0x21527d4c3f795ec1])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x4a43e9c5a697bf2d, 0xc657ac769626df17, 0xea81ed0cf9623562, 0x0ea789a1531c3c50]), alt_bn128::Fq::from_montgomery([0xa84892d669452d96, 0xbe5ecddbfdcd478a, 0x6328465cab0d3c93, 0x26620382429223bd]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x855df098ede41f49, 0xc934be6a9ac8f5b2, 0x3a59a2efcd744d86, 0x134b51f72edad8d6]), alt_bn128::Fq::from_montgomery([0x8d77b3f2449eb36b, 0x43ff3a35fced7c38, 0x3ad90ec51c6ac64e, 0x019a8361098864e3])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xc2206b39a3776be2, 0x777f92c056ac3c39, 0x155331c59937d3a4, 0x10a2f166bee8959e]), alt_bn128::Fq::from_montgomery([0xf757c8f5c89119d1, 0x5a296aeff4064a2e, 0x6d92fa810c97fe51, 0x1075bf102fe64696])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x84cfe388cf38c2fd, 0x1f2bad295de192a4, 0xac3844dad291dbf0, 0x27a702bbf91a90fe]), alt_bn128::Fq::from_montgomery([0x919528c7f532c3e4, 0x729d2c04111e3024, 0x565792c772e64f2a, 0x22f8e7323394f901]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xcd32644a1941ad6f, 0xdccd5d752b332769, 0x59eb037eb8b0502a, 0x24142dc66ec3881f]), alt_bn128::Fq::from_montgomery([0xd44ca5b05d99eafd, 0x105e15b0feede77d, 0x2302b067c357f0f8, 0x17172b351492c518])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x4c62312139ed4ea9, 0x8cb7ca924a804cf4, 0x62fc4a425dc8c61f, 0x06e04b49af4cbd66]), alt_bn128::Fq::from_montgomery([0xbcad0e9a55955e45, 0xa9c3f7d77710f58e, 0xfc5fa976f5f96567, 0x19baa5953b497269])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x71494533af626836, 0xd2b1782f1599fc89, 0x3a71f3e2fed25397, 0x12565f6f94edd007]), alt_bn128::Fq::from_montgomery([0x303077a0af8749c2, 0x30e7b89e46269d96, 0xcf5b29fd0e1a065b, 0x1c1cbe63696f65ea]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x94c6430de50b861b, 0xe6a27335d820e583, 0xd6f5f79806cd28e6, 0x17820dbbc325ccc7]), alt_bn128::Fq::from_montgomery([0x816e6ec9092d4c26, 0x67763a04dcaf991d, 0x4d7fe4381c98fbe9, 0x2a186e48e6e334e4])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xc1d3f76ce66f9af2, 0xcf082382e947f4b8, 0x3c6283f21854efbd, 0x05a62cc2b4927b0b]), alt_bn128::Fq::from_montgomery([0x9ab7362748fa9825, 0x0584a0e51b9ba6d0, 0x353914d208226f7a, 0x0ac79c7d46eb2ffb])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xcbe8cb5aa788c0ff, 0x44d4bc8424287342, 0x496d1a7a6f7a4c7f, 0x120da812b5449606]), alt_bn128::Fq::from_montgomery([0xe5392b4f1e4f24a1, 0x677a7c11d663a732, 0x65cc0f32370cfcad, 0x0bf70d179af8d77b]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xda4e97536eb8a5a7, 0x5ae7f61b3e26e8a8, 0xf673cdb90e9ecfda, 0x259819b238ed2d0c]), alt_bn128::Fq::from_montgomery([0x078aed6f367db5ae, 0xe677423477cf6463, 0x3a2394384349b3f8, 0x139fc428311d9e3a])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x5d60626731050ca8, 0x079f9db68e96a5e6, 0x8c61b9cff63e5f9e, 0x09b7ab9c0d144728]), alt_bn128::Fq::from_montgomery([0xe8376b607e50da76, 0x9d423da3577312d8, 0xe1762a67bccc1cf0, 0x168e19f16c12f45d])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x59f42fcd0bd98d6e, 0x8c4f3fa16cb1baef, 0x95cb27c8f198fdeb, 0x04141defdbe63707]), alt_bn128::Fq::from_montgomery([0x0d50fee0a7617d8d, 0x8bd88863808e1ef9, 0xefad1b4324af32e3, 0x0185e8c7d9583aa3]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xada13c869f00117e, 0x5b6598b570fdc8cf, 0x836ec0fc6ab315e0, 0x085910ecbe7a54d4]), alt_bn128::Fq::from_montgomery([0xfcf5c461cf784989, 0x058af37eed2d2774, 0xb07cc747ed26d021, 0x0d74a3ffbe5db84d])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x9436de49cfa1a43f, 0x98264db70280f47b, 0x6bfac300ca91f6f4, 0x24964ecab6916f58]), alt_bn128::Fq::from_montgomery([0xf4d7013847cdbd4c, 0x81691c4b1e96b376, 0x88e525a4f9ff030b, 0x2fccf6758061138c])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xbd3b732d45a7dce3, 0x90785c1665896fd4, 0x68de36bf1313c079, 0x21af61741c11ff72]), alt_bn128::Fq::from_montgomery([0x2f4ecf49e905f5f8, 0x85af25b0abbd89f1, 0xeaf525ae3d3508e1, 0x137004b641ac62a8]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x9c11f0b1a5c7f51a, 0xa405ec315687639a, 0x813c34987887f741, 0x10c3641fb24ed9cf]), alt_bn128::Fq::from_montgomery([0x28cdab63a87392eb, 0x012c1af9e85e71a2, 0x32025c8c641ccadc, 0x2387611e88b7aa94])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x026baab1be4e6861, 0xf90ffbc16ac52d64, 0xc07d1d79420eed8d, 0x24dde72567327e94]), alt_bn128::Fq::from_montgomery([0xf5077b08f8d7273c, 0xce19fcd68488f54d, 0x9f715d40ff1bd911, 0x182df1090f46ffa7])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xde917500e32c8077, 0xc0bbecdc9f13f93d, 0x4c3ec7bd7909ced0, 0x0d12778edc24f150]), alt_bn128::Fq::from_montgomery([0x37aadb0a993706b8, 0x049abcbb1d43d883, 0x06c5b88cd201371b, 0x2e5e4da6421e09ce]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x7a149157899cdcd7, 0x55647da958be97f7, 0x3db311a536c23bf0, 0x3023fc9317c31fd0]), alt_bn128::Fq::from_montgomery([0xdb79a32f6b6cd469, 0x29023c0bf5ec30ed, 0x47312b0b364f8045, 0x0e104a8ab6ac68b8])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xdb01921b449aea58, 0x817fce3ab9574289, 0xb6d46a4c86879500, 0x21afe8c3271b6bcb]), alt_bn128::Fq::from_montgomery([0xd2d558309ccc98a3, 0x713b871de92553cc, 0x2ccf45f2b39134ac, 0x302f1cc37dc30130])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x6bb7eaa3afb94a12, 0xb9eccd751e78c94b, 0x1ad8c5998e211d8f, 0x0342740534779a7f]), alt_bn128::Fq::from_montgomery([0x7b033ded98243548, 0xaea8819f38b6d89d, 0xb33ab71151598e6f, 0x1b270b3941d2bf6b]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x2c0f1bd3f3d35c82, 0xe4e6e8163ec589a4, 0xa2cae2e824d848fc, 0x1466b3b7fe39edd2]), alt_bn128::Fq::from_montgomery([0x7a7e00f34319c8ce, 0x33608f2bc022f3dd, 0xc9226a6c779cc8b2, 0x11a78993a6bbe2e1])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x20c96093eb629b77, 0x8959722e3666b750, 0x20b912a458b955aa, 0x0d1fc4fb40b9ccf8]), alt_bn128::Fq::from_montgomery([0x54b0b63829b21a51, 0xa361ab07d059e03c, 0x011c0e2d2dc0d058, 0x0461028df6c55bc5])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xbb44d4434b649f9b, 0xa239f8f8c5a484a6, 0xdb8923cb9735a99b, 0x02b6fd349e812db4]), alt_bn128::Fq::from_montgomery([0xefeaf200bac8b434, 0x985d9ac85566e7a0, 0x39376615500dbcd3, 0x21aba3b047310f6a]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xf3d551f7d79c92a3, 0x97fa450a08ed7e0c, 0xedfe05062b3eb7bd, 0x0d0c735416cdc9ba]), alt_bn128::Fq::from_montgomery([0x873bdc0bbac08b41, 0x85f15d860fd9bc7e, 0xf1bef3c19865f5d0, 0x15c16a7213129548])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x4f25fe04f94cf069, 0x414e6ee08b5c86f2, 0x2ca663888d50e2d8, 0x1caf64a77377bc19]), alt_bn128::Fq::from_montgomery([0x109972a34d43c564, 0xb9fe601b584cea30, 0x40cfa60f93ab684d, 0x04b7831f3e02bf78])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x98918d57ede5fb6a, 0xe6d760a7b74a1bf7, 0x7d4dfb82ebf8e994, 0x18638cf32a8bc1f4]), alt_bn128::Fq::from_montgomery([0xa1f04520e2514b42, 0xc2e8d0813135f713, 0x436b8a0a387d1fc0, 0x04d0fe1a40f3a280]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x5f72b1ccc126330f, 0x9733713935aa7a21, 0x65ee30e761cd3761, 0x2d6fe2cefe93ce6f]), alt_bn128::Fq::from_montgomery([0x0f42314f1769b010, 0x4acdb32cba31701d, 0xce7981bd34fc1007, 0x157533a1d638d249])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x00db8ce0d079611d, 0x41c7e3964b2f1615, 0x77b0e623a6a14ad4, 0x10a4468c7b5c3d53]), alt_bn128::Fq::from_montgomery([0xac37ee04cad34241, 0xc81ffee40a512237, 0xf460272b1be6d263, 0x12423b72ed11adab])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x45c2caea94490ff8, 0x88f878ea16f2b080, 0x7098dbb818b69e79, 0x0142d7fd1e9595e3]), alt_bn128::Fq::from_montgomery([0x5fb54b085146ea04, 0xf021fad222b5205a, 0x70d9f42735d98b18, 0x2b4e55a180f94176]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x982afd6f2e2185c0, 0x20452114990ae422, 0xdba80fbfd1c49ea0, 0x041b345004b12d6a]), alt_bn128::Fq::from_montgomery([0x97e363cef8f96984, 0x7bd9a68c01b88cfc, 0x89427644f0563919, 0x20142fdc014a459c])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x8db58a87b36c16d1, 0xe373c09c303ce1f8, 0xcbd38d5912bd7d01, 0x2320d3105bc055e3]), alt_bn128::Fq::from_montgomery([0x8bd0848b8b4b49d9, 0x3f2b211fcb9192b0, 0x1072e5d5f2524beb, 0x276e576a756e3dfa])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x8d754dd015e003f4, 0xdd0a7bd0b9264367, 0x8538c5a179ce59a2, 0x1ec03467cff3ff22]), alt_bn128::Fq::from_montgomery([0xf6cfa034b58b60ac, 0x27c66325175cd8ef, 0x76e9655d6944021e, 0x2d2ca12c695f57bb]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x2fc6b6b55b3ae088, 0xc70722ed2a4ba088, 0x3cd4c479691d9716, 0x25cc0449db2cc220]), alt_bn128::Fq::from_montgomery([0xc8e57d9135caf4c5, 0x122a5a2bba592d1d, 0x0df69a29a0aac364, 0x1052b4bc9a33558f])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xf9526c530e18dbcc, 0xd1fa93c1a8d49584, 0x6c6d8fa3581474c4, 0x2a996a49657f1312]), alt_bn128::Fq::from_montgomery([0xda6268df6d2811f4, 0xd59989d37f6cbacd, 0x953215497b1da225, 0x298582105e153496])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x86b2e69b898cc92a, 0x8b070b2114a859ca, 0x6ebd83972b10d608, 0x15f398b499b1641b]), alt_bn128::Fq::from_montgomery([0xcfb26f3ba53be720, 0xade250564c987dd4, 0xc5017d8f92d3c97b, 0x07fc728ec8cceadf]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xb426175ce1f683c6, 0x7051f48ddd80eeed, 0xabd941a79d2c8107, 0x0fab95c7eeb00da8]), alt_bn128::Fq::from_montgomery([0x519e12bb008765d8, 0x6ffb3ba3ea55b200, 0x8d4d13703890e577, 0x11a2340f85159734])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x204d28e50a076cc4, 0x2e6c4a949f010e24, 0x059ef595f6f0977c, 0x2c247cebc1532c8a]), alt_bn128::Fq::from_montgomery([0xd4aac5a761ed7a4d, 0x39b164764e9531d1, 0x88de3f372a0039a4, 0x145963b8acd6a3d1])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x035232933014c80f, 0x4ae8be9e79864aa2, 0x41252b1857bcacb1, 0x2744951a5c1bd722]), alt_bn128::Fq::from_montgomery([0x66444ce034192f3f, 0x9d0c802263f47156, 0xd71c4d2fc799e8ab, 0x1e9e63676267cc6e]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xbf5eb78908eef3a7, 0x468c952b4e90d73e, 0xa295426b5806949b, 0x2adeaccc30de6534]), alt_bn128::Fq::from_montgomery([0xd54086f245a102b4, 0x735fc69430afe826, 0x14f99ae90ecfb290, 0x04b87f1b9d32b24a])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x1ba8ba53c9108e1a, 0xe834be2c10d6cc96, 0xd7f2540da5633055, 0x15be3acf368c4c47]), alt_bn128::Fq::from_montgomery([0x817843ac7aaf5e05, 0xcba2803021783333, 0x089fbb3ba7229c29, 0x0c18f243bad386cb])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x82e4a9244eccfdef, 0x6169417f5ebf4dd3, 0xb05dc4dabebd80de, 0x1a781dde41ee5ec5]), alt_bn128::Fq::from_montgomery([0x80cfbc93a55073bc, 0x8d43cebdd4c15210, 0x99bed5e2f152f162, 0x0e46fbb5cdcae265]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xa23cfb4eb9533b2c, 0xd4cc66ffc1185872, 0x17132217bc47278f, 0x08e57c1850926877]), alt_bn128::Fq::from_montgomery([0x0bdf6c8e7fcfe1c3, 0x8d0116e27b2062ed, 0xea681afede54b7d9, 0x180b22f80d7ea40f])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x370714b44ec48418, 0xcce0ff9c70c6a06c, 0x43decbe4f3af01c7, 0x0e2e2311ac52c923]), alt_bn128::Fq::from_montgomery([0x46b034f71a5bc53e, 0xda99eecf939a0ee4, 0x2d5b195f96b6ead3, 0x2dea039f03b7ea6d])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xb8b57447f32ab8ca, 0xe13bff622bfa3052, 0x1dd2b8fab2a24621, 0x01bb46802aae9577]), alt_bn128::Fq::from_montgomery([0x14611065c76d5722, 0x97832604b054bf2e, 0xe155edea2ce67098, 0x0bc5561c2b5fe02f]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x1971fba5b249438c, 0x29413db651e64544, 0x9fdbd314572cb51e, 0x1e53e7c577f07850]), alt_bn128::Fq::from_montgomery([0xa427c52c097c76e0, 0x3e286ded4b00db5f, 0xbd72c46397852d5f, 0x2ea0346ee7bc53fa])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x81752ee7cf0bb336, 0x9de39549c7b7dd1f, 0xaebeb99bf50fbc74, 0x29d9e95e74a16d01]), alt_bn128::Fq::from_montgomery([0x30a70a3d10bad3e8, 0x7310f354457febc5, 0x63b2e0e3ddedd16a, 0x1d61424f32c2ff13])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x3cb8760b5f525a8c, 0x4115ad22c94264ce, 0x2dc33844000701cc, 0x201985f7105b47de]), alt_bn128::Fq::from_montgomery([0xb016ef42697be493, 0x6f5ccac534751e5d, 0x7e8b91c54b79a5bc, 0x28d7da0b8a29837f]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xf359291f8aebad78, 0x2697ee0b3c73f3ac, 0x58c51c736cc5915f, 0x10d7d48dee5e0e05]), alt_bn128::Fq::from_montgomery([0x6acac6606209810f, 0x3cc3fb6f50d9a5f0, 0x97c49bc7254e90a0, 0x2ca33a94d0c2ca14])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xdfd95059bfb7d607, 0xcbc4b28e82238dac, 0x8073e264b95b1793, 0x292edba3bf33018f]), alt_bn128::Fq::from_montgomery([0x70593038ca47fe2c, 0x740cc2a12d9ec85a, 0x86d43363841fb75d, 0x1b9af3f554541317])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xac383485dbd94212, 0x99906c121e269fff, 0x67d31ad4a2d85b26, 0x2b27d26d46654e01]), alt_bn128::Fq::from_montgomery([0xe20318f4bab4930f, 0x9669b40ddfa3131d, 0x22786a47125c44fa, 0x2509a7aca80d08ed]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x58994cc625fea6b7, 0x4b7d7c55b63dbab3, 0xb1b1934c2d157757, 0x04c360dd909f8359]), alt_bn128::Fq::from_montgomery([0x79d622d028f2974b, 0xbeeacf25ed776471, 0xfc2822aaf9e9b05f, 0x2c2074d729447f3f])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xe2bbf0c3988808d8, 0x344012ebd236ee18, 0xf961ddb797b89b8e, 0x2558ea1da2396f3f]), alt_bn128::Fq::from_montgomery([0xfe79746219095fbf, 0x3b7b5cb1af35f905, 0xf951d9646f5d19ca, 0x05ca4082f3985311])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x6b7798640adee94d, 0x967f6585d63c97e7, 0xa01f94ec6303a722, 0x1f48258e6e7c817e]), alt_bn128::Fq::from_montgomery([0xe8a46b2a296465cb, 0x565e556569998fbf, 0x216a54cd09f16143, 0x21ae256da9ff0b93]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x6bd3cb777e763012, 0x07a862dc4d56b917, 0x27b3bfe66595caa8, 0x2338d4c6610bf58c]), alt_bn128::Fq::from_montgomery([0xe8158459db57568f, 0xabc3e1d8c6ad20b0, 0x6ce06127acf20007, 0x2bb5d54c10367ef1])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x6ef06760a4f16769, 0x382c19be7d759498, 0x3d854e5fd25a8788, 0x2e2d842f2b6701d8]), alt_bn128::Fq::from_montgomery([0xbc23563638343b76, 0x3db6dfe84a0c198a, 0x26fa94fe556c529c, 0x0d105ca3e9d87e95])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xb6a7d8539382d5f2, 0x09a7b84903d638bb, 0x43bc10de1e299c57, 0x128016e0da4e0bfd]), alt_bn128::Fq::from_montgomery([0x701272944a130611, 0x70faee3d5411f596, 0x25c733f03128b62e, 0x0d9afa2c6be1c067]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x1699ea2721ba8d68, 0x37162080a219d3cf, 0x95c159ff1045748e, 0x07fdf45344bec6b0]), alt_bn128::Fq::from_montgomery([0xcbcac1076332500c, 0x3f14ba3e33c274b8, 0xc26baa378d380a09, 0x25250231201992a5])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xf2f8b89f9fe0449e, 0xe8e375ac9ce769ef, 0xd01c52f7ae9eb4c5, 0x2afbc89526d8c3d8]), alt_bn128::Fq::from_montgomery([0xb7a77b72ad3abbd8, 0x1377a787a2c0da2f, 0x94b2808c8407b6d5, 0x1d5c1d828d1f2807])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x2d3196d31c33db81, 0xd88c4dc1db5e267f, 0xd8ad9da86c743389, 0x01f6c1ca01b92da5]), alt_bn128::Fq::from_montgomery([0x75d3b970e7f5d9e9, 0x425a36dde57a6dbb, 0x09cdded6d448000d, 0x0915534b382356a3]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x20982a62e89efcab, 0xcb1317704216d71a, 0x986c469427a598a9, 0x16a6605267b53d87]), alt_bn128::Fq::from_montgomery([0x6ba0ac337b7f6e61, 0xf330a213de6ade2d, 0xcbb0b99ae24e1d1d, 0x180d944e6c58cd38])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x940cecce2411df19, 0x8c7550ef60ba003b, 0x7048d337c3fb7d13, 0x00ec9102706d608a]), alt_bn128::Fq::from_montgomery([0x5b22b9dd0712467f, 0x92b49afe998ae9f5, 0x46b56e631a9bd90e, 0x18764642f7bc68f6])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x72e1414d97d92776, 0x67bb6a7bcea31963, 0xeca7a3ca52e96037, 0x1ab39c548688ce2e]), alt_bn128::Fq::from_montgomery([0xaf0472aea35fe79a, 0x5ec79fc68aed1a41, 0x6ddefbc3ef6f24f3, 0x0fe4a593ff63d3db]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x88aec781d1df6ca8, 0x6ebc9f73c70ac59d, 0xb08f009d2e72518d, 0x21c8b473bab262c7]), alt_bn128::Fq::from_montgomery([0x523115129c54dc07, 0xc9851ca1d45dc591, 0x527afed6672d250d, 0x2bbb261612e024ec])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x33991f364cb78e42, 0x6991a5434c3f9563, 0xfa76ab02266f24be, 0x094a467fa97b0382]), alt_bn128::Fq::from_montgomery([0x25eb8f67c5d545e3, 0x7b83f85fc8babb29, 0x6baa469dad100e7d, 0x1e7f91f825e86051])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x94660e8d6c438be5, 0x6e9574d44d2bf656, 0x0c9c99c70143ba6f, 0x17ea37243cecfb03]), alt_bn128::Fq::from_montgomery([0x21c405f8fb056cad, 0x52b2a6070d0e853c, 0x2a9f9cbdad3e7117, 0x1e93859719df61f0]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xf5e9b5ae38c0ddd2, 0x0dc80d00de68f1b0, 0xd715086bc993d3af, 0x118d05d3aa768024]), alt_bn128::Fq::from_montgomery([0xd08a2668159d627b, 0xa02ea0918a30f6bc, 0xdfd7f88eee4022cb, 0x0681b9f0e595d7a0])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x463f0343541d175b, 0x2b8147aa15931c4d, 0x126be975466c3fb2, 0x0d958775729203fd]), alt_bn128::Fq::from_montgomery([0xef502f5100e596ca, 0x9613ffa44f7df5be, 0x8fb012dd7ddb5497, 0x0c976c4b870961c2])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x92c14a9e696d1c88, 0xb834299d6465df04, 0xfb3dcf352289a084, 0x1284dadf829231ef]), alt_bn128::Fq::from_montgomery([0xd808e4a4e048159e, 0x50639749d8835456, 0xc22994edd3af57fc, 0x2758bc2d06499ad5]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x65e2c78c80258267, 0xe26c3a0dea88b08c, 0xc37bcc2953744301, 0x16a929961bbad185]), alt_bn128::Fq::from_montgomery([0xb0ee7387145a4080, 0x8850a8b0c72b8885, 0xf5dfbee7a04ed537, 0x2ab9f5b39def455e])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x49883c51ace58544, 0x167e0606139c1531, 0x3aa17a9c26060fb1, 0x293cf1417a7cd31a]), alt_bn128::Fq::from_montgomery([0xfa04ef2911895cfe, 0xfa3c56b93a58cd3b, 0xbefaf23aef8c5252, 0x1f1146670394d715])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x2ec8466d0b37b7f6, 0x024dff134cb0a82a, 0xb43125c721e8e8fc, 0x16338f24b4ce885e]), alt_bn128::Fq::from_montgomery([0xa5af0b504dafdb04, 0xf93cf4421ad6547a, 0xf6135e19e3999a0e, 0x0f710a0d89717f29]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x7d184d88331d2cd6, 0xc8100db7654d1374, 0x45305da0be4bfddc, 0x2a002b6022cec981]), alt_bn128::Fq::from_montgomery([0xd82348351fd5add6, 0x8866bcc16231c4ff, 0xc70b0e887c89d33e, 0x1bf01e48d13ca0e7])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x027486ee8630e7e6, 0x743f5d32ca231a48, 0x7ef9f72f2aecbaf1, 0x20b8b200aea279f3]), alt_bn128::Fq::from_montgomery([0x63307c63785e9550, 0xcdcc45c59ea3f378, 0x99f0008a5652b243, 0x2f6fb5455e33c23f])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xbdc04f53cd17e1c9, 0xf7608c388454ee09, 0x3b3a2e7128aa4ac1, 0x1d20e5dca0b8841b]), alt_bn128::Fq::from_montgomery([0x7d6772e46af82301, 0xcb481ab846d8e514, 0x857246e4cc004c97, 0x1afb6daaf8d1c66f]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x30efe91f781f3711, 0x0059e192b00a3e2f, 0x1292c85b01eb1d36, 0x196d41d9402e68f3]), alt_bn128::Fq::from_montgomery([0xa0765e2c6a000413, 0x6d5382776012eb12, 0x00fe935120cf5009, 0x1040a6a38484cf41])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x14b236653c8d6331, 0xbd511c9aca1dab92, 0x903a6b5184f5b6ac, 0x039e012a1d635584]), alt_bn128::Fq::from_montgomery([0xf735eb54d25aeb2c, 0xeb8cfeff85ac7d66, 0xe0750d5109d51676, 0x058514a8925286ae])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xfd291ca953c653df, 0x1b8747f55ebb1047, 0x050f3f28d2616616, 0x07a261513d971211]), alt_bn128::Fq::from_montgomery([0xdd9903cb21058426, 0x1a2df2b4ca3b6467, 0x8aa8d524f32595f0, 0x2725de51ee211429]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x7d48975f507371e4, 0x11da2a790b5fa66e, 0x1d70ff1cd545ec7f, 0x24e07c90162894b5]), alt_bn128::Fq::from_montgomery([0x8a7bc50918645f2c, 0xbdf07ee1f4e2146c, 0x83f40776540d58ec, 0x2d3a3664d8dd745a])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x008b551d6dac9c4a, 0x9d9e58f0069d084f, 0x42da9da574619194, 0x030d27a3da4adbc6]), alt_bn128::Fq::from_montgomery([0x7501170195699b6d, 0x0bff60ed0827cd35, 0x9df04839fc4f19db, 0x0f49034c65fa5853])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xfb80be6cbde36eb8, 0xde77a9111d997ea6, 0x265c1d63c8b15ff3, 0x2616b3f2e8648821]), alt_bn128::Fq::from_montgomery([0x5d601ba529e9b86a, 0xd0f891b3d02b9ca9, 0xd6f5edced1bbc0d8, 0x05c869c7236ddc20]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x31c7b495080f160b, 0x8cb4faf9ec9864a4, 0x76d872b2223e1ef0, 0x11ef1b10b43ac965]), alt_bn128::Fq::from_montgomery([0x33dc033548157c8b, 0xc6a5a1b3f179d845, 0x86037f88a53b6354, 0x1e20c3c391aace94])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x341ad87304eb1d11, 0x7d1fa9ebc6fb7e8c, 0xb7227140a7797244, 0x1fea81ddad324af0]), alt_bn128::Fq::from_montgomery([0x832e0157c4490342, 0xaf6911527edc26fc, 0x980e7f350f2a329f, 0x250ccbebe8aa8ae3])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x9bf468813c779e94, 0x3eac328b54eb697d, 0xc86e59f9d75d9bdf, 0x0f23924274393b1c]), alt_bn128::Fq::from_montgomery([0x74279ba7f7ea6823, 0x9623d3a11426b1a6, 0x3cc1bd2c88a16e1c, 0x1f15cc54051a37c9]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xf5fe3239f1485e63, 0xfdf2bd769d228555, 0x0049e351facc81a3, 0x03aed7436732342a]), alt_bn128::Fq::from_montgomery([0x921669cce604d7cf, 0xdfe652bd2c4b63aa, 0xf3831afeb1a15b7a, 0x14770f6cb54c8a37])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x71dd0d53e24bf303, 0x340317e2ce9a9f83, 0x3d4ffd36b6179ae0, 0x0a4d4157d8ca6f87]), alt_bn128::Fq::from_montgomery([0xd699b63534afc5a5, 0xcb551ed0db326d8a, 0xbd8be843b31c04b5, 0x0f09ab4c99fea82d])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x285d8bcff37cb6ca, 0xd37fa2f4166c6bdb, 0x4b35ae5d581d44fe, 0x2c0d4f4bcf5901c5]), alt_bn128::Fq::from_montgomery([0x436c882d07f8a82f, 0x3be5324807b04eb0, 0x24ae6bfdac83c663, 0x0128732975f8607d]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x1963620b93c0a2fc, 0x5f75e48f25d8c9e8, 0xddcdae16505441aa, 0x0c638e28742990f9]), alt_bn128::Fq::from_montgomery([0x30b16355395b0160, 0x20954d4dea43aeb3, 0xe4c4d2c9b83f23d3, 0x1d693c82ba2e6d09])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x63836351969d63d3, 0xccb44342b627e47c, 0x1936e07529b21412, 0x1132ddab4ff94e1d]), alt_bn128::Fq::from_montgomery([0xc4bc9e90878022bd, 0x8374df412309924a, 0x9ece0674bbaf2a92, 0x1b7c7ce619bd4b7c])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xd7049d93b514726b, 0xd3a8d0d1b88014b6, 0x1d416836697a71be, 0x0fbc1f51d4b5860d]), alt_bn128::Fq::from_montgomery([0x8e9011161aaff7b3, 0x4ffd2737e415273c, 0x7e017360f0e31565, 0x1974fde261db91a9]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x8f587c4a3fcd0617, 0x8c9adb3efd465aea, 0x0f758a845e8c7381, 0x06ef88d50beb572c]), alt_bn128::Fq::from_montgomery([0x85d921bd2d136205, 0x08e5784168abcac7, 0x19f837e22996191a, 0x08303cdea93781ea])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x9242f730ce9be501, 0x73ee6a708d430a71, 0x538279c950f82437, 0x160ec0eaecc5d638]), alt_bn128::Fq::from_montgomery([0xf10e75389c968e69, 0x8fab8b5bcf387904, 0xdfae58e58da4b527, 0x286a7e7600873cc2])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x6f84332fbd20cbfb, 0xbe30600012f7bca3, 0x367cc80cc6c88884, 0x28b31c265a8b060a]), alt_bn128::Fq::from_montgomery([0x4618c83b35dc67ea, 0x9c70fc035b219719, 0xfe8384e09a3b7cb2, 0x15952090c33cba49]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xcdcb3a6365c1e8c1, 0x3ab61bd5372e17d0, 0x1a49f381a5819d87, 0x248704855cca391f]), alt_bn128::Fq::from_montgomery([0xabcb3a9f26613d54, 0x93a1b4dbe004d578, 0x64abe77eae67a097, 0x22b7ea72e8e3c159])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x3295c88dc9ce8fd2, 0xf7f9a17a494b58a8, 0x3bfdb8527b97d6f0, 0x0f8f78d40336bf75]), alt_bn128::Fq::from_montgomery([0x781484e4e54f7689, 0xf8d2cc6c500a19e9, 0x55de8a169bf8390f, 0x28e36953f4ed6015])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x2e07a9a597fdf325, 0xb96afe9fc9130f94, 0x1c492a0e3ced8cfb, 0x2c53fa36f89cfc8b]), alt_bn128::Fq::from_montgomery([0xf531c7de3cf38649, 0x50a81958d17e7a2f, 0x2cc6caaaa42ffaeb, 0x0399d6ff4d7b8166]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x04f44f170ba8e446, 0x8bd34629f81c1346, 0x31f7f29c73b81b0a, 0x1f1e0c8f6ea8b548]), alt_bn128::Fq::from_montgomery([0xd9ceb0e1be9f3f59, 0xbd0b4a31211bd0bb, 0x1e2f237d14921052, 0x2c80d53d41cec9f8])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x5fb1cbfb8354d122, 0xf34587413dd25bdc, 0xfb274b82fce047a7, 0x17c7f42c98e8ca49]), alt_bn128::Fq::from_montgomery([0x86df2f27ce0f15db, 0xffbce1ed5c945250, 0x0d3ea8c468b6064c, 0x0262eef0bd06cad9])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xe48e45422ffe3f0c, 0x155fc16e108e939e, 0x73c99b3720371468, 0x1f6ec80de7c9f0ce]), alt_bn128::Fq::from_montgomery([0x67bf51f8f08c2fd5, 0x1e11f1cca8025bc2, 0x36391b045c8a4bf8, 0x0973e55e70fd1d7c]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xf1505bed3b805404, 0x5785fbc1fe025f5c, 0x8c74232c9ff8e35f, 0x1fd166aa33e0048c]), alt_bn128::Fq::from_montgomery([0x53df57fdf9c3d107, 0x192dcb8059a69305, 0xdbe53e2cea6bf363, 0x24695f3178d7cc63])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xaca8f1cffb858962, 0xcdf89127db2c44af, 0xe489e5e57fff2069, 0x26cd37be0cb58053]), alt_bn128::Fq::from_montgomery([0xd936d1f825fffffd, 0xc3efc0df23cec80a, 0x6121f55800715058, 0x0b3737fc57db7627])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x969c32c5f74eeef8, 0x90fdf3a518999919, 0x73bd04473a83334f, 0x289c01ae11163275]), alt_bn128::Fq::from_montgomery([0x8ffeeb3589d5856e, 0xd210ef0534314104, 0x9f9403acf9f561ba, 0x0593ec6301f50169]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xccefd33c84506d02, 0xf6b6d8f307ea2de0, 0x6b2dd69798595968, 0x12aa0155363c3552]), alt_bn128::Fq::from_montgomery([0x0f6e45b129f2a5a9, 0xa8317059a81cc12d, 0x8ecc931c42cff712, 0x12c07f7e41de4295])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x4d0ad44dba15a74c, 0xf74d969cd75116c2, 0xe42d6a20052198c7, 0x28254619a3f5147c]), alt_bn128::Fq::from_montgomery([0x0168cc3c07de29de, 0x88297e5c77b93df4, 0xe0811d8fcfb53c98, 0x22b8ba3fbc644d99])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xc6b6d3eb2ef24cdc, 0x8dc920b1be4f93b6, 0x90ebbccb5b98e63c, 0x021afb9fc9d00ed5]), alt_bn128::Fq::from_montgomery([0xd8bc679edb138d45, 0x13cefcb77e947b7f, 0xce069e4c0d0e6cfc, 0x2878033f632307f7]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x9d54628cfc9d574d, 0x2d8854e89fd26d8c, 0xb9e2e6c054e612c7, 0x155e60c0b15cc348]), alt_bn128::Fq::from_montgomery([0xb46d4306004d4d05, 0xe60c63190e454280, 0xc6c8a2748f0b2def, 0x2ed79b9f12542af0])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xe7a4438cae6840e0, 0x50a239e2c4323a41, 0x933e5efd343a3b7b, 0x2cddbe9dd0b2a62a]), alt_bn128::Fq::from_montgomery([0x666b40bf87e4015d, 0xf6cbffa1e9c17b68, 0x78fa9e8a50e4f648, 0x1036f8149938da09])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xf74f9b7a4d96608d, 0xe065f6d6d2970098, 0x4b73dc4b335484be, 0x2a2765c7663f506c]), alt_bn128::Fq::from_montgomery([0xc448bcdece13c9a4, 0x355b5d00ff5d98a6, 0x0a34b2f7ba1dc33d, 0x00a2a86237631e66]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xc599ead0e853b6ca, 0xf8da6a74dd8c8148, 0x785184dfc7a8e164, 0x227e2ea765c15628]), alt_bn128::Fq::from_montgomery([0x267fa4ea2ea671d1, 0x6e575386644d853f, 0xc2ae54471b35df48, 0x2b9a72e74ac9a209])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x50866f0625ea7f24, 0xc12081c8d06ec5fc, 0x72c6fe041549cc4d, 0x1d0537828d1a0c52]), alt_bn128::Fq::from_montgomery([0xe2949da28e52247a, 0x7a99c1ac6db1a548, 0xc4fbe4f672b510d9, 0x0d92cedc2bd7905a])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x660fc5ccdfca4f8c, 0x9351742466c7f84a, 0x035229d1b60aaf18, 0x2d4b4ea3d87f1603]), alt_bn128::Fq::from_montgomery([0x39276b9c3679eb2a, 0x2f8f23fca89e575e, 0x23716b14900d205c, 0x060584ba3d6c8683]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x8cc68c60a44db663, 0x4cb0be8f76d0b4fa, 0x21ac449f4ba58b27, 0x073ac36f10260d16]), alt_bn128::Fq::from_montgomery([0x914cec53e7eefbb4, 0x930ce49796bf359f, 0xb6a14e907fe9b159, 0x09184ec8df9bdc9b])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x800c2e5ca4a0cf96, 0x0135a90d9aa0c75b, 0x0510ee2d04a0e601, 0x1cc720467ca93bbe]), alt_bn128::Fq::from_montgomery([0xb2169026e5ea151c, 0x55e3605c312e1821, 0x60e2d7131f25c54b, 0x17021b520ad1c51e])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x185d8cb0fdd160b1, 0x60b5567ab8bcca5e, 0xaf2437695fcac716, 0x1274aaf25b47f86f]), alt_bn128::Fq::from_montgomery([0x199523033a35e44d, 0x9bb7341e13034ee6, 0x6e76bd13cb7f997b, 0x301666ca7f015781]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x34709b91644b27ed, 0x7f0747f80cb14503, 0x453277e7f43e990d, 0x2fca01382d3970f4]), alt_bn128::Fq::from_montgomery([0xcfec97efb38598df, 0x3d6d12a2fe70d301, 0xb40a4f8444153ede, 0x16079e0eb8857574])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xcec68fcb10532a81, 0x12a0f1082913db22, 0xf3800533043be7bc, 0x17f4cd65315a3e47]), alt_bn128::Fq::from_montgomery([0x968457eb02dfa09f, 0x52bbacec181ccffc, 0x9d7861f5913d5dee, 0x08f17ce8a23993a2])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xacfe463238c326c4, 0x9851547437e22678, 0x71eceda0a4d8bbb4, 0x293484da0c230e02]), alt_bn128::Fq::from_montgomery([0x561f94886f95ab1f, 0x4694ce71f5505832, 0xd84480cc60b0068e, 0x27d83ffb3c85660f]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x3c83e56aecfaf724, 0x4d4bf7001455e402, 0x0905b0b9e5f86c53, 0x2623d0067f5331eb]), alt_bn128::Fq::from_montgomery([0x721333e7bc5a3409, 0x9bf7002615d3036b, 0xabe905efb64264ff, 0x2c3ca76d208d7144])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x460ecdde88fa2d0b, 0x588d821d1be04f62, 0x21f3a445982dc9ca, 0x104cf88244646235]), alt_bn128::Fq::from_montgomery([0x27e37c8da0673ed6, 0x6b24b7650cc2758d, 0x0a22971c4d3c3093, 0x1b0f8f103e10d9d5])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x2aabcca60919db48, 0x0318816c5b607af4, 0xf14021ece90a2ee1, 0x086248cbd3c113e2]), alt_bn128::Fq::from_montgomery([0x44384c54138329ca, 0xcb8ada091050365e, 0x7f2a2cfe42128886, 0x08f2c3846ee03b2e]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0346f2f039cb2b2b, 0x0f87fa02ccb2995d, 0xe1a638337ca21f82, 0x0d25ba7a7580de28]), alt_bn128::Fq::from_montgomery([0x10baf170dec4ed68, 0xf84170f3015ee57b, 0x8d60433d0955836d, 0x278ec13da3f56c87])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x08c8dfc48b043fbd, 0xa47dc41f038c8b26, 0x516e1f56338a648c, 0x25b208dc24a94499]), alt_bn128::Fq::from_montgomery([0x2f289d6e5b33a9a2, 0xa55dbbf63f3142b4, 0x20952f64d8828af9, 0x0025d963bc5ba7f1])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x543387085989d682, 0x3d01f8ec2b2bdc7f, 0xdadf9c3dcbd9be53, 0x28a48c14f1c7d0cc]), alt_bn128::Fq::from_montgomery([0x1489e727b101a882, 0xcf279890957e6a5f, 0x25fe2870d6f12c53, 0x139b8b9abeb04350]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xfe63fe69d9dd4110, 0xf470cf8b2e072740, 0xd8d0400167db0260, 0x17af88e1f5453a71]), alt_bn128::Fq::from_montgomery([0x88cf9c7aaf3a94c9, 0x78be8457a0db36f5, 0xb3bc463065950e69, 0x1eea2f22667924ea])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x9e1e5d1df3a6ff53, 0x5b95ca1558258603, 0xe3036221ffd7ff5c, 0x2cf385f2a642bac8]), alt_bn128::Fq::from_montgomery([0x78a9b93c8106f434, 0x0d8c61b2f06d51a6, 0x180872589c7b1b01, 0x2193af3c27191c53])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xc5636cbc6863044b, 0x27a7a86cfec0b183, 0xb4ded5f85438dbaf, 0x222d4f729d1b2600]), alt_bn128::Fq::from_montgomery([0xee89df8b7e77f2a4, 0x053608336c052fde, 0x31f79d60e5485327, 0x1355bf42a84a4d4d]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x236ebd04323ad766, 0x1fd7ade9ccf49b7f, 0x881729402f908b17, 0x133bb122557cd824]), alt_bn128::Fq::from_montgomery([0xdd8e97d8cb28c894, 0x97cd2cf8d2ee9e2b, 0xe5ab3c1ccd173add, 0x12b3e6cdd82f42be])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x3c308e3a179985d1, 0x58bf4ff584da761b, 0xab64b5cde1064524, 0x2131472fbbafa8ac]), alt_bn128::Fq::from_montgomery([0x9e610b45c5b096fb, 0x7a8be048df82d586, 0xd343f23a5a43f2ef, 0x0787381ad04477fa])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x57a9b47865120145, 0x1a14a2408e5e5c14, 0xce6a6acb03901b12, 0x11107902f7b5a70a]), alt_bn128::Fq::from_montgomery([0x147d0400ef49af80, 0x83dffeb2206c8c16, 0x0f4754ed0e5a0f25, 0x25bfb46fbd42288b]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x1f9212e01b9129a8, 0x0fa2c972987815a8, 0xbda39d3743c8eab2, 0x1deaf72c98e0c5ea]), alt_bn128::Fq::from_montgomery([0xd6d241f563b918d8, 0xe87a6caacd17c957, 0x017df99694ae5857, 0x2f13b7bbdee9df0f])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xb4cdbe50831a4864, 0xa641cd51f8490473, 0xfeae7dcc60c19184, 0x0607a49ce4c09226]), alt_bn128::Fq::from_montgomery([0x004eafbe20ffa8e1, 0x5e73a3434a37c735, 0x569730e720f621b4, 0x240ae6afba9af5c5])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x6437585941563698, 0xb3542a6d0dfb0be1, 0xd6cb104cfe1b3ddc, 0x1be193212b39f3ad]), alt_bn128::Fq::from_montgomery([0x4e6054ec2f84a189, 0xde75aca95c13b5e9, 0x6a98f93f632709c8, 0x2d73f2cd485e80d4]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x83f449d04b98f14c, 0xccc6331034b06930, 0xa9d34f9d3a1827ff, 0x2e95cac809aacb7c]), alt_bn128::Fq::from_montgomery([0x5f6814de0f16f77d, 0xd237d7a9577bc276, 0xf839b4de9a3a26be, 0x0d44b796cd994939])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x645986ebfde01053, 0x7163a8a26525230c, 0x5b09192b08385963, 0x1a47fb5c38ae298e]), alt_bn128::Fq::from_montgomery([0x0344f79020c29f77, 0xaf2ec527c2fb6ee7, 0xe7c86fa3e818c510, 0x194adb05191901f5])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xdd19b147d74c4a0d, 0xfbd4cd2342bdb19f, 0xc94ecbcb2bff7b0b, 0x141ef51a1c84d9c8]), alt_bn128::Fq::from_montgomery([0x34e8ff7eac115db0, 0x0c2fac3d8cfb70ba, 0x47ed29579e6b9948, 0x2e0a73785c61de98]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x7c57436420406870, 0xdb50a71a693d735b, 0x46a79a5917443954, 0x26fb4ab3fb8a8492]), alt_bn128::Fq::from_montgomery([0x772ed2d77e69776c, 0x19c7300a7489f07c, 0xfc25990b6c9acfc4, 0x1a25c11853ad5a1f])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xe57e549b8742f0d9, 0x6486988f94aa851f, 0xdc5352b4d82b646a, 0x25381d97414e5dd4]), alt_bn128::Fq::from_montgomery([0xc63b647a81015371, 0x2e0b441fe933f7a0, 0x53a5ebeb0fea2411, 0x2db7b31008d459ba])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x9fdb6eaa0d93a92e, 0x03f0103a03d7c923, 0x04008fddf3486820, 0x2a68c9df67a4929d]), alt_bn128::Fq::from_montgomery([0x17cacdd9babe1191, 0x80866d56e64928c1, 0xe8a5ac4330167854, 0x2229b935b8ca4312]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x9ea066a2b476d5f4, 0x866064a91086e12f, 0xea1ce804585ec1ab, 0x1d7ea57be5ddf4d8]), alt_bn128::Fq::from_montgomery([0x587274325068c38c, 0x6432dba3292d4de1, 0x0f3f40a147086230, 0x23f6e433eaa4f43d])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x80d23d4ac496813c, 0x7a8e1443f81a24b1, 0xbe376e289ee63f9a, 0x2e947ea9e1e1c416]), alt_bn128::Fq::from_montgomery([0xdf529ff12f858ae3, 0x2feab42930e85873, 0x471bf66dffeb0610, 0x1af825cd5a155519])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x681f321f36b39cdc, 0x4d6e1d6fb5c3a772, 0x9c0149e874559a1e, 0x29fed2f7d32ee95c]), alt_bn128::Fq::from_montgomery([0x1c8327dbc8fef4cf, 0x0362413941089f9e, 0x9f51cf1c0b86e2a3, 0x12909cf3836d9a3b]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xd5b5eeec0ee0c5b0, 0x06ca116106c900a1, 0xc8ffae32bc963b13, 0x065a7e16beddfe92]), alt_bn128::Fq::from_montgomery([0xe3df9dffed146574, 0xb5a00a8140f1e3fc, 0x9354b8ec72240d26, 0x08bc3e749e8bf11f])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x3f92870b7f6439eb, 0x9882789c16494123, 0x0bef8c18473b9f11, 0x032edc4c00881795]), alt_bn128::Fq::from_montgomery([0x7561bd684a1ce3d7, 0x4603b8a15e66c976, 0x455a51a0da7ee6d3, 0x2d221128b6acba58])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x807beba68187045c, 0x99d77908e068224a, 0xdb9058279a1a5ceb, 0x0120e0cb3e8ee397]), alt_bn128::Fq::from_montgomery([0xc104fab2dbbc4ee5, 0x2d55de47eb3cda94, 0x03f6b404b02a5ec6, 0x2a493da7e8cae4d1]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x1f54dc7424b3158a, 0x4fbcd7572e33deb7, 0x4417edd021ffe593, 0x0d2cd526f693f576]), alt_bn128::Fq::from_montgomery([0xbbb424ed77c118a5, 0x69f06e0426e4ab10, 0x75501ce20a70bb27, 0x283c6ba622bd759a])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x3b72bc7332392f4e, 0xd5c830b292dd5106, 0xcf669cf80ee5ba03, 0x164fa3e7ebe7bf5d]), alt_bn128::Fq::from_montgomery([0x3e515cec747b301d, 0x14a091a38b81fc14, 0x56a05f79dc7eb2ee, 0x0aac0ef142e3f65d])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x19e9f9711ffcea48, 0x08e1d8397fe10c60, 0x0e825a4ee2be5cec, 0x2d04021912ccb03a]), alt_bn128::Fq::from_montgomery([0xf11a51bd4807b0b5, 0xfb6a4e8f53a20099, 0xfaf85c1ed14b19a7, 0x2ecf911152b20580]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x5e9b5ad165d7d5d2, 0x8b92bedf31e63447, 0x14898aad5597f4d4, 0x132db6d09722dcfe]), alt_bn128::Fq::from_montgomery([0x40d167b8b8ff8120, 0x27acb1c3d0e0ef71, 0xdb512e5407415bd8, 0x153c3961afcd616e])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xfc861338dc985fe7, 0x44f25ba155b78ce4, 0xe59f2290f1c37a8e, 0x095a87b86484372f]), alt_bn128::Fq::from_montgomery([0x56487fc60cd811ba, 0xde9ae52a662c1118, 0xc680020703788176, 0x2b743e23e65e63e4])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xb561b4384b974517, 0xb109e5abb1af06db, 0xd6091aef5d6b37af, 0x211d732b25a427c5]), alt_bn128::Fq::from_montgomery([0x50b5e37119b281a9, 0x9132b9c12aa237b1, 0x9e14316d135c2ce8, 0x0b827af5061d114c]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x902097e292a99373, 0x771828e1a0c75e92, 0xc9a92e86a0a77169, 0x2665d1cc7984835c]), alt_bn128::Fq::from_montgomery([0x6f2d480eac2c2b70, 0x19da356c812672c2, 0xa17951de67469843, 0x230d0311ce664218])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xc6e58c78d4539090, 0x791f637fba71be06, 0x528569a80956cdb5, 0x25774327013d0731]), alt_bn128::Fq::from_montgomery([0x215b5258ba577d9a, 0x33322ec609a9bb4d, 0xb9f2c2a79c21b089, 0x0da97a4dea2d2fce])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xa98db90e58e0c16e, 0xe28d814f5148d4e1, 0x1e0fa42e7845ef86, 0x189381a72d9c5b21]), alt_bn128::Fq::from_montgomery([0x8094c1d9850cbbb6, 0xce4c43a8a1b1085b, 0x2f2619c9e40fc283, 0x282d8a41b309f1f8]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x2c45f548da0837f2, 0x5a78b4495d536749, 0x01dcd10d056c6718, 0x2b0bf69ab6168079]), alt_bn128::Fq::from_montgomery([0xc9f14f4669414ab6, 0x0a2559b8d61cc7fb, 0x0566caaed249804e, 0x1f14cdac15ed4c3b])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x1a033ec64b2c4b4f, 0x82f7fcf68cad34b8, 0xb5f3d031d6171f19, 0x0a7631a0b913e321]), alt_bn128::Fq::from_montgomery([0x46984e3cc91eac4c, 0xee7c45be69b7c81a, 0x19899c5ce09ef13b, 0x1d32cefce8f854a4])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xdd3c4ece4bbbfda3, 0xc72d93291a4088a4, 0x582579830f9e0c0c, 0x13cd4cb0178bca1c]), alt_bn128::Fq::from_montgomery([0x460b1af32729f501, 0xca0828d56f5d8872, 0x862e662a901f12de, 0x071b5795eb4ad7de]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0c456493081ba5a6, 0xe9ab25405f908815, 0xe8efe1a211398943, 0x041b6ad40781c775]), alt_bn128::Fq::from_montgomery([0x216ce403f724f511, 0x793be179f7e4093e, 0x2376081d76200915, 0x07655659026010b2])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xee1e8cd9d3dadce5, 0x635fb83c3f8644df, 0xe40ad63a01a496fa, 0x29503e0bfa0bba76]), alt_bn128::Fq::from_montgomery([0x51fc9f5c7480e733, 0xbf1e099f85308b3b, 0xb74db8ae9549fe14, 0x2821dd99f5d47c9c])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x9644940b1efe88df, 0x5c94f1ea07ce149a, 0x4554555087d16bb3, 0x2fd2dfabfabc119d]), alt_bn128::Fq::from_montgomery([0xbabe9c58da1d87a1, 0x45e92480e06b737f, 0x011a6d305d321757, 0x218ac6e251a868bd]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xe12ddb4d293ed19f, 0xc98553b950a41cde, 0x27712a6c54fb9215, 0x1b64c60fa91f0bb5]), alt_bn128::Fq::from_montgomery([0x0bceddd9c84df683, 0x1a336ce069437fc7, 0x0fcc8297d87f2c87, 0x0ed4d15176161484])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xebcf1fc66a6e54a1, 0x736a91548dcbd429, 0x83856a5680973077, 0x15fdad5079bd348b]), alt_bn128::Fq::from_montgomery([0x1850c98c2621732f, 0xffe9015c3d35062d, 0x051159074ebb48d5, 0x134cccaf6e7d99f6])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x314852260ac2946d, 0xfd39ae754450b928, 0x6f2a946a97b5b533, 0x04c2d650c77badcb]), alt_bn128::Fq::from_montgomery([0x5eb9f183a8f576e1, 0x62401f527d9e53be, 0xd6f3d11d20a9b978, 0x1ba1389b0146907d]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x6dbf9257d6cfbbff, 0x8d2868528bba7147, 0xeac4516edc36dc25, 0x05d36356b43b22a3]), alt_bn128::Fq::from_montgomery([0x18a1b8ea84f77e16, 0x9e05656d7bf1a9d3, 0xc711074343c9430b, 0x139f5539b44df624])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xbcf643f5ec10f40d, 0x780c4034d7f08fd6, 0xdb067b0f6a4d34b1, 0x0262a94c73a269e6]), alt_bn128::Fq::from_montgomery([0x2d7e8b5d1c3594d6, 0x79e4ee4c3c5a5e89, 0x004684cbc70ca320, 0x3020c3af5a9d31b8])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xc267996a954308bd, 0x0002a4a664979310, 0x11496cb2cc62579a, 0x113e69a545a90dd6]), alt_bn128::Fq::from_montgomery([0x2141b1249dfa3d5a, 0xcaa165fee0b3f86f, 0x97ffa37af3e689cf, 0x2aa89a1fec3b1e49]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xc2f7a0e951602fdf, 0x2a2d08f403992755, 0xcf4101369a2ec9aa, 0x2f99b26d4022cb1a]), alt_bn128::Fq::from_montgomery([0xd9f37083c5474ab5, 0x96d88da23fbc9fd5, 0x14d396459acdff35, 0x26c495b8270f2245])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x7ff2ec22e16e60c5, 0x865657d83717254e, 0x695bb1bd4f9d890e, 0x2fa3208bcf5f1580]), alt_bn128::Fq::from_montgomery([0xa5cd5212391cf6b3, 0xf2b27319b901946c, 0x95429ed2773afdf6, 0x27211090b57574ee])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xd3a39cf2069fbbc2, 0x729f16564547fb94, 0xf36fe7e4be1e26b5, 0x1cc6ce5593c4caf5]), alt_bn128::Fq::from_montgomery([0xeb2501c66ee4d65b, 0xc39db41e4a2bb929, 0xfd6de3b785b8fe5c, 0x1adb578838a22008]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x953ddedaa7afa289, 0xe41f76187b579c33, 0x8d6b50ac140b00d1, 0x2b1ea6cbc1c01e16]), alt_bn128::Fq::from_montgomery([0x043a4888293050ac, 0x201a27afe8b097f9, 0x18ae109eabc3d024, 0x0492290c66046833])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xaa98bfbe8cf94a24, 0x41d5efe9d9f53c30, 0x2b5b0b6d4f5cc8a6, 0x0b60868148e25f1b]), alt_bn128::Fq::from_montgomery([0x8e12b7d43e5062bc, 0x68c421a7efc33335, 0xe6a85b430c9c015d, 0x214ccc424008a7cd])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x087bba12c0881738, 0x30659446ccfca726, 0x13e9203c1ff8963f, 0x0899b480fe787597]), alt_bn128::Fq::from_montgomery([0x990dbd2c4c8f2c24, 0xa2f11982c6f374c9, 0x71f080eefe7d797d, 0x13ac3df9e2683c33]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x3e4784562b4924a3, 0xbe1ec2f8f5e1081b, 0x6ff738b66059523c, 0x0b5d2092dd77e38d]), alt_bn128::Fq::from_montgomery([0xc6014187ab3506fd, 0x93feb606e4477af0, 0x8addee804648fbf6, 0x05521d77806970df])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0bae411dbe2b90d5, 0xd341306c36400d94, 0x31beb94c124331d9, 0x2ce948939fed2d4b]), alt_bn128::Fq::from_montgomery([0x0dc8a10eea057ff1, 0x9873287136cf6703, 0x76bf047ffff14d31, 0x23fd7f7678c3581d])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x6ab26fabae78b240, 0xadef61d5521783da, 0xce036efb3a2fde14, 0x02f10662f28d120b]), alt_bn128::Fq::from_montgomery([0x50782f7afc0dafa0, 0xe2304f1a934a9911, 0x8f4c71754a56916c, 0x22f925866441bda3]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x06611aedb2733222, 0xc4b804058acdb781, 0x78d6ec49d02c8f1a, 0x06024843abda830c]), alt_bn128::Fq::from_montgomery([0xc79ce7402c2aec1e, 0x2d66170988f6f9b0, 0x7f9b7b5023250a06, 0x259926816d0df700])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xc9e6159baea25d35, 0xc10305ecdb171ac8, 0x8bf18bfd6bcec2b4, 0x2c5e468679f0ecb7]), alt_bn128::Fq::from_montgomery([0x2b99eb1076c288f5, 0x402f64eadbc48369, 0xdfc53174dac47e8e, 0x20cc432f10ddd361])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xcb3a33b780ede978, 0x372c5dd084ff35d9, 0x1018d168b801bfbd, 0x0128bb736846c3a5]), alt_bn128::Fq::from_montgomery([0x3105784252ee0703, 0x88dd798229435572, 0xb2036a3fc23681ce, 0x27bfbe5e0a326514]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xf44f23c68267a951, 0x45eca525197af4f4, 0x441e817cda741988, 0x013a37577bfe5824]), alt_bn128::Fq::from_montgomery([0xfa1ac6204ead9a92, 0x6833dd53c385c8d4, 0x4926a4466a71df4b, 0x2843d54ab5543984])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x55d69c2cd3db300e, 0x14bac8288eff928e, 0x62eb44c3b4e4dbd4, 0x2d4f1c3a130449da]), alt_bn128::Fq::from_montgomery([0xda48f9ec230f68d0, 0x12175814f09d5f3a, 0x04fc46006746bc8b, 0x16d8868113ceb3a4])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x717af077e932dd6d, 0xa900601c89f140de, 0xbf4a1975fd43d9a5, 0x158c8af04d26b01d]), alt_bn128::Fq::from_montgomery([0x47c331919da3955b, 0x5b7a6b14769f4c69, 0x8c74f763003ec0f9, 0x0126a986ca7c4d72]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x1da1558338477295, 0x6fc430036e870e38, 0xeda5f3d7713ea61e, 0x2b82ceb659a161bb]), alt_bn128::Fq::from_montgomery([0x16be709bab3f2dd0, 0x9cefd1daa686c3ee, 0xb801d55ae1c09da1, 0x0323754fe05ef60e])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xe9f12c5a5a82319c, 0x3d880337d7828ae5, 0x4af4704b1bd4f89f, 0x1e24f213a0e81984]), alt_bn128::Fq::from_montgomery([0x856a99758c745765, 0x371357fbdc71008b, 0xc62dc4d02b30c51e, 0x060aeb826f87d72e])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x945207b9fe2cf93b, 0xd3ad3707db472604, 0x6af270a5b5b81a21, 0x07c116400bc449ef]), alt_bn128::Fq::from_montgomery([0xb5ddc634f520b169, 0xcd9d8c3051f942f1, 0x824679bdaa523fd6, 0x0cc3278a8d962e06]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xe85adc3741d3b3e7, 0x9a61b00a87ff7ce2, 0xbf0e7c938a0cd752, 0x2d5985515086197a]), alt_bn128::Fq::from_montgomery([0xc631fd9a8e128dd0, 0x73918aa65533225e, 0x5c8e0e234a3a1b96, 0x175cf980fa686836])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x4241863f4766224c, 0xdb3786fc0f372975, 0x92f344792125b44d, 0x161c95d429b62ced]), alt_bn128::Fq::from_montgomery([0xaee9478b42e82ed0, 0x9d6ef3619fbf405f, 0x479834a1cc6e7b1c, 0x1e7e8d46ce4fa91b])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xccb606ac8c06a243, 0x0f33f442f90e2c73, 0x8357886ad2041a45, 0x1b2c5d4a43827e91]), alt_bn128::Fq::from_montgomery([0x0aa08803dd583844, 0xcf552ee8998d5f01, 0x937adab991ff992d, 0x015529aefe0a0f47]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x67b2352f869820d0, 0xad497387c67e60b6, 0xf4457dca881ad94d, 0x0efc8da8542a5f8c]), alt_bn128::Fq::from_montgomery([0x648837ee6c0df764, 0xb07a4544db8655e6, 0x31296be75db9d1db, 0x03619d5e76c1aa03])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x33456f590e9b7eb4, 0x329e2e622f7c1df8, 0x04a3bc2c737bb0f8, 0x00b3d26ee0d1a241]), alt_bn128::Fq::from_montgomery([0xbd4303c9c7cbac5b, 0xccabf7e96f4adca7, 0x4cb866653083c2ac, 0x2788027a7312534f])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xfe042f2adb8369d7, 0x61e838279f0bf588, 0x22ef4fbb72575afd, 0x15f6c72261774fab]), alt_bn128::Fq::from_montgomery([0x0f135a9c14863749, 0x28f15efcf0ee4289, 0x5afd150a88591a85, 0x25934cd9fb96b4eb]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x67e8590b77fcb5f4, 0xc9cc73411243826d, 0x1d21b57fa68c9c4a, 0x0627f60254054e0f]), alt_bn128::Fq::from_montgomery([0x45672155970c966e, 0x1e1f0f11b232a9d7, 0xaac70298fef77e34, 0x065221757a591d4e])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x69053e49c1981725, 0x156460c74d90f019, 0xd9c6687de97a2eac, 0x105dc85cbc64e75d]), alt_bn128::Fq::from_montgomery([0x7b76289bf7109fd2, 0x40eb02e4418d5a5d, 0xb197e4b8e506f795, 0x10cdf907df0033d4])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x4a3c4795aa04f9d4, 0x7249d3462fa84e3b, 0x77a7fe36b15cf224, 0x188336ddaad54317]), alt_bn128::Fq::from_montgomery([0x061ad35aa963bf74, 0x3b8469d76ea3c7b4, 0x5222407cd48e86fd, 0x07c2501f924b186b]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x8eb52f504bd8c2e1, 0xfb7bb7c1afe650a3, 0x9d055079e09dd144, 0x267305a78e5e4489]), alt_bn128::Fq::from_montgomery([0x8357bd5d765fbd97, 0x4596356d845e100a, 0xa8f539a093fbe113, 0x0f837a39bc402989])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x7a1e0dddcf596216, 0x5e3dcb1c5f249b43, 0x60a18e3fc308d87d, 0x1586fccf992247f0]), alt_bn128::Fq::from_montgomery([0x20487f213853c77b, 0x142c2869a7e1d460, 0x3394793964c71a4b, 0x177c624dfa5ca1f8])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x4bcce37ec155d9da, 0xd6dab3848f975f5e, 0x962e9b57cdf2bb5e, 0x0afffa2935777dc5]), alt_bn128::Fq::from_montgomery([0x8d55cf650e2b0fdc, 0xbe0a44f6beaf6614, 0x344e308e58e03005, 0x041ea2a2b156d0fd]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x7d1e729743c52cbc, 0x8434f54f9c54eac2, 0xf549a354653d885f, 0x02ace57fcfc3d94c]), alt_bn128::Fq::from_montgomery([0x7619a58beb0e06a6, 0x1d9d32f42f030706, 0x58ea70afe6b75303, 0x09015ef5d5ddddbb])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xdbc0ee0b221fda27, 0xd68e0a6d90147ccc, 0x48e649b9836ab60f, 0x1bef9633b6abe2e8]), alt_bn128::Fq::from_montgomery([0xc5ce38107446b547, 0xb6e8e0dc6e056d27, 0xd29329c50da5c535, 0x06e354918805c59b])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xbc62d7a5930a75e5, 0xf23132639c3e3504, 0xe5bfad5bd73980d8, 0x0f104e9cc0cdcdda]), alt_bn128::Fq::from_montgomery([0x70fdb85ec1bf0fe7, 0xe63715742032acaa, 0x1519938f3933111d, 0x10d9c5376ee89add]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x034df5e9b510c7f7, 0x4f7977c1d82128e4, 0xa8dac1d4dc548dea, 0x0da4d3c41b4ffcbc]), alt_bn128::Fq::from_montgomery([0x7eaa24a2ab2815fb, 0x5b66d43f88ff53f7, 0x548fe271be3c9a6c, 0x2371b1ceedcd0819])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x916f8d3585a7b628, 0x0600410ecae1872e, 0xcd7e3bc3a89fd21f, 0x1af82202e6e77adb]), alt_bn128::Fq::from_montgomery([0x57f19245bbdafdda, 0xa4403e6f0fa1f2a9, 0x9a43338f50f675a5, 0x0710292bd490cb1a])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x78ac01252fac4b34, 0x3ff809784fb20971, 0x151561b7763a31d2, 0x1022ceb2a3b02c66]), alt_bn128::Fq::from_montgomery([0xb2a919c6c46c5851, 0x27a1a04a4ccc7673, 0xdf51e7661f9c26ae, 0x28790cbd899d3226]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x039e599497da7f9e, 0xc74e0cc64ae9034f, 0xc3385fad14f7a830, 0x088cc8a43c384228]), alt_bn128::Fq::from_montgomery([0xe1421839b5eeda5b, 0x956ad8e337ffc226, 0x1de04d2c118e6b49, 0x0d1bb15af8cbfa9c])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xa43a4cd3a0ce451f, 0x405ff53b8079bb52, 0xf85ce0d997e69b19, 0x08db0482bd81082d]), alt_bn128::Fq::from_montgomery([0xd582944f7d61dde1, 0xca55e573163286dd, 0x39951dd7b03b66f2, 0x1aae79eee6e34cd3])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x40e39ebb96aceb0b, 0x3a66a3d8a9a6ce8f, 0x298349125cc3a02a, 0x01909bdba57cdb28]), alt_bn128::Fq::from_montgomery([0x6c4c400a7f45e379, 0x35d8ebb1fabf6ca6, 0x79458b628ad5736d, 0x1b106e0e0eec1e43]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x4a857cb4dfd665f4, 0xacc2c69578433812, 0x244291ae03b75caa, 0x002411372db7fa03]), alt_bn128::Fq::from_montgomery([0xb9fe776cede66f4b, 0xe2e27e62c22b18de, 0x6cec7b18ff73f687, 0x0f86dcfc29df1905])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0e414a38128b217a, 0x7869d9a8ca14872f, 0x84c533e20f23e592, 0x08fc46a7d89abefb]), alt_bn128::Fq::from_montgomery([0x83ffc15b055cc990, 0x30ba8386cc4fb807, 0xe72aa0c0c8c6627e, 0x00f63447a33e387f])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xfbb4db7371f26888, 0xd7de7280da8a673c, 0x2e1db9d881ef9b97, 0x1f71856fce865d83]), alt_bn128::Fq::from_montgomery([0x476f917a31dfe90e, 0xfe48a24997bbe521, 0x89492d26f259d476, 0x05ea0b53601a4552]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x818111e016e3ae4a, 0x22a346752b553948, 0xa20d2a65a1302040, 0x2b32128f8f5cbe5b]), alt_bn128::Fq::from_montgomery([0x4aaf1b9176487057, 0xceaa27f5126d5fbd, 0xb5f46b35d308a245, 0x07c6ce4111eb4ada])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xfa7d9f9a8b69e7f0, 0xc387fa5a18fb8fbe, 0x3b9d25130d9747f9, 0x2df1559ab60ac03e]), alt_bn128::Fq::from_montgomery([0x815c4f671315ddfc, 0x58ed31f2b2355c15, 0x59d64bd8e0bc93b5, 0x085756150cb489e9])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xbe829a7c73fdbf08, 0x05e89208dff052ec, 0xa255d3cc4ed273af, 0x084255c30aac7506]), alt_bn128::Fq::from_montgomery([0xcb7d37e318404ba9, 0x22ffd4a550e924a9, 0x300d96ab41cc0112, 0x1c6732b1a330a156]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x32c9dcbb70b4df32, 0x49060b2262393cc3, 0x7fcdf5e1df7014c5, 0x107ed0c28bde11a6]), alt_bn128::Fq::from_montgomery([0xec8e41854b1d8802, 0xdf68686e486e0d4a, 0x92adcc162464c363, 0x24a78927bc040acc])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xe956acc036234378, 0x3aa10e2c9fabb55f, 0x3ec530e0b5be567f, 0x169b571721d58e3f]), alt_bn128::Fq::from_montgomery([0xf8c39a7c0c978d90, 0x757b5d336025e95d, 0xdf296cbd6b5e8796, 0x2dde2aa6b8cd86f6])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x619017d5f17c24bc, 0x70f82b42380f0ddd, 0x2fdf7926654c194f, 0x2e93e1e612af0e5c]), alt_bn128::Fq::from_montgomery([0x38012caf066ee4db, 0xf82dc3a68cba82c7, 0x391eba6a89b877bb, 0x19b74ee9b88f204b]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xe167e7fecf5c9f97, 0xfedcfa6467e3128a, 0x7856941c0ad580dd, 0x1691529f13e11ce5]), alt_bn128::Fq::from_montgomery([0x12efb3036ddcf014, 0x93a480bf5111727a, 0xc3e19f6f768f8769, 0x27a98b74973865f4])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xbb9eb206c2b77f2c, 0xa78423bd1f7495a0, 0xb1a125ff028f686b, 0x0f756cbca9aa0adb]), alt_bn128::Fq::from_montgomery([0x5d50a76179f24b90, 0x09eba21b34ed579d, 0x14ae23080d4c181e, 0x2ac235a57341ddd5])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x8896fb2beea271fb, 0xa1c22de346d26ed2, 0x737bc46b71c9d031, 0x234c1df62f2c19b2]), alt_bn128::Fq::from_montgomery([0x60c3ca085d065cc1, 0x1a759b01865cedd7, 0x0d2fbf99de288ad5, 0x0473de680035b245]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x104305ffe030fe8d, 0x49a523354381211b, 0xa4a6123b8a56c51a, 0x235125d9bdcdb8b4]), alt_bn128::Fq::from_montgomery([0x8a68439725bbe8e3, 0x4f09aa670bc3495e, 0x5e0c49907ff517fe, 0x1dd23755a8c7f3ee])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xb97c615e1c66d043, 0x63978052cdd0759b, 0x48dd82a2c3199c22, 0x0c81f9d5ab832553]), alt_bn128::Fq::from_montgomery([0xfd33a16c1de22beb, 0x7571d170385c9435, 0xdd0bd71e4012b71a, 0x12cbac08c08da6e4])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x5112360d7bea0802, 0xcaf6fc54da1dd3b3, 0x4b25aa1a8300b797, 0x08646c1bc4d90148]), alt_bn128::Fq::from_montgomery([0xf8d94707cdfe045a, 0xcd45d47efe77e672, 0x979de9814735a827, 0x0af385fdbf766ed3]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xa077d2ea5164495d, 0x1b5cac70ac39b6ca, 0xc1e182c4bb55ecf3, 0x09c498ec7aa77af4]), alt_bn128::Fq::from_montgomery([0xd1222ced36fb0412, 0x974dbb88f38fcc5b, 0x05248272528bfb63, 0x21b4a5eca9e9c373])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xb0b44bc060d4990e, 0xc993c5af4ea2ebfc, 0x8c1de42db16166a2, 0x1dd86c8ad7801e5c]), alt_bn128::Fq::from_montgomery([0xd1bdc10ec87ac3a6, 0x6b8b7a0c6be3e981, 0x4a1e13e2f5650c9c, 0x17cfe759f4445239])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xcd0159a64910601b, 0x7a9eb836cb5f5db8, 0xa0c771d4ee41ece9, 0x1d2faa68b73904d2]), alt_bn128::Fq::from_montgomery([0xd6af567d60c9a269, 0x6eff30f52e091c36, 0x3bd5c0a9c32ac8fc, 0x0e5811761acc18d8]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x254927e7817bbbe8, 0xd620292439629d55, 0xf908211e4f3c4862, 0x0162018bb36b13a3]), alt_bn128::Fq::from_montgomery([0x2ea3e6969cb553ed, 0xe0a099570e48b6bc, 0x9d60cca58fd3c880, 0x1a58627e40069c4a])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x539e9877182bcdcb, 0x39ea21d32b8e2901, 0xac3408a756fa789b, 0x05972e0d29633a83]), alt_bn128::Fq::from_montgomery([0xf2726c202894c559, 0x7533085bd0e6a562, 0x9731b2e94d39f3bd, 0x2e017dd7f231f1c9])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x23f755d5e49c43fe, 0x085f7e33de5450a2, 0x2e6faae513fc333b, 0x0c8ff36a55bf5c54]), alt_bn128::Fq::from_montgomery([0xac335c4e9ea8ed13, 0xfc88d3cb7a492b54, 0xe8231ea38f0ce90a, 0x29f051db03f1c885]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x980f97a2bafda882, 0x450ea5896ddede52, 0xe0accb051f5e99ab, 0x23c3241881206248]), alt_bn128::Fq::from_montgomery([0xebdf5d8a4b500ddb, 0xbc8e34789fdbe5d4, 0xf810910d85eddf26, 0x2a43181757487633])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x1e5f4f45865ff118, 0x66127b833a603100, 0x898953e3e9e9c75a, 0x09d2b097a2f28f21]), alt_bn128::Fq::from_montgomery([0x8de4127b17e044c9, 0xc0c241fb39e83efd, 0x568716cf1b854f8a, 0x1c37199c39ed9320])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x8a6c51b73e34d17b, 0xcdc90eab4d485264, 0x54b993e8d35cd8f6, 0x0f0625a544297d24]), alt_bn128::Fq::from_montgomery([0x82ce97c7c95efce5, 0x727ae0d8a4340681, 0x8cd6bf0235fef34f, 0x2b97b58122c9484d]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xfc7b38fc66d73eb6, 0xd69b4b7899f14c88, 0x2dd2f6c94e4759af, 0x2420999a0a14f561]), alt_bn128::Fq::from_montgomery([0x18382a205c2aecd5, 0x330cd5e23443109a, 0x0bbc768731749813, 0x05a0912f4623062a])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x75b19e302db01853, 0xaf98dd51b1b73516, 0x8c493317dd93be50, 0x1ef244a3c2e3c2bb]), alt_bn128::Fq::from_montgomery([0x73a57ab05a26cef9, 0x3ad64eca6f674338, 0xd063ac16199607bc, 0x13b1b0f9dad7995d])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x42d2f6bd26d13020, 0x13255daee2c3e532, 0xdf080404d6153433, 0x12a705e2fbb9305b]), alt_bn128::Fq::from_montgomery([0xd36638dc3976fa9a, 0x5f031e630a030314, 0x68e9a7a13c56ffd7, 0x23f55a1e8f60519c]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xd71f1fef9f4c15f7, 0x605fbf60f8066ec6, 0xc644c5f4b591ba44, 0x224a9b1fdf986897]), alt_bn128::Fq::from_montgomery([0x4fbd4e8bb5d04acf, 0x858e794b42e65d80, 0x9981b3aa5e164651, 0x01da582760e539d9])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xcebd593a0818f9d7, 0x5a06f8f32101ea9e, 0xfce198e4157f990d, 0x05b12d3eb268c3e0]), alt_bn128::Fq::from_montgomery([0xad83cd8c028a5b6f, 0xf84c778dd8ad9d09, 0x726beb701af1b25b, 0x2de4442e3ab98542])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x611b26690d701810, 0x53621f798ccb4438, 0x3dae8339ac5c6c1b, 0x097d166625ebd253]), alt_bn128::Fq::from_montgomery([0x6963906da8ee496b, 0xf367f17779dc60cf, 0x20c4cde9e8053575, 0x1e3a245dcd8db33b]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x15ff2c872b33f785, 0x21c28058621915f0, 0x888627d41db5af7a, 0x04f99534f9bff60e]), alt_bn128::Fq::from_montgomery([0xf70de257c54681fe, 0xa2bca08f84749353, 0x7b2bf73f5824678f, 0x017873a7710feef7])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x1d19035b8be91322, 0xcfb5cc429a5e09c0, 0xc9a392f1335145fe, 0x1900c0d1e3a35867]), alt_bn128::Fq::from_montgomery([0x09f092cca1347ecb, 0x2553edbc2c55a326, 0xd5ff0ce0c3331334, 0x24891dbdb3593227])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x225106c0edafb134, 0xd610173b2335dabc, 0xcc2236a337f91420, 0x00d164906cb2b43f]), alt_bn128::Fq::from_montgomery([0xd7602e3a81f8252e, 0x198f2f9470fa83e9, 0xade4ff2eac036a2f, 0x1106205a16445a1d]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x413472b048d70a96, 0xaae9bfe15d15cae1, 0x4ee09733e5e1badf, 0x2af17801d3784fd7]), alt_bn128::Fq::from_montgomery([0x2d816c22b0212f72, 0x0310fcd33b81ad0b, 0x3a6ce5a0604e9342, 0x133c08037d88e6c1])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xf0032ac9cd989ad7, 0x51c3e5f2d3278ec6, 0x2d96251457d4f4a5, 0x1c4effab1183987a]), alt_bn128::Fq::from_montgomery([0x5cbe828584937def, 0xa2854a9b34e8b834, 0xfa11210f5067f241, 0x0744adfaa02bfd1e])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xdaa64afe23cd6e3a, 0x222c7ad17f4ba0de, 0x92d5d8fb24992fa5, 0x2f6ad7867bb56b49]), alt_bn128::Fq::from_montgomery([0xe97054cc5f7b8548, 0x76300bbf54bc56ca, 0x9e081befb28cda68, 0x16b0c39d20b35360]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x48966468368c3658, 0xf65b3fbde3baa880, 0x5aa85c5a42c69a87, 0x2545837c589eb911]), alt_bn128::Fq::from_montgomery([0x5e562333e2a60d52, 0x192c8afada43835c, 0x5db7a8d2888f269e, 0x1249cfe2d73044f9])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x446420cf58030c95, 0x1715271c2d1afb92, 0xb95cf3a91b61af6f, 0x28dab9d3f3b5609a]), alt_bn128::Fq::from_montgomery([0x0027afc1fc4da820, 0x42d0e4d8cc1bee38, 0xcb67e0d2874110f6, 0x0896dfa2580da5fa])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xb15d198b92445c4d, 0xc40f82afd9c34ca0, 0x16a8da05640ea7cf, 0x038251eecd1d770a]), alt_bn128::Fq::from_montgomery([0x5a5f8013d558d8e3, 0x1f33617a4169cbfe, 0xc2ff01c82890a50d, 0x2cd86ed341f998a4]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x54cd624186be4529, 0xfb9dcaeda334cef1, 0xe6599daf213feadd, 0x063642a26c31f468]), alt_bn128::Fq::from_montgomery([0x1d7bf1269d3ecbce, 0xbfe01a2646d8e830, 0xef4cb14d51fbbb7b, 0x2b25f812c999ded8])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x05f5d980a454fdcd, 0x961d729604452908, 0x2e23955666c6fc3c, 0x14e9ea543a80158c]), alt_bn128::Fq::from_montgomery([0xf68d3a81acde8516, 0xe3bf865fe731e789, 0xf5288f045ff94787, 0x079b228a93b18e58])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x8e9a27c50dc9ee02, 0xe768b2025791abd8, 0x554bc3723b209d7e, 0x16ed884c8ec7264d]), alt_bn128::Fq::from_montgomery([0x97b5d7d68772e990, 0x401ff3fee4374df7, 0x524c45b187529401, 0x109d92a18bf271f7]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x5d70b138bb38f37a, 0xa756ed1e0ef05b87, 0x5c71afcd4b876e7e, 0x248519451c4f7cc4]), alt_bn128::Fq::from_montgomery([0x362989a289c931ef, 0x645272406eb276c0, 0xc51ef15e0f404f6d, 0x00f876d854ca268d])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x6ae035244491b51e, 0xc98807f186de1b30, 0xad66874ba52fc18f, 0x0d1d2e15405e4486]), alt_bn128::Fq::from_montgomery([0xa38a9f58163cd189, 0x7df7ce23251aff00, 0xcf3dcf522a97c4f6, 0x147b1a1600e6b713])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x4e3202bd6b59ee11, 0x0cc14dbec3e47549, 0xdbb445e07d5716af, 0x0faf12d54b1a55c1]), alt_bn128::Fq::from_montgomery([0x10f9cd0fd804de6e, 0x9091d9bbed360dda, 0x724dc5467196c074, 0x2d82894b7060aa43]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+],
+);
+
+pub const VK_GAMMA_G2: alt_bn128::G2Prepared = alt_bn128::G2Prepared::from_coeffs(
+alt_bn128::AffineG2::new_unchecked(
+    alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x8e83b5d102bc2026, 0xdceb1935497b0172, 0xfbb8264797811adf, 0x19573841af96503b]), alt_bn128::Fq::from_montgomery([0xafb4737da84c6140, 0x6043dd5a5802d8c4, 0x09e950fc52a02f86, 0x14fef0833aea7b6b])),
+    alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x619dfa9d886be9f6, 0xfe7fd297f59e9b78, 0xff9e1a62231b7dfe, 0x28fd7eebae9e4206]), alt_bn128::Fq::from_montgomery([0x64095b56c71856ee, 0xdc57f922327d3cbb, 0x55f935be33351076, 0x0da4a0e693fd6482])),
+),
+[
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xf7013cafcd07b981, 0x20b1ba2f7b1805e3, 0xaf3083571d71b244, 0x075bcadb3231bb99]), alt_bn128::Fq::from_montgomery([0x8b799f4c7e9e5668, 0xdd0a304b0b61c900, 0x2de0caf3b23e1f8a, 0x021a92c4fc271ec4])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xb50522f2a02226a2, 0x32032ff2e5a65e29, 0x716456a8bccbb4bd, 0x0ecd9f0e6526bc45]), alt_bn128::Fq::from_montgomery([0x740dd5694a4c4f6b, 0xded1784d03775116, 0x0c5dda3a1b17376f, 0x151b0ca5b936d725])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x394e11ae091ba150, 0xbf7d260228ec9aeb, 0x42f839a724862b29, 0x1b9b552aa968f6a7]), alt_bn128::Fq::from_montgomery([0x9a1bd19633543ba7, 0x90d41c7afcc6d382, 0xf7c2fda3ed09c85c, 0x2ea6588893cb1b47]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x2efed21c295e74f3, 0xdb03e00ebcb7c2ee, 0x3c36cf5eb42f5ec9, 0x2a79ed0cba9f321d]), alt_bn128::Fq::from_montgomery([0xb4e0f07a44d83fc0, 0x4fca56ed0aabc02f, 0x98ac9ac1c849bba4, 0x0ca17436e4db85ac])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xe3ad7e66f4eec355, 0x08f85118eb32500a, 0x1aec6bed3e5f19f2, 0x049434352a879125]), alt_bn128::Fq::from_montgomery([0x33c59414f7887774, 0x8197ecf3a2ac8e93, 0xa24830f7f5e261c0, 0x14bdf93a3ee8c553])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x1c3dfaecc733f1df, 0xcf338cd095708256, 0xd519e0ced9fa25ae, 0x06844f9c77622609]), alt_bn128::Fq::from_montgomery([0xbd2d9845ffd66cd6, 0x1ca8ea625be58c40, 0x5026d5c2cf05811a, 0x211283a9386d22bb]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xfea9211ecec3a564, 0x6e3d1cb7b8010c10, 0x23d6955facaa7817, 0x1dc4b6e28d42d532]), alt_bn128::Fq::from_montgomery([0x4e61dec716f142a5, 0x6a06fc1f73a7b94c, 0x294f8112e5a82459, 0x141f3084b9e8faa7])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x29fb68666baff204, 0xbd975495c6fb267a, 0x6f308febefa7a6d4, 0x3000d26f05005636]), alt_bn128::Fq::from_montgomery([0xfb73a17b34e14d94, 0xb16eca93c64f4a06, 0xcfe07b3ff79a4d76, 0x261f5f1b1aded8e1])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x3f8b1a9ec2c4b886, 0x77c03a4da46bbf4d, 0xdf51aed2d6a8085d, 0x0bd4e2137b4c46c9]), alt_bn128::Fq::from_montgomery([0xa7de0aaddcd359e3, 0xfd1f866c4e447ca2, 0x6461073e04e881b3, 0x2c545840e627c999]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0ccb7d9779020570, 0x684d5f22cc67eabf, 0xd9323257bb4e3aff, 0x23556188a3c6e2a4]), alt_bn128::Fq::from_montgomery([0x42b189b53bed001f, 0x3d132a8056cf7acc, 0xd3f63b923836d327, 0x04eaa0365319cce6])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x22a2f59f56ff2a19, 0xf7be12db266bc97c, 0xa3cab5edf8546e87, 0x07d1447702275a9e]), alt_bn128::Fq::from_montgomery([0x8a166398dfd9e84c, 0x32e0ffc89bfb2faa, 0x478034cda55579a1, 0x1ac789066c17e748])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xe811a1f9c169ded1, 0x2ff4748e85f9b982, 0x4b6d79c04a8b359d, 0x062d78a0f77778bf]), alt_bn128::Fq::from_montgomery([0xf395d89feb9def31, 0x58b68b1a37344e8d, 0xd493a1a865e99f1b, 0x1b922d8463cc9018]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x28a58f7c73e46ae9, 0x5f2839075e354da6, 0xbdf4a8566a1d204a, 0x0d613280b8b7e066]), alt_bn128::Fq::from_montgomery([0xd3c3c7611d158e40, 0xad8dd36612631e21, 0xad7ec8f39fd25202, 0x00ee5a7ceae5e7c0])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0342c717992c46dc, 0xb685d2316dc802d0, 0x0a3b1307f5ce68df, 0x29026ff37d5c5351]), alt_bn128::Fq::from_montgomery([0x0853be3ce36d8755, 0xfeb80d4e0eb4f17a, 0x2ed6a53e84906723, 0x23970dc9a22bc068])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xd29bbeb8d995b074, 0x76c20905c6ed8bb9, 0x12eb54c5b08fda73, 0x10be5fca4c3dc5ea]), alt_bn128::Fq::from_montgomery([0x13fa5c105546445c, 0x9e44fcd7055add54, 0x47de830b61281415, 0x10c62b458b2e559e]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x87849f07afbca6f9, 0x0899af1fe08f5e18, 0xea2d74a4a35a57a9, 0x1f62e49d6a1fb9c4]), alt_bn128::Fq::from_montgomery([0x454085c3bfc2434d, 0x27b6a0833c433647, 0x07c0e3e625b5efb2, 0x1638f689430d3036])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xeb5019b99d5c74be, 0xbde68b1752a48f85, 0xdb3a4052ccea3d34, 0x2a2ddd4321d57b49]), alt_bn128::Fq::from_montgomery([0xd94674b2dea2d662, 0xf2fda424cd199192, 0x78cc13819bf82f4a, 0x2aed01b37c5eb208])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0520101a61c14955, 0x4d67266905ade7c5, 0xe3c167f7d2362481, 0x1ee24cdfb63c3e1c]), alt_bn128::Fq::from_montgomery([0x5c1ff655d09b3e8d, 0xff0c16e817a2e05f, 0x84564230ad985e00, 0x22ae58b315872434]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xfe5c480144c8f8da, 0x099c597115eaabe5, 0x8d3c872b188596ef, 0x2840e8db0dbe4a75]), alt_bn128::Fq::from_montgomery([0xe78f803e4ce9966a, 0x0cfb121eec035bdc, 0x3f2f43bd5fbdab9c, 0x0f278d4f69d68874])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xe45d6264650b83b6, 0xc9bd34820dabd4c8, 0x55fc14a8483b0f71, 0x11ee82cba4c2b22a]), alt_bn128::Fq::from_montgomery([0xa771bd6837f1966d, 0xc5a244cbdc3297d4, 0x49b6e8d7480e300d, 0x05c09a399869aee1])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x726d4a455d6b2aeb, 0xd04754f38c33e0ba, 0x1a6dea4a281e9ca0, 0x1402b3e622707b14]), alt_bn128::Fq::from_montgomery([0xc33ae34fa776238d, 0x45a2d36640c79910, 0xc6d0af9e1614d4e9, 0x1c25125d327ebd0a]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x65ea247ec9e56563, 0xdefefcb0975a23dc, 0x475a754de1a15956, 0x0216750abc199732]), alt_bn128::Fq::from_montgomery([0x398f715fe1bdebf4, 0x58f2ea447ad5c66d, 0xe7384342310a4c93, 0x2b015d5c243e3ba8])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xce9aeb4f93856638, 0xe70c8ffb5cc53913, 0x6473e27cb9b227b0, 0x2456158b8a69c9fa]), alt_bn128::Fq::from_montgomery([0x588a13b8ed6066c3, 0x622183b15aa04de6, 0x2403e41888f9c81a, 0x01f954fd5a78bda9])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xaa9ff5c4ad64f9bf, 0x36fa2f3c58136dfb, 0xd8e5492ccb6196f8, 0x2c73165e48679f6f]), alt_bn128::Fq::from_montgomery([0xfd4cd8f8cd0b80b7, 0x5646e5cac63c23f5, 0xc85e869fdefb7f34, 0x0e884cd056073d11]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xca291d34b49f20a4, 0x9814290e3fb64869, 0x6ab09397993475ad, 0x0b92870e059b9fd0]), alt_bn128::Fq::from_montgomery([0xd6df95ac7b093af6, 0x5f6158636ce0e6d1, 0xdf87004884eb8396, 0x1a870412dcf8cada])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x7ff5f0c031444983, 0xf8f68aab295e7dd9, 0xe0aa3480e040a6ac, 0x2ee8396d5d019a2e]), alt_bn128::Fq::from_montgomery([0xdafd5a6ade50bb5f, 0x05f4bb87674a3300, 0x70e8fd7fca08e520, 0x0b5cf019b56d8e58])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x9b6e62247ad831a3, 0x379de1e76b3c854c, 0x204729f7c93fac0f, 0x1905ce6dcf7ad25f]), alt_bn128::Fq::from_montgomery([0xbae2eff666d83783, 0xcc41cf0eb7dc15e7, 0x42e765cb75a416a9, 0x16d554c3cda3d1f6]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xcfedc2c45061fd6b, 0x00b71e5f07976125, 0x0a5af33508f524c0, 0x0f347900d85a0c59]), alt_bn128::Fq::from_montgomery([0xa2fd71c4e6c86708, 0x9301596dba39b835, 0x72a6b67869a03482, 0x2aea9bb23a1e466c])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xe744e192f4e7890c, 0x5e449228354bfe67, 0x7c8ee9391c5e2c6e, 0x16e669586965ef35]), alt_bn128::Fq::from_montgomery([0x35fedffe10f031a5, 0x812acf49e456246a, 0x4d377dfd0113ef92, 0x0a528f096845883f])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0dac3418b99145d1, 0x6cb83b13247150ad, 0xdf06995535a9d4d6, 0x0f9c344da44ad683]), alt_bn128::Fq::from_montgomery([0xa5be0d350aa4e16e, 0x766e82867b070fb0, 0x159fe74a5cb96533, 0x039892f779641936]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xc155fc9e6023e594, 0x842f9b1e70ed8d74, 0xadbe86498dcbe78a, 0x05dcbd5d95d4422c]), alt_bn128::Fq::from_montgomery([0x9dabd46597764642, 0xd59d059ccbda4a7e, 0x24287e5d258b3747, 0x01f4354d70351bda])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x98978ba7719cead9, 0x33662e4ca52a4d35, 0x5282fffb55276b14, 0x1c4ac579c5b05d24]), alt_bn128::Fq::from_montgomery([0x8989d450efbebb0a, 0x358799af47eba294, 0xdaa3a1e40b428750, 0x09bfddaede8411a3])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xf1365a766ca599f7, 0xa80b082d933ef8e0, 0x4d1d604b017df2eb, 0x14134ee6fbbbcb35]), alt_bn128::Fq::from_montgomery([0xa0c2d1f875a4afa4, 0x1a4cf85c57664b41, 0x8df227a70bd168f1, 0x14fb6ad44d7d3e92]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x8b740c2a314ce111, 0xc74a39532858464a, 0x8393e548807a6a76, 0x0fbf587f080a16a4]), alt_bn128::Fq::from_montgomery([0x57d1bcf92489d42e, 0xd2acc418bd6b2ace, 0x58bb7aac534ad8c7, 0x26c71a5e59fb0864])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0906ad1fb8d9f76f, 0xfafe839c2de34664, 0x6d9aa536835f1cd4, 0x29417bc9cdf5ac09]), alt_bn128::Fq::from_montgomery([0x0527503df6e04b51, 0x3332c3a09335a9d1, 0xac9f09bbaadf8318, 0x296f5aa7e66e2151])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xbede277c8acc429c, 0x8f69675ace4729da, 0x85b876546c0abeef, 0x09502733fa232433]), alt_bn128::Fq::from_montgomery([0xf5975441678e2241, 0xe7086e81e2029b27, 0xe1587a3d528311a5, 0x27b010115c5a2fb2]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x1d06b49d72c7817c, 0x8f1c12f0a326dffa, 0x2968f4d7ff81c85e, 0x2b3c96c33dc1f14b]), alt_bn128::Fq::from_montgomery([0x01ad3c46aed1f6ec, 0x64ad33a923677063, 0x92e91345aa8cac93, 0x00378db130216f21])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xb6a0f59a3260e7ad, 0x7fdc1a2bd4decb55, 0x5da483ef0c323429, 0x2b7a5437e1be7918]), alt_bn128::Fq::from_montgomery([0xa086a8d680b06882, 0x8345925dcb3650df, 0xaa010d13e2333418, 0x1653c8cb00c901d8])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x3488ce776ba39b9f, 0xd740a0a50a3e9e46, 0xc2ce0a1b454335ba, 0x096969bd6beb2e27]), alt_bn128::Fq::from_montgomery([0xb743e93773210a8f, 0xe9451a73e019c421, 0xbefb3f4af8d641c2, 0x0ef62bc47abcdc82]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xacea119e0b33ebc8, 0x25c609545be021e5, 0x028c7b114b562219, 0x0ee4ef5cec2e9d4e]), alt_bn128::Fq::from_montgomery([0xe561a793a61d2fc7, 0xd1bc057b92a8321c, 0x1692a9f0388b52d1, 0x1511a9c146499b4d])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x69bd9ab0fab67fb6, 0xacf1b913bef28466, 0xbb72fdc4c5fa5f81, 0x041f38adf46bed46]), alt_bn128::Fq::from_montgomery([0x1c6a5014c3916034, 0xde1dc78e88a033c1, 0xf0f458953dd7b4e2, 0x24603879f7324277])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x79682bb7256deced, 0x139e617f48c91574, 0xcd12aa7cbc5fbd21, 0x12e31add2c158591]), alt_bn128::Fq::from_montgomery([0x77a3c5ea09ba748a, 0xed43b492f971253b, 0x6c52909f8aa6d1f5, 0x2137f5af79996841]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x38b38533a7afcd6f, 0x723c16a26eac8254, 0x5c741f6ed92fb0b7, 0x155e0a999901be30]), alt_bn128::Fq::from_montgomery([0xd85f35fb92c25db1, 0x7364d1e1976f2c20, 0x09769d76323a9e67, 0x10ccda6a5dbe09ab])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x6c48c8c121568222, 0x2bc3bd14feccded8, 0x93fd46806c14a3bd, 0x21d7203588f81a5a]), alt_bn128::Fq::from_montgomery([0xd5c727bf4a227e00, 0x114c527bf25b7952, 0x52d360d0d11feecf, 0x0732c36c98f399c5])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xa059d986b41fbff3, 0xbdfe567c0c20a03e, 0x44bba7ab968a543a, 0x1e8b0e0a5d2fb677]), alt_bn128::Fq::from_montgomery([0xc0ab0c50e8dfbae3, 0x64576666ef046633, 0xcc8992f7df20aeec, 0x27735b3b5d4255d4]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x6f507626ed19622f, 0x536d8fdeef18f874, 0x6049961326e3cf9e, 0x1d7e97a81bb9072b]), alt_bn128::Fq::from_montgomery([0xc37d68a367c5d6ce, 0x8a6cfdc7e6eaf21b, 0x8ae073964839be1c, 0x0b5ca2f0eca5f24f])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x232514f0f6470c99, 0x19d5b09f5cc52b41, 0xcaca476417e71275, 0x0bc429d2765e7b52]), alt_bn128::Fq::from_montgomery([0x684abb9b9120cd3b, 0xa03db7596af31e35, 0x2d8d4f8b569bd62d, 0x2ba478f0dadbc622])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xa756dbe5219a5645, 0xd8b3ae0b71686828, 0x4c4c6a1c5350aff2, 0x2e03e8d70434d5ad]), alt_bn128::Fq::from_montgomery([0x091941f97c529057, 0xdf2e8c89ed5b0520, 0x405493544192edb2, 0x02a1aebd9e156c95]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x70768128d28ab674, 0x3acc9021237810a2, 0x50876e289ce51002, 0x049b25e13b469ace]), alt_bn128::Fq::from_montgomery([0x0415317af04e83eb, 0xba4ca39e2b296281, 0x97eb6b042928d44c, 0x0d29b8aee25baa3f])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x23deac9e1cb34a95, 0x665326c8350ca7f7, 0x507ec7410ca1ffcd, 0x09d40267dec373d6]), alt_bn128::Fq::from_montgomery([0x587f153be97e3f4c, 0xcb17a0d50b42f1eb, 0x1fb05ec163d40e5b, 0x257131cdc0f4e77e])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x6d313f59c9b2ec44, 0xda36e50109679950, 0x052e961547d677f3, 0x01bb278ff83ffae1]), alt_bn128::Fq::from_montgomery([0x051ab00b229a92d4, 0xb15605cc7351c595, 0x24097e4b5f31fc17, 0x1d4fa7ef5475c510]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x5e5c8d70584fba94, 0x3d3e6cb662d22eaf, 0x919ceaa9c5a7ae29, 0x267cbafa4694bbef]), alt_bn128::Fq::from_montgomery([0x604c66f6b6139d26, 0x94dc75782885baef, 0x875ff71fdd365334, 0x2c16f483a686b5e5])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x578ada0f3d8757f4, 0x6a0521d0e51bbc4f, 0xae84d0bf7e7cd3d9, 0x1d8c120ac80adb38]), alt_bn128::Fq::from_montgomery([0x928eb6ab59853ef5, 0xc7e46b70af7062aa, 0xe27c1aa253caac91, 0x1d9ef06fa71f2832])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xf99924b76950a25f, 0x03ad8a30e5ade56f, 0x5e9d1b2203ffc4d0, 0x05f89371abf9924f]), alt_bn128::Fq::from_montgomery([0xf27c63c854f6b630, 0xa3250287bb599a96, 0x52bfc2235b649bf9, 0x083c67786f730bb2]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x1b6c538d7be7ff6b, 0xeb54174eeea6bc7a, 0x46144629afcdab1c, 0x15fe69fbacf341a1]), alt_bn128::Fq::from_montgomery([0x75e4f96c7e63443f, 0xf5f43e702756f9e2, 0x36051896e4a4ff51, 0x0fc12bd98bd733fd])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xd32683655760303a, 0x6e8b2f94c248a659, 0xc4c7d8016c85b908, 0x02260aacc630ab53]), alt_bn128::Fq::from_montgomery([0x2ad8ee71fc46d498, 0x16676bb238eeec56, 0x0d7ef4fd078af496, 0x2e9dd4ae0fb942d4])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x6a87a3318b8b9762, 0x74563d20eca3e5a2, 0x6ddd0df07d874192, 0x0195079303f47f2c]), alt_bn128::Fq::from_montgomery([0x3971973f1ca30b4d, 0x54ca51cae92c3747, 0x442584be83fee369, 0x2d4b269d78d21a90]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x388af24d0d3abb83, 0xb2e2200c3269359b, 0x5cfd1acc493d61a8, 0x17c05e5f038ced31]), alt_bn128::Fq::from_montgomery([0xc6222da6250e6be6, 0x1f7ccaebe4262c8b, 0x1f549a402ddfc438, 0x2e4a7e719f716609])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x24bf27883134fd95, 0xba9c3fe753a3e6b3, 0xad6666b4fb04ceea, 0x21a7f4ed203b7893]), alt_bn128::Fq::from_montgomery([0x8367a8c4b461ff2d, 0xe12731de08e3baee, 0xd6c8f4a63ce3ccc9, 0x2b5b6f7f038f3ec2])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x3727df051e872ae8, 0x6f74c53342cc38ee, 0x93eb6f03230b6c03, 0x1ffe3f18b633ef5d]), alt_bn128::Fq::from_montgomery([0x888a80542b22d6c7, 0x5b01b66139e23129, 0x13f0a39cec187172, 0x0f71a4d1986cbd07]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x126b371ed53b0f18, 0x900f115eca54528d, 0x0ccb56cb7526f063, 0x28c93736f49e2516]), alt_bn128::Fq::from_montgomery([0x28226bcb40b61d7b, 0x1d741252b803fdab, 0xb3f7de55b20fea8f, 0x0f76817a7014a261])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x9efffc4b036edfe0, 0xe04e98c60def2626, 0x898f269df32a1af7, 0x1aa59ca2731d2311]), alt_bn128::Fq::from_montgomery([0xb9950026d9683c95, 0x7a157e29c49acf05, 0x833b14616bcc3a6e, 0x2931278defadc9ab])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x3cf933dfcbb9c012, 0xf312cf9fe49246da, 0xfb0569e7f276bbc5, 0x1defb7401bf4be8b]), alt_bn128::Fq::from_montgomery([0xd6018db4428b1b85, 0xce83d6512198ebc7, 0x2d4497a7e448b3f6, 0x16dd5686f7f74104]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x93e65f24eaad7703, 0xc1d1105fbbe8f1ca, 0x4d6d2d464a2be497, 0x199adf5933c7e837]), alt_bn128::Fq::from_montgomery([0xb4c395c08b2b544c, 0x6f7f18e92d9fbbf4, 0x0bd98effccda9666, 0x2c6cc261ad0722b0])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x852559fa1011d649, 0xbe5d85820832c577, 0x01decdb5b5c7fb3c, 0x222937cfed4e914e]), alt_bn128::Fq::from_montgomery([0xba41f9739d0d2e2a, 0x2dae9ecdc262dc9c, 0xc8a1fcb900f30f65, 0x0c91fd22b89322cf])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0ce35c2d99601161, 0x58827118cfcbde7f, 0x31550d6149748b74, 0x177f637b1923f51f]), alt_bn128::Fq::from_montgomery([0xeb12c478cec1d993, 0xf0da56e08f59aae6, 0x98029acf6f3a20ed, 0x0e9b200342e3298c]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xf501c860d6a7645b, 0x698c9148041780e9, 0x2a5206618971eced, 0x294c8ff7e4364620]), alt_bn128::Fq::from_montgomery([0xe17186fdbd5c84d6, 0xe8d2c8b25f4d1fa5, 0x14b123cd1957e3e3, 0x0035a01884519239])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x4fa42c3666ef25fe, 0x2251523a72b598ca, 0x15b8fbd9d0a096b5, 0x252f2f34911b1e0f]), alt_bn128::Fq::from_montgomery([0xf7831ab7a3e1ab54, 0x8b16c742fb274a45, 0xc69ff64b95006b22, 0x03ab602762d6e0b3])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0178ab78d2016eb0, 0xe7dafc4d1d7be2a1, 0x1d72fc048d421dc8, 0x18fe80615f836fd2]), alt_bn128::Fq::from_montgomery([0x6bc0abd056df2a7d, 0x8533810c9e6d4547, 0xbb4276e3fdab3205, 0x2db6d4e99baf14fd]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xb28acc96477a9fb9, 0x411da3b2143799a7, 0x8d721aea679e1322, 0x0df64140640054b8]), alt_bn128::Fq::from_montgomery([0x28303ee490329d22, 0x4803967640562c0d, 0xfa62ab53d563e289, 0x2791011b19e8cece])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xd29db18509174c62, 0xf0b1a7917b4ef4b7, 0x1f51ddc2a18f6ab6, 0x123eb1ed21e78879]), alt_bn128::Fq::from_montgomery([0x1ba635583cb4cb25, 0x90a739f8a6960048, 0x75aa25e9a7c422ac, 0x1f1b3c02531d8c58])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x396022ca6751c62a, 0x7043aed9ca8473fa, 0xe020a8b842c0f809, 0x15512046f65cd5af]), alt_bn128::Fq::from_montgomery([0xb5bfaf2da2ec57f7, 0xea9f542d7abd0c44, 0x1f561e85d255ad7e, 0x1114ceb7372b7884]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x6ff05b8bcb9e9613, 0x909cdb1d4c719afa, 0xe17f573d6961ceb1, 0x17b18a3c8ced35f1]), alt_bn128::Fq::from_montgomery([0xdb4fa0a7c0b5f5f7, 0x0259e1bdae6a2f54, 0x357ef1ab3be2555e, 0x2230833e262fbc87])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xbcdeeb2d7c4c0782, 0x96c9ff73b5f792be, 0xada255e6cf75ef36, 0x1314915af7eb7859]), alt_bn128::Fq::from_montgomery([0x5bbee601863a21fe, 0x71e95cfb84f60c87, 0x36c06af40eb2efac, 0x2f4a89319087851e])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x8fe2569622ac0eec, 0xcb3bbf3af1c85c5d, 0x0b0dc13c5485eed0, 0x2ad4ffb5a7a3a1bd]), alt_bn128::Fq::from_montgomery([0xc08af4d6b7a848b0, 0x76531e8e6cd3084c, 0x3892051ccc98ca90, 0x0a1bd5dfd5880e9a]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xdcea1b30e5de3b0a, 0x9d9efbe32ae1f2d9, 0x21957b23f576285e, 0x160cba9146d74ef6]), alt_bn128::Fq::from_montgomery([0xddb1d0ea8ee488af, 0xb029a45042264bd3, 0x61365aef579d2b40, 0x26e21632bc218ac4])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xe022557941e892b2, 0x421f7f4db4af161e, 0x19311490590ecd85, 0x16fdcde746b3549b]), alt_bn128::Fq::from_montgomery([0xc4d705b11a3ba6c2, 0xd9ef76f52835e9b5, 0xb4a813c6641bfc10, 0x187f4410b2da477b])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xdbc749afc906992a, 0xff29a87c4b456a23, 0xeb641e1cd9891295, 0x1b1829a6c1d73245]), alt_bn128::Fq::from_montgomery([0x861dec7ba843ec5a, 0x7bf8a0371796c6ca, 0x3ac5687b404ef7c5, 0x23c207737b0d1924]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x07afa578ed210bd5, 0x6817a4c56a9e555b, 0x19c86a7f1261a1c4, 0x0d74d145f00ea123]), alt_bn128::Fq::from_montgomery([0x3b38c0105c943c8f, 0xabe985d2b82a9ec6, 0xcf0295212a26ccc2, 0x2c2e5bb36de9e6cc])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xef1890fd62e2caa7, 0x31d0f6eead1246f2, 0x5b9a3c66c9b3e820, 0x083e6468d9b91f70]), alt_bn128::Fq::from_montgomery([0xd8c58826883e86b3, 0x51f0934784233345, 0xd06063e5aedc3f18, 0x2786b801a4658d07])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xa6944763fb9f4be4, 0x26ee02989f5c5297, 0x8dcfc5bd1a926c67, 0x1292b8482b9a320e]), alt_bn128::Fq::from_montgomery([0xab7534e0056c4762, 0x799b4033af61c6ac, 0xd212b20e381ac83a, 0x181540653e77a4e3]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xec946111d9bdefe9, 0x15ce46adec6712eb, 0x3af7d094e084145c, 0x2d38bb2547f0d7d8]), alt_bn128::Fq::from_montgomery([0x2a42993b91fa4492, 0x637c81cfcf501bd7, 0xa06f651009637e6d, 0x05f6c03d12eb0353])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xdab199c76b235845, 0x4aaa8331c9638ac7, 0x120ce289c7c21233, 0x304b1976f65b9a77]), alt_bn128::Fq::from_montgomery([0xcdf44fe449170a42, 0x94c9ceb3ed6d4d1f, 0xc9f6e14344c8bae2, 0x0a6bc063249b6abe])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x14134deb214bfadc, 0xb0b34d233594f23a, 0x876c66af0aba4c0c, 0x0e0a61c5aca038be]), alt_bn128::Fq::from_montgomery([0xb762ae5ed386345c, 0x2c31c111e38b32ff, 0x63a49602d6126dca, 0x252a0bb86d4763d1]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x89e3c0a9c6e38e89, 0x4cb758fca84115cb, 0x372d0f8ab3935537, 0x20b81e379b7d2dac]), alt_bn128::Fq::from_montgomery([0xf3d95c5a7824a9c0, 0x68151e110a639946, 0xaefc20c432d46398, 0x300af4b31745a500])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x8d0101ba0c573372, 0x85865a5970feb2a5, 0xb277116c224f8cfe, 0x25ace24b5f1ac0bf]), alt_bn128::Fq::from_montgomery([0xdec40e9e1f6c9559, 0xd66e6055cf25e74f, 0x862e4a4fa5eb3574, 0x25fc9b9a9aadd9b3])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xb45cd464d8018865, 0xa5ae56030814e904, 0xdbe7bd9554bb6176, 0x0d624e91652ebde6]), alt_bn128::Fq::from_montgomery([0x3613f3fbfd923a33, 0x59695f51eda201b9, 0xbd584c7a351c0e29, 0x2a69021527f6851d]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xab7e2eef4f9b8d30, 0x80e046f51f3d3eed, 0x6560d64d84400394, 0x03f115fbf865db23]), alt_bn128::Fq::from_montgomery([0x9915315e06db029f, 0xab74bb043422076a, 0xab29be3bde7a2883, 0x190f9cb2d73100ce])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xcc82a40ed35fe45d, 0xb5c14f1bf1e5180e, 0x3802eb70870da252, 0x252e666e758c073b]), alt_bn128::Fq::from_montgomery([0x0bff4b93038d2d5e, 0xc20b1a81c7b4c4e2, 0xd70adbf6a3e525c8, 0x3049ce53dd1a0ae8])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x9867026545c81f0d, 0x822edea5556cf8d0, 0x4e8950cbd9d08c1b, 0x203aac3bee341fd7]), alt_bn128::Fq::from_montgomery([0x417f1d0e2c3924cf, 0x99c79e40afe71c12, 0x5ad3b19d3573526d, 0x1d5b448523e848cf]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xf2eb9055da2ca822, 0xdc53370a0a80a815, 0xe351c2ab6cafa2ad, 0x09fc0210cb3cf1a9]), alt_bn128::Fq::from_montgomery([0xe46345febe5adce3, 0xdab07dc68e282d4c, 0x60e246b4852f31a8, 0x12d71243792bdb60])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x3e1df53ea30bdc68, 0x29b7d55a40040021, 0x94943a5c0dbddc74, 0x01e33b22d7e36d4f]), alt_bn128::Fq::from_montgomery([0xb1014d0f95e084f6, 0x4991645397f294ad, 0x5360ce7c79754e15, 0x079fbc49f3f3836c])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x43e514756c68837d, 0xe38da165b4a926f3, 0xe09b426f47359436, 0x1ddb7eb622c44d9b]), alt_bn128::Fq::from_montgomery([0xab8cf0c95bc49081, 0x43a11382a9e73989, 0x82b55aa7d7f48faf, 0x0bd675c2f313ff58]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x8801a3de3f4e9dff, 0x81cbef3ee2693929, 0xfe9f8ff995930efb, 0x142b404ac539d80c]), alt_bn128::Fq::from_montgomery([0xfdf34fc1d064e7b3, 0x59a1f89ea8a556d2, 0xf4b9137cecbf91ec, 0x1371820bffe5c42c])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xa90b185654c690a6, 0xcae8761e8b25efe3, 0x5dd13b950ab49c49, 0x28e984dbb40f2ebc]), alt_bn128::Fq::from_montgomery([0xa2933069224ffd89, 0x7617c884fc2f2139, 0x16b7f1828dfddc49, 0x0357c67d5640e543])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xe7d6cba984465aa5, 0x1fa7c6658d4bd6d4, 0x1f2c2da84fd4a68a, 0x11022b6674369c4b]), alt_bn128::Fq::from_montgomery([0x426a6ea09d5fdd37, 0xa9d99d8c8173d87f, 0x2d947eb8fcb048dd, 0x2870ac4a485b1b06]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x91868ea202e89654, 0x12f152d066af89a2, 0xec6f8c293e7eb115, 0x280cf3249216c463]), alt_bn128::Fq::from_montgomery([0x511ef600c35861a0, 0x913c43e0cb01e286, 0x6283027ff628b6f9, 0x0c47390dd13ee466])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xa748ed056aa5ebc8, 0x8781a7a9aa7c8b0c, 0x4ab4e9e658a42693, 0x1c997eb9b6d85f49]), alt_bn128::Fq::from_montgomery([0xd14ff39e80cbd676, 0x9ad09bcf48532154, 0xae2ae93cfb777d4f, 0x21a83ca907f193a8])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xc23b0eae8503c03f, 0xa58f7200fa877b38, 0x85809be2e6686218, 0x0359d52f3263ac59]), alt_bn128::Fq::from_montgomery([0x9dab815d56acae16, 0xbfbf941fa0514bca, 0xb433100c4084cc33, 0x2b0c6fcb01ce0957]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x33aba4dc1aa9bfe1, 0x43af4f886d81d204, 0xbe87db5681695113, 0x019c2fe648249c99]), alt_bn128::Fq::from_montgomery([0x155d7a90a230105f, 0xa2531eafe6834112, 0xec11d3e001800104, 0x06ab869f75176283])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x5ec8cf9ca1259058, 0xae1f7c58a5fec748, 0xfc06ddd6f17b571d, 0x180f11e20beaa18f]), alt_bn128::Fq::from_montgomery([0xb680703c30e90ec8, 0x2aac6b178748a6ee, 0xa4e1a2ec9e278281, 0x014f06d3239c2b6b])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x8c368c56927835d2, 0x019893775de80dd9, 0x90d5eb7784662123, 0x1805ba7ce6e1dc5b]), alt_bn128::Fq::from_montgomery([0x1fbc46cc06831a49, 0x3f09da24c85c9184, 0xdadd89b6c8144420, 0x2aa587994430598e]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x5cacdcc4031b6acc, 0xd1fc4c43f0aa93fe, 0x600fb8163bfa52b0, 0x228e85c05ad8566d]), alt_bn128::Fq::from_montgomery([0xe9abcd90f496d4b4, 0x5aa24a124cc97447, 0xdf272eaf321e10b2, 0x1a89891a88f3b78d])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xb57f0965a4c22dfc, 0x0c2d9101c0ff9b83, 0xe72cf4a126cfd5aa, 0x00b254735cc83d02]), alt_bn128::Fq::from_montgomery([0xcb0ed3bbf989a60f, 0x6d0095750399bf26, 0x34f92bd4dd2c5251, 0x250bba25f7756668])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x32aeb8e6b0cd4fdd, 0x7b194a75fa28c895, 0xa9c5f952901f3f1f, 0x13157a0440a96455]), alt_bn128::Fq::from_montgomery([0x8c61050ea5c50478, 0x6f16b1db3be8570b, 0xc1c56fe69fa80ecf, 0x2e2c73215dbf386f]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x6019bda445acf62b, 0xac14145e84bc6086, 0x31da81ea5fd348c6, 0x22cede4226f9342d]), alt_bn128::Fq::from_montgomery([0xec9efad345305e5d, 0xd3e1ce6ba2743d39, 0xcfcb41fc9f1f2337, 0x2892c1e301c5234f])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x2c0134cbb8d305a0, 0xbaebe55d2401ff6a, 0x1865e82921c4f0f2, 0x2eb35b3793ced10d]), alt_bn128::Fq::from_montgomery([0xbfd857fcdfb2e017, 0x21f2e36e23934309, 0xdfe7496d6485aa54, 0x28e26b300744520e])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xb03cb4dc1953ee5f, 0xdd41a9b8a166d392, 0xbe8e00dfbf00c1ca, 0x09814d69b09bccb6]), alt_bn128::Fq::from_montgomery([0x20837169e61a19e8, 0x36086f2d0f0fb5a7, 0x5398d5b559373312, 0x17695ad39b494cfb]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xbdf8d3dda4daa03b, 0x1210acb0d38486fc, 0x6938e6133145d90b, 0x20a1318b891925ff]), alt_bn128::Fq::from_montgomery([0xa74bc0bf0bb74764, 0x8b74ca579fd772ac, 0x0a6b7927826153d3, 0x1f263b55a725925c])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x49631bf6605c7807, 0x77351f220afc0037, 0xab97d935a863034c, 0x0f1bd409f327bd7d]), alt_bn128::Fq::from_montgomery([0xd6ce8a2365525df1, 0x12aaccfba80a7892, 0xb300278bdb249e07, 0x20dc5d32c5a065e3])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xa014972294d9d8c8, 0xf8276f906c396ced, 0x51a00940fc172afd, 0x2d752f393a151932]), alt_bn128::Fq::from_montgomery([0xc802304974131ada, 0xf77635fd881e3f2a, 0x50a3c58e89faf395, 0x295e9e9465718e91]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x21bc0dc36346f821, 0x177fc4aa7e00b9db, 0xc08ea816d23f8f36, 0x082a188d2c44c7a0]), alt_bn128::Fq::from_montgomery([0x57989962e6db8ea1, 0xa2dd3f9242b6870d, 0xfdd54e890e2b5247, 0x207bca86cd8b68e5])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x98dcef3bebb7d399, 0x3245340ee3a6a84b, 0x6bc3daa9136ce81d, 0x1c2b7bd9699fc6b8]), alt_bn128::Fq::from_montgomery([0x92ef615880e685e7, 0xea34b476131e0535, 0xc81e9a077a8dcf8a, 0x0dd8a169da69bf7b])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xa8f196761ea30296, 0xcb4021fcf53ee48c, 0xe24501c369389000, 0x300bd4d81b9029dc]), alt_bn128::Fq::from_montgomery([0x1d44c5e5fd8b98f9, 0xecb317ef225e1247, 0x73d6f3814eae0921, 0x22d17c1334637ab1]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xe5392a7e4e5d8e23, 0xcb0dfd20b15435ab, 0x7dae5523709a7d77, 0x2b99448952c84ac2]), alt_bn128::Fq::from_montgomery([0x0c945830a86f4393, 0x8f51cabaa768d006, 0xc16bb1c315c1a74f, 0x05bf183ef3709094])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x5afdc027b2367183, 0x3630ce4a1e174afc, 0x61fe267eeeb92d7d, 0x0fc8756bc1024b1c]), alt_bn128::Fq::from_montgomery([0x1fbc145365f3b0f1, 0x57985eb9ffa07ca8, 0xc0b37c4af6e01784, 0x22bb2ef66ad2547e])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x9e4d0e602776b2c7, 0x0ffbff84d79a1220, 0x6ce8afca4c4bf3f7, 0x1d181166362575bd]), alt_bn128::Fq::from_montgomery([0x4e7215d76454935d, 0xbf95376c005496b8, 0x8fb468a56c5086b6, 0x1ba57cbe2ba23db9]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xd7ffcf2392aa8f1e, 0xf744acc69671d946, 0x1b59cf23927dfde8, 0x0125b9fd3c988653]), alt_bn128::Fq::from_montgomery([0xe6dc05fca5312c94, 0x767a3db5622eb3cd, 0x0cd976241202cce6, 0x1673eb55e773f22d])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x5e121f3477b2fbad, 0xba2357994b630d9a, 0xbb02280fd2b3ab54, 0x00bc471ee765e54c]), alt_bn128::Fq::from_montgomery([0xdf9a8ecaec268657, 0x666c192d85539238, 0xb2eb2f1e0d089e6a, 0x2e0faecad2c85605])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x9e3ee94c6fc86895, 0x57b3df6705bfdb78, 0xd7f703183c5dc225, 0x302725b86cd901ab]), alt_bn128::Fq::from_montgomery([0x0aff0cf197a6c230, 0x05892515fa4a8906, 0x0ecd95f9ff579162, 0x0dbd9c04109c4b16]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x27b7ff64c138ca77, 0xde5e48efea2d8bec, 0xfd0a49187434a34a, 0x1dedbfeeb3df3ef1]), alt_bn128::Fq::from_montgomery([0x12afe2a4647b7fc0, 0xa2c4156cd9b68083, 0x7bf98c56d74e4eef, 0x0f23857641dbf43c])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x6fa08ad2b165a7cc, 0xe31bd6f79cf75d1b, 0x6c3b27987cf917f9, 0x172ffd496457be19]), alt_bn128::Fq::from_montgomery([0xb5d87577184a7b11, 0x545b719c63d92b17, 0x86901a3cf4474a08, 0x0ce46f5b307c9d71])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x9f338b499fe9266d, 0x07022fb2c5677665, 0x8c32af23d6e8085b, 0x14a63dcb612dc431]), alt_bn128::Fq::from_montgomery([0x802223fe020114f9, 0x7002a6a2124f9725, 0xa10026c2b53a6b8f, 0x1a638f23c20e75cb]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x6e7b78a588409945, 0x23211ccc403492ea, 0x0d7d02e8561b2c08, 0x0d7cbf2f48a9fc8c]), alt_bn128::Fq::from_montgomery([0x3e5a296a5d808c40, 0x7a0c5e7907643fcc, 0x6febfd8577b062c5, 0x125c980bfd4678b3])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0981ce89024a25fb, 0x3efbf552878e6c41, 0xc972138e9138a2eb, 0x070755da682b6327]), alt_bn128::Fq::from_montgomery([0xdb8f31e7f716d016, 0x0b88a5e5d90baf66, 0x29acd533b54cd2c6, 0x060ab0e3b540f0a7])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x7f1053156275e22e, 0x40566d6a8eecc3f2, 0xe1f86a85d616f274, 0x2ee915bff736342d]), alt_bn128::Fq::from_montgomery([0xe204d00622af01b9, 0xa1629738139d8c69, 0x37a2c2b55c88426e, 0x1c5bd206fe6cf2be]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xb514505995c25c3c, 0x6a9411261829b8af, 0x5e34fea8460cac22, 0x0a19bb28b7a08c0d]), alt_bn128::Fq::from_montgomery([0x54cf5d9b52553e2c, 0x8e7a70d9891a18c6, 0x62a091895506298c, 0x1cf6a4a9fa962722])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0b5a020ac4ccf864, 0x1277212a8324b889, 0xd30a1fbbd691cff6, 0x23f524d7e8e76da0]), alt_bn128::Fq::from_montgomery([0x50150b8b27332997, 0x5a4f821ef6263880, 0xef40665d22bd5cd0, 0x14580351c516ffca])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xf78a46c7794448ba, 0x92ebf73d03630fa4, 0xa82f0d86f937063c, 0x07852380a0c6d015]), alt_bn128::Fq::from_montgomery([0xd000f46766d411e6, 0x51d2ed701614e820, 0x77561f76a1431e54, 0x1d7bd514e48d3487]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x2d8133617ba82e34, 0xa7f4cf53294c15f0, 0x60e6d47926979dcb, 0x276b6f2650c91687]), alt_bn128::Fq::from_montgomery([0x09f258e1240087d3, 0x09173decb2d03dd1, 0xd841d5e906d5dfb2, 0x00f39c5e8523d5f0])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x30ddfd95f2704cd4, 0xb4a423898b9b2232, 0x09c35cd87c8a0331, 0x1c60baf267d76e8d]), alt_bn128::Fq::from_montgomery([0xde289b4f6e5f8391, 0x6838069edca82a16, 0x52e44a4c64034ffb, 0x059846caef8491f6])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x837efce957f34d51, 0x0ddfef805f3e7435, 0x3468396a034fc587, 0x0e35bad880f9816c]), alt_bn128::Fq::from_montgomery([0xbd1873bfc9380e84, 0x8810c154968a4406, 0x766fe6593645dd93, 0x13d8c1bb46845d48]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x1cc9f72b374a5ab0, 0xbb8a775a774faa2b, 0x09376ab263b6efd8, 0x2dd083843b1875c9]), alt_bn128::Fq::from_montgomery([0x5a9bbd29faa662df, 0x44675f43ba8f1603, 0x58a2c540b272dafc, 0x1dde60f3abccce0c])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x9f89565c55b9f68a, 0x67887cee65460ba2, 0x95e80f2fa0c55978, 0x13081430ff99070d]), alt_bn128::Fq::from_montgomery([0xf556a1fda9524200, 0xbba727ec959dd714, 0xd04106b80518a504, 0x1c9bbf98d0c85d5c])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xc3e28bdc21004f71, 0xe89b87b5ad4b6b7a, 0xec428c9584a34bb7, 0x1027dbe34264c1dc]), alt_bn128::Fq::from_montgomery([0x38d923face8c86b8, 0x890580624e7f2525, 0xe022a0ccf0a8eb67, 0x146a93ce60569866]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xf460521cbe28282a, 0xbcfef6449a87fdfe, 0x71a0ece76d273065, 0x0918a3de788fd031]), alt_bn128::Fq::from_montgomery([0xd9f1e62838cd17f5, 0x45869b5350a4a588, 0x045f44f05dc400fa, 0x186f93c7d446d207])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x210d7ed8b2d327d8, 0x5c57e07050bdf4e6, 0x88b74a3a97830153, 0x2f7c3adf58898e85]), alt_bn128::Fq::from_montgomery([0x12481de9d9b43bbb, 0x93bc08aa0ce18524, 0x8bdc44580a339edf, 0x13ed76b602bd52eb])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x427f8594b970e056, 0xe38a5341420fead7, 0x6448938842742db3, 0x0facec1a34b83732]), alt_bn128::Fq::from_montgomery([0xeda117b07fa17173, 0xb38b2eac38152909, 0x432cac1f054996c5, 0x0fc741ee26695483]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xe15984bf7c6be00e, 0xe4b1312b6048f978, 0x8bcd49ed40aed304, 0x27397aa802138864]), alt_bn128::Fq::from_montgomery([0x671663a95ef4742b, 0xee796af0994d0898, 0xaf3166a8f6723306, 0x01c4c7ac54a964a4])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xdb063dd90a18b6cc, 0x435255ccc1798807, 0x375b4f66ab4714f3, 0x12eef724c163d0b6]), alt_bn128::Fq::from_montgomery([0xc48313d83e9fc6d8, 0xf885ad085f7bee3f, 0xc7e47a314d0c74cf, 0x05b655f139635447])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x6f4a44f0d637db60, 0xf296976116af7738, 0xac1f7fc80ab4095c, 0x25c832a563cc600d]), alt_bn128::Fq::from_montgomery([0x1045f0f17684582b, 0x5814f68e72e96933, 0xed6fcfcad0d36c0d, 0x19beeef12a2b18f8]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xb33bada3e4c68b52, 0x6554818b316c6806, 0x13d0792282cb56a4, 0x298591ca6098bf48]), alt_bn128::Fq::from_montgomery([0x2d63b485659f1915, 0xe5cea53b9dd0fa8c, 0x8e1a8cf9a214693a, 0x260e2f1b9bb8cefc])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xc5357bf860e8b6c1, 0x74a19a12b10d7efa, 0x29d08cc7e178280b, 0x10d2604a9f7f8325]), alt_bn128::Fq::from_montgomery([0x31df4eb378031b3d, 0xe1792bb1de93e49a, 0x1f07f9c68e984c29, 0x12b24013d4f5965f])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x2dcd50cafca4c762, 0xc3f2bad67c85407d, 0xc81c437a53edfe60, 0x1dea0b788e2c467b]), alt_bn128::Fq::from_montgomery([0x7c126eacfbd66dfc, 0x930a2bd8ba31e441, 0x8f94a33d3287bb5f, 0x2b0b7a5cda12a47c]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x62e8f6fe30d5cc27, 0xa0edff4e51062a37, 0xf9621b9664c2e619, 0x28b6c38cfa9854d6]), alt_bn128::Fq::from_montgomery([0x04f0e8e087cc5914, 0x8848b0b64119baa2, 0xe38626042c9a3f3a, 0x050aa7726703f9c9])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xe0f448f964014744, 0xfd973d57e51d30ef, 0xf90772d60212db9c, 0x24a287153cf2ad48]), alt_bn128::Fq::from_montgomery([0x46f4887f0eee04c4, 0x46734ffbacb18659, 0xf32f8cb1a203ab8a, 0x22150db2597cc5a8])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x36a51cd6605bfab8, 0x584c3dd087632c03, 0x86d4f1c11bb8ea82, 0x2d66fc964a52bad5]), alt_bn128::Fq::from_montgomery([0xcd09393282dcb82b, 0x3811de351f16f611, 0x1dffaecda7749b75, 0x25c262eaad6c6827]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xde69f48741ad926f, 0xc7fc8cd78479431e, 0xfd1c9f7e40d87c69, 0x016c532c49b2c22e]), alt_bn128::Fq::from_montgomery([0xeb19a842cffba24e, 0x04495c4addb890f2, 0x7114efe601360f1c, 0x20ed5fee72692085])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xf2ac09f69d2163ef, 0x18f439b04099c68f, 0xd122e54ab172e047, 0x2ebdad17b52f8daf]), alt_bn128::Fq::from_montgomery([0x845a16628051ba14, 0x2508a9336f44b1ca, 0xf536e39376e676a9, 0x1e74e5581abd61de])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x77880612b160b490, 0x2918d8d0c504b164, 0x5e328798f02a9f6e, 0x1bdb50a43b41421f]), alt_bn128::Fq::from_montgomery([0xf5b3630b2877a8a9, 0xe8052a0887ed21b0, 0xa59b7b673bcac5ee, 0x1b10cc1556f2ea11]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xe8d4c5321b4ad111, 0xd20834ad6f0594c6, 0xbfe5545b58ef24ba, 0x1d5e7cef003b266e]), alt_bn128::Fq::from_montgomery([0x6d3f8a6b2d2898f7, 0x930314553de82f76, 0xaf7aa6647f5c67c6, 0x2afca5acbb949b6a])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xa3c9bc5ee90fe458, 0xc9fdb498ec907a36, 0x1ed504f196452f8c, 0x29ba6abb97a7ee01]), alt_bn128::Fq::from_montgomery([0x7e50c7bcb236eb6c, 0x369ddcfe804897bd, 0xab120cab2c83eebd, 0x0ca448c3b2130beb])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x5f27aeba3bb7ae72, 0x5f7dae1eca63fb27, 0x69a03b3a19378351, 0x12be9499ce3883ea]), alt_bn128::Fq::from_montgomery([0xf4e1cad03c11c81b, 0x2d93183f79480d1f, 0x9a50391f37415562, 0x04a48ab2e31b7d78]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xfdf399a176b61165, 0xcc734058450d8e4f, 0xbdd50664c7073a58, 0x1cdbd34ac484c36f]), alt_bn128::Fq::from_montgomery([0x66e57f6a856f3f84, 0xb009f5f6430d9bb5, 0xbcc59d86d73cf6ab, 0x243aa6b8df202393])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xc08999c7bc3dc02f, 0x8264bade36376a41, 0x6c6161042388f174, 0x06e1c9bd5163cf64]), alt_bn128::Fq::from_montgomery([0x05bc147cc28d60d1, 0xbec2e4d991adc170, 0x25c233efac8caf8e, 0x061cb1419199345c])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xa6d9e494d86f6647, 0xfc629c03fee4e4c8, 0xa47972a3cd381d83, 0x025b28b5e1b552a6]), alt_bn128::Fq::from_montgomery([0x5981ed3ce974aeed, 0x2673e4319b495cdc, 0x83318a9954d3c8d2, 0x0d51020949f1ef2b]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x2570ed74aefd5252, 0x0d032199cf712882, 0xeb8756b71fc9367e, 0x2a25216c3ad3c64f]), alt_bn128::Fq::from_montgomery([0x2aa3e94948fe2902, 0x5f6659b0f5304fc2, 0x4c62af75469a522e, 0x00522fc6233a9645])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xd44645eba81a4992, 0x1f3f997e69f335d0, 0xee49cf1afa4ba049, 0x12e530aa87bb4eb9]), alt_bn128::Fq::from_montgomery([0xa5f72733e2008294, 0x7751eacbd31c9b6c, 0x4b215b50a7d9c1e6, 0x1744019f6009ddf0])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xf87d3c4bc146cfcf, 0x8d122f83d3af8546, 0x904de4e4fcbb78aa, 0x023c2d42805a805b]), alt_bn128::Fq::from_montgomery([0xcec7b949b4836e30, 0x0e56b19440e9583f, 0x7dd79fe1fbc7319c, 0x23d8f3a20dc06ae0]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x49a8a5751b76e479, 0x3a8f3012d60757e4, 0x23c501786cc4722c, 0x005d72634cafd415]), alt_bn128::Fq::from_montgomery([0x7af5724e3bfb8864, 0xb8fbc390d77f34bf, 0x4e5f80c8f24fa23e, 0x2d8b793b3f90cc8d])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x26991d6fe67cddef, 0x14577f10ca662a6d, 0xcd6c88d4e0c045d7, 0x1216d0f1ce066209]), alt_bn128::Fq::from_montgomery([0x982059dbd9d1a87d, 0xe1c31c1aa04b7e44, 0x30fb1138c68b53f3, 0x2a002b35141ca4ef])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xd13d419f4d695f20, 0x02625c853adcc39f, 0x09a207b10596e1bd, 0x29bf957bf60cc44f]), alt_bn128::Fq::from_montgomery([0x5daefc78c933837c, 0xb61ed9deb394e28a, 0xa9c5ba0e0d5f822a, 0x1693ab590c0dfaff]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x645eb8c9aa72367f, 0xc198b6d5b6d091b8, 0x977f470e92a1a75b, 0x22cc1236c6635328]), alt_bn128::Fq::from_montgomery([0xcc783f31bce50b83, 0x27816d38d18b1de6, 0xfc9b91c1a58eec36, 0x0ab39786e53f0128])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xfdde8896e37cf771, 0x91e4851c16d264a1, 0x86fce33f4e2ff248, 0x128962b7d979e05b]), alt_bn128::Fq::from_montgomery([0x524dfd5380b289ae, 0xb481fcf540a0de03, 0xcc40659698cf2e50, 0x24fe4b61115b1489])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xadd72945d093aa77, 0x18a053fc8250f8c7, 0x29a909a624f7fcca, 0x04795453200dc1b0]), alt_bn128::Fq::from_montgomery([0x28cdeea7625d76fd, 0x9bcf51a2b452a375, 0x7446583c06942c63, 0x2159c12f2f6598f4]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x87dfa0db5c679d35, 0xe71332e42ca31e78, 0xa2b179930c1687c4, 0x13a4e736bede33d3]), alt_bn128::Fq::from_montgomery([0x9ac029659e745d22, 0x7ff49fae3539c191, 0x14e191d117932f75, 0x17beb32854d84f55])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x92388a0b6fe86828, 0xb1b69fa5c2252657, 0xb21098663c5edcc8, 0x23a36fa46a5e8c91]), alt_bn128::Fq::from_montgomery([0x4e30beeb7e1d097d, 0x26e938fc5810313d, 0x35d74405c949eeaf, 0x2a37b889bde884e9])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x8d451f68f5622aca, 0x2801067ed5ddf128, 0xa12803ddd85fa001, 0x11814f2d8882fd9c]), alt_bn128::Fq::from_montgomery([0x0f5658325727190a, 0xde6ff1bc0fafa429, 0x2593a9b577e51855, 0x251816a687fd9973]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xa9ca6b99aff9941b, 0x33bcd3f65626cef2, 0x56a05ac25601a9fb, 0x21afd93556718453]), alt_bn128::Fq::from_montgomery([0x82ab2fd34a947adb, 0xb394871a0fd08300, 0x0640ffc95fb0ec20, 0x2131e9d5af0c0a2a])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x26782f49ba92f8b4, 0x81124efbdd674eee, 0x1a310b21dc02b370, 0x1aa37bc33c4cecd5]), alt_bn128::Fq::from_montgomery([0xaf53a139419ae9d6, 0xf28946e2f6622022, 0xe66f458e311871a0, 0x222e4849fa880e0c])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xd984edaf7470e59d, 0x5c17ac8ce15dad6a, 0x6d4d19cf0a28b130, 0x157c6800705b78ec]), alt_bn128::Fq::from_montgomery([0xff3643c998c8d0e4, 0x50c235e2edf41961, 0x3bf2b19a23e59761, 0x2189141c6fc0e088]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xd7d56629fe55f54c, 0xa2b37405357324c2, 0xbfcda46f93cce604, 0x0945032b81ec6c4b]), alt_bn128::Fq::from_montgomery([0x9fdaf29d8ca95573, 0x9b82508f840b7fdd, 0x1636bd1696ff2384, 0x2cc11e21da91d32f])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xfc78a68cd6476e9e, 0x8ea89c26bacb3f5f, 0xe7103d03b09010d9, 0x1e24f440739f2d8d]), alt_bn128::Fq::from_montgomery([0xd8fdcbb00bbbbcc5, 0x52641342765609f8, 0x75c89189e9125362, 0x240d8b1f0a420164])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xf2e0fd39aefacfb7, 0x9b2dd2adba531aff, 0xaa87ee422d500394, 0x13dd2af75fcfb37d]), alt_bn128::Fq::from_montgomery([0x389790f128bb8a39, 0xb1aa273005ef3c95, 0xf6b5ac2024dae725, 0x157770809c206509]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xb0871a51b9e21b01, 0x88a634e48738b669, 0x3549df7a1d859eab, 0x1baf34a4f59e2918]), alt_bn128::Fq::from_montgomery([0x909d427877f04d99, 0xa1228698db146a44, 0xdbd0e83b508362f5, 0x2f1f1886e5cdcb06])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x2b8b2e72a8ddb529, 0xf5f5192f1785f29d, 0x29065e02b6c8fffd, 0x115620a56edb4d76]), alt_bn128::Fq::from_montgomery([0xa33f9457b533d3e0, 0x974d24187b15e803, 0x4516a5ef2219ecda, 0x0446c55e966d6e2e])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x32189791302d74a3, 0xf4cecc48861cba8b, 0xae84c5e2b8acfdb2, 0x20d5d4619934e83d]), alt_bn128::Fq::from_montgomery([0x206dcb20a19bcf4b, 0x443d23dab3bab84e, 0xabcfc3f5b3645530, 0x00826cfe354643e9]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xe749598fa71a505a, 0x94f1ea558682497c, 0x8f9f0c6b5c7a4851, 0x1c4da26229086434]), alt_bn128::Fq::from_montgomery([0x0d516b710a466a4a, 0x23398f4daf8f029a, 0xf10e2d87bed50ff2, 0x06497b696a316673])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x048d1096b9daf690, 0xd5a17cc0886b7683, 0x866eeacee3e85ae3, 0x15ce1992487cfd7c]), alt_bn128::Fq::from_montgomery([0xc9f82b35561d99fe, 0xa3fd66da2b6061e5, 0x0bf1c1088d6c1b62, 0x20d8cb0097b3c658])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x23464957f8b53e1d, 0x03d9cd40ceb2a0a3, 0xaa1722cac44e64d6, 0x0f326c954ba9bfd2]), alt_bn128::Fq::from_montgomery([0x9976e00992ccf192, 0x25993c378a1fd083, 0xf90aa649124ad040, 0x2e830d16696c19a6]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xe62bef4c2422bdde, 0x77de1a354a4112a5, 0x52b2875a0c44ee18, 0x1d60626d60e0eb1e]), alt_bn128::Fq::from_montgomery([0x736448df9ee1c0b1, 0x9c793592eecf63ce, 0x1b0dcda367cf724a, 0x1b1562c199090e58])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xae586e5839bd2c43, 0xb4f5d7905b0c06f8, 0x5e17b78f24d568d6, 0x1a2ce4eca5651c31]), alt_bn128::Fq::from_montgomery([0x6e199badb5f815b9, 0x4744a186fd115f41, 0xcbe72165ffe5e06d, 0x06c3882a5c4ecef3])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x6174ae359896803d, 0xe1ad3ea0b8fc9a70, 0xc5f298a5d44dbc22, 0x0eac71c97f6b830f]), alt_bn128::Fq::from_montgomery([0xba7003b41a78b51f, 0x69ce4eb8b844863a, 0x185e9e02db8f829c, 0x1185626d0a368315]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xedd55515b7d1485c, 0xd5f80c56679a8fca, 0xbff402c78999971c, 0x180e81650bb71482]), alt_bn128::Fq::from_montgomery([0xdb39b5263ac2afc0, 0x53960e865412fc6a, 0x54c125232539492b, 0x027404f35841bd15])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x5646813983155023, 0xb6278406ca3ec723, 0x92cb005010a7eb42, 0x0b5c4338013aefec]), alt_bn128::Fq::from_montgomery([0x49d6c9810eaefd7f, 0x3d2db981adc6f6de, 0xe0bb05aa1fbe234b, 0x20725969c947c93c])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x4fd4a94a384b6b9f, 0xd6c004dd1165a02d, 0xfc279c113ffd3788, 0x14cfca1a2b165317]), alt_bn128::Fq::from_montgomery([0x6104bf3edc83ec67, 0xa24e050200624be3, 0x0468dca722a199d4, 0x2a5b74392e94e79f]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x10104d2d82e907de, 0x924504a53da86376, 0xbfe80b2b94fb875b, 0x1f6b40b1d23778f7]), alt_bn128::Fq::from_montgomery([0x80b733532003fd76, 0x43b0900f76e622a8, 0x0c72dc349fd32fb0, 0x18fdc31668e22263])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x6aa0f707dac204ff, 0x9e89cb4c250eda76, 0x1cd3e153b553bc92, 0x24fc80b1f89f6ed9]), alt_bn128::Fq::from_montgomery([0xa1c59f68e78824db, 0x770d174e18f010c7, 0x5421b220222afa8e, 0x2785598a17497850])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x2a2432bf9cc5addd, 0x018c06731b8ee744, 0xd15af168539e3ec5, 0x2df549d9b26d97e2]), alt_bn128::Fq::from_montgomery([0xc499385e347f74b3, 0xefe14e30989cd382, 0x285b5a62b4b24f4c, 0x0764f5057d9c7375]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x14b3d331d34eb2b0, 0xd69d69f69bb63fe1, 0x2dd874d49f1fcf9f, 0x2ba482bb39582a6c]), alt_bn128::Fq::from_montgomery([0xe0ece8d26b6ebed0, 0x0e9fa1e0f34b579c, 0xeaa4e45e6248dfe8, 0x2d73fa2c70efa496])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xe38ac8a000063a49, 0xba5a831fbd0b8b6d, 0x94c45dfd0c483382, 0x220954edd7592430]), alt_bn128::Fq::from_montgomery([0x785913ac6b29cc03, 0x9644601d390290cb, 0x4a2295ccbace74b8, 0x16b4e7c2d58ed199])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x772ecb0afa06a95b, 0x30920570c5992ac2, 0xafb63331adccc69b, 0x0b4655795531fb59]), alt_bn128::Fq::from_montgomery([0x2b9721533c4781e4, 0x82a80d27abdaa9b3, 0xd0477863aaa0eeda, 0x24b372e3599c4ce2]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xb279952a8052ffb2, 0x75858aa9a5ac5259, 0xbef26bec1aed4b35, 0x119cf7f57bbe2ba8]), alt_bn128::Fq::from_montgomery([0xe66933ffebe50ed5, 0x3862623df2c22764, 0x54d72f516b71b815, 0x2549e6c62a23c108])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xdc9ad47fb4443d28, 0x4ccbb185b1102dbd, 0x4f149204111af69e, 0x075744ea2b7f782c]), alt_bn128::Fq::from_montgomery([0xe7baa458903abbdc, 0xc5c33a791090eeb9, 0x31ffe21255c1a642, 0x1fc20a2a48de6340])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x5f617b8dc4a16f96, 0xb80fb8daae62a9c5, 0xe3bcd0abdf810477, 0x002965e9dd8e7353]), alt_bn128::Fq::from_montgomery([0x10babd11d46bed1f, 0x0f137d4a55d160a1, 0x4fe57643ac4c8a07, 0x219aa319220ea037]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x7547503d393e98fe, 0xa6ee93b10deaa831, 0x0304b67c37a80649, 0x0955ebe8fb201b64]), alt_bn128::Fq::from_montgomery([0x8fe7f719b76e33e7, 0x8b569d7f84831a25, 0x83c60f4a0d728011, 0x1f44a1b750858e66])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x5c103a77d926e1e0, 0xed56d99986c08d7c, 0xcef365fb19ad8128, 0x17852f09306337b9]), alt_bn128::Fq::from_montgomery([0x87fda15973c0dc35, 0x75b2e5baf7ed0a49, 0x02c1219cf4b31e22, 0x033c2a6098f89fcf])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xace121c5f68c8f73, 0x349cebba1420c6ca, 0x5706c38a361af173, 0x293f4182318284d5]), alt_bn128::Fq::from_montgomery([0x285015185bd310dc, 0x4f398255ccfa55da, 0xeb46b9ea5d8e24b2, 0x01c0adb70bf5b8ea]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x6fb5c5ccaf6716bf, 0xa95f07fae7080fde, 0x183282e1a598ef09, 0x2623da83e49fd79f]), alt_bn128::Fq::from_montgomery([0xe7c4d66a8850979c, 0xcadd774ea6b4c3ab, 0xe74e2394375eb226, 0x1d9f3cf8a07ee091])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x36082853ea6a2ee5, 0xcd65adb42761df87, 0x8dbdbdb8eeae838d, 0x14ba4ae9550e891b]), alt_bn128::Fq::from_montgomery([0x0d0fbaa5704fe39d, 0x73b2d41b16c324a3, 0x93a907c4df96bece, 0x0ae7ab56dd03b333])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xc228f87b3940f914, 0xecd5f8f798ca02f5, 0xd8ebbc05094f2e0f, 0x254681cbd1a6b17e]), alt_bn128::Fq::from_montgomery([0x37f129a8bd6cff44, 0xcbeec29d7f1d2d41, 0x1a756b80f25b8554, 0x1b27da5d2107ace5]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x2e3d9147de8924fc, 0x6430cdecbabd6b53, 0xe2b240bf265b2ba5, 0x0f2bfc0b72cbef2b]), alt_bn128::Fq::from_montgomery([0x93e105de5de5430b, 0xe35d2686b4f7af4f, 0x4752c187d3988534, 0x2c940641f459db8e])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x43cb7a289f64947b, 0x6ff670f5aa785e26, 0xa273e0431cdf64f4, 0x00ac6adaa9eb1f56]), alt_bn128::Fq::from_montgomery([0x8a2c0711125feae7, 0x7323c3f821e23e16, 0x63fd33299d59f0c7, 0x2e36f6415071a629])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xf142a4c6b56f6c08, 0xb0a84f5816ce7ad7, 0x8fee5a7cb5f12d43, 0x06d54c07b07441c3]), alt_bn128::Fq::from_montgomery([0x17b6d1c41fd312b8, 0x67b1d1e4335634c2, 0x524b2df43e3dc15f, 0x250f1b35edf98a90]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x26189ca342e8cd0a, 0xff2c3c17317dfeb0, 0x275b8f9a6aa22cd0, 0x0b701bfcf9452a3d]), alt_bn128::Fq::from_montgomery([0x2f50fb84224cc955, 0xaa1764cf1af31cff, 0x9170d9afaff0301a, 0x130d41c5f2043db5])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x978dd8950cd94fcd, 0xef722355bf40de33, 0xd33ef72a39e60468, 0x080a1dfccc911f64]), alt_bn128::Fq::from_montgomery([0x09184ba390c2aeca, 0x1bf90b289613ba39, 0xc149f50c77700826, 0x102ad3c06d794929])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x091e210f224a9b97, 0x487c7e2d02da0cdf, 0x7f71a19d5622889f, 0x2c68561acae17fa1]), alt_bn128::Fq::from_montgomery([0xaf47838f174f6f25, 0xf391c780b87b1a2f, 0x0c582b5644f05161, 0x2e368c23b675ac70]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xad3bb26021748509, 0x809bb7227af96621, 0xf6b8b6e2f519c5d3, 0x04804e40cc2c395b]), alt_bn128::Fq::from_montgomery([0x01a743eb1c1a7539, 0x3d739f994c87fa5c, 0xe046ca568f85eeb2, 0x10f6ebed91cee963])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xfd4db900859657a2, 0xc3fa611e8fc0396f, 0xec0b417c1aa9982a, 0x2be863af5e18b66b]), alt_bn128::Fq::from_montgomery([0x87c2d50d8c89d9b7, 0x0227c365216743ca, 0x2f0ec4356d7dd17b, 0x2df195b84949fc74])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xb9377835260616f5, 0xbe1a5dee11c7d227, 0x4abf9ced0c1de545, 0x25798f7c16e9bd40]), alt_bn128::Fq::from_montgomery([0xb7cbe1143234caaa, 0xd6d0b8bbed7197e5, 0xa07bb56e3de83e25, 0x12083684aa52a423]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x24d7b029bbb5d2ef, 0x8d0d926a23e26f9e, 0xb302f24ac1951c04, 0x0a0ed6685bef7ecb]), alt_bn128::Fq::from_montgomery([0x801d87679239c232, 0x796398b8434f962a, 0xb0da69719b4de51f, 0x198fcf1662c7bbbe])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xb73aca68bdd4a283, 0xda3145af73893844, 0xc3dee7b2e7e89bf6, 0x0460cefb53fa7bc6]), alt_bn128::Fq::from_montgomery([0x9b162ba6aed12acf, 0x4f655ac7d67a67c4, 0xbd238db27e8b8dbd, 0x26bab79156811383])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x74309d0869a38e05, 0x5858544b8ba583e8, 0x3fa4f3a542a5e852, 0x071fc2419aef3537]), alt_bn128::Fq::from_montgomery([0x59a7446725e1c089, 0xa353c2e9638bc617, 0xb83456ec9f214f33, 0x174bd9cd401561a8]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x243749c9ee41e591, 0x6f00510670549327, 0xde7ef79bf2510d4e, 0x088d55d8a8da4816]), alt_bn128::Fq::from_montgomery([0x07a99ed605c45acd, 0x7e61131a20e2076b, 0x21ad7a9bc9852fa6, 0x29a2cbeae997e713])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x601eed1c76b28388, 0xa263eb4d2be09535, 0xf7f00b9dff19ca2e, 0x189738dc488520a9]), alt_bn128::Fq::from_montgomery([0x8cb07bf348cb5d46, 0x0f337a18b297acfe, 0xac49c59c1b663d0f, 0x1d32d4ace0b82e50])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x502f4bede1b9da95, 0xef226547177c4867, 0x2253f25b49995a96, 0x1376dd5a6f288160]), alt_bn128::Fq::from_montgomery([0xeadb3c104294eb38, 0x2dbea2ef9381ca04, 0xed921978a5840112, 0x2bb8844198899850]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x15d39cbb479a469b, 0x56eee7d776cb7388, 0x11f03dc72cd1d688, 0x2eda6b33f7c069ed]), alt_bn128::Fq::from_montgomery([0xe92d7218c73452e8, 0x94c63c7e4fb5c6af, 0x8647b2c1bf8ec530, 0x271694ebb3e628a1])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xcfc34890d390311e, 0x5488b6cbc340fa18, 0xb32dfa8b7320ba30, 0x28c95224644a4544]), alt_bn128::Fq::from_montgomery([0x51cd49ece0e3f0f8, 0x5fa880127344294c, 0x60f84fa47a25e0af, 0x1ca1aef9b4058a82])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x3bb123793198355f, 0x50d07d9a07679b5f, 0x662e9e90c4d530d5, 0x2696d20fe0f72015]), alt_bn128::Fq::from_montgomery([0x97456c87bb70eb03, 0xe8532afd2a7958c7, 0x49d2a8f3532fd047, 0x0e05606a028b7910]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xe3738f591dddfc3e, 0x770aa11defac0a67, 0x0d0fa6bd05156243, 0x25225e4638ce037b]), alt_bn128::Fq::from_montgomery([0xad0adedc6dc90ef3, 0x536a0628ffcb2e5c, 0x900414f38bc960dd, 0x0b36b34e1e4d6afd])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x2a7cbd5655b715e6, 0x5cb8bb58c20d63d0, 0x26d3f7f377890606, 0x09c29fe66e5e33ad]), alt_bn128::Fq::from_montgomery([0xbb12d7b704764f8f, 0x81da6c5121129a16, 0xf7b84fdfef80510f, 0x03c4b5c26ca03d41])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x7bcf550b3c2a9a51, 0xf7f1a822119edc13, 0x227aa33a038e08c9, 0x294b619100c920dd]), alt_bn128::Fq::from_montgomery([0x9e18b57e1d107fd6, 0xaf6a41a897ba0cce, 0x4ce839ccf5ae8c3b, 0x1b598bde35290401]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x385e4d1d97465e61, 0x61efde193530024e, 0x102b3194b514f42f, 0x0bbff36451074a47]), alt_bn128::Fq::from_montgomery([0xb614e649e1cc0876, 0x87af91537fa9f45a, 0x247dc4130f582a9e, 0x02397953e302c3d9])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x234dfb98f9676380, 0xa3a59c7eed2f7fb9, 0xdd1c4986d514b8ed, 0x047c09bbc6b07bc0]), alt_bn128::Fq::from_montgomery([0x83e0d78c4ebcbb4f, 0x3446396ccfcce9c8, 0x89de8e65b8be0015, 0x1465a3774700c263])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x57a46f4b2c197b9a, 0x1131e79d22aee0eb, 0x692932c9e9eb3e83, 0x07cd9d1533bb0d47]), alt_bn128::Fq::from_montgomery([0x54c5c3d8d391f468, 0xbe9ec233726b1fe3, 0x67c6b0b51e4054aa, 0x030edf23be5c1a5d]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xb1378654a5e15dac, 0xfad7fa29f975226a, 0xf29f4bdfe8b613e5, 0x10f64527306957af]), alt_bn128::Fq::from_montgomery([0x9ca5165b2123f00b, 0xead17ef8d7aa855f, 0xc2178ac9a040210d, 0x2c5b0f5ef5c2f74b])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xf566d52740730b6a, 0xe32c07b6aed517cb, 0xa9c140164068faaf, 0x14613ce6e58248c5]), alt_bn128::Fq::from_montgomery([0xdcb01f7ca50e988e, 0x0f76afd369aa05e8, 0xc45368beb2aad670, 0x07b67d0544aa3fa6])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x6d81af2d5f3d0043, 0xf6aeec97739b6898, 0xf63f8454f8e8469e, 0x1ee73338cac972e4]), alt_bn128::Fq::from_montgomery([0x03aa521f84aab1cf, 0x473640d861c1c683, 0x51b5b775496c95c9, 0x174f0330b8cfb053]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x053072371f349e61, 0x675e0fdca65e71a2, 0xee5edc8be9049c7b, 0x149fc6a8569b1882]), alt_bn128::Fq::from_montgomery([0x11e502da2ffdf970, 0x39366aff9b29094d, 0x2fcc8d0982ee39f0, 0x0133cc6e4428f91e])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xfaf104c65f609a50, 0x02efeddace173149, 0xb2e6664847035880, 0x0b80672eed050fc3]), alt_bn128::Fq::from_montgomery([0xc7143424a319cef3, 0xfabbe0562c7f5a51, 0xccc3ed69d7f1e182, 0x2eb00323ba767a7c])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xf05cd96a3bcd40b0, 0x55ae1f70ef84b2fe, 0xed3d6cb3292b7e48, 0x2fb8039eee3c56af]), alt_bn128::Fq::from_montgomery([0xb0af080d8fbcecfb, 0x8b3401a60d90c8dd, 0x2b743130f707153a, 0x2c9a2742703ed781]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0d5f3083b73ade5f, 0x8e4a827f98541058, 0xb885bbeab972e313, 0x168eec216ef02e5e]), alt_bn128::Fq::from_montgomery([0x81e0598a2839885f, 0xb99a58bedde87d40, 0xfca9239a36931ddd, 0x2cd647dc19984311])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x9bd425f7a2f4db16, 0x0db3dd815171e3e0, 0x6830a4fda41514c7, 0x156240512648039c]), alt_bn128::Fq::from_montgomery([0xf02ea18bd3f265db, 0x91da524d7bd70f2a, 0xc9ce41285e1374e6, 0x0c5fc2918dee02a4])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x09843ff0890f8096, 0x56459c21168be725, 0x10f468073fddec9e, 0x1f50a9fb14b52e07]), alt_bn128::Fq::from_montgomery([0x8d83982a970e02f1, 0x9650fb4a60516d2c, 0x11366d18564faba9, 0x1f5bf9d5aa8b87d5]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x7334f6b52a53e5b4, 0xccd1ebcde04f2da6, 0x4339cc2df4b34a9b, 0x0801c107e1935d6f]), alt_bn128::Fq::from_montgomery([0x1e28c7b4709794f2, 0x39ceb6c89cc1b203, 0xf468ef7a9b2690bb, 0x2709ef1011f4886b])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xa95d22fb232be6bc, 0x399d2e11154669f1, 0x6e25aeeebcca5dd1, 0x1c3db9a707744f98]), alt_bn128::Fq::from_montgomery([0xe60a0b897d70b07e, 0x905ad61a6804aa56, 0xcba1480674c747ad, 0x025d51b470038c66])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xa054688f6ebe235a, 0x92b72064f90a3ca9, 0x3a8418787722c2d3, 0x2b35f01560891d3c]), alt_bn128::Fq::from_montgomery([0x31217840e252dc9c, 0x6a3504a479a87e40, 0x3b8698e3643423be, 0x15b1f46c78274a67]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xcb7bd477b5da85f3, 0xea9c79d13aea8fd9, 0x77525be8808df2c6, 0x186456188b346390]), alt_bn128::Fq::from_montgomery([0xcb9693cb04ea2f8c, 0xd93b3894f6129616, 0x0ce76ad159abb4e0, 0x0ee96c4d8736f8a0])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xafd954db422dc753, 0x2af7b77113008d2a, 0xe2c4a0c166738c38, 0x16b839cfd441f40d]), alt_bn128::Fq::from_montgomery([0xdbdac0b2cf315ce8, 0xc96089ee256d8d0a, 0x774d1f9b4e42a6b7, 0x13d0cf337cb1e099])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xfb608f8f1886d7bd, 0x3cd504a5ef97b938, 0xb3828194ecdd8c00, 0x133a3bc0b9b2184d]), alt_bn128::Fq::from_montgomery([0xe94730d2f1eb6fce, 0x38e2ae766798e351, 0x416621f9f623e502, 0x27818c0c0fb7581c]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xb5f937348cc60da1, 0x3b824dc2edc78934, 0xaa656fa46598edf3, 0x2b2f34ea0fe948b1]), alt_bn128::Fq::from_montgomery([0x7c54c76f7b76053e, 0x56ef3ba68dfb037d, 0x01bcd5b0a221d98e, 0x01caf252e60f3b80])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x1b857530faf71f6b, 0xb2c17874de5d14d1, 0x17479eb5fdef17ec, 0x079c8915f7b24fdc]), alt_bn128::Fq::from_montgomery([0x9e1d726bca106596, 0x936ec45a22a4296e, 0x16a26e81dc3a1894, 0x227d9475fcee3391])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xa952f77bf6bdf651, 0x60b3339575fc062c, 0x04b32533bd2e5f1f, 0x132e9d88d8236b9c]), alt_bn128::Fq::from_montgomery([0x279ec9af8d544cd9, 0x7748c7bcc5299dfc, 0x39f4dd602f4c4aab, 0x0654d59c329ecedc]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xadd23a5fbbc18d6e, 0xb9807d702f2179b3, 0xcf8cc350167c6b75, 0x26b7d4c8422c27d5]), alt_bn128::Fq::from_montgomery([0x628d87260214b77a, 0x26f645549852b37c, 0xf9ca02fa22f5fa5e, 0x14ee464498befe5f])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xdfcbac5698a81769, 0xc8a0456ed8895f92, 0x00f8f32c626c5a1d, 0x2b62b6fab6b98fca]), alt_bn128::Fq::from_montgomery([0xeaf648ad47182c62, 0x0f667cf777a901d7, 0xf8d510630900872c, 0x0b2b269fce0b7f40])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xad28182abc68a65f, 0x620d15ca5a1e539a, 0x4c55bc28c8a6ae38, 0x069d79f16ad893c9]), alt_bn128::Fq::from_montgomery([0xbb52f9b50abe5445, 0x3426912108f7f430, 0x05472d112cb09f30, 0x15e2e3875670b9da]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x467579681d2e4b6d, 0xbd9ed33322bac32b, 0x827589fec4b231e1, 0x21000f4e19ec72c5]), alt_bn128::Fq::from_montgomery([0x753b3f628c906da8, 0xa338e0d9d4331eb2, 0x201992c65bf8aa2b, 0x02e1d49a829f7c06])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x06bc0fe423b1cdcd, 0x41f42bb9d65038e9, 0x4c69d5f21fcdf3f9, 0x2746e514256366a6]), alt_bn128::Fq::from_montgomery([0x6a0f94d871dde592, 0xdd9a8d9f26752339, 0x1d3423cbd98b76e9, 0x2aa7beef357195ce])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x9c396422a817945a, 0xee16bcdcd0632e48, 0x647544fd2b1c03ed, 0x153076f249993465]), alt_bn128::Fq::from_montgomery([0x0c806faea130b02f, 0xfae8c0f8ef066898, 0xae64989852282530, 0x19b781d26e536054]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xe04fc960cbf1768b, 0xd37639366ebcf2c2, 0xe080010e10cf04c7, 0x05b8c5b174ac67f3]), alt_bn128::Fq::from_montgomery([0x3680f673d645cd33, 0x66a8191284507273, 0x19ddef0400d3b2cc, 0x186f368481f1e31b])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x768047e26f1b982d, 0x53ce223471e6ce80, 0x86d50dea9235b63f, 0x1cd84edd78b1f1c2]), alt_bn128::Fq::from_montgomery([0x95f843879a24a359, 0x3dfa47440f55fb0b, 0x2e71b355552ee959, 0x10362d8ff5beafba])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x07be487509a568b9, 0xd6b046de70b30245, 0x08eda365358da951, 0x2cc3e2f4832ca024]), alt_bn128::Fq::from_montgomery([0x5dec3f0eb76cdb48, 0xdb4d8b3c05194c8a, 0x0f4323c367161122, 0x0caf6ba5e1b31dbe]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x951d72795a67f431, 0x3e7c445d43e888b6, 0x06a238176df5011d, 0x1d9677471e001914]), alt_bn128::Fq::from_montgomery([0x04807c6489d09715, 0x7e2976e75e2c41b9, 0x9449d0e6bc2e2d86, 0x1d005f6ef72568e3])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0078c7a42159c87e, 0xc27d7f38ef88eb8f, 0x6ad4f55000526476, 0x1c684517e6bcd387]), alt_bn128::Fq::from_montgomery([0x36af3d4084e07478, 0xf81c322b1fc321c1, 0x6447125ee3e055e6, 0x1b587c09367c33bb])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x6662ebc63226a420, 0xa6a6e49e0eff6e0c, 0xe5b5905090c717b4, 0x2d0343302445d2d7]), alt_bn128::Fq::from_montgomery([0x20e441c550d19cc4, 0x56a460fb365d6eb8, 0x0cad964d3f0ac21b, 0x2267eaf548700c30]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x692435e0f5e46dbf, 0xaff85bf9f43c7dd6, 0x7a7eb5bcb3889464, 0x1171b4207d4e6f74]), alt_bn128::Fq::from_montgomery([0x61acf8918dfbd8a1, 0x484683cfd7bac5be, 0xb029da73b14201c9, 0x08cee3555115a583])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x88c6df1e316acd10, 0x35adca693d2304d5, 0x1b737483fc7ad310, 0x21a8d5548149b6c3]), alt_bn128::Fq::from_montgomery([0x9f1952bda3bd6232, 0x65d483b3b5f4747e, 0x14d52db6ac368716, 0x2bac6d413d39d1be])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x601331be617d8bf5, 0x05039af0db6c78f2, 0xbb0081c63f04e8de, 0x033e84933416202b]), alt_bn128::Fq::from_montgomery([0x8f560dd429b14c17, 0x2d347af5acf427b8, 0xb9a36f1efedacd92, 0x0bb7a85c985414dc]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xc7b3889182ba22e5, 0x75df34965cabd34a, 0x7fddec4c9c43fe7a, 0x21e9dc244c186bba]), alt_bn128::Fq::from_montgomery([0xda6af93bf39eaffc, 0xdbe8216f1ed056f2, 0x96d044f6422fa930, 0x0f96e5427e7e05a6])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xa39c5ef3d0e4c9aa, 0xf7fce260a1c2e5dc, 0x3ec98fbde6703b4a, 0x03c7aa30c9c050d9]), alt_bn128::Fq::from_montgomery([0x4e1c961bf74251eb, 0x751c52b1ffeb3be0, 0xbc000ba9f6478dbd, 0x13565d5d9ac46792])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xc8101bf18b7cd314, 0x6c964bc9bc9fe69b, 0x4f6230f9248e5d73, 0x2673a8cf787542af]), alt_bn128::Fq::from_montgomery([0x2f7f1c64f0f48ef8, 0x575e3b873f1eff40, 0x8755b75094854c10, 0x089792a3f87ec601]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+],
+);
+
+pub const VK_DELTA_G2: alt_bn128::G2Prepared = alt_bn128::G2Prepared::from_coeffs(
+alt_bn128::AffineG2::new_unchecked(
+    alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x45efba475fa35e08, 0xe9485d06022f8c67, 0xa91abf4324a34433, 0x2f51b31d54e79963]), alt_bn128::Fq::from_montgomery([0xb8a14e792de65e4a, 0xb3989e39119733b0, 0xf9dd468e450e3401, 0x173850ba6ecc67c1])),
+    alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x7d42817a63475f44, 0xe04abea0878db5c7, 0x60d43ee8a79adc05, 0x2376807da4b19413]), alt_bn128::Fq::from_montgomery([0x0897083d97db55f6, 0x86b080b31ca62716, 0x9f971d0056e93302, 0x1dc8756bcb9eacc0])),
+),
+[
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x253c4c85ce534ded, 0xc42401881edb2491, 0x79abc7dd3685b3d4, 0x14a272f50e131bd4]), alt_bn128::Fq::from_montgomery([0xea3b487632095a42, 0x8bbe16b13cb77249, 0x9ec6d066ff14b623, 0x04caa22ab7b7e559])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x7dbc1538ea6b3c06, 0x6e6d57e1c1c8298b, 0xaef80d9bb3ccf8af, 0x19db9bea7900182c]), alt_bn128::Fq::from_montgomery([0x671307b281434ea2, 0x21a1d3bc979746ee, 0x3172516c55304ab6, 0x2537b20e2b25e6d2])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x68fb6358c2ad1b99, 0xcaef1ec36467c5d7, 0xf1797dbfde643b85, 0x24b090987a19a620]), alt_bn128::Fq::from_montgomery([0x7cc0f04a5eccda6a, 0x2f478e4f65b86627, 0x3bb25299115fe2b2, 0x12e4f7dd8e45ebd5]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x30182bc85f762f17, 0xa30b21280cbea6a3, 0xaffe4f971f738923, 0x2f36cc59a8e67329]), alt_bn128::Fq::from_montgomery([0x3bda6a99883b7864, 0xb66ffa8b9c68b4bf, 0x8bb4881c9716affb, 0x15aae0f45175570a])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x21fece38168487a8, 0xefca211db4c48e62, 0x1183ab07b9794d54, 0x19a5d4f6c0af0f66]), alt_bn128::Fq::from_montgomery([0x635a05cd15c64810, 0xf194f4624693bd23, 0x7ea140ab7cdbd0e4, 0x18894ae0134b0a2d])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x6cd0ad59ac1f68ca, 0xc213f585bed11d6c, 0x3ff7c55c91d49302, 0x258514f765f5443e]), alt_bn128::Fq::from_montgomery([0xceb08bfdb86d10a1, 0xc8b50b48d30c8d7c, 0x3eb3197993ef03ea, 0x0df0eebded5ece78]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x974bcfad5f1a044c, 0x860906134c04c51d, 0x5702b4edaba37e12, 0x23dd7932b043e7ef]), alt_bn128::Fq::from_montgomery([0x975c762fbd53719e, 0x65622a255968a84b, 0xd7819a8b01b9f995, 0x209ea87f41b8abb3])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xdfe8625942b321de, 0x40b538576e7debb2, 0x4959bb227c86bd4d, 0x22772b23c6a368e8]), alt_bn128::Fq::from_montgomery([0xaf36db21b1bffc4a, 0x56948704ebccf53d, 0x98db2b88131a39e1, 0x04130d2be5c1cf44])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x5e5ed26511363455, 0xe3714f01953dca1a, 0x5a0b47b7563a9927, 0x12da9f4ffb86bb07]), alt_bn128::Fq::from_montgomery([0xb263c1b5b98c585e, 0x5f9e26ca14c2d96f, 0x49c8d92c1cef7c1c, 0x1855c665803f0dc9]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x43f10e8e9132edf8, 0x0477e21b64e12871, 0x3de9a1477ad2f43d, 0x1053f754ad0bb66d]), alt_bn128::Fq::from_montgomery([0x52df929dd9681a77, 0xace109a013b3aa2c, 0x95b6ba390f199994, 0x28a5b78b48d1830f])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xb0c04a22a65091b9, 0xbfca5a7fa5a888c9, 0x13b7359a76b11aee, 0x27d0d417a2627f71]), alt_bn128::Fq::from_montgomery([0x6fa1b701131a02e0, 0x22f553b9097ede03, 0xb54ad5fee2897b1c, 0x194769df4a0355df])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xda789d25695ecce4, 0xc4a9359d19fc9d87, 0x064dda29c5add346, 0x19a1096972dca754]), alt_bn128::Fq::from_montgomery([0x7d24d02a0116cb91, 0xf9667000162eb98b, 0xcbbbe6cb10005908, 0x1f4925c12d04d752]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x47edfaaf02c4e3a8, 0x37bfd3d44da602f3, 0x1f17417c7147a30f, 0x14e3bd2d9a0cd03b]), alt_bn128::Fq::from_montgomery([0x2a409311e07dcd66, 0x8b14da5b90d56645, 0xd7fd3b6827ef12f9, 0x053f9d5d7a7c6772])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xd213b2087dad7bc8, 0x3406cff396c4cb36, 0xe3d5fe6091d07878, 0x25c1a0fec516a317]), alt_bn128::Fq::from_montgomery([0x346d003f7d71d3aa, 0x2a578b846f2341e8, 0x16b6d92abed35ccf, 0x17bd6255d9d25fa3])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x022d0e6e4bab2376, 0x837feb55adde3268, 0xfb4d53d682deeb98, 0x0c01520566de24ca]), alt_bn128::Fq::from_montgomery([0xeae28c45506e0cd1, 0xf2f26d9abe4428c6, 0xc0556aab9fc7a595, 0x069efe69fabc5e02]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xfe710baf6f317ebc, 0x6b4ecae1a217b191, 0x1508d34f8eab4536, 0x1a54ce5bc6835c8b]), alt_bn128::Fq::from_montgomery([0xc35540c6ded8fcd4, 0xef1c662b30b73bb6, 0xc2f64b312a5a6be6, 0x1ce3fc0edf918dd7])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x4726cc1f86c18453, 0xd8035be48f2ec160, 0xffa0fab9f0f0e0a7, 0x1b77b3384715bcc0]), alt_bn128::Fq::from_montgomery([0x338ac0fde473543f, 0x51336cb96df80f0d, 0x4eaaeff2bc8e1526, 0x24a99467b401e68a])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x31a86b55ea3275fb, 0xece919365f20860c, 0x17ab8ca4cde44cbb, 0x08c862e509bed6fc]), alt_bn128::Fq::from_montgomery([0x010889ec698278ea, 0xc26b5b6080afda7f, 0x6e4fbb3eca7b9e29, 0x081c885055808e67]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x1513ccbacc215824, 0x5648847eacda1b3a, 0x59e04752081f8111, 0x1fdc745a9319469d]), alt_bn128::Fq::from_montgomery([0xe41eb01ab5a1e26e, 0xfe9c2b37c6cf6a53, 0x56eb32c68e1e7fd1, 0x066c5e0579f1b474])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x069563321818e475, 0xe9c1e973d5a93b54, 0x07fa72c6bb320a96, 0x173c2682cc9722cf]), alt_bn128::Fq::from_montgomery([0x22456275ff385998, 0x9ea3a807beffe6b3, 0x3342a021d61e2211, 0x0cacd5cc2849881b])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xa874028a8fb081e7, 0x76b9171f5115e7d5, 0x832a11f9f36257bf, 0x182aa941caca08c5]), alt_bn128::Fq::from_montgomery([0x4216975a623f5d84, 0x7cd01e93b7c39f33, 0x7b5758f49f58f580, 0x061ce1940cfcd993]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x9881830684b3c59b, 0x880581f8d58a96d0, 0xc085e399c9449038, 0x2125ffc214cd1259]), alt_bn128::Fq::from_montgomery([0xd6e15a625db92a3c, 0x29fddfc313367019, 0x2a5a17c756e235c8, 0x1fb8ef1d2ba45f3f])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xe582b2b17cd1e89f, 0xb5a658cc539ec76a, 0xfeb75f369fe2da74, 0x2d5ed4e66b0f8fab]), alt_bn128::Fq::from_montgomery([0xc754d77e20336ba5, 0xe78d4d59c62c47fc, 0xc9d3144f19453693, 0x1f368de2f3c7c3a1])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x36368d8137121693, 0xdc59f1a92f55c97b, 0x88a438a2b24fe996, 0x16e4dad762a5747d]), alt_bn128::Fq::from_montgomery([0xb0dae50c867a64e9, 0x7df1416b8fdbba28, 0x3411d3015cf8cc6c, 0x1f4f11bfb22bf664]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x4d244f9c83032e6d, 0xaacbe47b62d004b2, 0x582a9442704ac0a4, 0x13035b7f7ba92c58]), alt_bn128::Fq::from_montgomery([0xbfca5b13b2f9a288, 0xd7dd1f1f063cdb7b, 0xa82db1a16b5945a4, 0x264bb7809141ded4])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x19beeeded1bea516, 0x3e90593666244c20, 0x9a77ff82dd649fac, 0x277c6c7f5782ce55]), alt_bn128::Fq::from_montgomery([0xcc99bd9a4f8c8f0d, 0x754e4aa51ac125b4, 0xeff498c084f971fe, 0x00f6cb1a3c32b0ec])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x60e73b33fcf8a0db, 0xea10977afee48067, 0xd94322b65ae422b4, 0x0c8898aabdaf5784]), alt_bn128::Fq::from_montgomery([0x42ea3a0f1677c783, 0xeec45a401f249f5e, 0xe50c34bcf486d411, 0x1b8cf863ec1bc057]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x7110822ff959c68a, 0x8fe0c84f4b827696, 0x1a6ae15ca2939af1, 0x0b8f16d2539be255]), alt_bn128::Fq::from_montgomery([0xfa58b8beba6ae065, 0xa09a18840248ca5a, 0xea96fe78a9f7c6de, 0x209211989c4cb33d])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xfc5b2a75145fde8e, 0xc1cc91e7a5865c34, 0x8a210b2c019e1e67, 0x0bd55b2f15b04788]), alt_bn128::Fq::from_montgomery([0x515588b16c8d6518, 0x0bab772c362131e2, 0xd53da30d6c9453b5, 0x0b3c81bfdbe1c880])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0e288b180244db06, 0x3959b91e44abd5a8, 0xf2301993864653fc, 0x0b15ffb6dd049483]), alt_bn128::Fq::from_montgomery([0x3ccfceb672e219c6, 0xe0c1da3594f73eee, 0xfde1f46804aa4a37, 0x11fd0b85d4c65f6a]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xe5bcdab243629136, 0xccd5b9417f6a6943, 0xda308373ac86064d, 0x2fbb3cdcf796ce37]), alt_bn128::Fq::from_montgomery([0xc2f8de8a7a992a52, 0xba51266304d0b141, 0x27c0f3287705957a, 0x1d738a4287940937])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xe28cf73c812e66b2, 0x9c582b03d7431f03, 0x64f9c403c4d38f5e, 0x096d183785b555b7]), alt_bn128::Fq::from_montgomery([0x3dde3ed80f1e6171, 0x2178ee38a3260fa3, 0x6f1f2e9a8a360b7a, 0x0ec8c79c29ec5dd5])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x29e64756f1af7d32, 0x242e1d3e245c31ff, 0x62c2ef4478c2582b, 0x2369908f1294d3dd]), alt_bn128::Fq::from_montgomery([0xd3e5f69af4e9daa2, 0x747e68481d977065, 0x8057e7919273c5d2, 0x16e2dba174ad8b66]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x4a643870b92c27e8, 0xaed711c0587ebd5c, 0x2857ae59108cbd03, 0x1ffb4bd0e2d04d68]), alt_bn128::Fq::from_montgomery([0x9f69057bcfd4221b, 0x15fb4af9971683f6, 0x18feeeff0a2f1a3f, 0x036cdd38bd75728c])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xb0302cc1c71eaa90, 0x4821eb43c9c46dae, 0x6943a725a86e3301, 0x1d0ad1e17f4260b0]), alt_bn128::Fq::from_montgomery([0x945b899224bd10a6, 0xbc00dc626a65a60d, 0xb03610868d4cc254, 0x0120c014536d8d2c])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x981576ef01b6946f, 0xab2b5d6c21c11581, 0x00ad78c387e3d3ce, 0x2a38e069b4df9a4b]), alt_bn128::Fq::from_montgomery([0x6efebf271a431373, 0xde083354d70a98ff, 0x007664e8856e3573, 0x1d913e4fb0055830]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x4a47eb908dd0f806, 0x5d40c58a12288d56, 0xac9d9f8eb0420eaa, 0x1973178d4ee189b9]), alt_bn128::Fq::from_montgomery([0x01e8e29ea052293a, 0xcfc8f930a1700da9, 0xb493a8b678d360d7, 0x24313563ee29d22c])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x6c2eeb2869656a40, 0x27972d0492ed4c18, 0x9179f1626e609f64, 0x063b03b1b71a03bc]), alt_bn128::Fq::from_montgomery([0x9a5bb608dd006cbd, 0x2d32aa8a42dbcd94, 0x96a9356d57a0a387, 0x072c94ad51329466])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x7a696f5c11514eee, 0x740b04bc7fcef361, 0xefd70ef6aba2f527, 0x109f5e450294b533]), alt_bn128::Fq::from_montgomery([0xb1bd842658377c66, 0x2a4afed8e3a708bf, 0x1698263fd5678ae8, 0x14b75f744c512643]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xd1cb1f97aa7c4f52, 0x0383254b90c5a405, 0xc5145d7a0f1e3dd1, 0x03eaa5b1a1685a31]), alt_bn128::Fq::from_montgomery([0x2903fe568b14cfd7, 0x557a39bdc8273ac5, 0xa8976312da68ce7d, 0x29b001d71bb4c836])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x8140d78966853f14, 0x00aba807f46435a0, 0xed0e0e49a1f716b9, 0x05fbe28cfc5394f1]), alt_bn128::Fq::from_montgomery([0x08d80024cee2725e, 0x88afd4f35c8b2160, 0xd508ed86c4a0751c, 0x1565abee156e9870])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xe0616a029a679938, 0x23c63401fb3221ca, 0xa013d79ce12baaa6, 0x304bf13989f50feb]), alt_bn128::Fq::from_montgomery([0xadf2fec918070ff5, 0xf049f2228fea9298, 0x6c107de480893fd1, 0x0549517f203d4272]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x38ef2bacc1c378ef, 0xf3d257727acb4a11, 0x54e9302e9b165222, 0x1dc7411865997a9a]), alt_bn128::Fq::from_montgomery([0x0a167c380e67ae56, 0x1c069bcd265a694c, 0x80ad1558b1139efa, 0x0f673d4c1cbecc73])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xfa8f8ba91c5ef618, 0x0acc44b2b470ffd6, 0x31babea798310af9, 0x010b9fefb9d7d247]), alt_bn128::Fq::from_montgomery([0x8cf1d45b28a5d2c0, 0xd6d17484ac9c1fef, 0xc9eb94003675cc82, 0x2217776d3551c9f1])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xbd65ce30a3a864b0, 0xf69d59e6b0440fbb, 0x8a972bef44e31065, 0x1f656850cee11e5e]), alt_bn128::Fq::from_montgomery([0x7eb49d34ec2d6ab4, 0x6bf53183093a2a15, 0x1fa56740d7903775, 0x28d6e42923ec8d0e]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x92e7087afb46a98e, 0x278d56fffafe7625, 0xc8236aac8288d50f, 0x0d1c2c44e05bebc8]), alt_bn128::Fq::from_montgomery([0xdc81fcc0c2feb826, 0xcfbd07d547223098, 0x5fb811b212455e67, 0x26bb4d752150b830])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xb5b2cd811cb188d0, 0x04a4b94760aa1078, 0x1ef08333b126a5fa, 0x268a07c44e3f13e9]), alt_bn128::Fq::from_montgomery([0xabc342459753cd57, 0x2b4378d6dcb5e26c, 0xbf77d1cd307d32b1, 0x226a788d1b9e19ca])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xea8eb8537ef8cb74, 0xedc4a0a3ee511eb0, 0x96f1af8beda7e508, 0x16995bca8b28be69]), alt_bn128::Fq::from_montgomery([0x813ed2c6aafc3a3e, 0x9d33c29cc77b92e0, 0xe9943b0573a08535, 0x2d996d8145540035]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xbf13ceb8ffcf3e45, 0xcc1c325b69fffa0e, 0xff4eb06585605dca, 0x18ba10d42a7a6a3f]), alt_bn128::Fq::from_montgomery([0xc02cf63d78025cd4, 0x3803d54841c1b57f, 0x2a14ded5a29fe46d, 0x025dfc58d22b6001])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x54807a8ce6df3f77, 0x2fcdf68ac38cbcb8, 0x4f9d21c6f954ad83, 0x1d7e3c79998ce468]), alt_bn128::Fq::from_montgomery([0xdfcbfcdabf824b80, 0x8198e9f755179b05, 0x6fbe5c98ab0e61ca, 0x1fd41679256f2ac9])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x3004d8321368684e, 0xa22f07238e06f370, 0x8c7f5080064701ef, 0x145039a9cb7d7bac]), alt_bn128::Fq::from_montgomery([0xfc6771487b91fbd5, 0x371f3d1c2ef7e062, 0x25fa59a1c5fda710, 0x17da28492f4cfbab]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x181651645cdc8f87, 0x0f82e814c3b7c19a, 0x9809ebec1c051e87, 0x2dec6c475a3840a3]), alt_bn128::Fq::from_montgomery([0x75e43b67e67072dd, 0xf9e2b2db3c4de395, 0x094790b8894f4903, 0x2cc41cea5aa7043f])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x3936e12d3aed7fe1, 0xcc31b300ce863988, 0xf4cfc89bce075536, 0x10fb3dafbd5b575a]), alt_bn128::Fq::from_montgomery([0x059b676d8c9ebec4, 0xc44ecb1070c2de91, 0x85cdee2e688527f5, 0x13f3f65256ffb92e])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x6198813791f35de1, 0xc5d17eec37227e5c, 0x8ab959cb5dfc06c4, 0x04b3b975ae270a28]), alt_bn128::Fq::from_montgomery([0x001a21dfb293ee2f, 0xe68f6ea66e836e18, 0xf7efb71687145578, 0x15a3a7785e58d721]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x5301c2144f0fe04b, 0xbb0fd4707bc6f9f1, 0xaa1ea5fdb2fbaa3e, 0x0824b18a3d6b94a6]), alt_bn128::Fq::from_montgomery([0x0822ebec73317c43, 0x1e184243cc473830, 0xc8e5225f98fc5741, 0x0c959c88f43338f0])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x91a9e525c7d2d0f0, 0x5ee0e1b166095974, 0x7a9188317611946a, 0x11accd3f7434e355]), alt_bn128::Fq::from_montgomery([0x5c06d3819363dd10, 0x337ad734f02b2e4b, 0x33f8ddaa4557861e, 0x1488022aa25f2d12])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xae3d863577040b5b, 0x07f4ab8e23c37d83, 0x0c39f0e843d691cf, 0x233a3654e067cff6]), alt_bn128::Fq::from_montgomery([0xbbb72e88dea26ca9, 0xe327f5064e6dfaee, 0x109b71a49284b5e6, 0x142d40fa1546fc8b]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x00e018c7735d4383, 0xfdc7ccaf7dcad338, 0x033689233e7b18f6, 0x2244fcf100b7be9f]), alt_bn128::Fq::from_montgomery([0xaef8d809b2fda050, 0xaa8f88be5ab40208, 0x2629c5c500b9f6ec, 0x168381a375122550])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x752ce701e285b2dc, 0x03c5315dcd06db1d, 0x8c43fbf3e3923a75, 0x258e4fb44ce970a8]), alt_bn128::Fq::from_montgomery([0x2995723523df7490, 0x90d43bf0514b79ba, 0x355a323f7acbf611, 0x121c30a3fb195d34])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x7a99d5c01c3ffb22, 0x297a67bf746987cd, 0x3b3307708afb93e1, 0x215a7fda4942298d]), alt_bn128::Fq::from_montgomery([0xa914aaef58da2922, 0x8150e1f541211c23, 0x995d8503a2fb29a2, 0x110a732f531d7178]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x74f2e364d1486d8a, 0xccc121c57a6fcc87, 0x8d2f3343eda1fff8, 0x1104e039f38ee9b2]), alt_bn128::Fq::from_montgomery([0x3f17188e4e61da87, 0xabac6720f4c2b12f, 0xbf23738bf1aa083f, 0x2881619a3d1424b0])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x73916f9086821555, 0x9bf53cee2d85ae4c, 0x2381ddbda9449657, 0x2eda769b3b5c82cd]), alt_bn128::Fq::from_montgomery([0xb5c3b230fe6d3949, 0x9dfa46cfdc067b91, 0xe6a9dd72c45f76d4, 0x14b1817e9fe38948])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x5897df2081d9dcf5, 0xb8c3c00898f97a46, 0x3990c417cf1c01d0, 0x202c7f32fa463da7]), alt_bn128::Fq::from_montgomery([0xb5c9906935af00f1, 0xf0a09e11dc572c53, 0xc54579bf64a200b7, 0x0fe43861f9589387]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x1fae386dcf840e53, 0x6387bdb5157351a6, 0x9a66d3bb6adde741, 0x0bea447e14743470]), alt_bn128::Fq::from_montgomery([0x4bb69a5c29388594, 0xb872d7efdc80b243, 0xe81dd4fc5a2f12e8, 0x05f3e4cdd1abb3e0])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x1f2fe89c9035b053, 0xff5c39e8935227e3, 0x34eed78d7d87463f, 0x153068d6d2ccebdd]), alt_bn128::Fq::from_montgomery([0x26d64cad192a0eee, 0xe3c3c2921936a867, 0xef939f111b5bdfe4, 0x10d3072bf0c2d427])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x7a4e831ea2485a7b, 0xc222ae8921d1039b, 0x1b3e579e02807bfb, 0x16ed2447bd41d8ff]), alt_bn128::Fq::from_montgomery([0xe53745c835e9d937, 0x353674da910989b1, 0xa5477822c620d6fe, 0x283421caec5c8169]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x15137e6209e0f7b9, 0xe39d359b05ae6ef5, 0x7a0cdbf02977b446, 0x197c35da53d05723]), alt_bn128::Fq::from_montgomery([0x358fb4c09d59f00b, 0xb7ec2c0b6429490e, 0x325828b9bbe0d516, 0x050c3f92fdbaebb5])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xa1a656a1ca3c8037, 0x368a2703ec997f01, 0x46ec09e0d6acf047, 0x22899c3c8586fb76]), alt_bn128::Fq::from_montgomery([0x9ed3b39e029e2f11, 0x0cd80c21c04cf3cf, 0x7c7130c69a4704f6, 0x2fe6b6b3db59fcdb])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xe36dd17dc9b03cc0, 0x89fc60932c8b6996, 0x724caca83bb422a8, 0x14de39195183256f]), alt_bn128::Fq::from_montgomery([0x7d7ecdf731e97131, 0xf4f0632a69e5c77b, 0xc0797d638a4c876a, 0x1c7d5d02d317d518]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xe59d72ddac846546, 0xd83baa6ca0c08d43, 0x6aeeeb4efdc85ef6, 0x163d29ea5dfb4b48]), alt_bn128::Fq::from_montgomery([0x0cbd2b20a4b3fea4, 0x01741fd9b3e2845f, 0xe43c1a10dd604f9c, 0x0c6630c68e32629a])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x12b2137733c06951, 0x852e522aea8c318c, 0xf0c6029abc687d78, 0x19ed5c750bb0e5e7]), alt_bn128::Fq::from_montgomery([0xc1b290bda3cb634f, 0xef20d38c0365a84f, 0x7694bcd1de65d0f9, 0x0be98220902e07f9])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x05322003ff133415, 0x544bfbe698beb561, 0xcf8b1a6c0646e13e, 0x2d55f50616165b51]), alt_bn128::Fq::from_montgomery([0x2e30cb95ea0429e6, 0xfdaedc0df63e7251, 0xf9f9449c11ce9365, 0x239a76b15abfa6b2]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xf1d2361051a2e4b7, 0xe75cc9a6a0704429, 0x98f32a7cdb80ec5a, 0x19c7a48ab9b58868]), alt_bn128::Fq::from_montgomery([0x1a473b1c0cf0a9bf, 0xf14b720a462ea738, 0xa591b391850cae15, 0x18036bb6b64235c6])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xcd7035a960fc7f4d, 0xccacd571ecef50d5, 0x18a77f953d0231ec, 0x144a361d51cd186e]), alt_bn128::Fq::from_montgomery([0xbfc341056d638bfe, 0x22e3e38f5b160ca4, 0xf8c8fb66cb67a9ea, 0x2e452f3a384cf9d4])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x4dbe74707a2d22e2, 0xa1fa1da6df37bb0d, 0xa57ce97e1e2191cf, 0x2819afc23bba9556]), alt_bn128::Fq::from_montgomery([0xe69c8dd279661c43, 0x36b5d342d22c1a2e, 0x23cd6d8e33c718fd, 0x123e980b663646ed]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xb93bfba2f1bd2c91, 0xfcee7acf7fb0da21, 0x6aa057b0bca83be6, 0x0239f2d7677867d0]), alt_bn128::Fq::from_montgomery([0xf2a705036b89baf3, 0x716ed1e884caf443, 0x354644d9949812d5, 0x2ba9db2025a1b542])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x96e49140f9ab6029, 0xb4f3ada2ea5879c1, 0xa13ddad01d7ae294, 0x16f97956b3f406d2]), alt_bn128::Fq::from_montgomery([0x9afc6dedd73e6754, 0x54b7254a64c60201, 0x02774cce2cd63a39, 0x20ef4bc841fd93b1])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xcb5f0a347bdedb63, 0x2b54f75428357415, 0xe7906519eaf503f6, 0x2ddc5c8731bff0e1]), alt_bn128::Fq::from_montgomery([0xcdee9fef4de5beeb, 0xad3ac4a98d7662e3, 0xb2645d6ee647fc89, 0x0e56fd9cc3de319c]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x1e806a5d7541cda0, 0x2f66ec33e98e2406, 0x695a02409a621c6f, 0x065f6f9ed4b8b0eb]), alt_bn128::Fq::from_montgomery([0xc7fba5bba7cb0bc4, 0xdfce750eb0034b2b, 0x83730b154089ef1c, 0x1f75a12658964bbf])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xd15f26bb64fb26d5, 0x3ec1ebe4203a0cf4, 0xcb5fc4225d0db9e3, 0x2330f26945d03543]), alt_bn128::Fq::from_montgomery([0xd14bd59a6bb58b6e, 0xaf343cb6e050d98a, 0x2222c312b00c8941, 0x0225ae0d09df0fa6])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x9e00db1703ffd846, 0x701c9ecd0ae3bfea, 0xc86efbbb5ea77872, 0x2bf598032448ef02]), alt_bn128::Fq::from_montgomery([0xd5e0e48161b15d14, 0x1bee9b9a33cf7ece, 0x880a3904c338bef0, 0x05ae889bb8cdcccb]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xd2b549939fc1b5ea, 0x82e433cf4f45788b, 0xef115fade3f15aec, 0x1e26bc180071f488]), alt_bn128::Fq::from_montgomery([0x0528fcdd6ba19883, 0xedb4926aa4487792, 0x4686f7baa6a642f2, 0x2b76346f94f021bd])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x8042fd6d85309f6b, 0x87b085861e56f2bc, 0x13e106a93c1a47c8, 0x032b94afba3cc583]), alt_bn128::Fq::from_montgomery([0x935d0a7a9558332a, 0x31f5b70eb0e98123, 0xed5d5423d6ba28c4, 0x11e967f9cde1d923])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x51b788726ce1b01e, 0x2e873701f1aa7420, 0x9da4a0e92c5aed5b, 0x23371d322240d450]), alt_bn128::Fq::from_montgomery([0x2db9e7c6df13efd8, 0xcc268c20a96412ee, 0x37279c5840f6ad8c, 0x1d813036d970e858]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x9e1f9a7dbccf8295, 0x5339313abd14a7fa, 0x90aebed311e7b96d, 0x2f0419fdaff2a993]), alt_bn128::Fq::from_montgomery([0x30a943905f8a2bf2, 0xb3e7c365de960f95, 0x53ba15e3f38a3bd6, 0x19460ad6d6d2d8c4])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x7b788fc2823b25a9, 0xf32d4b0c6fce725d, 0xc77e480d721f1313, 0x0417164cd7ebf961]), alt_bn128::Fq::from_montgomery([0xc4963e0540ce34bd, 0xcc4e359e295bb318, 0x8e9154949bd76554, 0x095b07603b75d633])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0a8721a3cf4ac730, 0x4cab5c47973e88b3, 0xf9bf8253b3ee4458, 0x0f88dc120882ebbc]), alt_bn128::Fq::from_montgomery([0x12a6baa15565ecfd, 0x4aece9176f099785, 0xfcb890508bb046b6, 0x2fc9ee462d5123cf]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x22793a4a73d95b6b, 0x8e7f1fb64798d745, 0x1ca5922bf4badc6a, 0x189021f3a37cd87e]), alt_bn128::Fq::from_montgomery([0xbde88d0271d9b5d9, 0x7282e5411269b260, 0xa2f6cc757702e84e, 0x103aa3b1ee7736d6])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x415f924f31d93560, 0x753c0bb34d90dcb5, 0x09d4e0c0894d3a2b, 0x0038240e4b441407]), alt_bn128::Fq::from_montgomery([0xb1c6cbb492c025ed, 0xdc176b13d16d7ac9, 0xedbe7723828f261f, 0x22cf9a7ed7b22131])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x666c9fbd9e91dd72, 0xc4bb9b1672ac308f, 0xc5682282aa0e50f5, 0x2b3f1ec8f373175b]), alt_bn128::Fq::from_montgomery([0xbef9e35c92257050, 0xbf6452b9580dc80f, 0x7f77eaf55faa5e5a, 0x03046c4890a9b4e2]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x7f98d9ac4ed9208e, 0x768a8a980aa5986b, 0x11c040d7bb9f381e, 0x2b97e965ae6e1759]), alt_bn128::Fq::from_montgomery([0x3478eaf99f1ddbe6, 0x973568613b5b7a06, 0x6e42af516b1aeee5, 0x17c70980734da651])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x50b6333487286e0f, 0x107778b169df597f, 0x5f2c2e23773401aa, 0x2545804b823d0e9a]), alt_bn128::Fq::from_montgomery([0x9284bd7dc2d2e50a, 0xe0c7d0b2b88c58fe, 0x6e83c420f730d2d9, 0x293062ff277c1b32])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x27cbc9afa09bcc5f, 0xd06c505c67127d94, 0xdeefa503856537da, 0x0f501d649b1ed2d7]), alt_bn128::Fq::from_montgomery([0xad7dd0efce722b8c, 0x347b7e9131897f1d, 0xe50f96e33c69055a, 0x1a105ad93b92f175]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xd06d50690e271e16, 0xa05b2bebfd2aa974, 0xfbf4a386335d32b4, 0x1dbb5aacf2f67eb4]), alt_bn128::Fq::from_montgomery([0xcccbc9b258009e4a, 0x0d359ba28188b275, 0x083c0885c2fff168, 0x2c99494cd2f5b8e3])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x573ef480fb231061, 0x22266d5d5b780aaa, 0x2ea83f1bc77d3683, 0x0f4fb0ffa9fcbac4]), alt_bn128::Fq::from_montgomery([0x3dcaef86347a3625, 0xa21170b30145b51e, 0xe9367ba44ac7c138, 0x093f4081b1df5419])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x5f8b1315a6c70831, 0x22eaefa5791c05dd, 0x5997f1831fc0ece4, 0x01b597e22f4c9b53]), alt_bn128::Fq::from_montgomery([0x1605382402b7dab0, 0x9818c85a6c5eb88c, 0xdd6e33446fb5b824, 0x2c520a4acb2f63ed]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x04d5fd404b3e96c3, 0x37b46c0fb928f5c9, 0x62106846143878a7, 0x16f566cce40b9391]), alt_bn128::Fq::from_montgomery([0x9b8c32c8e3beb3b5, 0x1a375bb44f9307bc, 0x125394f9960553f6, 0x219911e536ccdf3c])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0a61e099a79e6fb3, 0xae5d6cabb2da6c91, 0xf7bfa0f4af8e091c, 0x1389ea284dbb7ff8]), alt_bn128::Fq::from_montgomery([0xa47443740393f48f, 0x627aa9a94235e7f4, 0xd29bb8bddfd08301, 0x2462c5a684e3a1ec])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x3df01d03bbcd3583, 0x3fdff1307c47c3d1, 0xaeb4d6a3e31c2eb6, 0x14b5262f65c9d06c]), alt_bn128::Fq::from_montgomery([0x5af7c1427704b989, 0xaa8b86f06ce08ea1, 0x81f4cf06acc78501, 0x022b0264600f5993]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x064f7c5328c67e33, 0x435b560e78311653, 0x6c920a0c06134bc0, 0x21dd3a1df768cb32]), alt_bn128::Fq::from_montgomery([0xeb8d4474071a181b, 0xb9eeb600b19f5970, 0x63b3a730189e282b, 0x29bb8ecdc5ea53a3])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xa72964a578ff30e7, 0x864183afa4877154, 0x14e1970ea81b3694, 0x01b4a566fffbf41c]), alt_bn128::Fq::from_montgomery([0xa5f74182151d7096, 0xdd8d0042a3728757, 0xfbf6cb5fc0367e5e, 0x173cccc02e34f653])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x9418d4d027c17cf2, 0xf233d31549dbb3b7, 0x6bce052abf0675cb, 0x0d7cc279ca2e76a6]), alt_bn128::Fq::from_montgomery([0xed86fcd107d3ae68, 0xc27d1db4faa1ffab, 0xdf7c35ba8a50fb67, 0x245a3cc8bc5f2ecd]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x1aeac7fb6af2b27d, 0x8cfe0d100c5947cc, 0x3462c4fe78b76216, 0x2ae0334e16cbb58e]), alt_bn128::Fq::from_montgomery([0x6674b811ee2ef39d, 0xe8d61a5c67e9451f, 0xeb3a530c5204f3a3, 0x2c16d875a1344610])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x08213abb43e8b8d8, 0x310cf9502270d809, 0x985aa37f2ae22707, 0x1650d3936f600885]), alt_bn128::Fq::from_montgomery([0x4b65c16d9e95dda5, 0x61c504beec05eb07, 0xf71d1048f7f32581, 0x24c189ab3e28dcc0])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xd1543cc58a271dc7, 0x6103d76e10e2113a, 0xbf0113585c001f78, 0x0d073ad955a6636e]), alt_bn128::Fq::from_montgomery([0x4f3666a2d6a40684, 0x06f01a5ed80f3964, 0xe4abb07fb3e80b79, 0x15d34a820c53526f]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xeab2eea92622fcb2, 0x1637299ea164676c, 0x5654691ca18b1b85, 0x021fa7ff3c881c5f]), alt_bn128::Fq::from_montgomery([0x4f2ef87f7b76aac0, 0xf10f449ca1fff60d, 0xdafac7cbe88fb946, 0x11fc71bb30e90bc2])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x02ee337e782c39dc, 0x454002e3e40c30b5, 0x3dcc2c337209e5c5, 0x05f6049eb1f5111b]), alt_bn128::Fq::from_montgomery([0x77a38458aab40d74, 0x441b167124b28727, 0x46cd99ccab97d44a, 0x0595f59ea2504be5])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x06e24102457b6086, 0x533809d4a96d4f2e, 0x07dc5bb6a2db112c, 0x131ed70943a85f62]), alt_bn128::Fq::from_montgomery([0xb46f2d5f7b00ad13, 0x218ffe0e3c5e7d9a, 0xbbc08fff3f37f259, 0x2e5fe90ec4b46170]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x540e555a642f84f5, 0xdbadc81667436bfc, 0x1dbf1df644a888e3, 0x1a1aa6425f2378cb]), alt_bn128::Fq::from_montgomery([0x895035c9a91fe573, 0xbf7ca9a41ab97cb9, 0xb764b44053bdaa35, 0x10a7b7bd6736af8e])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xfb825b82164441cc, 0x5d0e241966c8eb74, 0x8ac513b479c55007, 0x2ab28a7612779740]), alt_bn128::Fq::from_montgomery([0xeffc73df2658f68d, 0x56976687b5c0cbd0, 0x63edcd5473d5ee9e, 0x243c67f4c6fe5e32])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x2869aa35965e3a16, 0x1eed79a5025db57b, 0x5b00a037de1fbe50, 0x1cb83efaec2bb1cd]), alt_bn128::Fq::from_montgomery([0x2d74bcd06ff48eb2, 0xddd298307801a9af, 0x99a973b810151868, 0x09053800f6b70980]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xfb4d8757725220ef, 0xeb0ab5fcba23a61f, 0xe33f6077cb5c494a, 0x29e2dc4283065d69]), alt_bn128::Fq::from_montgomery([0x0450a724d5ebe6ec, 0x60bad534bd5a0766, 0x4a3b311e1d7b122f, 0x0fcd3bb933cabb36])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xfb0b76205d7a368b, 0xe4aaddd7b0839eb4, 0x1f6b1903b4491c09, 0x0bcae9d8e130bf52]), alt_bn128::Fq::from_montgomery([0xebfaba4788345298, 0xb0ce201de2f20e57, 0x33a956994336fec2, 0x23fdc174b0b76d6b])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xf3d819aaf386cebc, 0xbd616804378add2e, 0x20e297e92cc637d7, 0x2c507de60564bf55]), alt_bn128::Fq::from_montgomery([0x54f41c0500975b6b, 0x839b221acee4ddc4, 0x9c53820f274656a0, 0x168707836347ccc6]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xe6b5f2150dbfd4a1, 0xacb98dfba2996887, 0x816a3b250bb201c3, 0x19025bcb2bb908bd]), alt_bn128::Fq::from_montgomery([0x6f2dde1998bd0f67, 0xf01aefd91dd5287a, 0x6974ccd503559f3b, 0x127fa8c15cf72fc6])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0f13bdccaf4f6c62, 0x7f3984d2ffd368dc, 0x06706617c727b77e, 0x0de7b299daa74b56]), alt_bn128::Fq::from_montgomery([0xf1a1ea03e32e7d73, 0xbddacdd16b854926, 0x2080c6f6cbc707d8, 0x01c6b041e9632606])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x706d2b465fe5fd6d, 0x37f4aab5f2daa34f, 0xfec03ba4a2eb69c9, 0x2919da74d6f87563]), alt_bn128::Fq::from_montgomery([0xe29593fdc86d2108, 0x55ad0ee9370c3b96, 0xf63abf7d4983a4e4, 0x16cc864736f8edda]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xf4feef2be15ea254, 0xdb5deddbcc9fd3be, 0xe981a90e5d85d633, 0x212046f8b61d2cba]), alt_bn128::Fq::from_montgomery([0xa61ef0c516b07a6e, 0x693fac3edd0eeaa2, 0x02090b5ebfc94ab8, 0x2d00c2c0018a70da])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0724905bf1c644ab, 0x63183a5a21320142, 0x55a42512a24a20b3, 0x2da2a34e08f12b16]), alt_bn128::Fq::from_montgomery([0x5f1076da1ee31ba3, 0x59864ac36e0bbf45, 0x53688d217c86c09a, 0x0caae49a6591a9b7])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xa11f8eac51c2c96f, 0x04e7717b36e12ce9, 0xece9ffe28fc59651, 0x2f74e28473c76134]), alt_bn128::Fq::from_montgomery([0xc49306bee89355fd, 0x42c594960169117a, 0xa0fb66189129b5f4, 0x08b588d4bee5f9c7]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x70c2cb722ad566b3, 0x0a9789c82f39cb2a, 0x1b04a8f95778e3c8, 0x17b08d8a7ba12a2d]), alt_bn128::Fq::from_montgomery([0x474606684bb1a2d8, 0x38685d872bd6272c, 0x9a39888521eb8308, 0x179ff900ad0c6e08])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x112bf1f611c81f5d, 0xa7a3aafbec84d128, 0x8aef88b1fbd39277, 0x1d765a23b70d3425]), alt_bn128::Fq::from_montgomery([0x278b2176ac43f095, 0x2fb5baf047509ef0, 0x404a0c760c5396c6, 0x0cea9baebf7c02f3])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xc4f6977772c0ccd4, 0x48f785b007648682, 0xb30901667cb691b0, 0x1509cb5f085ec1b9]), alt_bn128::Fq::from_montgomery([0x83cf2cd680b6124f, 0x76af785dc2eeb6c0, 0xa30deb2db2399c79, 0x06647beca183370e]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0c9c5733d447f343, 0xd8e9ddbe3e766e38, 0x5a6910ddfc3aaed3, 0x0fc58a141e702add]), alt_bn128::Fq::from_montgomery([0x831661e9f0be068c, 0xf61d48f1098a18da, 0x9550e2d82cabf5a7, 0x0e2ae8eeb46394a3])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x940d65a0ad12aaac, 0xf728c065bbcdadca, 0x8dfeba05fd0c4ce5, 0x10bcc117214f8ebb]), alt_bn128::Fq::from_montgomery([0x5dc4e5c58b80b1f6, 0x481da27af5616c2b, 0xa6a08bdbf3c4c2bd, 0x1a0cfa72f97971aa])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xb1255366c4153179, 0x628caa620eb46370, 0x855392857383b63e, 0x2b0f3a1c2b22c33d]), alt_bn128::Fq::from_montgomery([0x9b43510fc4f5724c, 0x74369f03b8fb45e5, 0x0b009e226ac8d4e0, 0x2535d63d112332cb]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x3081ee4634b5103e, 0x964a53b83b18bb51, 0xe3c65ad47d4cf432, 0x1ed88bfdcb15a191]), alt_bn128::Fq::from_montgomery([0xcbc36e4659cb96e0, 0x0566293d24034d33, 0xea7e79e7dfcca88c, 0x2f2d0268026d8ea3])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xee9cb4acff532cd2, 0xd90c0cee19ab6fb8, 0xbc3840ea58bd5e6d, 0x06c8be13e1eeab36]), alt_bn128::Fq::from_montgomery([0x8099323102583f94, 0x5dbce0baceba8cff, 0x30c5d3c1116983de, 0x1dfa01eca7c64a97])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x9c17079ad7a2a80e, 0x4ee39aec67b55499, 0xf3eb1cc1bc69a8b5, 0x252245dcb5bc8524]), alt_bn128::Fq::from_montgomery([0x3a4a5a7586e26aa6, 0xbbc9e571b27972f3, 0x09f3304420aab2fd, 0x117e5c20812b152b]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x7229c904ca9e5518, 0x7203b76676f1ddc0, 0x20a65914c86d2ffb, 0x262b970a781c6abd]), alt_bn128::Fq::from_montgomery([0xbea126db784745ce, 0xe0060f8827e9e65e, 0xbec33844d4a59516, 0x1791982cf7ffae08])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x6c26186de3357477, 0xb88f695c51650c14, 0x8085ed7188111d96, 0x275543604d59dd67]), alt_bn128::Fq::from_montgomery([0xf7d62672d265d78f, 0xb2788182aa9176c7, 0x7a13c378cf571bc9, 0x18301298c746db3c])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x67a00b62dffdfa11, 0xf3b3e7a3b6e27943, 0x8adcb774cde5d9e3, 0x124e755ed77865b0]), alt_bn128::Fq::from_montgomery([0x5655d7a53b8a55b2, 0x5fc96e904992065b, 0xa624d400fafeb720, 0x24de60871f4f1e57]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x7ddfaba2e9a7b88b, 0xf301b094d8f69822, 0xb41e92ccd414af7f, 0x0c3eed4baa940942]), alt_bn128::Fq::from_montgomery([0xe5f6837ea403dbfa, 0xf01386fa499a6cd9, 0xec747870f2271c76, 0x26779023bf9999a1])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x471682ef70a1b6fb, 0x8ddeb2db53f59179, 0x7be7b37d28658b8c, 0x2edf1c7907130f4e]), alt_bn128::Fq::from_montgomery([0x56cc7fd241d1d368, 0x3b2c238f12605e09, 0x2e91abc124f06e3a, 0x1c1ffe4ad89eb3ad])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xd00802abcbea26c8, 0xf28eb2dc670e9924, 0x63fc0ecf6d5e910a, 0x1040ba78547e4dbe]), alt_bn128::Fq::from_montgomery([0x02e9525c82578411, 0x9ee6d86cd31ceb68, 0x40245bd5024798e3, 0x1a793b95fbef2f36]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xd4f6e06aaf3f6358, 0xcb4d0b53b3adf303, 0x85fc1e7fa1419e17, 0x06435f9879520eec]), alt_bn128::Fq::from_montgomery([0x8ccd2b3c668595e8, 0x05b5893a4949c306, 0xc856a38360350e39, 0x0e356d22b727b091])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xa67ee104df711956, 0x77a7fb1248edaa57, 0x1cf50f840dd4e2c3, 0x2377295061726a3b]), alt_bn128::Fq::from_montgomery([0x6a21f8dd5c4bb23e, 0xc16da542f9c117cc, 0x07245c776c9e360d, 0x1102b73fdef7354e])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x5ee04f047024ac64, 0xeabc25cd19dd452e, 0x0f98fe3c6356e287, 0x29eb13b3416c731d]), alt_bn128::Fq::from_montgomery([0x770d43fcd8d07326, 0x88401b7875c2ee1e, 0x6786b8c8aad0cf51, 0x12ceb712bbe31901]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0c27554bbb2983bb, 0xa3355e032711c789, 0xef58f8124ee4b72c, 0x0edd33eb43067e43]), alt_bn128::Fq::from_montgomery([0xcaebec2993f7120d, 0xb7950edb473adceb, 0x637c28bfb956ad46, 0x03d6d56b9a867eeb])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x02969ca21f8e1347, 0xeccd819a0bc3f3e7, 0x5a5042592e28706e, 0x25f06afeb9945bb4]), alt_bn128::Fq::from_montgomery([0x1c1997e31cc195b8, 0xaba3e202224deb69, 0x0b0514ace199ce7c, 0x28f8f8c908b4741d])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x9d01df930ee3909e, 0x4e4675b9c6fd2629, 0x2fd642c6c84a8e61, 0x18525679fe3dc0da]), alt_bn128::Fq::from_montgomery([0x869f1d5745585c56, 0x575158a997e776f5, 0x03db926ff30b84fe, 0x0d4b2f6cdd263046]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x4d7a7cae43c218bd, 0xcea3e1cac053f6f8, 0x04bef8a31cbac083, 0x24762eedda88f9cb]), alt_bn128::Fq::from_montgomery([0x0fa744e77d858822, 0x7909a3f153cc14f9, 0xa61cde5804c65722, 0x272c72f22805b77b])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x3c0f7ecfacbb75cb, 0x7b8a6fb2da096548, 0x9bb7974f336bf7b0, 0x0ced5d43c794b7ce]), alt_bn128::Fq::from_montgomery([0xc71661fdc893ac27, 0x827b7ceca2b86fc8, 0xf80b39863cb70761, 0x11e4821f599547c0])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xcac25fdfe5e81752, 0x36d64fe604d1def7, 0x5ed5b1d28e348d21, 0x125691ab328d431b]), alt_bn128::Fq::from_montgomery([0x8c00b1338a1e3eaa, 0x4068bc96b2340174, 0x9f0f79c314d59691, 0x2b104aedd921e0ee]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xeb1f122181d59a0b, 0xb51d01836d31f208, 0x647ac53dcb0f6504, 0x00f6b8fa20693c5e]), alt_bn128::Fq::from_montgomery([0xb39e7463947d0373, 0xf59e9e10612c009b, 0x9bdd4bd705ca327a, 0x0e842af7b38f4412])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x10485ec1b4151e91, 0xfc2a6d04cb701ac9, 0x6571e05d18d8b512, 0x2fb229edfd1b3051]), alt_bn128::Fq::from_montgomery([0xe54d93f809225430, 0xf97d4006a9c31f56, 0xd7477efc506740bb, 0x2a1da2ffa3383358])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xc6a71262baac7617, 0x3e0d9f7022e2cbaf, 0x6e08185a042865de, 0x2303ae8eac0018d5]), alt_bn128::Fq::from_montgomery([0xce25ea1b2b8e354b, 0xf747533d15bf2b07, 0x9745b9655dd03479, 0x03b479525298b68d]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xee862b8afed10685, 0x7777649312894964, 0x78356110fe84c37f, 0x19057d224a8f78a8]), alt_bn128::Fq::from_montgomery([0xb95cf27a950d52b0, 0x505576048bce4a97, 0xfac6ef1f6c24344d, 0x049bc27c86e990d2])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xab7b4e9327d37fed, 0x8739c4633e040ed1, 0x7eeb6f68a5f0d651, 0x02b90f3773d47e7c]), alt_bn128::Fq::from_montgomery([0xe0afea03dc3bf9de, 0x603f108b13a134a6, 0xe3c47c63808e4f78, 0x0d33e1ae45f127f6])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x7f66c669ad3e39cc, 0xab1b3a592eb5e81f, 0xe65d8779eed61540, 0x1c8d46b3fb4aa37a]), alt_bn128::Fq::from_montgomery([0x485a34a2b77c3e0f, 0xa0d588e4d70adf24, 0x4ddd6a34aae4b784, 0x1faaec752cf35ff0]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x346a165d506ed348, 0x41c1fdb7214563a2, 0x739ccd399d9f33c0, 0x09e5681e526f0549]), alt_bn128::Fq::from_montgomery([0xe0794d652f12319f, 0x09281d047e73a238, 0xd0133809b6c8eb87, 0x1fbad5553e67ff6d])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x7fc6c15e2285e4e6, 0x99f76083e410ed64, 0x903ae85940bfe3f1, 0x091bae7792cd65ed]), alt_bn128::Fq::from_montgomery([0x7c67c5dd6f23d0ed, 0x4c18e77fec932265, 0x248564d8b65fe252, 0x2a2deae6ddf2b03d])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xf38ab8fc6397dbed, 0x6a20871f8199c015, 0x723469457299865b, 0x16876250c6eac6bd]), alt_bn128::Fq::from_montgomery([0x90c783f7e5aeb1a4, 0x5271f5fabfdcf440, 0xc471b36aa8141807, 0x1fb2a6ae2e263527]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xf6d79fbb0298b2cb, 0x84c32b487aa6cc87, 0x57a035821bd6bffa, 0x22fbd29d02244f95]), alt_bn128::Fq::from_montgomery([0x848610e9fa8c08f7, 0x55542968c345e19b, 0x61cae1abe93b7588, 0x225a8c3e5b1bd27a])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0ed5954017d3a6df, 0x3c70fd5e399f1f3d, 0xb6c69034fb9d47a3, 0x100c1753dff3676a]), alt_bn128::Fq::from_montgomery([0x9a2e684bceb345dc, 0xc30394526aadbb28, 0xdc02e3d6e743fadb, 0x08333e99321d474b])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x213d89859d05b9c7, 0xec0cd0060ca3b778, 0xda9db986a1ac8937, 0x2f3354f25bdf84bc]), alt_bn128::Fq::from_montgomery([0x64945d56106b53b9, 0x87aeddaa565241ee, 0x4f7bf3ba53688455, 0x168278e80c2db365]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xb92e9b03da1ecb70, 0x7e1c5acefd71a1cb, 0x29e5c0513415c0b8, 0x1edd7cf88e29e655]), alt_bn128::Fq::from_montgomery([0x3d9213300ba72cff, 0xd46ef049d3256e1b, 0x2a2b1f6de26c471a, 0x2d6b45c79e669fc5])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xd22d6e81ea173466, 0xfba0079ebfdf2877, 0xb0e0eb6ac493f44b, 0x278bd24ec6bb79f6]), alt_bn128::Fq::from_montgomery([0x4dea254a95a2134d, 0xb7ac79f9f64f23bc, 0x08feb76ca74b2b22, 0x273a7821b892e1bb])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x3f3ae8266ed8fcc2, 0x343b103d5bb964ed, 0x2a930b6d24be96e4, 0x0c0f2da48c05c6db]), alt_bn128::Fq::from_montgomery([0x0587b6c3e730da99, 0xb37b7c1edd53558f, 0x25b39e0b04250095, 0x29d0f50e6b875907]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x8a0a008528cfd57a, 0x389b9261a7db76f8, 0x2176d4ee03b3922d, 0x223b5a58d848ec77]), alt_bn128::Fq::from_montgomery([0xdc1b524e8d8e9f67, 0x4fc6f08450655087, 0xf8e665677ff63919, 0x2472f66c3408842f])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xc7aa5fa5a311233a, 0x09e5124234979182, 0xf9ee7ccb74d03eef, 0x0076df3a2b2c9738]), alt_bn128::Fq::from_montgomery([0xa6cb1a95eb0e73bc, 0xe9823a34f5bfce6a, 0x091b8032bf84bc1d, 0x23e1af70ca76410d])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x05bb2970408ea450, 0xcc2b4cdccdc13756, 0x267ae30e1487f83e, 0x1278d9e8b9922dac]), alt_bn128::Fq::from_montgomery([0x544c335245b28031, 0x92c8615c95e8b125, 0x38905fc90961251b, 0x1f6af497b384d722]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x341191dc879ea9e8, 0x49971f32f8e27680, 0x66933fb74449f4cf, 0x1ebff0d40f95ebfe]), alt_bn128::Fq::from_montgomery([0xba9b19d0c8cc0782, 0x1114f4182793925f, 0x220788660ba3fab9, 0x195427a5b3430d62])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x754beeaa31f5da2d, 0x839e6467c9fded80, 0x70685ec5d037122e, 0x01e804e95975c661]), alt_bn128::Fq::from_montgomery([0xf28659f4b3406b94, 0x17c2ef096e8a6584, 0xa14e21d5ee73ff29, 0x12d65cb03234f281])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xc7d0a00ef2d0477c, 0x5d31f621d586f9b1, 0xdb2b16639b1d2dd0, 0x2d3da084cd41f87e]), alt_bn128::Fq::from_montgomery([0xd393b1859a822ead, 0xaa0c5c4d74933a40, 0xf0fb6e58ddf62eec, 0x2b918b5068b8567e]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xe9205092d23fdc8e, 0x1e52ceaa07d1f3e0, 0xabd1a8423036c58a, 0x001b62eed6a1b645]), alt_bn128::Fq::from_montgomery([0x2114aaeeb203afb0, 0x33f8c3910d0b3eb7, 0xc91da13c2f1c4b3c, 0x096eff8bce24f9be])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x4251748a0e60f31b, 0x140eeb7790f48637, 0x64b798fa2164013e, 0x0d3bc3d3d63eb461]), alt_bn128::Fq::from_montgomery([0xd6ebaf69587652e3, 0xb549ecad8c5d5005, 0xa9f1627f794523b8, 0x24609413097d5a65])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x82cf9aa6970d6b2e, 0x4e07c9532ad86a34, 0x8872fd609e28fe68, 0x0b0f88af8380c9fc]), alt_bn128::Fq::from_montgomery([0x68ea1be0dc021f9a, 0x7f02593d24ff0189, 0x129ef419f8459a0d, 0x13228458bf08b35b]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xaeefad7a8f213403, 0x31c69f26a18a7030, 0xbecdfc77b88c366e, 0x18dac77c86325df8]), alt_bn128::Fq::from_montgomery([0xa05c07d106a993fc, 0x1869c99d1172bfe1, 0x2e8be1a74bd02455, 0x1deae907e3e4169b])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0cb97435b2dc80d8, 0xb11eaf3a7037c39b, 0xf6d887bf949d80ef, 0x1c8a6748c9a33f37]), alt_bn128::Fq::from_montgomery([0xd09af66098aa90ba, 0x2b7d8906b3f617ae, 0xeb1973109459d299, 0x2d25830bbf4d8e61])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x18efa9df5cf33612, 0x91d287ab45876bf8, 0xf7af2daf39f41b40, 0x22da7fddfa8fbc7d]), alt_bn128::Fq::from_montgomery([0x8aa779fdf8e7d542, 0xe17362c55512c349, 0x5c3e9830723eeb42, 0x0c462e69d9ed08aa]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x8a88ee1167b29ec3, 0xe9e95876ff699552, 0xe239e17cdc10f518, 0x16b6a87502f3f4b0]), alt_bn128::Fq::from_montgomery([0xf078540724fc7ad8, 0xcb10ec4d19d2d072, 0xfde1291f2fdb1e0f, 0x043309466b80a826])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x79006e97036012da, 0x3ef369b5c038ec68, 0xcea5f3ea3c226b9d, 0x29cbda4a7cb9fd73]), alt_bn128::Fq::from_montgomery([0x7d7b6a0eaa1e1ef0, 0x0487fbab0a04de91, 0x8e7a83c4273dc820, 0x07d5050c18c3b81e])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x13502aca4f0b5a5f, 0x8687d7a829391c59, 0x8e3ae2949e011f6a, 0x12229b9fed7d5c91]), alt_bn128::Fq::from_montgomery([0xe34f002a03a42b1a, 0xfd5e8889c4973531, 0x80bd706a5d0c61ea, 0x2b64f60c14f1052c]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xb6d4a5e11f5b83c3, 0x607023f4a526de6a, 0xf2ee51958a50a383, 0x19447eeeb13d942b]), alt_bn128::Fq::from_montgomery([0x886c3d006fd929df, 0x55670fd413745ed2, 0xfccb22fb8f3df489, 0x11d5aabe1b49c391])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x74c4fe9d99eda7b5, 0x63878a62a73b104a, 0xabf659df7c41c898, 0x03cc90ea94acbf94]), alt_bn128::Fq::from_montgomery([0x47d1566f455de56d, 0x9562bfd186743e48, 0xf5caa40cd8b61d01, 0x0213b747dd330ac9])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xada865b50b5f92c5, 0x90d19e7de5e51f92, 0x6bff8314a8a17494, 0x25877cd2f9772870]), alt_bn128::Fq::from_montgomery([0x93a6f8268c621c22, 0x181fb28cdb6bf886, 0x6b5dfba4047ae8d0, 0x0f1bd9cbafda6709]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x799f05a311ae3fb2, 0xf2ca69aa3a551b77, 0xda0d8ae8baeb29eb, 0x105c27da465cfd6d]), alt_bn128::Fq::from_montgomery([0x87547ccf0104a6d8, 0x33a8f5f06f149588, 0xc40d4f49283ad49d, 0x17b35c653440bb3f])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xfe48e8d8a49a24ca, 0x6b0142a24d692631, 0xa998067dbb78d0eb, 0x29da9bc3fc81b012]), alt_bn128::Fq::from_montgomery([0x3f9f1a23e13f4bb4, 0xe0ea561d298e5eb8, 0x04c718bf496d0fbd, 0x01d72f1f715d355d])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xbfc6d8ed06eb1a78, 0x813017dd8d4197b0, 0x2f48c2231e683ba3, 0x096ae5265fa7897b]), alt_bn128::Fq::from_montgomery([0x92401b1802acc7bf, 0x74420b0189136ca0, 0x1097205f5351d581, 0x2e0426ec622cbbaa]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x8590e4cf79968f02, 0x800a3bbc0cc5f7d7, 0x1b8b2d64a5cc14b5, 0x089d7a2aad555367]), alt_bn128::Fq::from_montgomery([0x2ccf8436ca86c17d, 0x005cd0f24029f6e8, 0x1216e33c687c2932, 0x2d748dee32e80b3d])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x961ea45e8a8fb305, 0x964e7000c029649d, 0xef53e7557ad1c0f4, 0x0931f5e690c979a4]), alt_bn128::Fq::from_montgomery([0x5bcf7aa38ea89d19, 0x47cb0d9f2060342d, 0xd5ff5d6ebb731bd4, 0x24e9870960cec497])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x9b7cd10563c11a94, 0x488b5f7fdff30eca, 0xbfa6a613123e790c, 0x0da3acefa32ee38c]), alt_bn128::Fq::from_montgomery([0x1b541ef1f0dc5051, 0x9a112ea6ebd79e1a, 0xbdf32c247c469249, 0x054bc3eea301bf57]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x211fdb68a183382a, 0x294c7132d65537ae, 0xc9ee052454b4db55, 0x21183e087e0800db]), alt_bn128::Fq::from_montgomery([0x38b5e565f5e0a465, 0x402c7260f255a011, 0x1760f40adc12771f, 0x206fe0eb0e340855])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xfdf559a3fce38b93, 0x5bb6cf8131800e40, 0xfba72115980a6525, 0x2bda9a493eba82b3]), alt_bn128::Fq::from_montgomery([0x254b4758a4f71e4f, 0xccbc08189cce42c7, 0x42fff92f3721f85a, 0x2a44dbb1e50e1574])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x73a95873f303eefc, 0x7b547b46319125ce, 0x900c7e1aa8a873fd, 0x1383e8cb81721db3]), alt_bn128::Fq::from_montgomery([0x53f59c6c3e48f135, 0xd3fceb17ad475a31, 0x1e0998b2a877d47b, 0x13efc3167aa0c52b]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x4e78c98d85f311fe, 0xd979692318550450, 0x7cbeeeb25713e5d8, 0x065474129fc32946]), alt_bn128::Fq::from_montgomery([0x6e5f00ae0374dac5, 0xb69439e65ac7d439, 0x9a40a11e2f88b73c, 0x23db0527fa94ec01])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x229a996dba0f1340, 0xe6ade95b71d4ed3d, 0x3d1b57a68f3fa270, 0x1dbce770701dd356]), alt_bn128::Fq::from_montgomery([0x60f4b748c3c51c0c, 0x7a21e79e98aaad98, 0xd68d225ce017afbf, 0x1d0b73c4ccc65c17])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x805f82a2c84cb8c3, 0x8af8d8925af08074, 0xac4f9b143eb4b63b, 0x0ab7ef2d5e275c46]), alt_bn128::Fq::from_montgomery([0xccf9eba2d54c1717, 0xd4a6721a581be041, 0x9e1eecd1e349ca36, 0x223ed1143d34c82d]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xaed379dc0080cfae, 0x4b754ed0835e2377, 0xdea281734f550b87, 0x1da30bebf594a0d5]), alt_bn128::Fq::from_montgomery([0x6334198c7f84ac36, 0xf8f98f9b42617db9, 0x75df2856895f3bba, 0x023e6eea458e1008])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xd015d1e6b2203c6d, 0x134521c2aa996a65, 0xbac9e4cb91b4cdd1, 0x0e97e8fa4d07c860]), alt_bn128::Fq::from_montgomery([0x052148e6b75439c8, 0x889cf81a44d89c78, 0x0d05cede67564f2c, 0x060d78dd14424063])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x9b8111feeeaaa66e, 0x9ce93b02bdca16a9, 0xa74b9518e4ffdc9a, 0x15c04cfac4d22435]), alt_bn128::Fq::from_montgomery([0xe52006582fac2431, 0xbe2ee8fa06362489, 0xb1a922462fa0e012, 0x2b4b08ec5648b2a1]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x10dc8621949c0617, 0xb1cb40c6673577ec, 0x80c88aef9030a432, 0x094d53d2050e8583]), alt_bn128::Fq::from_montgomery([0x97e0ac411f12902b, 0xc911df9f97018dbe, 0x51565a4dd877dd9c, 0x09d8347034fa7dfd])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xd587b49c83181dc8, 0xd6e5fda07646933b, 0x89f8d33504b51f94, 0x1931c92710c05ccd]), alt_bn128::Fq::from_montgomery([0x4bbab078d8ea671f, 0x314b23a59eb5d43b, 0x445c10bf7e76124b, 0x0e29d0b1c65751ac])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xb401af993a6670fd, 0xf43baa806b269737, 0x7c0414f3e076d229, 0x239371986694a8c1]), alt_bn128::Fq::from_montgomery([0xd7727e51e6827518, 0x7626744962c0deb3, 0x984e79096e5dd990, 0x09847eb2c3ff2d36]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x6c39cd4d8e8be0a2, 0xc60a4b456db2ded6, 0xd3143cc382dc7085, 0x2208cf9c41d0a5af]), alt_bn128::Fq::from_montgomery([0x0302870cc5ddfcc0, 0x7902c78ea8506f3e, 0x85c2807b2a4d3552, 0x03fcdc6e99ab992c])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x6c07eec194f5ee07, 0x92ddb11a3832cd90, 0xaebcabfac4420286, 0x0db782c6d1d0b8df]), alt_bn128::Fq::from_montgomery([0x3c0eb661f6733c6b, 0xdfe70f86662a784d, 0x8541c55527365213, 0x0b911b562864d379])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xb7cf67d4c8030a24, 0x7dd9f5d3a066af50, 0x1b55391ff8b4e236, 0x1b8452f430aa053f]), alt_bn128::Fq::from_montgomery([0x63cba1d66d91404e, 0xd6b9933f696e9921, 0x10f536cf384e5af6, 0x0927ad102e54d4d5]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x8cd603fedf027aff, 0x11808247929c40cb, 0x0d4c5675cf9ccd84, 0x16274cae66df63b3]), alt_bn128::Fq::from_montgomery([0x075039f4c5ddbb49, 0x1e591f69510d85c3, 0x56a1a7e98165c17a, 0x12bdf0f50a5c1aa4])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x6983c086a808f252, 0x6f70e78241f59815, 0xbc455b4748b3afe1, 0x0d6bc55deba7f709]), alt_bn128::Fq::from_montgomery([0x8a1eebd62fbdf73f, 0x80c1a4a979f4ab3c, 0xb4cac94528b513c5, 0x137034c0eb9c66dc])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xb9d37d74c039c1ba, 0xdccc070f76543c04, 0x4338bd814f80c4a0, 0x18de7cc522393ea2]), alt_bn128::Fq::from_montgomery([0x4391f715a5665c88, 0x1aab9198ebb92506, 0xcf915c8603357cfc, 0x0407f05179482293]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xde7b3678e3935638, 0xdb05241500787251, 0x6c182e9305b1d049, 0x173d741bff6264d6]), alt_bn128::Fq::from_montgomery([0x783376ced72cbd7a, 0xbdd92d5019bc456d, 0x5562afdcd1175814, 0x1f7aea19af121460])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x6c4dc1d7301ebc60, 0x65b572aa583fc1fc, 0x01169730179dee8a, 0x09df0e7c1cf885b7]), alt_bn128::Fq::from_montgomery([0x35890d3bae7a87bb, 0x75a79001a1dd4c4a, 0xd07dc7deab06dafd, 0x16aba62c74114442])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0e9c95e8955c58f3, 0xd7dc39acca78a31b, 0x6ca640009035f179, 0x1d0c767781c56a23]), alt_bn128::Fq::from_montgomery([0x86d90c62e5a3a607, 0x4bc731dd3ead1880, 0xd70052c9be7b3de1, 0x293cbac1f1884312]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xa38ce3524b8ff9d1, 0x6d0fb38e64f9d524, 0xd4eccbc9e62d22bc, 0x0a549c779aca4a7d]), alt_bn128::Fq::from_montgomery([0x98347b9661723b72, 0x791a7ac024507e66, 0xdc5153f90a0becf0, 0x230faa7f4574d81b])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x488edc256f47dce9, 0xfc2fdc74aa4daf43, 0x49660af4e6833124, 0x094c4e261e53729f]), alt_bn128::Fq::from_montgomery([0xadb820902b7c93cb, 0x5c979bb72ac9ab16, 0xde0a46b164fb98c1, 0x299c0e5494ecc751])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x7b530292df1e6217, 0x077bfbf5011fc3ee, 0x7a01d23b5e49a73c, 0x071038a1011efd3c]), alt_bn128::Fq::from_montgomery([0x84d1e79f8afb97b5, 0xb5d7258d319c7a22, 0xba5c68f5a94684ea, 0x1a3ac703b02ea17e]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xc080b4293e47e38d, 0xa28d7e22994c1abc, 0x86b48b48df2b0d0b, 0x08ea1573775d6052]), alt_bn128::Fq::from_montgomery([0x8d2441aaeb1fb2a7, 0xed09d0904097fb60, 0xe844bf90789b64bc, 0x19ba89936373a92b])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x58b304cb80192694, 0xc8a952a88de9d98b, 0xe9dcb207d21db563, 0x1df72b1ea2fd6dd6]), alt_bn128::Fq::from_montgomery([0xc2f9159e9b04a088, 0xf545ec476610d1c4, 0x3aae8ad84fa559eb, 0x1fa979ea9000da4c])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xb3c54d0aa39439d1, 0x721d93e85e128174, 0xc35ca344b23bbdd2, 0x07f6de2f26e52a28]), alt_bn128::Fq::from_montgomery([0x28168c56d3749d3e, 0x60d1de576de8e8c8, 0xbf144de3876c603c, 0x1d9dc4a68ae8fbc7]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x817c5c484f54e6a3, 0x58431ebf81dc28f9, 0x49ee4e5d20261639, 0x13bbc7467f94ee2a]), alt_bn128::Fq::from_montgomery([0xf74b52c880160d69, 0x9651cddd40b3e8f6, 0xc0e5b561516c0fe4, 0x049aaf15fc88295b])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xd7494c4fa17127ff, 0xd7a7034cf7a096e0, 0x20ff1b50bd3d9fbe, 0x254e50dffab63b24]), alt_bn128::Fq::from_montgomery([0x230149eb632e53c5, 0xb26b2cfc754341f6, 0xea5d264f0ccb8e43, 0x0c2099692b501653])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x3bdc5219455be150, 0xa4521523aa0db9e3, 0x5e7b7b71c3ebc811, 0x0d73b377c96c9e0c]), alt_bn128::Fq::from_montgomery([0x4b7d0dc1af1a14e0, 0xd7f76e4c7ff89b3e, 0xb739d76cf05292fc, 0x0bc60a24f80a9332]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xf289f561d6a6ff84, 0xd72f30be65d93f2e, 0x930f5ee8878ed315, 0x2b70fe8c4a2c355e]), alt_bn128::Fq::from_montgomery([0x1c9f0997f9868b49, 0x7e0032d60e034cef, 0x08eeec51d491dbcb, 0x08e2db4f503c5324])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x8f0b29a4012fed18, 0x4c98c2345afc8db8, 0xcbc027dc8f77b75b, 0x260faad5f703e0d7]), alt_bn128::Fq::from_montgomery([0x0711007fa60bbf0a, 0x3a32f800e8f22cd2, 0x01b7892f3c7598b8, 0x29fc632278284b52])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x899eef4ba1195b51, 0xdc91438118b8e2c7, 0x9c7e3690a45dbb28, 0x0e45730a58977def]), alt_bn128::Fq::from_montgomery([0xc24fa63b90abeb6c, 0xcb75c3d130bdf7af, 0x316c1be520cd7b38, 0x0f0f35f916c6b4a0]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xf41fc087031913e8, 0x7eb0a50458c1715d, 0xd965db59933692cd, 0x0ed6e23cd23adfc9]), alt_bn128::Fq::from_montgomery([0x92dc4fb71914db5e, 0x3cdff8dc98b72b7c, 0x16750217692e1e2d, 0x1cdea811ad8aa6ba])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x2f22a9bd2ddff3f1, 0x1dc84570998ff9a3, 0x179ebbed3d2ee830, 0x0edddd58cc640aba]), alt_bn128::Fq::from_montgomery([0xcca0af50c5228ee4, 0x2963e7e623bc237f, 0xb984530428655810, 0x254ed2b4569c0eaa])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x73190fc37d5442f0, 0xe06edd0e1d1ac039, 0x5963d3c5af75fdc9, 0x0b5e642b3d9ad4d2]), alt_bn128::Fq::from_montgomery([0xcd47c1af591e8297, 0x125aab8603e60698, 0x1d6c0765acc06482, 0x0ac252f1fc3bfe1d]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x79982be838b1b3e2, 0xbda55b4a9141c365, 0x10dfc6229b3c161c, 0x2a04d0556c15f9bf]), alt_bn128::Fq::from_montgomery([0xdfeef1431133cfb1, 0xc0edfb1fa1551cfd, 0x15edb7d4bd0f550f, 0x0f0f19a6bf7a93c5])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xb37db1a3e93efa92, 0x9c9a25ef6a408800, 0x36010c535fa77c49, 0x03d94c116fef0f61]), alt_bn128::Fq::from_montgomery([0xb242150e88584c68, 0x42321d5c5d9b103e, 0xe0f9470d8ae2eb15, 0x04ba3c62187db4f1])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x9c181668b7ae6788, 0x14ae821969aa7e12, 0xdfb026f440c15f15, 0x2716ac84c8fbd491]), alt_bn128::Fq::from_montgomery([0x1aa17a3bacbdeaef, 0xcbcc1ae0f76fac0c, 0xc30de8e743d4a271, 0x1774e057ef58d8c3]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x30c8e62218fcb1a8, 0x7f820bbeb5301f17, 0x6aafdc59a1e94627, 0x2e3481cf0dc62d7d]), alt_bn128::Fq::from_montgomery([0x1d854884aac248c0, 0x96858735ca466340, 0x03fd3e60e1251f54, 0x0f18603b451f4c01])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x1e6b50995c0828da, 0x186b7734994d0a28, 0xefe22303fbb39421, 0x16600ffd2e93446a]), alt_bn128::Fq::from_montgomery([0xcc6c3d136951a53c, 0xd63add5015e5a659, 0x2f5ef802907626e7, 0x2ace1472cba3fb4b])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x82dd75a4c6ecf4c4, 0x38f13b1d70c3771e, 0xb0b86d7a704dcc81, 0x05b63cca80b47b09]), alt_bn128::Fq::from_montgomery([0x6423bed7afc7d76b, 0x095c5d3c53557ac6, 0x1da03f68df40ce50, 0x2214991e5dcc5270]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xa0d043efc4c0d3c8, 0x5b3edb704ed2c14b, 0xe3ce2baf2eb9656c, 0x2ad5691203847edd]), alt_bn128::Fq::from_montgomery([0x24cfe76b34440d09, 0xd25774a5326075cd, 0xfc658a6cb3a4eb38, 0x2a4d85ccdfebb32d])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xf62590f1769aedca, 0x6ef5aea1aa5ea418, 0x0e3c946f90a25910, 0x24208d6b8e322321]), alt_bn128::Fq::from_montgomery([0xfeb22d595225aed1, 0xb190cd6fd8c491c3, 0x5b728a963e920f35, 0x253d67c71001ade0])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x18d91911db645942, 0x59888b6314108b5b, 0xfbd78b85dd3ca1e8, 0x0415f8593d5eb53e]), alt_bn128::Fq::from_montgomery([0x38b011a1b266b33d, 0x210103e367715cf9, 0x98578c08f9c3af77, 0x27686dde85cb0712]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xfff40acff99482f7, 0x6a4d4cad837ca6c8, 0x835cf02506ccd05c, 0x1e8f9a03d2a0e326]), alt_bn128::Fq::from_montgomery([0x992244416ba6010e, 0x5d58b3485ecf457f, 0x7cc1fe6464f75e6e, 0x0c1c77c2b155cd3e])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xbf6e733a6cfbe025, 0xecd3f120d9bc7664, 0x41437e6d8bc7207a, 0x1713c37859034d5f]), alt_bn128::Fq::from_montgomery([0xdb41fafcdc5cf508, 0x074bf132eb1252c9, 0x2dc6ad1df87e90d3, 0x0b8084268aff69a0])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xd65ebbff8f7bc329, 0x440cf241111a55fc, 0xe78a4b879fc70eec, 0x20bd9bd2d40e7866]), alt_bn128::Fq::from_montgomery([0x8cc3895c3694c62d, 0x4f249ccdf320d27a, 0x8f188531024b3291, 0x16acfad4260763ef]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x37f039aed2bcb18b, 0x38f1e389d7267ff4, 0x68ec11bb64d6cf55, 0x04d0782b43d8bd52]), alt_bn128::Fq::from_montgomery([0xee1d779da3f91aa7, 0xa2bd6bb20ba5de9f, 0xcd191085f81bd5a0, 0x2a8be43f49f88123])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0bf3f21011b339fa, 0xeb2f81c437199b2e, 0x46e77c90fc9bba5b, 0x24e1219e69c5e4d8]), alt_bn128::Fq::from_montgomery([0x5fb7a29b79dd028e, 0x873c15221f6555e1, 0x270b2dc21e314776, 0x11829574e326f7e6])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xc9a8d74c9eb876d9, 0x02fe9dff523ccb83, 0x948f07b7620d82f3, 0x0e7a553aacc696b2]), alt_bn128::Fq::from_montgomery([0x0eaecbe5a7bb08de, 0x6406cb9606155521, 0x45ae073779182b64, 0x13c6861cd86cb3ee]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x3762bb43fbe620be, 0x66463670afe347bb, 0x0cc70d3607413bff, 0x1e95f2a21f7fab7c]), alt_bn128::Fq::from_montgomery([0xb698674510c8db5f, 0xef4236197718942a, 0x7a72f4bf69f3333a, 0x09e5d54999be6c83])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xa953dd2a8bdc1019, 0xa460efea28e5bfda, 0x4f88b21398681b5c, 0x1df3f90080b58205]), alt_bn128::Fq::from_montgomery([0x35d3a1abc7f3a502, 0xf3782e6fa8baf1ab, 0x760bdf916c424de6, 0x04002ebc5e92ed92])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xfb1c63e2ac5bb713, 0x287e0709edaeb3fe, 0xe16ad556dc619d62, 0x0169ae2ddcf1d394]), alt_bn128::Fq::from_montgomery([0x855113b5b5ee43fd, 0x519d26f9d819c54f, 0xd297c835bc5a194a, 0x2bfcb49ba29246ac]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x4a88e48c079b599b, 0x243cc01eb407fe28, 0x430d869ac4b88b17, 0x146abddc81b27182]), alt_bn128::Fq::from_montgomery([0x5a4f058f25599742, 0xa2ec157e1170cf60, 0x59f68f68a683b94d, 0x260eca5b49e35c1c])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xaf4a967137ff61d9, 0xb6278b0449911c7f, 0x0f8c26ec184f3184, 0x193142457cbc83e7]), alt_bn128::Fq::from_montgomery([0xb1b7fdc9eeddcceb, 0x025ec00105e20864, 0xf259d43a0eb4f170, 0x04cbc219178b7566])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x1604b3e217c04084, 0xca3bcb873deabef0, 0x856249989f042e90, 0x1ad78439ab50578f]), alt_bn128::Fq::from_montgomery([0x4070f6cc00594e51, 0xcc05cf297763368d, 0xeef6484300503773, 0x176e8620407e701d]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x946118c046779516, 0xbc7a2c443393e19c, 0x01f7d23c60d20675, 0x05aba7ff6f4263bf]), alt_bn128::Fq::from_montgomery([0x319a98feba2387f8, 0xa640deab1110fb86, 0xd1d1e4df80215203, 0x100fa129ab980a72])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xcbd70028b062fcf7, 0x5e15aee5c98ddd23, 0xa344221703d11c98, 0x273ee1237c057473]), alt_bn128::Fq::from_montgomery([0xe8f21981b1c5fb28, 0xf627b16b0c6398b8, 0xf3842ec7db033ff8, 0x17e8c28ca801e6d3])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xb89d7e18add09cb4, 0x33c275cceb75b2fe, 0x00d3358ae0be1f27, 0x0b49776e7705c330]), alt_bn128::Fq::from_montgomery([0x314a33a622686b83, 0x0edac7bb380bda92, 0x9105f95f9fb77895, 0x18c4bc8e11026089]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xa2222239d91cdfc3, 0x061e7c85da752a68, 0x1480b6946978ed2a, 0x204527a6535d6fd8]), alt_bn128::Fq::from_montgomery([0xa3e24371ed3d1de5, 0x766ed3e398f00d4b, 0x51e2c9ebea5837d3, 0x28d1a74359822fec])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x38cca3e570b91126, 0x7f10a29586e39ac2, 0xc19401c68b953a15, 0x164bd8932a6f6105]), alt_bn128::Fq::from_montgomery([0xe5f1eb686ae0c632, 0x0ec6509c88047fc0, 0x7e216a2aa5385434, 0x092faba867faa833])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xccdb38f3987b8901, 0xc7528644c8719441, 0x7422ce12041166cb, 0x2eee0e73083db395]), alt_bn128::Fq::from_montgomery([0xd8056be0a71ce2b7, 0x9957f31f0ab252bc, 0xadc234dd4e243851, 0x2db1a68fa766ecce]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x892e687526da9f10, 0xb09247f22ae4192f, 0xba90298cde1f548c, 0x0d937dc788aa49f4]), alt_bn128::Fq::from_montgomery([0x7844e2f4afaf4745, 0x15067dc93792dfef, 0x11085daa570d4a07, 0x001d810ee5e2d578])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xfb1da71de72ff9d2, 0x95f5d58949e26bdc, 0x93172760ba89cfe4, 0x2d4bec3452f1fa33]), alt_bn128::Fq::from_montgomery([0xb6238cfadc60c3b7, 0x2e4531e0f93b5f24, 0xc328f99c4080d0c7, 0x18af956f6b8be7e7])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x64ea5ab32893e378, 0x0940c2612f4f33a6, 0x39c93a29b7fc7488, 0x05841f82619e4538]), alt_bn128::Fq::from_montgomery([0x8ecd92846670d48d, 0xd24ae95b543349c7, 0x41a3fb042b2b7645, 0x15de187dc14ef1f7]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xed67f94a49e29942, 0x4299f7045e9c81cc, 0x84952807d27bb403, 0x227091bde970d366]), alt_bn128::Fq::from_montgomery([0xc62fad560189829f, 0x0b1556d1dd81ca43, 0x8296575f227a3444, 0x2366e8c60830fe73])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x4fa419dab5be8d9e, 0x26805f633952eef6, 0x23545cba27074778, 0x13b47e04557c1a24]), alt_bn128::Fq::from_montgomery([0xebaaf2f81a8b890f, 0x8469e9d74e1b2f94, 0x11dd6f8f1827be0a, 0x2065d6a6744317a7])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x9bf1c35ad46dcae9, 0x75dcfa3d0d0284f5, 0xa8cf15177782ef5a, 0x015e1c718624e3ed]), alt_bn128::Fq::from_montgomery([0x6d7939c868368198, 0x93a84c0d398ff55c, 0x3820b6712f01a4fe, 0x1524314bc10ae9c2]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x9db97081b0ef4202, 0x2a2e309993a053ad, 0xeddbbc423c1b1182, 0x105787d573f3d159]), alt_bn128::Fq::from_montgomery([0xc5b4639688bf4dcf, 0x40e3feec0e493fde, 0x4480364db1e8c21e, 0x2429f38be9ee7a6a])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x2cb6929acb012345, 0xd5dff719ea22d1ec, 0x806bb8601d97b267, 0x13a5b562e4e3aade]), alt_bn128::Fq::from_montgomery([0x2c36d5930f66479b, 0x7c024a4a987dd2b0, 0xc29a7f7e3a35fbc4, 0x21d0c73bb445676d])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x81206a6591188e57, 0x8a3476bc8c46a20b, 0x24ba8672ec56b9cb, 0x0daf86a37b7bb060]), alt_bn128::Fq::from_montgomery([0xb85b9530c3bed979, 0x796133530fa7aaf4, 0x9986bde79ebc13e8, 0x2eec9f9a3dd25a41]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x020f53c7428e4d79, 0x9f02bf6d9b0fd314, 0x86b6804857611a1e, 0x0c90c77ffba548f5]), alt_bn128::Fq::from_montgomery([0x581e0076c288fd1f, 0xa351e62bd5454c28, 0x85fea51990f06a7a, 0x279dbafbf415fcc4])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xf1057686f70a8185, 0xd9f2fa01afbe1490, 0x62e28d2b99e02c8d, 0x0c729bc0b8501516]), alt_bn128::Fq::from_montgomery([0xee1f89edbbf72b91, 0x8fdf83033ec8f703, 0xffa0abee0100e8fd, 0x263d0447dc176a51])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x2f3e1e45ec1c9a3f, 0xb09782667882cef9, 0x242c280f29fff7c1, 0x1571a042ce8a92b0]), alt_bn128::Fq::from_montgomery([0x58f3ecd08767ea0f, 0x10265a0cb5c26b9a, 0xc187428c56b66ee2, 0x1c738c1895daa5ea]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x31f85f3cc828dbdb, 0x3c17b981955e028f, 0x73c22b61cfd9c378, 0x178f7330eab54ddb]), alt_bn128::Fq::from_montgomery([0x8a9821f13634d692, 0x8df99af695b27173, 0x32ff5ace04d8b3b4, 0x118ebfc7e2d5a11f])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0xbe9ad2dc78705bf9, 0xf073127044086e54, 0x18c921659c80ca94, 0x10da35465cfedff3]), alt_bn128::Fq::from_montgomery([0xfc3818c685142752, 0x22b811de1c38e4f8, 0xf42708830dce5aca, 0x1359b5ae9af6aead])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x7994ca3c58543694, 0x28d258506e4bb9df, 0x920e588401df45f9, 0x22c351308ba81a7d]), alt_bn128::Fq::from_montgomery([0x892113e0d7a483a8, 0xe05ac326095e0c6a, 0xe09aa4837611f841, 0x1a645e13d2470da5]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+    [alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000])), alt_bn128::Fq2::new(alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]), alt_bn128::Fq::from_montgomery([0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000]))],
+],
+);
+
+pub const VK_IC: [alt_bn128::AffineG1; 2] = [
+alt_bn128::AffineG1::new_unchecked(
+    alt_bn128::Fq::from_montgomery([0x919cd247a634970c, 0x606ea191e7d9130b, 0x4aaf8dc3a3f22126, 0x06cb4bd44a99c740]),
+    alt_bn128::Fq::from_montgomery([0x298ecb2148f7c62e, 0x472e9bb31f8a3d84, 0x9d5c25255bebbd46, 0x0506b88d3647b87b]),
+),
+alt_bn128::AffineG1::new_unchecked(
+    alt_bn128::Fq::from_montgomery([0xa5e3e6db0ac7ee8a, 0xedfefe47e6b170d0, 0x0c04bc15b1a0f35a, 0x1741cc655ce36a5d]),
+    alt_bn128::Fq::from_montgomery([0x573af0afde1fac90, 0x66e59e8226a21a0e, 0x48346287baab3d53, 0x157962d25574518c]),
+),
+];
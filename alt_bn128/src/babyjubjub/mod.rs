@@ -0,0 +1,379 @@
+// Baby Jubjub, the twisted Edwards curve a * x^2 + y^2 = 1 + d * x^2 * y^2 over Fr with
+// a = 168700 and d = 168696, as in circomlib (EIP-2494). The group has order 8 * l; the
+// prime order subgroup is generated by B8 = 8 * G.
+//
+// Points are kept in extended coordinates (X : Y : T : Z) with x = X / Z, y = Y / Z and
+// x * y = T / Z. Since a is a square and d is not, the addition formulas are complete.
+//
+// A compressed point is y in 32 little-endian bytes with the top bit set when
+// x > (r - 1) / 2, the `packPoint` encoding of circomlibjs.
+use crate::{arith::U256, Fr};
+use core::ops::{Add, Neg, Sub};
+
+mod blake512;
+pub mod eddsa;
+
+pub const A: Fr = Fr::from_montgomery([
+    0x95accf61fff261e0,
+    0x24780d659df7d378,
+    0xe0ac11b07e906ae8,
+    0x0f35db2216d3def3,
+]);
+
+pub const D: Fr = Fr::from_montgomery([
+    0x2735f484aff261f5,
+    0x70ba1b579a2e0f63,
+    0xff41c9a91e2caa8c,
+    0x07704a8e8fe6025f,
+]);
+
+/// The order of the prime order subgroup.
+pub const SUBGROUP_ORDER: U256 = U256([
+    0xab3eedb83920ee0a677297dc392126f1,
+    0x060c89ce5c263405370a08b6d0302b0b,
+]);
+
+pub const COFACTOR: u64 = 8;
+
+// (r - 1) / 2
+const HALF_MODULUS: U256 = U256([
+    0x9419f4243cdcb848a1f0fac9f8000000,
+    0x183227397098d014dc2822db40c0ac2e,
+]);
+
+/// The generator `Generator` of circomlib, of order 8 * l.
+pub const GENERATOR: AffinePoint = AffinePoint {
+    x: Fr::from_montgomery([
+        0x04b1094e6adddecd,
+        0x055ba7d9099f7fa1,
+        0x7bbf2870e518e5de,
+        0x0981eab540ef8b9b,
+    ]),
+    y: Fr::from_montgomery([
+        0x345a1f442ffffffd,
+        0x8764472692d3ae4c,
+        0xd70f2edc7b7bf6e8,
+        0x2ed314a75c6b1f82,
+    ]),
+};
+
+/// `Base8` of circomlib, 8 * GENERATOR, which generates the subgroup of order l.
+pub const BASE8: AffinePoint = AffinePoint {
+    x: Fr::from_montgomery([
+        0x0a8fc7bc1a89fa86,
+        0xa7d9d786e9e48627,
+        0xee6158b465bea369,
+        0x14a0ff6d2f874519,
+    ]),
+    y: Fr::from_montgomery([
+        0xb83342d20d0201aa,
+        0x2ffef2f7cdcfeac7,
+        0xbfa79a9425a6e625,
+        0x0dfb859dc3a44b70,
+    ]),
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    NotOnCurve,
+    InvalidEncoding,
+}
+
+// Whether x > (r - 1) / 2, the sign of circomlibjs.
+fn is_negative(x: Fr) -> bool {
+    x.into_u256() > HALF_MODULUS
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AffinePoint {
+    x: Fr,
+    y: Fr,
+}
+
+impl AffinePoint {
+    pub fn new(x: Fr, y: Fr) -> Result<Self, Error> {
+        let (x2, y2) = (x * x, y * y);
+        if A * x2 + y2 != Fr::one() + D * x2 * y2 {
+            return Err(Error::NotOnCurve);
+        }
+        Ok(AffinePoint { x, y })
+    }
+
+    pub const fn new_unchecked(x: Fr, y: Fr) -> Self {
+        AffinePoint { x, y }
+    }
+
+    pub fn identity() -> Self {
+        AffinePoint {
+            x: Fr::zero(),
+            y: Fr::one(),
+        }
+    }
+
+    pub fn x(&self) -> Fr {
+        self.x
+    }
+
+    pub fn y(&self) -> Fr {
+        self.y
+    }
+
+    pub fn to_compressed(&self) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        self.y.into_u256().to_big_endian(&mut out).unwrap();
+        out.reverse();
+        if is_negative(self.x) {
+            out[31] |= 0x80;
+        }
+        out
+    }
+
+    /// Decodes `unpackPoint` of circomlibjs. Non-canonical encodings, y >= r or the sign
+    /// bit set with x = 0, are rejected. The point may be outside the subgroup.
+    pub fn from_compressed(bytes: &[u8; 32]) -> Result<Self, Error> {
+        let mut buf = *bytes;
+        let sign = buf[31] & 0x80 != 0;
+        buf[31] &= 0x7f;
+        buf.reverse();
+        let y = U256::from_slice(&buf)
+            .ok()
+            .and_then(Fr::new)
+            .ok_or(Error::InvalidEncoding)?;
+
+        // x^2 = (1 - y^2) / (a - d * y^2), where a - d * y^2 is never zero as a / d is
+        // not a square.
+        let y2 = y * y;
+        let x2 = (Fr::one() - y2) * (A - D * y2).inverse().unwrap();
+        let mut x = x2.sqrt().ok_or(Error::NotOnCurve)?;
+        if is_negative(x) {
+            x = -x;
+        }
+        if sign {
+            if x.is_zero() {
+                return Err(Error::InvalidEncoding);
+            }
+            x = -x;
+        }
+        Ok(AffinePoint { x, y })
+    }
+
+    /// Decodes x and y as 32 big-endian bytes each.
+    pub fn from_uncompressed(bytes: &[u8; 64]) -> Result<Self, Error> {
+        let coord = |b: &[u8]| {
+            U256::from_slice(b)
+                .ok()
+                .and_then(Fr::new)
+                .ok_or(Error::InvalidEncoding)
+        };
+        AffinePoint::new(coord(&bytes[..32])?, coord(&bytes[32..])?)
+    }
+
+    pub fn to_uncompressed(&self) -> [u8; 64] {
+        let mut out = [0u8; 64];
+        self.x.into_u256().to_big_endian(&mut out[..32]).unwrap();
+        self.y.into_u256().to_big_endian(&mut out[32..]).unwrap();
+        out
+    }
+
+    pub fn is_in_subgroup(&self) -> bool {
+        Point::from(*self).is_in_subgroup()
+    }
+
+    pub fn is_small_order(&self) -> bool {
+        Point::from(*self).is_small_order()
+    }
+}
+
+/// A point in extended coordinates.
+#[derive(Copy, Clone, Debug)]
+pub struct Point {
+    x: Fr,
+    y: Fr,
+    t: Fr,
+    z: Fr,
+}
+
+impl From<AffinePoint> for Point {
+    fn from(p: AffinePoint) -> Self {
+        Point {
+            x: p.x,
+            y: p.y,
+            t: p.x * p.y,
+            z: Fr::one(),
+        }
+    }
+}
+
+impl Point {
+    pub fn identity() -> Self {
+        AffinePoint::identity().into()
+    }
+
+    pub fn generator() -> Self {
+        GENERATOR.into()
+    }
+
+    pub fn base8() -> Self {
+        BASE8.into()
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.x.is_zero() && self.y == self.z
+    }
+
+    pub fn to_affine(&self) -> AffinePoint {
+        let zinv = self.z.inverse().unwrap();
+        AffinePoint {
+            x: self.x * zinv,
+            y: self.y * zinv,
+        }
+    }
+
+    // dbl-2008-hwcd
+    pub fn double(&self) -> Self {
+        let a = self.x * self.x;
+        let b = self.y * self.y;
+        let c = self.z * self.z;
+        let c = c + c;
+        let d = A * a;
+        let xy = self.x + self.y;
+        let e = xy * xy - a - b;
+        let g = d + b;
+        let f = g - c;
+        let h = d - b;
+        Point {
+            x: e * f,
+            y: g * h,
+            t: e * h,
+            z: f * g,
+        }
+    }
+
+    /// `k * self` for any 256-bit `k`, not reduced modulo the group order.
+    pub fn mul(&self, k: &U256) -> Self {
+        let mut acc = Point::identity();
+        let mut started = false;
+        for bit in k.bits() {
+            if started {
+                acc = acc.double();
+            }
+            if bit {
+                acc = acc + *self;
+                started = true;
+            }
+        }
+        acc
+    }
+
+    pub fn mul_by_cofactor(&self) -> Self {
+        self.double().double().double()
+    }
+
+    /// Whether l * self is the identity, so the point is in the prime order subgroup.
+    pub fn is_in_subgroup(&self) -> bool {
+        self.mul(&SUBGROUP_ORDER).is_identity()
+    }
+
+    /// Whether the point is one of the 8 points of order dividing the cofactor.
+    pub fn is_small_order(&self) -> bool {
+        self.mul_by_cofactor().is_identity()
+    }
+}
+
+impl PartialEq for Point {
+    fn eq(&self, other: &Point) -> bool {
+        self.x * other.z == other.x * self.z && self.y * other.z == other.y * self.z
+    }
+}
+
+impl Eq for Point {}
+
+// add-2008-hwcd
+impl Add<Point> for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        let a = self.x * other.x;
+        let b = self.y * other.y;
+        let c = D * self.t * other.t;
+        let d = self.z * other.z;
+        let e = (self.x + self.y) * (other.x + other.y) - a - b;
+        let f = d - c;
+        let g = d + c;
+        let h = b - A * a;
+        Point {
+            x: e * f,
+            y: g * h,
+            t: e * h,
+            z: f * g,
+        }
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point {
+            x: -self.x,
+            y: self.y,
+            t: -self.t,
+            z: self.z,
+        }
+    }
+}
+
+impl Sub<Point> for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        self + (-other)
+    }
+}
+
+#[test]
+fn curve_arithmetic() {
+    let g = Point::generator();
+    let b8 = Point::base8();
+    assert_eq!(g.mul_by_cofactor(), b8);
+    assert_eq!(g.mul(&U256::from(8)).to_affine(), BASE8);
+    assert!(AffinePoint::new(BASE8.x, BASE8.y).is_ok());
+    assert_eq!(AffinePoint::new(BASE8.x, BASE8.x), Err(Error::NotOnCurve));
+
+    assert!(b8.is_in_subgroup());
+    assert!(!g.is_in_subgroup());
+    assert!(!g.is_small_order());
+    assert!(b8.mul(&SUBGROUP_ORDER).is_identity());
+    // G has order 8 * l, so l * G has order 8.
+    let torsion = g.mul(&SUBGROUP_ORDER);
+    assert!(!torsion.is_identity() && torsion.is_small_order());
+    assert!(!(b8 + torsion).is_in_subgroup());
+
+    let (x, y) = (U256::from(12345), U256::from(678));
+    let sum = b8.mul(&x) + b8.mul(&y);
+    assert_eq!(sum, b8.mul(&U256::from(12345 + 678)));
+    assert_eq!(sum - b8.mul(&y), b8.mul(&x));
+    assert_eq!(b8.double(), b8 + b8);
+    assert_eq!(b8 - b8, Point::identity());
+
+    for p in [
+        BASE8,
+        GENERATOR,
+        sum.to_affine(),
+        (-sum).to_affine(),
+        torsion.to_affine(),
+    ] {
+        assert_eq!(AffinePoint::from_compressed(&p.to_compressed()), Ok(p));
+        assert_eq!(AffinePoint::from_uncompressed(&p.to_uncompressed()), Ok(p));
+    }
+    let mut bad = AffinePoint::identity().to_compressed();
+    bad[31] |= 0x80;
+    assert_eq!(
+        AffinePoint::from_compressed(&bad),
+        Err(Error::InvalidEncoding)
+    );
+    assert_eq!(
+        AffinePoint::from_compressed(&[0xff; 32]),
+        Err(Error::InvalidEncoding)
+    );
+}
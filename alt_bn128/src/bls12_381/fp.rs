@@ -0,0 +1,299 @@
+use crate::fields::FieldElement;
+use crate::FieldError;
+use core::ops::{Add, Mul, Neg, Sub};
+
+// p = 0x1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab
+const MODULUS: [u64; 6] = [
+    0xb9feffffffffaaab,
+    0x1eabfffeb153ffff,
+    0x6730d2a0f6b0f624,
+    0x64774b84f38512bf,
+    0x4b1ba7b6434bacd7,
+    0x1a0111ea397fe69a,
+];
+
+// -p^-1 mod 2^64
+const INV: u64 = 0x89f3fffcfffcfffd;
+
+// 2^384 mod p, the Montgomery form of 1.
+const R: [u64; 6] = [
+    0x760900000002fffd,
+    0xebf4000bc40c0002,
+    0x5f48985753c758ba,
+    0x77ce585370525745,
+    0x5c071a97a256ec6d,
+    0x15f65ec3fa80e493,
+];
+
+// 2^768 mod p
+const R2: [u64; 6] = [
+    0xf4df1f341c341746,
+    0x0a76e6a609d104f1,
+    0x8de5476c4c95b6d5,
+    0x67eb88a9939d83c0,
+    0x9a793e85b519952d,
+    0x11988fe592cae3aa,
+];
+
+const P_MINUS_2: [u64; 6] = [
+    0xb9feffffffffaaa9,
+    0x1eabfffeb153ffff,
+    0x6730d2a0f6b0f624,
+    0x64774b84f38512bf,
+    0x4b1ba7b6434bacd7,
+    0x1a0111ea397fe69a,
+];
+
+// (p + 1) / 4, p = 3 mod 4.
+const SQRT_EXP: [u64; 6] = [
+    0xee7fbfffffffeaab,
+    0x07aaffffac54ffff,
+    0xd9cc34a83dac3d89,
+    0xd91dd2e13ce144af,
+    0x92c6e9ed90d2eb35,
+    0x0680447a8e5ff9a6,
+];
+
+// (p - 1) / 2
+const EULER_EXP: [u64; 6] = [
+    0xdcff7fffffffd555,
+    0x0f55ffff58a9ffff,
+    0xb39869507b587b12,
+    0xb23ba5c279c2895f,
+    0x258dd3db21a5d66b,
+    0x0d0088f51cbff34d,
+];
+
+#[inline(always)]
+fn mac(a: u64, b: u64, c: u64, carry: &mut u64) -> u64 {
+    let t = a as u128 + b as u128 * c as u128 + *carry as u128;
+    *carry = (t >> 64) as u64;
+    t as u64
+}
+
+#[inline(always)]
+fn adc(a: u64, b: u64, carry: &mut u64) -> u64 {
+    let t = a as u128 + b as u128 + *carry as u128;
+    *carry = (t >> 64) as u64;
+    t as u64
+}
+
+#[inline(always)]
+fn sbb(a: u64, b: u64, borrow: &mut u64) -> u64 {
+    let t = (a as u128).wrapping_sub(b as u128 + *borrow as u128);
+    *borrow = (t >> 127) as u64;
+    t as u64
+}
+
+fn geq(a: &[u64; 6], b: &[u64; 6]) -> bool {
+    for i in (0..6).rev() {
+        if a[i] != b[i] {
+            return a[i] > b[i];
+        }
+    }
+    true
+}
+
+fn sub_modulus(a: &mut [u64; 6]) {
+    let mut borrow = 0;
+    for i in 0..6 {
+        a[i] = sbb(a[i], MODULUS[i], &mut borrow);
+    }
+}
+
+/// An element of the base field of BLS12-381 in Montgomery form, six 64-bit limbs.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Debug)]
+#[repr(C)]
+pub struct Fp([u64; 6]);
+
+#[inline]
+pub const fn const_fp(i: [u64; 6]) -> Fp {
+    Fp(i)
+}
+
+impl Fp {
+    /// Converts little-endian limbs so long as they're below the modulus.
+    pub fn from_limbs(limbs: [u64; 6]) -> Option<Self> {
+        if geq(&limbs, &MODULUS) {
+            None
+        } else {
+            Some(Fp(limbs) * Fp(R2))
+        }
+    }
+
+    /// The canonical value in little-endian limbs.
+    pub fn to_limbs(&self) -> [u64; 6] {
+        (*self * Fp([1, 0, 0, 0, 0, 0])).0
+    }
+
+    /// Reads 48 big-endian bytes of a value below the modulus.
+    pub fn from_slice(slice: &[u8]) -> Result<Self, FieldError> {
+        if slice.len() != 48 {
+            return Err(FieldError::InvalidSliceLength);
+        }
+        let mut limbs = [0u64; 6];
+        for (i, b) in slice.iter().rev().enumerate() {
+            limbs[i / 8] |= (*b as u64) << (8 * (i % 8));
+        }
+        Fp::from_limbs(limbs).ok_or(FieldError::NotMember)
+    }
+
+    /// Writes the value in 48 big-endian bytes.
+    pub fn to_big_endian(&self, slice: &mut [u8]) -> Result<(), FieldError> {
+        if slice.len() != 48 {
+            return Err(FieldError::InvalidSliceLength);
+        }
+        let limbs = self.to_limbs();
+        for (i, b) in slice.iter_mut().rev().enumerate() {
+            *b = (limbs[i / 8] >> (8 * (i % 8))) as u8;
+        }
+        Ok(())
+    }
+
+    pub fn from_u64(v: u64) -> Self {
+        Fp([v, 0, 0, 0, 0, 0]) * Fp(R2)
+    }
+
+    // The exponent is little-endian limbs, for the exponents of p that don't fit a U256.
+    fn pow_limbs(&self, exp: &[u64; 6]) -> Self {
+        let mut res = Fp::one();
+        for i in (0..384).rev() {
+            res = res.squared();
+            if exp[i / 64] >> (i % 64) & 1 == 1 {
+                res = res * *self;
+            }
+        }
+        res
+    }
+
+    pub fn legendre(&self) -> i8 {
+        let euler = self.pow_limbs(&EULER_EXP);
+        if euler.is_zero() {
+            0
+        } else if euler == Fp::one() {
+            1
+        } else {
+            -1
+        }
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.legendre() >= 0
+    }
+
+    /// The parity of the canonical value, `sgn0` of RFC 9380.
+    pub fn sgn0(&self) -> bool {
+        self.to_limbs()[0] & 1 == 1
+    }
+
+    pub fn sqrt(&self) -> Option<Self> {
+        let a = self.pow_limbs(&SQRT_EXP);
+        if a.squared() == *self {
+            Some(a)
+        } else {
+            None
+        }
+    }
+}
+
+impl FieldElement for Fp {
+    #[inline]
+    fn zero() -> Self {
+        Fp([0; 6])
+    }
+
+    #[inline]
+    fn one() -> Self {
+        Fp(R)
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0.iter().all(|l| *l == 0)
+    }
+
+    fn inverse(self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            Some(self.pow_limbs(&P_MINUS_2))
+        }
+    }
+}
+
+impl Add for Fp {
+    type Output = Fp;
+
+    #[inline]
+    fn add(self, other: Fp) -> Fp {
+        // p < 2^381, so the sum never carries out of the top limb.
+        let mut out = [0u64; 6];
+        let mut carry = 0;
+        for (i, o) in out.iter_mut().enumerate() {
+            *o = adc(self.0[i], other.0[i], &mut carry);
+        }
+        if geq(&out, &MODULUS) {
+            sub_modulus(&mut out);
+        }
+        Fp(out)
+    }
+}
+
+impl Sub for Fp {
+    type Output = Fp;
+
+    #[inline]
+    fn sub(self, other: Fp) -> Fp {
+        let mut out = [0u64; 6];
+        let mut borrow = 0;
+        for (i, o) in out.iter_mut().enumerate() {
+            *o = sbb(self.0[i], other.0[i], &mut borrow);
+        }
+        if borrow != 0 {
+            let mut carry = 0;
+            for (i, o) in out.iter_mut().enumerate() {
+                *o = adc(*o, MODULUS[i], &mut carry);
+            }
+        }
+        Fp(out)
+    }
+}
+
+impl Mul for Fp {
+    type Output = Fp;
+
+    // Montgomery multiplication, coarsely integrated operand scanning. As p < 2^381 the
+    // running sum stays below 2^384 and needs no extra limb.
+    #[inline]
+    fn mul(self, other: Fp) -> Fp {
+        let mut t = [0u64; 6];
+        for bi in other.0.iter() {
+            let mut carry = 0;
+            for (tj, aj) in t.iter_mut().zip(self.0.iter()) {
+                *tj = mac(*tj, *aj, *bi, &mut carry);
+            }
+            let top = carry;
+
+            let u = t[0].wrapping_mul(INV);
+            let mut carry = 0;
+            mac(t[0], u, MODULUS[0], &mut carry);
+            for j in 1..6 {
+                t[j - 1] = mac(t[j], u, MODULUS[j], &mut carry);
+            }
+            t[5] = top + carry;
+        }
+        if geq(&t, &MODULUS) {
+            sub_modulus(&mut t);
+        }
+        Fp(t)
+    }
+}
+
+impl Neg for Fp {
+    type Output = Fp;
+
+    #[inline]
+    fn neg(self) -> Fp {
+        Fp::zero() - self
+    }
+}
@@ -0,0 +1,297 @@
+use super::fp::{const_fp, Fp};
+use super::fp2::Fp2;
+use super::fp6::Fp6;
+use crate::arith::U256;
+use crate::fields::FieldElement;
+use core::ops::{Add, Mul, Neg, Sub};
+
+// |x| for the curve parameter x = -0xd201000000010000.
+pub(crate) const X_ABS: u64 = 0xd201000000010000;
+
+// (|x| + 1) / 3, so that (x - 1) / 3 is its negation.
+const X_ABS_PLUS_ONE_DIV3: u64 = 0x460055555555aaab;
+
+fn frobenius_coeffs_c1(power: usize) -> Fp2 {
+    match power % 12 {
+        0 => Fp2::one(),
+        1 => Fp2::new(
+            const_fp([
+                0x07089552b319d465,
+                0xc6695f92b50a8313,
+                0x97e83cccd117228f,
+                0xa35baecab2dc29ee,
+                0x1ce393ea5daace4d,
+                0x08f2220fb0fb66eb,
+            ]),
+            const_fp([
+                0xb2f66aad4ce5d646,
+                0x5842a06bfc497cec,
+                0xcf4895d42599d394,
+                0xc11b9cba40a8e8d0,
+                0x2e3813cbe5a0de89,
+                0x110eefda88847faf,
+            ]),
+        ),
+        2 => Fp2::new(
+            const_fp([
+                0xecfb361b798dba3a,
+                0xc100ddb891865a2c,
+                0x0ec08ff1232bda8e,
+                0xd5c13cc6f1ca4721,
+                0x47222a47bf7b5c04,
+                0x0110f184e51c5f59,
+            ]),
+            Fp::zero(),
+        ),
+        3 => Fp2::new(
+            const_fp([
+                0x3e2f585da55c9ad1,
+                0x4294213d86c18183,
+                0x382844c88b623732,
+                0x92ad2afd19103e18,
+                0x1d794e4fac7cf0b9,
+                0x0bd592fc7d825ec8,
+            ]),
+            const_fp([
+                0x7bcfa7a25aa30fda,
+                0xdc17dec12a927e7c,
+                0x2f088dd86b4ebef1,
+                0xd1ca2087da74d4a7,
+                0x2da2596696cebc1d,
+                0x0e2b7eedbbfd87d2,
+            ]),
+        ),
+        _ => unimplemented!(),
+    }
+}
+
+/// Fp6[w] / (w^2 - v).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(C)]
+pub struct Fp12 {
+    c0: Fp6,
+    c1: Fp6,
+}
+
+impl Fp12 {
+    pub fn new(c0: Fp6, c1: Fp6) -> Self {
+        Fp12 { c0, c1 }
+    }
+
+    fn final_exponentiation_first_chunk(&self) -> Option<Fp12> {
+        match self.inverse() {
+            Some(b) => {
+                let a = self.unitary_inverse();
+                let c = a * b;
+                let d = c.frobenius_map(2);
+
+                Some(d * c)
+            }
+            None => None,
+        }
+    }
+
+    // (p^4 - p^2 + 1) / r = (x - 1)^2 / 3 * (x + p) * (x^2 + p^2 - 1) + 1, the
+    // decomposition of Hayashida, Hayasaka and Teruya with the 3 taken out of (x - 1).
+    fn final_exponentiation_last_chunk(&self) -> Fp12 {
+        let a = self.cyclotomic_pow(X_ABS_PLUS_ONE_DIV3).unitary_inverse();
+        let a = a.exp_by_x() * a.unitary_inverse();
+        let b = a.exp_by_x() * a.frobenius_map(1);
+        let c = b.exp_by_x().exp_by_x() * b.frobenius_map(2) * b.unitary_inverse();
+
+        c * *self
+    }
+
+    pub fn final_exponentiation(&self) -> Option<Fp12> {
+        self.final_exponentiation_first_chunk()
+            .map(|a| a.final_exponentiation_last_chunk())
+    }
+
+    pub fn frobenius_map(&self, power: usize) -> Self {
+        Fp12 {
+            c0: self.c0.frobenius_map(power),
+            c1: self
+                .c1
+                .frobenius_map(power)
+                .scale(frobenius_coeffs_c1(power)),
+        }
+    }
+
+    /// The power by the negative x, for elements of the cyclotomic subgroup.
+    pub fn exp_by_x(&self) -> Fp12 {
+        self.cyclotomic_pow(X_ABS).unitary_inverse()
+    }
+
+    pub fn unitary_inverse(&self) -> Fp12 {
+        Fp12::new(self.c0, -self.c1)
+    }
+
+    /// Multiplies by the sparse ell_0 + ell_v v + ell_vw v w, a line of the M-type twist.
+    pub fn mul_by_014(&self, ell_0: Fp2, ell_v: Fp2, ell_vw: Fp2) -> Fp12 {
+        let aa = self.c0.mul_by_01(ell_0, ell_v);
+        let bb = self.c1.mul_by_1(ell_vw);
+        let c1 = (self.c0 + self.c1).mul_by_01(ell_0, ell_v + ell_vw) - aa - bb;
+
+        Fp12 {
+            c0: bb.mul_by_nonresidue() + aa,
+            c1,
+        }
+    }
+
+    pub fn cyclotomic_squared(&self) -> Self {
+        let z0 = self.c0.c0;
+        let z4 = self.c0.c1;
+        let z3 = self.c0.c2;
+        let z2 = self.c1.c0;
+        let z1 = self.c1.c1;
+        let z5 = self.c1.c2;
+
+        let tmp = z0 * z1;
+        let t0 = (z0 + z1) * (z1.mul_by_nonresidue() + z0) - tmp - tmp.mul_by_nonresidue();
+        let t1 = tmp + tmp;
+
+        let tmp = z2 * z3;
+        let t2 = (z2 + z3) * (z3.mul_by_nonresidue() + z2) - tmp - tmp.mul_by_nonresidue();
+        let t3 = tmp + tmp;
+
+        let tmp = z4 * z5;
+        let t4 = (z4 + z5) * (z5.mul_by_nonresidue() + z4) - tmp - tmp.mul_by_nonresidue();
+        let t5 = tmp + tmp;
+
+        let z0 = t0 - z0;
+        let z0 = z0 + z0;
+        let z0 = z0 + t0;
+
+        let z1 = t1 + z1;
+        let z1 = z1 + z1;
+        let z1 = z1 + t1;
+
+        let tmp = t5.mul_by_nonresidue();
+        let z2 = tmp + z2;
+        let z2 = z2 + z2;
+        let z2 = z2 + tmp;
+
+        let z3 = t4 - z3;
+        let z3 = z3 + z3;
+        let z3 = z3 + t4;
+
+        let z4 = t2 - z4;
+        let z4 = z4 + z4;
+        let z4 = z4 + t2;
+
+        let z5 = t3 + z5;
+        let z5 = z5 + z5;
+        let z5 = z5 + t3;
+
+        Fp12 {
+            c0: Fp6::new(z0, z4, z3),
+            c1: Fp6::new(z2, z1, z5),
+        }
+    }
+
+    pub fn cyclotomic_pow<I: Into<U256>>(&self, by: I) -> Self {
+        let mut res = Self::one();
+
+        let mut found_one = false;
+
+        for i in by.into().bits() {
+            if found_one {
+                res = res.cyclotomic_squared();
+            }
+
+            if i {
+                found_one = true;
+                res = *self * res;
+            }
+        }
+
+        res
+    }
+}
+
+impl FieldElement for Fp12 {
+    fn zero() -> Self {
+        Fp12 {
+            c0: Fp6::zero(),
+            c1: Fp6::zero(),
+        }
+    }
+
+    fn one() -> Self {
+        Fp12 {
+            c0: Fp6::one(),
+            c1: Fp6::zero(),
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero()
+    }
+
+    fn squared(&self) -> Self {
+        let ab = self.c0 * self.c1;
+
+        Fp12 {
+            c0: (self.c1.mul_by_nonresidue() + self.c0) * (self.c0 + self.c1)
+                - ab
+                - ab.mul_by_nonresidue(),
+            c1: ab + ab,
+        }
+    }
+
+    fn inverse(self) -> Option<Self> {
+        (self.c0.squared() - (self.c1.squared().mul_by_nonresidue()))
+            .inverse()
+            .map(|t| Fp12 {
+                c0: self.c0 * t,
+                c1: -(self.c1 * t),
+            })
+    }
+}
+
+impl Mul for Fp12 {
+    type Output = Fp12;
+
+    fn mul(self, other: Fp12) -> Fp12 {
+        let aa = self.c0 * other.c0;
+        let bb = self.c1 * other.c1;
+
+        Fp12 {
+            c0: bb.mul_by_nonresidue() + aa,
+            c1: (self.c0 + self.c1) * (other.c0 + other.c1) - aa - bb,
+        }
+    }
+}
+
+impl Sub for Fp12 {
+    type Output = Fp12;
+
+    fn sub(self, other: Fp12) -> Fp12 {
+        Fp12 {
+            c0: self.c0 - other.c0,
+            c1: self.c1 - other.c1,
+        }
+    }
+}
+
+impl Add for Fp12 {
+    type Output = Fp12;
+
+    fn add(self, other: Fp12) -> Fp12 {
+        Fp12 {
+            c0: self.c0 + other.c0,
+            c1: self.c1 + other.c1,
+        }
+    }
+}
+
+impl Neg for Fp12 {
+    type Output = Fp12;
+
+    fn neg(self) -> Fp12 {
+        Fp12 {
+            c0: -self.c0,
+            c1: -self.c1,
+        }
+    }
+}
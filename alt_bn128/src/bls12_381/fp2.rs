@@ -0,0 +1,180 @@
+use super::fp::{const_fp, Fp};
+use crate::fields::FieldElement;
+use core::ops::{Add, Mul, Neg, Sub};
+
+// 1 / 2
+const TWO_INV: Fp = const_fp([
+    0x1804000000015554,
+    0x855000053ab00001,
+    0x633cb57c253c276f,
+    0x6e22d1ec31ebb502,
+    0xd3916126f2d14ca2,
+    0x17fbb8571a006596,
+]);
+
+/// Fp[u] / (u^2 + 1).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct Fp2 {
+    c0: Fp,
+    c1: Fp,
+}
+
+impl Fp2 {
+    pub const fn new(c0: Fp, c1: Fp) -> Self {
+        Fp2 { c0, c1 }
+    }
+
+    pub fn scale(&self, by: Fp) -> Self {
+        Fp2 {
+            c0: self.c0 * by,
+            c1: self.c1 * by,
+        }
+    }
+
+    /// Multiplies by u + 1, the non-residue of Fp6 and Fp12.
+    pub fn mul_by_nonresidue(&self) -> Self {
+        Fp2 {
+            c0: self.c0 - self.c1,
+            c1: self.c0 + self.c1,
+        }
+    }
+
+    pub fn frobenius_map(&self, power: usize) -> Self {
+        if power.is_multiple_of(2) {
+            *self
+        } else {
+            Fp2 {
+                c0: self.c0,
+                c1: -self.c1,
+            }
+        }
+    }
+
+    pub fn real(&self) -> &Fp {
+        &self.c0
+    }
+
+    pub fn imaginary(&self) -> &Fp {
+        &self.c1
+    }
+
+    /// The Legendre symbol of the norm `c0^2 + c1^2`, which is a square in Fp exactly when
+    /// the element is a square in Fp2.
+    pub fn legendre(&self) -> i8 {
+        (self.c0.squared() + self.c1.squared()).legendre()
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.legendre() >= 0
+    }
+
+    /// `sgn0` of RFC 9380: the parity of `c0`, or of `c1` when `c0` is zero.
+    pub fn sgn0(&self) -> bool {
+        self.c0.sgn0() || (self.c0.is_zero() && self.c1.sgn0())
+    }
+
+    /// The complex method: with alpha the root of the norm, x0^2 = (c0 +- alpha) / 2 for
+    /// one of the signs, as -1 is not a square, and x1 = c1 / (2 x0).
+    pub fn sqrt(&self) -> Option<Self> {
+        if self.c1.is_zero() {
+            return match self.c0.sqrt() {
+                Some(x0) => Some(Fp2::new(x0, Fp::zero())),
+                None => (-self.c0).sqrt().map(|x1| Fp2::new(Fp::zero(), x1)),
+            };
+        }
+        let alpha = (self.c0.squared() + self.c1.squared()).sqrt()?;
+        let x0 = ((self.c0 + alpha) * TWO_INV)
+            .sqrt()
+            .or_else(|| ((self.c0 - alpha) * TWO_INV).sqrt())?;
+        let x1 = self.c1 * TWO_INV * x0.inverse()?;
+        Some(Fp2::new(x0, x1))
+    }
+}
+
+impl FieldElement for Fp2 {
+    fn zero() -> Self {
+        Fp2 {
+            c0: Fp::zero(),
+            c1: Fp::zero(),
+        }
+    }
+
+    fn one() -> Self {
+        Fp2 {
+            c0: Fp::one(),
+            c1: Fp::zero(),
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero()
+    }
+
+    fn squared(&self) -> Self {
+        // (c0 + c1 u)^2 = (c0 + c1)(c0 - c1) + 2 c0 c1 u
+        let ab = self.c0 * self.c1;
+
+        Fp2 {
+            c0: (self.c0 + self.c1) * (self.c0 - self.c1),
+            c1: ab + ab,
+        }
+    }
+
+    fn inverse(self) -> Option<Self> {
+        (self.c0.squared() + self.c1.squared())
+            .inverse()
+            .map(|t| Fp2 {
+                c0: self.c0 * t,
+                c1: -(self.c1 * t),
+            })
+    }
+}
+
+impl Mul for Fp2 {
+    type Output = Fp2;
+
+    fn mul(self, other: Fp2) -> Fp2 {
+        // Karatsuba
+        let aa = self.c0 * other.c0;
+        let bb = self.c1 * other.c1;
+
+        Fp2 {
+            c0: aa - bb,
+            c1: (self.c0 + self.c1) * (other.c0 + other.c1) - aa - bb,
+        }
+    }
+}
+
+impl Sub for Fp2 {
+    type Output = Fp2;
+
+    fn sub(self, other: Fp2) -> Fp2 {
+        Fp2 {
+            c0: self.c0 - other.c0,
+            c1: self.c1 - other.c1,
+        }
+    }
+}
+
+impl Add for Fp2 {
+    type Output = Fp2;
+
+    fn add(self, other: Fp2) -> Fp2 {
+        Fp2 {
+            c0: self.c0 + other.c0,
+            c1: self.c1 + other.c1,
+        }
+    }
+}
+
+impl Neg for Fp2 {
+    type Output = Fp2;
+
+    fn neg(self) -> Fp2 {
+        Fp2 {
+            c0: -self.c0,
+            c1: -self.c1,
+        }
+    }
+}
@@ -0,0 +1,231 @@
+// BN462, the BN curve of the pairing-friendly curves draft for 128 bits of security:
+// u = 2^114 + 2^101 - 2^14 - 1, q and r of 462 bits, y^2 = x^3 + 5 with G1 generated by
+// (-1, 2) and a twist over Fq2 = Fq[i] / (i^2 + 1) with xi = 2 + i. The tower, the groups
+// and the pairing are those of alt_bn128 running on eight limbs; `fields/bn.rs` shows how
+// another BN curve is set up the same way.
+//
+// Field elements are built from their canonical value, least significant limb first.
+use crate::fields::bn462::{Bn462, Fq as Bn462Fq, Fr as Bn462Fr};
+use crate::fields::{BnFq12, BnFq2, FieldElement, PrimeField};
+use crate::groups::{self, AffineG, BnG1Params, BnG2Params, BnPairingBatch, GroupElement};
+use crate::GroupError;
+use core::ops::{Add, Mul, Neg, Sub};
+
+macro_rules! field_wrapper {
+    ($name:ident, $inner:ty) => {
+        #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+        #[repr(C)]
+        pub struct $name($inner);
+
+        impl $name {
+            pub fn zero() -> Self {
+                $name(<$inner>::zero())
+            }
+            pub fn one() -> Self {
+                $name(<$inner>::one())
+            }
+            /// None unless the value is below the modulus.
+            pub fn new(limbs: [u64; 8]) -> Option<Self> {
+                <$inner>::new(limbs).map($name)
+            }
+            pub fn to_limbs(&self) -> [u64; 8] {
+                self.0.to_limbs()
+            }
+            pub fn modulus() -> [u64; 8] {
+                <$inner>::modulus_limbs()
+            }
+            pub fn inverse(&self) -> Option<Self> {
+                self.0.inverse().map($name)
+            }
+            pub fn is_zero(&self) -> bool {
+                self.0.is_zero()
+            }
+        }
+
+        impl Add<$name> for $name {
+            type Output = $name;
+
+            fn add(self, other: $name) -> $name {
+                $name(self.0 + other.0)
+            }
+        }
+
+        impl Sub<$name> for $name {
+            type Output = $name;
+
+            fn sub(self, other: $name) -> $name {
+                $name(self.0 - other.0)
+            }
+        }
+
+        impl Neg for $name {
+            type Output = $name;
+
+            fn neg(self) -> $name {
+                $name(-self.0)
+            }
+        }
+
+        impl Mul for $name {
+            type Output = $name;
+
+            fn mul(self, other: $name) -> $name {
+                $name(self.0 * other.0)
+            }
+        }
+    };
+}
+
+field_wrapper!(Fq, Bn462Fq);
+field_wrapper!(Fr, Bn462Fr);
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(C)]
+pub struct Fq2(BnFq2<Bn462>);
+
+impl Fq2 {
+    pub fn new(real: Fq, imaginary: Fq) -> Self {
+        Fq2(BnFq2::new(real.0, imaginary.0))
+    }
+    pub fn real(&self) -> Fq {
+        Fq(*self.0.real())
+    }
+    pub fn imaginary(&self) -> Fq {
+        Fq(*self.0.imaginary())
+    }
+}
+
+macro_rules! group_wrapper {
+    ($name:ident, $affine:ident, $params:ty, $base:ident) => {
+        #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+        #[repr(C)]
+        pub struct $name(groups::G<$params>);
+
+        impl $name {
+            pub fn zero() -> Self {
+                $name(groups::G::zero())
+            }
+            pub fn one() -> Self {
+                $name(groups::G::one())
+            }
+            pub fn is_zero(&self) -> bool {
+                self.0.is_zero()
+            }
+            pub fn double(&self) -> Self {
+                $name(self.0.double())
+            }
+            /// None at infinity.
+            pub fn to_affine(&self) -> Option<$affine> {
+                self.0.to_affine().map($affine)
+            }
+        }
+
+        impl Add<$name> for $name {
+            type Output = $name;
+
+            fn add(self, other: $name) -> $name {
+                $name(self.0 + other.0)
+            }
+        }
+
+        impl Sub<$name> for $name {
+            type Output = $name;
+
+            fn sub(self, other: $name) -> $name {
+                $name(self.0 - other.0)
+            }
+        }
+
+        impl Neg for $name {
+            type Output = $name;
+
+            fn neg(self) -> $name {
+                $name(-self.0)
+            }
+        }
+
+        impl Mul<Fr> for $name {
+            type Output = $name;
+
+            fn mul(self, other: Fr) -> $name {
+                $name(self.0 * other.0)
+            }
+        }
+
+        #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+        #[repr(C)]
+        pub struct $affine(AffineG<$params>);
+
+        impl $affine {
+            /// Checks that the point is on the curve and in the subgroup of order r.
+            pub fn new(x: $base, y: $base) -> Result<Self, GroupError> {
+                AffineG::new(x.0, y.0).map($affine)
+            }
+            pub fn x(&self) -> $base {
+                $base(*self.0.x())
+            }
+            pub fn y(&self) -> $base {
+                $base(*self.0.y())
+            }
+        }
+
+        impl From<$affine> for $name {
+            fn from(p: $affine) -> $name {
+                $name(p.0.to_jacobian())
+            }
+        }
+    };
+}
+
+group_wrapper!(G1, AffineG1, BnG1Params<Bn462>, Fq);
+group_wrapper!(G2, AffineG2, BnG2Params<Bn462>, Fq2);
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(C)]
+pub struct Gt(BnFq12<Bn462>);
+
+impl Gt {
+    pub fn one() -> Self {
+        Gt(BnFq12::one())
+    }
+}
+
+impl Mul<Gt> for Gt {
+    type Output = Gt;
+
+    fn mul(self, other: Gt) -> Gt {
+        Gt(self.0 * other.0)
+    }
+}
+
+pub fn pairing(p: G1, q: G2) -> Gt {
+    Gt(groups::pairing(&p.0, &q.0))
+}
+
+/// The product of the pairings with one final exponentiation.
+pub fn pairing_batch(pairs: &[(G1, G2)]) -> Gt {
+    let mut batch = BnPairingBatch::default();
+    for (p, q) in pairs {
+        batch.push(&p.0, &q.0);
+    }
+    Gt(batch.finalize())
+}
+
+#[test]
+fn bn462_api() {
+    let (p, q) = (G1::one(), G2::one());
+    let g1 = p.to_affine().unwrap();
+    assert_eq!(G1::from(AffineG1::new(g1.x(), g1.y()).unwrap()), p);
+    assert_eq!((-g1.x()).to_limbs(), {
+        let mut one = [0; 8];
+        one[0] = 1;
+        one
+    });
+    assert!(Fq::new(Fq::modulus()).is_none());
+
+    let a = Fr::new([6, 0, 0, 0, 0, 0, 0, 0]).unwrap();
+    let b = Fr::new([35, 0, 0, 0, 0, 0, 0, 0]).unwrap();
+    assert_eq!(pairing(p * a, q * b), pairing(p * (a * b), q));
+    assert_eq!(pairing_batch(&[(p * b, q), (-p, q * b)]), Gt::one());
+    assert_ne!(pairing(p, q), Gt::one());
+}
@@ -0,0 +1,207 @@
+// Build script helpers that turn a verifying key into Rust source with `const` points and
+// prepared G2 line coefficients already in Montgomery form, so a verifier embeds its key
+// without spending any cycles on decoding or subgroup checks at runtime.
+//
+// In build.rs:
+//
+//     let src = alt_bn128::codegen::snarkjs_groth16_vk(&std::fs::read_to_string("vk.json")?)?;
+//     std::fs::write(std::path::Path::new(&std::env::var("OUT_DIR")?).join("vk.rs"), src)?;
+//
+// and in the crate:
+//
+//     include!(concat!(env!("OUT_DIR"), "/vk.rs"));
+use crate::{json, AffineG1, AffineG2, Fq, Fq2, G2Prepared, GroupError};
+use core::fmt::Write;
+use std::string::String;
+
+const CRATE: &str = env!("CARGO_PKG_NAME");
+
+#[derive(Debug)]
+pub enum Error {
+    InvalidJson(&'static str),
+    InvalidNumber,
+    Group(GroupError),
+}
+
+impl From<GroupError> for Error {
+    fn from(ge: GroupError) -> Self {
+        Error::Group(ge)
+    }
+}
+
+impl From<json::Error> for Error {
+    fn from(e: json::Error) -> Self {
+        match e {
+            json::Error::InvalidJson(msg) => Error::InvalidJson(msg),
+            json::Error::InvalidNumber => Error::InvalidNumber,
+            json::Error::Group(ge) => Error::Group(ge),
+        }
+    }
+}
+
+/// Parses a base field element written in decimal or, with a `0x` prefix, in hex.
+pub fn parse_fq(s: &str) -> Result<Fq, Error> {
+    Ok(json::parse_fq(s)?)
+}
+
+fn write_fq(out: &mut String, e: &Fq) {
+    let r = e.0.raw().0;
+    write!(
+        out,
+        "{}::Fq::from_montgomery([0x{:016x}, 0x{:016x}, 0x{:016x}, 0x{:016x}])",
+        CRATE,
+        r[0] as u64,
+        (r[0] >> 64) as u64,
+        r[1] as u64,
+        (r[1] >> 64) as u64
+    )
+    .unwrap();
+}
+
+fn write_fq2(out: &mut String, e: &Fq2) {
+    write!(out, "{}::Fq2::new(", CRATE).unwrap();
+    write_fq(out, &e.real());
+    out.push_str(", ");
+    write_fq(out, &e.imaginary());
+    out.push(')');
+}
+
+fn write_affine_g1(out: &mut String, p: &AffineG1) {
+    write!(out, "{}::AffineG1::new_unchecked(\n    ", CRATE).unwrap();
+    write_fq(out, &p.x());
+    out.push_str(",\n    ");
+    write_fq(out, &p.y());
+    out.push_str(",\n)");
+}
+
+fn write_affine_g2(out: &mut String, p: &AffineG2) {
+    write!(out, "{}::AffineG2::new_unchecked(\n    ", CRATE).unwrap();
+    write_fq2(out, &p.x());
+    out.push_str(",\n    ");
+    write_fq2(out, &p.y());
+    out.push_str(",\n)");
+}
+
+fn write_g2_prepared(out: &mut String, p: &AffineG2) {
+    let prepared = G2Prepared::new(*p);
+    writeln!(out, "{}::G2Prepared::from_coeffs(", CRATE).unwrap();
+    write_affine_g2(out, p);
+    out.push_str(",\n[\n");
+    for c in prepared.0.coeffs.iter() {
+        out.push_str("    [");
+        write_fq2(out, &Fq2(c.ell_0));
+        out.push_str(", ");
+        write_fq2(out, &Fq2(c.ell_vw));
+        out.push_str(", ");
+        write_fq2(out, &Fq2(c.ell_vv));
+        out.push_str("],\n");
+    }
+    out.push_str("],\n)");
+}
+
+/// `pub const <name>: AffineG1` for the point (x, y).
+pub fn g1(name: &str, x: &str, y: &str) -> Result<String, Error> {
+    let p = json::parse_affine_g1(x, y)?;
+    let mut out = String::new();
+    write!(out, "pub const {}: {}::AffineG1 = ", name, CRATE).unwrap();
+    write_affine_g1(&mut out, &p);
+    out.push_str(";\n");
+    Ok(out)
+}
+
+/// `pub const <name>: AffineG2` for the point (x[0] + x[1] * i, y[0] + y[1] * i).
+pub fn g2(name: &str, x: [&str; 2], y: [&str; 2]) -> Result<String, Error> {
+    let p = json::parse_affine_g2(x, y)?;
+    let mut out = String::new();
+    write!(out, "pub const {}: {}::AffineG2 = ", name, CRATE).unwrap();
+    write_affine_g2(&mut out, &p);
+    out.push_str(";\n");
+    Ok(out)
+}
+
+/// `pub const <name>: G2Prepared` for the point (x[0] + x[1] * i, y[0] + y[1] * i).
+pub fn g2_prepared(name: &str, x: [&str; 2], y: [&str; 2]) -> Result<String, Error> {
+    let p = json::parse_affine_g2(x, y)?;
+    let mut out = String::new();
+    write!(out, "pub const {}: {}::G2Prepared = ", name, CRATE).unwrap();
+    write_g2_prepared(&mut out, &p);
+    out.push_str(";\n");
+    Ok(out)
+}
+
+/// Source for the Groth16 verification key written by `snarkjs zkey export verificationkey`:
+/// `VK_ALPHA_G1`, `VK_BETA_G2`, `VK_GAMMA_G2`, `VK_DELTA_G2` as prepared points and
+/// `VK_IC` with one point per public input plus one.
+pub fn snarkjs_groth16_vk(src: &str) -> Result<String, Error> {
+    if json::str(json::get(src, "protocol"))? != "groth16" {
+        return Err(Error::InvalidJson("protocol is not groth16"));
+    }
+    if json::str(json::get(src, "curve"))? != "bn128" {
+        return Err(Error::InvalidJson("curve is not bn128"));
+    }
+    let field = |k: &'static str| json::get(src, k).ok_or(Error::InvalidJson(k));
+
+    let mut out = String::new();
+    write!(out, "// Generated by {}::codegen. Do not edit.\n\n", CRATE).unwrap();
+    write!(out, "pub const VK_ALPHA_G1: {}::AffineG1 = ", CRATE).unwrap();
+    write_affine_g1(&mut out, &json::affine_g1(field("vk_alpha_1")?)?);
+    out.push_str(";\n\n");
+    for (name, key) in [
+        ("VK_BETA_G2", "vk_beta_2"),
+        ("VK_GAMMA_G2", "vk_gamma_2"),
+        ("VK_DELTA_G2", "vk_delta_2"),
+    ] {
+        write!(out, "pub const {}: {}::G2Prepared = ", name, CRATE).unwrap();
+        write_g2_prepared(&mut out, &json::affine_g2(field(key)?)?);
+        out.push_str(";\n\n");
+    }
+    let ic = field("IC")?;
+    writeln!(
+        out,
+        "pub const VK_IC: [{}::AffineG1; {}] = [",
+        CRATE,
+        json::items(ic).count()
+    )
+    .unwrap();
+    for p in json::items(ic) {
+        write_affine_g1(&mut out, &json::affine_g1(p)?);
+        out.push_str(",\n");
+    }
+    out.push_str("];\n");
+    Ok(out)
+}
+
+#[test]
+fn snarkjs_groth16_vk_snapshot() {
+    let src = snarkjs_groth16_vk(include_str!("../fixtures/snarkjs/verification_key.json"));
+    assert_eq!(
+        src.unwrap().replace(CRATE, "alt_bn128"),
+        include_str!("../fixtures/snarkjs/verification_key.rs")
+    );
+}
+
+#[test]
+fn embedded_verifying_key() {
+    use crate::{pairing_batch_prepared, Fr, Gt, G1};
+
+    mod vk {
+        include!("../fixtures/snarkjs/verification_key.rs");
+    }
+
+    let proof = include_str!("../fixtures/snarkjs/proof.json");
+    let a = json::affine_g1(json::get(proof, "pi_a").unwrap()).unwrap();
+    let b = json::affine_g2(json::get(proof, "pi_b").unwrap()).unwrap();
+    let c = json::affine_g1(json::get(proof, "pi_c").unwrap()).unwrap();
+    let mut vk_x = G1::from(vk::VK_IC[0]);
+    let public = include_str!("../fixtures/snarkjs/public.json");
+    for (x, ic) in json::items(public).zip(vk::VK_IC[1..].iter()) {
+        vk_x = vk_x + G1::from(*ic) * Fr::from_str(json::string(x).unwrap()).unwrap();
+    }
+    let r = pairing_batch_prepared(&[
+        (-G1::from(a), &G2Prepared::new(b)),
+        (vk::VK_ALPHA_G1.into(), &vk::VK_BETA_G2),
+        (vk_x, &vk::VK_GAMMA_G2),
+        (c.into(), &vk::VK_DELTA_G2),
+    ]);
+    assert!(r == Gt::one());
+}
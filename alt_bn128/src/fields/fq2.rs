@@ -1,92 +1,96 @@
 use crate::arith::{U256, U512};
-use crate::fields::{const_fq, FieldElement, Fq};
+use crate::fields::fp::{FQ_EULER_EXP, FQ_SQRT_EXP};
+use crate::fields::{Bn254, BnCurveConfig, FieldElement, Fq, LazyReduction};
+use core::marker::PhantomData;
 use core::ops::{Add, Mul, Neg, Sub};
 
-#[inline]
-fn fq_non_residue() -> Fq {
-    // (q - 1) is a quadratic nonresidue in Fq
-    // 21888242871839275222246405745257275088696311157297823662689037894645226208582
-    const_fq([
-        0x68c3488912edefaa,
-        0x8d087f6872aabf4f,
-        0x51e1a24709081231,
-        0x2259d6b14729c0fa,
-    ])
-}
-
-#[inline]
-pub fn fq2_nonresidue() -> Fq2 {
-    Fq2::new(
-        const_fq([
-            0xf60647ce410d7ff7,
-            0x2f3d6f4dd31bd011,
-            0x2943337e3940c6d1,
-            0x1d9598e8a7e39857,
-        ]),
-        const_fq([
-            0xd35d438dc58f0d9d,
-            0x0a78eb28f5c70b3d,
-            0x666ea36f7879462c,
-            0x0e0a77c19a07df2f,
-        ]),
-    )
-}
-
+/// Fq[i] / (i^2 + 1) of a BN curve.
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
 #[repr(C)]
-pub struct Fq2 {
-    c0: Fq,
-    c1: Fq,
+pub struct BnFq2<C: BnCurveConfig> {
+    c0: C::Fq,
+    c1: C::Fq,
 }
 
-impl Fq2 {
-    pub fn new(c0: Fq, c1: Fq) -> Self {
-        Fq2 { c0: c0, c1: c1 }
+pub type Fq2 = BnFq2<Bn254>;
+
+impl<C: BnCurveConfig> BnFq2<C> {
+    pub const fn new(c0: C::Fq, c1: C::Fq) -> Self {
+        BnFq2 { c0, c1 }
     }
 
-    pub fn scale(&self, by: Fq) -> Self {
-        Fq2 {
+    pub fn scale(&self, by: C::Fq) -> Self {
+        BnFq2 {
             c0: self.c0 * by,
             c1: self.c1 * by,
         }
     }
 
+    // (c0 + c1 i)(k + i) = (k c0 - c1) + (c0 + k c1) i for xi = k + i.
     pub fn mul_by_nonresidue(&self) -> Self {
-        *self * fq2_nonresidue()
+        let mul_by_k = |a: C::Fq| {
+            let mut r = C::Fq::zero();
+            for i in (0..64 - C::XI_C0.leading_zeros()).rev() {
+                r = r + r;
+                if C::XI_C0 >> i & 1 == 1 {
+                    r = r + a;
+                }
+            }
+            r
+        };
+
+        BnFq2 {
+            c0: mul_by_k(self.c0) - self.c1,
+            c1: self.c0 + mul_by_k(self.c1),
+        }
+    }
+
+    /// The product with both coefficients left unreduced, by Karatsuba.
+    pub fn mul_wide(&self, other: &Self) -> BnFq2Wide<C> {
+        let aa = self.c0.mul_wide(other.c0);
+        let bb = self.c1.mul_wide(other.c1);
+        let mut c0 = aa;
+        C::Fq::sub_wide(&mut c0, &bb);
+        let mut c1 = (self.c0 + self.c1).mul_wide(other.c0 + other.c1);
+        C::Fq::sub_wide(&mut c1, &aa);
+        C::Fq::sub_wide(&mut c1, &bb);
+
+        BnFq2Wide::new(c0, c1)
     }
 
     pub fn frobenius_map(&self, power: usize) -> Self {
-        if power % 2 == 0 {
+        // i^q = (-1)^((q - 1) / 2) i = -i
+        if power.is_multiple_of(2) {
             *self
         } else {
-            Fq2 {
+            BnFq2 {
                 c0: self.c0,
-                c1: self.c1 * fq_non_residue(),
+                c1: -self.c1,
             }
         }
     }
 
-    pub fn real(&self) -> &Fq {
+    pub fn real(&self) -> &C::Fq {
         &self.c0
     }
 
-    pub fn imaginary(&self) -> &Fq {
+    pub fn imaginary(&self) -> &C::Fq {
         &self.c1
     }
 }
 
-impl FieldElement for Fq2 {
+impl<C: BnCurveConfig> FieldElement for BnFq2<C> {
     fn zero() -> Self {
-        Fq2 {
-            c0: Fq::zero(),
-            c1: Fq::zero(),
+        BnFq2 {
+            c0: C::Fq::zero(),
+            c1: C::Fq::zero(),
         }
     }
 
     fn one() -> Self {
-        Fq2 {
-            c0: Fq::one(),
-            c1: Fq::zero(),
+        BnFq2 {
+            c0: C::Fq::one(),
+            c1: C::Fq::zero(),
         }
     }
 
@@ -101,10 +105,8 @@ impl FieldElement for Fq2 {
 
         let ab = self.c0 * self.c1;
 
-        Fq2 {
-            c0: (self.c1 * fq_non_residue() + self.c0) * (self.c0 + self.c1)
-                - ab
-                - ab * fq_non_residue(),
+        BnFq2 {
+            c0: (self.c0 - self.c1) * (self.c0 + self.c1),
             c1: ab + ab,
         }
     }
@@ -113,84 +115,128 @@ impl FieldElement for Fq2 {
         // "High-Speed Software Implementation of the Optimal Ate Pairing
         // over Barreto–Naehrig Curves"; Algorithm 8
 
-        match (self.c0.squared() - (self.c1.squared() * fq_non_residue())).inverse() {
-            Some(t) => Some(Fq2 {
+        (self.c0.squared() + self.c1.squared())
+            .inverse()
+            .map(|t| BnFq2 {
                 c0: self.c0 * t,
                 c1: -(self.c1 * t),
-            }),
-            None => None,
-        }
+            })
     }
 }
 
-impl Mul for Fq2 {
-    type Output = Fq2;
+impl<C: BnCurveConfig> Mul for BnFq2<C> {
+    type Output = Self;
 
-    fn mul(self, other: Fq2) -> Fq2 {
+    fn mul(self, other: Self) -> Self {
         // Devegili OhEig Scott Dahab
         //     Multiplication and Squaring on Pairing-Friendly Fields.pdf
         //     Section 3 (Karatsuba)
 
-        let aa = self.c0 * other.c0;
-        let bb = self.c1 * other.c1;
-
-        Fq2 {
-            c0: bb * fq_non_residue() + aa,
-            c1: (self.c0 + self.c1) * (other.c0 + other.c1) - aa - bb,
-        }
+        self.mul_wide(&other).reduce()
     }
 }
 
-impl Sub for Fq2 {
-    type Output = Fq2;
+impl<C: BnCurveConfig> Sub for BnFq2<C> {
+    type Output = Self;
 
-    fn sub(self, other: Fq2) -> Fq2 {
-        Fq2 {
+    fn sub(self, other: Self) -> Self {
+        BnFq2 {
             c0: self.c0 - other.c0,
             c1: self.c1 - other.c1,
         }
     }
 }
 
-impl Add for Fq2 {
-    type Output = Fq2;
+impl<C: BnCurveConfig> Add for BnFq2<C> {
+    type Output = Self;
 
-    fn add(self, other: Fq2) -> Fq2 {
-        Fq2 {
+    fn add(self, other: Self) -> Self {
+        BnFq2 {
             c0: self.c0 + other.c0,
             c1: self.c1 + other.c1,
         }
     }
 }
 
-impl Neg for Fq2 {
-    type Output = Fq2;
+impl<C: BnCurveConfig> Neg for BnFq2<C> {
+    type Output = Self;
 
-    fn neg(self) -> Fq2 {
-        Fq2 {
+    fn neg(self) -> Self {
+        BnFq2 {
             c0: -self.c0,
             c1: -self.c1,
         }
     }
 }
 
-lazy_static::lazy_static! {
-    static ref FQ: U256 = U256::from([
-        0x3c208c16d87cfd47,
-        0x97816a916871ca8d,
-        0xb85045b68181585d,
-        0x30644e72e131a029
-    ]);
-
-    static ref FQ_MINUS3_DIV4: Fq =
-        Fq::new(3.into()).expect("3 is a valid field element and static; qed").neg() *
-        Fq::new(4.into()).expect("4 is a valid field element and static; qed").inverse()
-        .expect("4 has inverse in Fq and is static; qed");
-
-    static ref FQ_MINUS1_DIV2: Fq =
-        Fq::new(1.into()).expect("1 is a valid field element and static; qed").neg() *
-        Fq::new(2.into()).expect("2 is a valid field element and static; qed").inverse()
-            .expect("2 has inverse in Fq and is static; qed");
+/// An Fq2 element with unreduced coefficients, see `LazyReduction`. Products are summed in
+/// this form and each coefficient is reduced once at the end, as in Aranha, Karabina,
+/// Longa, Gebotys and Lopez, "Faster Explicit Formulas for Computing Pairings over Ordinary
+/// Curves".
+#[derive(Copy, Clone, Debug)]
+pub struct BnFq2Wide<C: BnCurveConfig> {
+    c0: Wide<C>,
+    c1: Wide<C>,
+    curve: PhantomData<C>,
+}
+
+type Wide<C> = <<C as BnCurveConfig>::Fq as LazyReduction>::Wide;
+
+impl<C: BnCurveConfig> BnFq2Wide<C> {
+    fn new(c0: Wide<C>, c1: Wide<C>) -> Self {
+        BnFq2Wide {
+            c0,
+            c1,
+            curve: PhantomData,
+        }
+    }
+
+    pub fn reduce(&self) -> BnFq2<C> {
+        BnFq2 {
+            c0: C::Fq::reduce_wide(&self.c0),
+            c1: C::Fq::reduce_wide(&self.c1),
+        }
+    }
+
+    pub fn mul_by_nonresidue(&self) -> Self {
+        let mul_by_k = |a: &Wide<C>| {
+            let mut r = Wide::<C>::default();
+            for i in (0..64 - C::XI_C0.leading_zeros()).rev() {
+                let d = r;
+                C::Fq::add_wide(&mut r, &d);
+                if C::XI_C0 >> i & 1 == 1 {
+                    C::Fq::add_wide(&mut r, a);
+                }
+            }
+            r
+        };
+
+        let mut c0 = mul_by_k(&self.c0);
+        C::Fq::sub_wide(&mut c0, &self.c1);
+        let mut c1 = mul_by_k(&self.c1);
+        C::Fq::add_wide(&mut c1, &self.c0);
+        BnFq2Wide::new(c0, c1)
+    }
+}
+
+impl<C: BnCurveConfig> Add for BnFq2Wide<C> {
+    type Output = Self;
+
+    fn add(mut self, other: Self) -> Self {
+        C::Fq::add_wide(&mut self.c0, &other.c0);
+        C::Fq::add_wide(&mut self.c1, &other.c1);
+        self
+    }
+}
+
+impl<C: BnCurveConfig> Sub for BnFq2Wide<C> {
+    type Output = Self;
+
+    fn sub(mut self, other: Self) -> Self {
+        C::Fq::sub_wide(&mut self.c0, &other.c0);
+        C::Fq::sub_wide(&mut self.c1, &other.c1);
+        self
+    }
 }
 
 impl Fq2 {
@@ -199,10 +245,10 @@ impl Fq2 {
     }
 
     pub fn sqrt(&self) -> Option<Self> {
-        let a1 = self.pow::<U256>((*FQ_MINUS3_DIV4).into());
+        let a1 = self.pow(FQ_SQRT_EXP);
         let a1a = a1 * *self;
         let alpha = a1 * a1a;
-        let a0 = alpha.pow(*FQ) * alpha;
+        let a0 = alpha.pow(Fq::modulus()) * alpha;
 
         if a0 == Fq2::one().neg() {
             return None;
@@ -211,16 +257,32 @@ impl Fq2 {
         if alpha == Fq2::one().neg() {
             Some(Self::i() * a1a)
         } else {
-            let b = (alpha + Fq2::one()).pow::<U256>((*FQ_MINUS1_DIV2).into());
+            let b = (alpha + Fq2::one()).pow(FQ_EULER_EXP);
             Some(b * a1a)
         }
     }
 
+    /// The Legendre symbol of the norm `c0^2 + c1^2`, which is a square in Fq exactly when
+    /// the element is a square in Fq2.
+    pub fn legendre(&self) -> i8 {
+        (self.c0.squared() + self.c1.squared()).legendre()
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.legendre() >= 0
+    }
+
+    /// `sgn0` of RFC 9380: the parity of `c0`, or of `c1` when `c0` is zero.
+    pub fn sgn0(&self) -> bool {
+        self.c0.sgn0() || (self.c0.is_zero() && self.c1.sgn0())
+    }
+
+    #[allow(clippy::wrong_self_convention)]
     pub fn to_u512(&self) -> U512 {
         let c0: U256 = (*self.real()).into();
         let c1: U256 = (*self.imaginary()).into();
 
-        U512::new(&c1, &c0, &FQ)
+        U512::new(&c1, &c0, &Fq::modulus())
     }
 }
 
@@ -261,3 +323,25 @@ fn sqrt_fq2() {
             .is_none()
     );
 }
+
+#[test]
+fn legendre_sgn0_fq2() {
+    let mut x = Fq2::new(
+        Fq::from_str("271828").unwrap(),
+        Fq::from_str("1828").unwrap(),
+    );
+    for _ in 0..16 {
+        x = x * x + Fq2::one();
+        let sq = x * x;
+        assert_eq!(sq.legendre(), 1);
+        assert_eq!(sq.sqrt().unwrap().squared(), sq);
+        // u + 1 = 9 + i is not a square, so neither is any square times it.
+        let ns = sq * Fq2::new(Fq::from_str("9").unwrap(), Fq::one());
+        assert_eq!(ns.legendre(), -1);
+        assert!(ns.sqrt().is_none());
+        assert_ne!(x.sgn0(), (-x).sgn0());
+    }
+    assert_eq!(Fq2::zero().legendre(), 0);
+    assert!(Fq2::new(Fq::zero(), Fq::one()).sgn0());
+    assert!(!Fq2::new(Fq::zero(), -Fq::one()).sgn0());
+}
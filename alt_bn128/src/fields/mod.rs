@@ -1,7 +1,12 @@
+mod bn;
+mod cyclotomic;
 mod fp;
 mod fq12;
 mod fq2;
 mod fq6;
+#[cfg(any(feature = "bn462", test))]
+mod mont_fp;
+mod montgomery;
 
 use crate::arith::U256;
 use core::{
@@ -9,10 +14,13 @@ use core::{
     ops::{Add, Mul, Neg, Sub},
 };
 
-pub use self::fp::{const_fq, Fq, Fr};
-pub use self::fq12::Fq12;
-pub use self::fq2::{fq2_nonresidue, Fq2};
-pub use self::fq6::Fq6;
+#[cfg(any(feature = "bn462", test))]
+pub use self::bn::bn462;
+pub use self::bn::{Bn254, BnCurveConfig, BN254_LINES};
+pub use self::fp::{const_fq, const_fr, const_secp256k1_fq, Fq, Fr, Secp256k1Fq, Secp256k1Fr};
+pub use self::fq12::{BnFq12, Fq12};
+pub use self::fq2::{BnFq2, Fq2};
+pub use self::fq6::{BnFq6, Fq6};
 
 pub trait FieldElement:
     Sized
@@ -46,3 +54,25 @@ pub trait FieldElement:
         res
     }
 }
+
+/// Montgomery multiplication with the reduction deferred. Wide values are products of
+/// Montgomery forms and their sums and differences, kept below the modulus times R so that
+/// one reduction brings any of them back to a field element. A field may keep them reduced
+/// instead, giving up the saving but not the results.
+pub trait LazyReduction: FieldElement {
+    type Wide: Copy + Debug + Default;
+
+    fn mul_wide(self, other: Self) -> Self::Wide;
+    fn add_wide(a: &mut Self::Wide, b: &Self::Wide);
+    fn sub_wide(a: &mut Self::Wide, b: &Self::Wide);
+    fn reduce_wide(a: &Self::Wide) -> Self;
+}
+
+/// A prime field of scalars, whose canonical values in little-endian 64-bit limbs multiply
+/// group elements bit by bit.
+pub trait PrimeField: FieldElement {
+    type Limbs: AsRef<[u64]>;
+
+    fn modulus_limbs() -> Self::Limbs;
+    fn to_limbs(&self) -> Self::Limbs;
+}
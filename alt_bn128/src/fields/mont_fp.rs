@@ -0,0 +1,134 @@
+// Prime fields on N 64-bit limbs, running the `const fn`s of `montgomery`, for moduli the
+// U256 of `field_impl!` cannot hold: BN462 is a BN curve on eight limbs. Wide values stay
+// reduced, so the tower runs on them unchanged without a 2N-limb type.
+
+use crate::fields::montgomery::{self as mont, add_mod, geq, mul, small, sub_mod};
+use crate::fields::{FieldElement, LazyReduction, PrimeField};
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use core::ops::{Add, Mul, Neg, Sub};
+
+pub trait MontParams<const N: usize>:
+    'static + Copy + Clone + Default + Debug + PartialEq + Eq
+{
+    const MODULUS: [u64; N];
+}
+
+/// An element of Fp in Montgomery form, R = 2^(64 N).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MontFp<P: MontParams<N>, const N: usize>([u64; N], PhantomData<P>);
+
+impl<P: MontParams<N>, const N: usize> MontFp<P, N> {
+    const INV: u64 = mont::inv64(P::MODULUS[0]);
+    const ONE: [u64; N] = mont::r_pow(&P::MODULUS, 1);
+    const R2: [u64; N] = mont::r_pow(&P::MODULUS, 2);
+
+    /// An element from its Montgomery form.
+    pub const fn from_mont(a: [u64; N]) -> Self {
+        MontFp(a, PhantomData)
+    }
+
+    /// Converts a canonical value so long as it's below the modulus.
+    pub fn new(a: [u64; N]) -> Option<Self> {
+        if geq(&a, &P::MODULUS) {
+            None
+        } else {
+            Some(MontFp(
+                mul(&a, &Self::R2, &P::MODULUS, Self::INV),
+                PhantomData,
+            ))
+        }
+    }
+}
+
+impl<P: MontParams<N>, const N: usize> Default for MontFp<P, N> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<P: MontParams<N>, const N: usize> FieldElement for MontFp<P, N> {
+    fn zero() -> Self {
+        MontFp([0; N], PhantomData)
+    }
+
+    fn one() -> Self {
+        MontFp(Self::ONE, PhantomData)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == [0; N]
+    }
+
+    fn inverse(self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            Some(MontFp(mont::inverse(&self.0, &P::MODULUS), PhantomData))
+        }
+    }
+}
+
+impl<P: MontParams<N>, const N: usize> LazyReduction for MontFp<P, N> {
+    type Wide = Self;
+
+    fn mul_wide(self, other: Self) -> Self {
+        self * other
+    }
+
+    fn add_wide(a: &mut Self, b: &Self) {
+        *a = *a + *b;
+    }
+
+    fn sub_wide(a: &mut Self, b: &Self) {
+        *a = *a - *b;
+    }
+
+    fn reduce_wide(a: &Self) -> Self {
+        *a
+    }
+}
+
+impl<P: MontParams<N>, const N: usize> PrimeField for MontFp<P, N> {
+    type Limbs = [u64; N];
+
+    fn modulus_limbs() -> [u64; N] {
+        P::MODULUS
+    }
+
+    fn to_limbs(&self) -> [u64; N] {
+        mul(&self.0, &small(1), &P::MODULUS, Self::INV)
+    }
+}
+
+impl<P: MontParams<N>, const N: usize> Add for MontFp<P, N> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        MontFp(add_mod(&self.0, &other.0, &P::MODULUS), PhantomData)
+    }
+}
+
+impl<P: MontParams<N>, const N: usize> Sub for MontFp<P, N> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        MontFp(sub_mod(&self.0, &other.0, &P::MODULUS), PhantomData)
+    }
+}
+
+impl<P: MontParams<N>, const N: usize> Mul for MontFp<P, N> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        MontFp(mul(&self.0, &other.0, &P::MODULUS, Self::INV), PhantomData)
+    }
+}
+
+impl<P: MontParams<N>, const N: usize> Neg for MontFp<P, N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        MontFp(sub_mod(&[0; N], &self.0, &P::MODULUS), PhantomData)
+    }
+}
@@ -0,0 +1,220 @@
+// Montgomery arithmetic as `const fn`s over little-endian 64-bit limbs, to derive the
+// constants of a field from its modulus at compile time. The field types of the crate keep
+// their own arithmetic; only the N-limb `mont_fp` of BN462 runs this one.
+
+const fn mac(a: u64, b: u64, c: u64, carry: u64) -> (u64, u64) {
+    let t = a as u128 + b as u128 * c as u128 + carry as u128;
+    (t as u64, (t >> 64) as u64)
+}
+
+const fn adc(a: u64, b: u64, carry: u64) -> (u64, u64) {
+    let t = a as u128 + b as u128 + carry as u128;
+    (t as u64, (t >> 64) as u64)
+}
+
+pub const fn geq<const N: usize>(a: &[u64; N], b: &[u64; N]) -> bool {
+    let mut i = N;
+    while i > 0 {
+        i -= 1;
+        if a[i] != b[i] {
+            return a[i] > b[i];
+        }
+    }
+    true
+}
+
+// a - b modulo 2^(64 N).
+pub const fn sub<const N: usize>(a: [u64; N], b: &[u64; N]) -> [u64; N] {
+    let mut a = a;
+    let mut borrow = 0;
+    let mut i = 0;
+    while i < N {
+        let t = (a[i] as u128).wrapping_sub(b[i] as u128 + borrow as u128);
+        a[i] = t as u64;
+        borrow = (t >> 127) as u64;
+        i += 1;
+    }
+    a
+}
+
+// a + b and the carry out.
+pub const fn add<const N: usize>(a: [u64; N], b: &[u64; N]) -> ([u64; N], bool) {
+    let mut a = a;
+    let mut carry = 0;
+    let mut i = 0;
+    while i < N {
+        let (t, c) = adc(a[i], b[i], carry);
+        a[i] = t;
+        carry = c;
+        i += 1;
+    }
+    (a, carry != 0)
+}
+
+pub const fn small<const N: usize>(v: u64) -> [u64; N] {
+    let mut a = [0; N];
+    a[0] = v;
+    a
+}
+
+// a * b + c for a single limb b, dropping what overflows N limbs.
+pub const fn mul_small<const N: usize>(a: [u64; N], b: u64, c: u64) -> [u64; N] {
+    let mut a = a;
+    let mut carry = c;
+    let mut i = 0;
+    while i < N {
+        let (t, k) = mac(0, a[i], b, carry);
+        a[i] = t;
+        carry = k;
+        i += 1;
+    }
+    a
+}
+
+pub const fn div_small<const N: usize>(a: [u64; N], d: u64) -> [u64; N] {
+    let mut a = a;
+    let mut rem = 0u128;
+    let mut i = N;
+    while i > 0 {
+        i -= 1;
+        let t = rem << 64 | a[i] as u128;
+        a[i] = (t / d as u128) as u64;
+        rem = t % d as u128;
+    }
+    a
+}
+
+pub const fn bit<const N: usize>(a: &[u64; N], i: usize) -> bool {
+    a[i / 64] >> (i % 64) & 1 == 1
+}
+
+/// -m^-1 mod 2^64, for an odd modulus.
+pub const fn inv64(m0: u64) -> u64 {
+    // Each Newton step doubles the number of correct low bits, from one.
+    let mut x = 1u64;
+    let mut i = 0;
+    while i < 6 {
+        x = x.wrapping_mul(2u64.wrapping_sub(m0.wrapping_mul(x)));
+        i += 1;
+    }
+    x.wrapping_neg()
+}
+
+/// -m^-1 mod 2^128, the `inv` of the u128 limbs of `arith`.
+pub const fn inv128(m0: u128) -> u128 {
+    let mut x = 1u128;
+    let mut i = 0;
+    while i < 7 {
+        x = x.wrapping_mul(2u128.wrapping_sub(m0.wrapping_mul(x)));
+        i += 1;
+    }
+    x.wrapping_neg()
+}
+
+/// 2^(64 N k) mod m by doubling, so R^k in Montgomery terms: k = 1 is the form of one,
+/// k = 2 converts into the form.
+pub const fn r_pow<const N: usize>(m: &[u64; N], k: usize) -> [u64; N] {
+    let mut a = small::<N>(1);
+    let mut i = 0;
+    while i < 64 * N * k {
+        let (d, carry) = add(a, &a);
+        a = if carry || geq(&d, m) { sub(d, m) } else { d };
+        i += 1;
+    }
+    a
+}
+
+/// a b / R mod m, coarsely integrated operand scanning with two extra limbs of carry so any
+/// odd modulus below 2^(64 N) works.
+pub const fn mul<const N: usize>(a: &[u64; N], b: &[u64; N], m: &[u64; N], inv: u64) -> [u64; N] {
+    let mut t = [0u64; N];
+    let mut top = 0u64;
+    let mut i = 0;
+    while i < N {
+        let mut carry = 0;
+        let mut j = 0;
+        while j < N {
+            let (v, c) = mac(t[j], a[j], b[i], carry);
+            t[j] = v;
+            carry = c;
+            j += 1;
+        }
+        let (v, over) = adc(top, carry, 0);
+
+        let u = t[0].wrapping_mul(inv);
+        let (_, mut carry) = mac(t[0], u, m[0], 0);
+        let mut j = 1;
+        while j < N {
+            let (w, c) = mac(t[j], u, m[j], carry);
+            t[j - 1] = w;
+            carry = c;
+            j += 1;
+        }
+        let (w, c) = adc(v, carry, 0);
+        t[N - 1] = w;
+        top = over + c;
+        i += 1;
+    }
+    if top != 0 || geq(&t, m) {
+        sub(t, m)
+    } else {
+        t
+    }
+}
+
+pub const fn add_mod<const N: usize>(a: &[u64; N], b: &[u64; N], m: &[u64; N]) -> [u64; N] {
+    let (s, carry) = add(*a, b);
+    if carry || geq(&s, m) {
+        sub(s, m)
+    } else {
+        s
+    }
+}
+
+pub const fn sub_mod<const N: usize>(a: &[u64; N], b: &[u64; N], m: &[u64; N]) -> [u64; N] {
+    if geq(a, b) {
+        sub(*a, b)
+    } else {
+        add(sub(*a, b), m).0
+    }
+}
+
+/// The Montgomery form of a canonical value.
+pub const fn to_mont<const N: usize>(a: &[u64; N], m: &[u64; N]) -> [u64; N] {
+    mul(a, &r_pow(m, 2), m, inv64(m[0]))
+}
+
+pub const fn pow<const N: usize>(a: &[u64; N], e: &[u64; N], m: &[u64; N]) -> [u64; N] {
+    let inv = inv64(m[0]);
+    let mut res = r_pow(m, 1);
+    let mut i = 64 * N;
+    while i > 0 {
+        i -= 1;
+        res = mul(&res, &res, m, inv);
+        if bit(e, i) {
+            res = mul(&res, a, m, inv);
+        }
+    }
+    res
+}
+
+/// The inverse in Montgomery form by Fermat, for a prime modulus.
+pub const fn inverse<const N: usize>(a: &[u64; N], m: &[u64; N]) -> [u64; N] {
+    pow(a, &sub(*m, &small(2)), m)
+}
+
+pub const fn to_u64s(a: [u128; 2]) -> [u64; 4] {
+    [
+        a[0] as u64,
+        (a[0] >> 64) as u64,
+        a[1] as u64,
+        (a[1] >> 64) as u64,
+    ]
+}
+
+pub const fn to_u128s(a: [u64; 4]) -> [u128; 2] {
+    [
+        (a[1] as u128) << 64 | a[0] as u128,
+        (a[3] as u128) << 64 | a[2] as u128,
+    ]
+}
@@ -0,0 +1,484 @@
+// Decoders for the binary format written by gnark's `WriteTo` (compressed points) and
+// `WriteRawTo` (uncompressed points) for BN254 Groth16 verifying keys and proofs.
+//
+// Points follow gnark-crypto's bn254 encoding: coordinates are 32-byte big-endian and
+// the two most significant bits of the first byte carry the flags
+//
+//   0b00 uncompressed, (0, 0) is the point at infinity
+//   0b01 compressed point at infinity
+//   0b10 compressed, y is the lexicographically smallest root
+//   0b11 compressed, y is the lexicographically largest root
+//
+// G2 coordinates are written imaginary part first. Slices are prefixed by their length
+// as a big-endian u32.
+use crate::{AffineG1, AffineG2, FieldError, Fq, Fq2, GroupError, G1, G2};
+
+pub const MAX_K: usize = 32;
+pub const MAX_COMMITMENTS: usize = 4;
+
+const FLAG_MASK: u8 = 0b11 << 6;
+const FLAG_UNCOMPRESSED: u8 = 0b00 << 6;
+const FLAG_COMPRESSED_INFINITY: u8 = 0b01 << 6;
+const FLAG_COMPRESSED_LARGEST: u8 = 0b11 << 6;
+
+#[derive(Debug)]
+pub enum Error {
+    UnexpectedEof,
+    InvalidEncoding,
+    TooManyElements,
+    TrailingBytes,
+    Field(FieldError),
+    Group(GroupError),
+}
+
+impl From<FieldError> for Error {
+    fn from(fe: FieldError) -> Self {
+        Error::Field(fe)
+    }
+}
+
+impl From<GroupError> for Error {
+    fn from(ge: GroupError) -> Self {
+        Error::Group(ge)
+    }
+}
+
+struct Decoder<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Decoder<'a> {
+    fn new(buf: &'a [u8]) -> Self {
+        Decoder { buf, pos: 0 }
+    }
+
+    fn take(&mut self, n: usize) -> Result<&'a [u8], Error> {
+        if self.buf.len() - self.pos < n {
+            return Err(Error::UnexpectedEof);
+        }
+        let r = &self.buf[self.pos..self.pos + n];
+        self.pos += n;
+        Ok(r)
+    }
+
+    fn peek_flags(&self) -> Result<u8, Error> {
+        self.buf
+            .get(self.pos)
+            .map(|b| b & FLAG_MASK)
+            .ok_or(Error::UnexpectedEof)
+    }
+
+    // The bytes of a compressed point with the flags cleared from the first one, the only
+    // byte that carries them.
+    fn take_compressed<const N: usize>(&mut self) -> Result<[u8; N], Error> {
+        let mut b = [0u8; N];
+        b.copy_from_slice(self.take(N)?);
+        b[0] &= !FLAG_MASK;
+        Ok(b)
+    }
+
+    fn finish(&self) -> Result<(), Error> {
+        if self.pos != self.buf.len() {
+            return Err(Error::TrailingBytes);
+        }
+        Ok(())
+    }
+
+    fn read_u32(&mut self) -> Result<u32, Error> {
+        let b = self.take(4)?;
+        Ok(u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+    }
+
+    fn read_u64(&mut self) -> Result<u64, Error> {
+        let b = self.take(8)?;
+        let mut a = [0u8; 8];
+        a.copy_from_slice(b);
+        Ok(u64::from_be_bytes(a))
+    }
+
+    fn read_len(&mut self, max: usize) -> Result<usize, Error> {
+        let n = self.read_u32()? as usize;
+        if n > max {
+            return Err(Error::TooManyElements);
+        }
+        Ok(n)
+    }
+
+    fn read_g1(&mut self) -> Result<G1, Error> {
+        let flags = self.peek_flags()?;
+        if flags == FLAG_UNCOMPRESSED {
+            let b = self.take(64)?;
+            if b.iter().all(|&e| e == 0) {
+                return Ok(G1::default());
+            }
+            let x = read_fq(&b[0..32])?;
+            let y = read_fq(&b[32..64])?;
+            return Ok(AffineG1::new(x, y)?.into());
+        }
+        let x = read_fq(&self.take_compressed::<32>()?)?;
+        if flags == FLAG_COMPRESSED_INFINITY {
+            if !x.is_zero() {
+                return Err(Error::InvalidEncoding);
+            }
+            return Ok(G1::default());
+        }
+        let mut y = (x * x * x + G1::b())
+            .sqrt()
+            .ok_or(Error::Group(GroupError::NotOnCurve))?;
+        if fq_lexicographically_largest(y) != (flags == FLAG_COMPRESSED_LARGEST) {
+            y = -y;
+        }
+        Ok(AffineG1::new(x, y)?.into())
+    }
+
+    fn read_g2(&mut self) -> Result<G2, Error> {
+        let flags = self.peek_flags()?;
+        if flags == FLAG_UNCOMPRESSED {
+            let b = self.take(128)?;
+            if b.iter().all(|&e| e == 0) {
+                return Ok(G2::default());
+            }
+            let x = read_fq2(&b[0..64])?;
+            let y = read_fq2(&b[64..128])?;
+            return Ok(AffineG2::new(x, y)?.into());
+        }
+        let x = read_fq2(&self.take_compressed::<64>()?)?;
+        if flags == FLAG_COMPRESSED_INFINITY {
+            if !x.is_zero() {
+                return Err(Error::InvalidEncoding);
+            }
+            return Ok(G2::default());
+        }
+        let mut y = (x * x * x + G2::b())
+            .sqrt()
+            .ok_or(Error::Group(GroupError::NotOnCurve))?;
+        if fq2_lexicographically_largest(y) != (flags == FLAG_COMPRESSED_LARGEST) {
+            y = -y;
+        }
+        Ok(AffineG2::new(x, y)?.into())
+    }
+
+    fn read_g1_slice(&mut self, out: &mut [G1]) -> Result<usize, Error> {
+        let n = self.read_len(out.len())?;
+        for e in out.iter_mut().take(n) {
+            *e = self.read_g1()?;
+        }
+        Ok(n)
+    }
+}
+
+// Coordinates are below q < 2^254, so the top bits are clear unless they hold flags.
+fn read_fq(b: &[u8]) -> Result<Fq, Error> {
+    if b[0] & FLAG_MASK != 0 {
+        return Err(Error::InvalidEncoding);
+    }
+    Ok(Fq::from_slice(b)?)
+}
+
+fn read_fq2(b: &[u8]) -> Result<Fq2, Error> {
+    let c1 = read_fq(&b[0..32])?;
+    let c0 = read_fq(&b[32..64])?;
+    Ok(Fq2::new(c0, c1))
+}
+
+fn fq_lexicographically_largest(a: Fq) -> bool {
+    a.into_u256() > (-a).into_u256()
+}
+
+fn fq2_lexicographically_largest(a: Fq2) -> bool {
+    if a.imaginary().is_zero() {
+        fq_lexicographically_largest(a.real())
+    } else {
+        fq_lexicographically_largest(a.imaginary())
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct CommitmentKey {
+    pub g: G2,
+    pub g_root_sigma_neg: G2,
+}
+
+#[derive(Clone, Debug)]
+pub struct VerifyingKey {
+    pub alpha_g1: G1,
+    pub beta_g1: G1,
+    pub beta_g2: G2,
+    pub gamma_g2: G2,
+    pub delta_g1: G1,
+    pub delta_g2: G2,
+    k: [G1; MAX_K],
+    k_len: usize,
+    committed: [[u64; MAX_K]; MAX_COMMITMENTS],
+    committed_len: [usize; MAX_COMMITMENTS],
+    committed_count: usize,
+    commitment_keys: [CommitmentKey; MAX_COMMITMENTS],
+    commitment_keys_len: usize,
+}
+
+impl VerifyingKey {
+    pub fn from_bytes(data: &[u8]) -> Result<Self, Error> {
+        let mut d = Decoder::new(data);
+        let mut vk = VerifyingKey {
+            alpha_g1: d.read_g1()?,
+            beta_g1: d.read_g1()?,
+            beta_g2: d.read_g2()?,
+            gamma_g2: d.read_g2()?,
+            delta_g1: d.read_g1()?,
+            delta_g2: d.read_g2()?,
+            k: [G1::default(); MAX_K],
+            k_len: 0,
+            committed: [[0; MAX_K]; MAX_COMMITMENTS],
+            committed_len: [0; MAX_COMMITMENTS],
+            committed_count: 0,
+            commitment_keys: [CommitmentKey::default(); MAX_COMMITMENTS],
+            commitment_keys_len: 0,
+        };
+        vk.k_len = d.read_g1_slice(&mut vk.k)?;
+        vk.committed_count = d.read_len(MAX_COMMITMENTS)?;
+        for i in 0..vk.committed_count {
+            vk.committed_len[i] = d.read_len(MAX_K)?;
+            for j in 0..vk.committed_len[i] {
+                vk.committed[i][j] = d.read_u64()?;
+            }
+        }
+        vk.commitment_keys_len = d.read_len(MAX_COMMITMENTS)?;
+        for i in 0..vk.commitment_keys_len {
+            vk.commitment_keys[i] = CommitmentKey {
+                g: d.read_g2()?,
+                g_root_sigma_neg: d.read_g2()?,
+            };
+        }
+        d.finish()?;
+        Ok(vk)
+    }
+
+    /// [K_i]1 for the constant one wire, each public input and each commitment.
+    pub fn k(&self) -> &[G1] {
+        &self.k[..self.k_len]
+    }
+
+    /// Number of BSB22 commitments in the circuit.
+    pub fn nb_commitments(&self) -> usize {
+        self.committed_count
+    }
+
+    /// Public wire indices (1-based) committed to by the `i`-th commitment.
+    pub fn public_and_commitment_committed(&self, i: usize) -> &[u64] {
+        &self.committed[i][..self.committed_len[i]]
+    }
+
+    pub fn commitment_keys(&self) -> &[CommitmentKey] {
+        &self.commitment_keys[..self.commitment_keys_len]
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Proof {
+    pub a: G1,
+    pub b: G2,
+    pub c: G1,
+    commitments: [G1; MAX_COMMITMENTS],
+    commitments_len: usize,
+    pub commitment_pok: G1,
+}
+
+impl Proof {
+    pub fn from_bytes(data: &[u8]) -> Result<Self, Error> {
+        let mut d = Decoder::new(data);
+        let a = d.read_g1()?;
+        let b = d.read_g2()?;
+        let c = d.read_g1()?;
+        let mut commitments = [G1::default(); MAX_COMMITMENTS];
+        let commitments_len = d.read_g1_slice(&mut commitments)?;
+        let commitment_pok = d.read_g1()?;
+        d.finish()?;
+        Ok(Proof {
+            a,
+            b,
+            c,
+            commitments,
+            commitments_len,
+            commitment_pok,
+        })
+    }
+
+    pub fn commitments(&self) -> &[G1] {
+        &self.commitments[..self.commitments_len]
+    }
+}
+
+// The public inputs of the SP1 proof in fixtures/gnark.
+#[cfg(test)]
+pub(crate) const SP1_PUBLIC: [&str; 2] = [
+    "406432647295647673015241033990011138953233847343093038612820599671924330193",
+    "6265467995298269824615662043982564374977105610814771179903635896347370323221",
+];
+
+// The WriteTo encoding of a proof without commitments from its WriteRawTo one: the x of each
+// point flagged with the sign of its y, and the proof of knowledge at infinity.
+#[cfg(test)]
+fn compress_proof(raw: &[u8]) -> [u8; 164] {
+    let mut b = [0u8; 164];
+    for &(from, to, n) in [(0, 0, 32), (64, 32, 64), (192, 96, 32)].iter() {
+        b[to..to + n].copy_from_slice(&raw[from..from + n]);
+        let y = &raw[from + n..from + 2 * n];
+        let largest = if n == 32 {
+            fq_lexicographically_largest(read_fq(y).unwrap())
+        } else {
+            fq2_lexicographically_largest(read_fq2(y).unwrap())
+        };
+        b[to] |= if largest {
+            FLAG_COMPRESSED_LARGEST
+        } else {
+            0b10 << 6
+        };
+    }
+    b[132] = FLAG_COMPRESSED_INFINITY;
+    b
+}
+
+#[test]
+fn read_verifying_key() {
+    let vk = VerifyingKey::from_bytes(include_bytes!("../fixtures/gnark/vk.bin")).unwrap();
+    assert_eq!(vk.k().len(), 3);
+    assert_eq!(vk.nb_commitments(), 0);
+    assert!(vk.commitment_keys().is_empty());
+}
+
+#[test]
+fn read_proof() {
+    use crate::{pairing_batch, Fr, Group, Gt};
+
+    let vk = VerifyingKey::from_bytes(include_bytes!("../fixtures/gnark/vk.bin")).unwrap();
+    let raw = include_bytes!("../fixtures/gnark/proof_raw.bin");
+    let proof = Proof::from_bytes(raw).unwrap();
+    let compressed = Proof::from_bytes(&compress_proof(raw)).unwrap();
+    assert_eq!(proof.a, compressed.a);
+    assert_eq!(proof.b, compressed.b);
+    assert_eq!(proof.c, compressed.c);
+    assert!(proof.commitments().is_empty());
+    assert!(proof.commitment_pok.is_zero());
+    assert!(compressed.commitment_pok.is_zero());
+
+    let k = vk.k();
+    let vk_x = k[0]
+        + k[1] * Fr::from_str(SP1_PUBLIC[0]).unwrap()
+        + k[2] * Fr::from_str(SP1_PUBLIC[1]).unwrap();
+    let r = pairing_batch(&[
+        (proof.a, proof.b),
+        (-vk.alpha_g1, vk.beta_g2),
+        (-vk_x, vk.gamma_g2),
+        (-proof.c, vk.delta_g2),
+    ]);
+    assert!(r == Gt::one());
+}
+
+#[test]
+fn read_commitment_extension() {
+    // The SP1 key and proof have no commitment, so the extension is spelled out on top of
+    // them: the key commits to public wire 1 with its own gamma and delta as the Pedersen
+    // key, and the proof carries its A as the commitment and its C as the proof of knowledge.
+    let data = include_bytes!("../fixtures/gnark/vk.bin");
+    let mut buf = [0u8; 536];
+    buf[..388].copy_from_slice(&data[..388]);
+    buf[391] = 1;
+    buf[395] = 1;
+    buf[403] = 1;
+    buf[407] = 1;
+    buf[408..472].copy_from_slice(&data[128..192]);
+    buf[472..536].copy_from_slice(&data[224..288]);
+    let vk = VerifyingKey::from_bytes(data).unwrap();
+    let extended = VerifyingKey::from_bytes(&buf).unwrap();
+    assert_eq!(extended.k(), vk.k());
+    assert_eq!(extended.nb_commitments(), 1);
+    assert_eq!(extended.public_and_commitment_committed(0), &[1]);
+    let key = CommitmentKey {
+        g: vk.gamma_g2,
+        g_root_sigma_neg: vk.delta_g2,
+    };
+    assert_eq!(extended.commitment_keys(), &[key]);
+
+    let data = include_bytes!("../fixtures/gnark/proof_raw.bin");
+    let mut buf = [0u8; 388];
+    buf[..256].copy_from_slice(&data[..256]);
+    buf[259] = 1;
+    buf[260..324].copy_from_slice(&data[..64]);
+    buf[324..388].copy_from_slice(&data[192..256]);
+    let proof = Proof::from_bytes(&buf).unwrap();
+    assert_eq!(proof.commitments(), &[proof.a]);
+    assert_eq!(proof.commitment_pok, proof.c);
+
+    buf[259] = MAX_COMMITMENTS as u8 + 1;
+    match Proof::from_bytes(&buf) {
+        Err(Error::TooManyElements) => {}
+        r => panic!("unexpected {:?}", r),
+    }
+}
+
+#[test]
+fn reject_invalid_points() {
+    let data = include_bytes!("../fixtures/gnark/proof_raw.bin");
+
+    let mut buf = [0u8; 324];
+    buf.copy_from_slice(data);
+    buf[63] ^= 1;
+    match Proof::from_bytes(&buf) {
+        Err(Error::Group(GroupError::NotOnCurve)) => {}
+        r => panic!("unexpected {:?}", r),
+    }
+
+    // B replaced by the point of the twist with the smallest real x, which is outside the
+    // subgroup of order r but for a chance of one in the cofactor, itself about r.
+    buf.copy_from_slice(data);
+    let mut x = Fq2::zero();
+    let y = loop {
+        x = x + Fq2::one();
+        if let Some(y) = (x * x * x + G2::b()).sqrt() {
+            break y;
+        }
+    };
+    for (i, c) in [x.imaginary(), x.real(), y.imaginary(), y.real()]
+        .iter()
+        .enumerate()
+    {
+        c.to_big_endian(&mut buf[64 + 32 * i..96 + 32 * i]).unwrap();
+    }
+    match Proof::from_bytes(&buf) {
+        Err(Error::Group(GroupError::NotInSubgroup)) => {}
+        r => panic!("unexpected {:?}", r),
+    }
+
+    match Proof::from_bytes(&data[..data.len() - 1]) {
+        Err(Error::UnexpectedEof) => {}
+        r => panic!("unexpected {:?}", r),
+    }
+}
+
+#[test]
+fn reject_flags_outside_first_byte() {
+    let raw = include_bytes!("../fixtures/gnark/proof_raw.bin");
+    let compressed = compress_proof(raw);
+
+    // y of A, c0 of x of B, and c0 of y of B in the raw proof; c0 of x of B compressed.
+    for &(data, i) in [
+        (&raw[..], 32),
+        (&raw[..], 96),
+        (&raw[..], 160),
+        (&compressed[..], 64),
+    ]
+    .iter()
+    {
+        for &bit in [0x80u8, 0x40].iter() {
+            let mut buf = [0u8; 324];
+            buf[..data.len()].copy_from_slice(data);
+            buf[i] |= bit;
+            match Proof::from_bytes(&buf[..data.len()]) {
+                Err(Error::InvalidEncoding) => {}
+                r => panic!("unexpected {:?}", r),
+            }
+        }
+    }
+    assert!(Proof::from_bytes(&compressed).is_ok());
+}
@@ -0,0 +1,260 @@
+// A minimal zero-copy JSON reader, enough for the key and proof files written by
+// snarkjs. Values are returned as raw slices of the input and decoded on demand.
+use crate::{arith::U256, AffineG1, AffineG2, Fq, Fq2, Fr, Group, GroupError, G1};
+
+#[derive(Debug)]
+pub enum Error {
+    InvalidJson(&'static str),
+    InvalidNumber,
+    Group(GroupError),
+}
+
+impl From<GroupError> for Error {
+    fn from(ge: GroupError) -> Self {
+        Error::Group(ge)
+    }
+}
+
+fn skip_ws(b: &[u8], mut i: usize) -> usize {
+    while i < b.len() && (b[i] == b' ' || b[i] == b'\t' || b[i] == b'\n' || b[i] == b'\r') {
+        i += 1;
+    }
+    i
+}
+
+fn skip_string(b: &[u8], mut i: usize) -> Option<usize> {
+    if b.get(i) != Some(&b'"') {
+        return None;
+    }
+    i += 1;
+    while i < b.len() {
+        match b[i] {
+            b'\\' => i += 2,
+            b'"' => return Some(i + 1),
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+// Returns the index just past the value starting at `i`.
+fn skip_value(b: &[u8], i: usize) -> Option<usize> {
+    match b.get(i)? {
+        b'"' => skip_string(b, i),
+        b'{' | b'[' => {
+            let mut depth = 0;
+            let mut i = i;
+            while i < b.len() {
+                match b[i] {
+                    b'"' => {
+                        i = skip_string(b, i)?;
+                        continue;
+                    }
+                    b'{' | b'[' => depth += 1,
+                    b'}' | b']' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            return Some(i + 1);
+                        }
+                    }
+                    _ => {}
+                }
+                i += 1;
+            }
+            None
+        }
+        _ => {
+            let mut j = i;
+            while j < b.len() && !b",]} \t\n\r".contains(&b[j]) {
+                j += 1;
+            }
+            if j == i {
+                None
+            } else {
+                Some(j)
+            }
+        }
+    }
+}
+
+/// Returns the raw value stored under `key` in the object `obj`.
+pub fn get<'a>(obj: &'a str, key: &str) -> Option<&'a str> {
+    let b = obj.as_bytes();
+    let mut i = skip_ws(b, 0);
+    if b.get(i) != Some(&b'{') {
+        return None;
+    }
+    i = skip_ws(b, i + 1);
+    if b.get(i) == Some(&b'}') {
+        return None;
+    }
+    loop {
+        let k_end = skip_string(b, i)?;
+        let k = &obj[i + 1..k_end - 1];
+        i = skip_ws(b, k_end);
+        if b.get(i) != Some(&b':') {
+            return None;
+        }
+        i = skip_ws(b, i + 1);
+        let v_end = skip_value(b, i)?;
+        if k == key {
+            return Some(&obj[i..v_end]);
+        }
+        i = skip_ws(b, v_end);
+        match b.get(i)? {
+            b',' => i = skip_ws(b, i + 1),
+            _ => return None,
+        }
+    }
+}
+
+/// Iterates over the raw elements of the array `arr`.
+pub fn items(arr: &str) -> Items<'_> {
+    Items {
+        src: arr,
+        pos: 0,
+        done: false,
+    }
+}
+
+pub struct Items<'a> {
+    src: &'a str,
+    pos: usize,
+    done: bool,
+}
+
+impl<'a> Iterator for Items<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        if self.done {
+            return None;
+        }
+        let b = self.src.as_bytes();
+        let mut i = skip_ws(b, self.pos);
+        if self.pos == 0 {
+            if b.get(i) != Some(&b'[') {
+                self.done = true;
+                return None;
+            }
+            i = skip_ws(b, i + 1);
+            if b.get(i) == Some(&b']') {
+                self.done = true;
+                return None;
+            }
+        }
+        let end = match skip_value(b, i) {
+            Some(end) => end,
+            None => {
+                self.done = true;
+                return None;
+            }
+        };
+        let j = skip_ws(b, end);
+        match b.get(j) {
+            Some(b',') => self.pos = j + 1,
+            _ => self.done = true,
+        }
+        Some(&self.src[i..end])
+    }
+}
+
+/// The contents of a string value. Escape sequences are left as they are.
+pub fn string(raw: &str) -> Option<&str> {
+    let raw = raw.trim();
+    if raw.len() >= 2 && raw.starts_with('"') && raw.ends_with('"') {
+        Some(&raw[1..raw.len() - 1])
+    } else {
+        None
+    }
+}
+
+/// The string value `raw`, if present.
+pub fn str(raw: Option<&str>) -> Result<&str, Error> {
+    raw.and_then(string)
+        .ok_or(Error::InvalidJson("expected a string"))
+}
+
+fn parse_u256(s: &str) -> Result<U256, Error> {
+    let s = s.trim();
+    match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(h) => U256::from_hex_str(h),
+        None => U256::from_dec_str(s),
+    }
+    .ok_or(Error::InvalidNumber)
+}
+
+/// Parses a base field element written in decimal or, with a `0x` prefix, in hex.
+pub fn parse_fq(s: &str) -> Result<Fq, Error> {
+    Fq::from_u256(parse_u256(s)?).map_err(|_| Error::InvalidNumber)
+}
+
+/// Parses a scalar written in decimal or, with a `0x` prefix, in hex.
+pub fn parse_fr(s: &str) -> Result<Fr, Error> {
+    Fr::new(parse_u256(s)?).ok_or(Error::InvalidNumber)
+}
+
+pub fn parse_affine_g1(x: &str, y: &str) -> Result<AffineG1, Error> {
+    Ok(AffineG1::new(parse_fq(x)?, parse_fq(y)?)?)
+}
+
+pub fn parse_affine_g2(x: [&str; 2], y: [&str; 2]) -> Result<AffineG2, Error> {
+    Ok(AffineG2::new(
+        Fq2::new(parse_fq(x[0])?, parse_fq(x[1])?),
+        Fq2::new(parse_fq(y[0])?, parse_fq(y[1])?),
+    )?)
+}
+
+/// A G1 point written by snarkjs as projective `[x, y, z]` strings with z = 1.
+pub fn affine_g1(raw: &str) -> Result<AffineG1, Error> {
+    let mut it = items(raw);
+    let x = str(it.next())?;
+    let y = str(it.next())?;
+    if str(it.next())? != "1" {
+        return Err(Error::InvalidJson("G1 point is not normalized"));
+    }
+    parse_affine_g1(x, y)
+}
+
+/// As `affine_g1`, also accepting the point at infinity `["0", "1", "0"]`.
+pub fn g1(raw: &str) -> Result<G1, Error> {
+    let mut it = items(raw);
+    if [str(it.next())?, str(it.next())?, str(it.next())?] == ["0", "1", "0"] {
+        return Ok(G1::zero());
+    }
+    Ok(affine_g1(raw)?.into())
+}
+
+/// A G2 point written by snarkjs as `[[x0, x1], [y0, y1], ["1", "0"]]`.
+pub fn affine_g2(raw: &str) -> Result<AffineG2, Error> {
+    let mut c = [[""; 2]; 3];
+    let mut it = items(raw);
+    for e in c.iter_mut() {
+        let mut it = items(it.next().ok_or(Error::InvalidJson("expected a G2 point"))?);
+        e[0] = str(it.next())?;
+        e[1] = str(it.next())?;
+    }
+    if c[2] != ["1", "0"] {
+        return Err(Error::InvalidJson("G2 point is not normalized"));
+    }
+    parse_affine_g2(c[0], c[1])
+}
+
+#[test]
+fn read_json() {
+    let src = r#" { "a": [ "1", ["2", "3"], {"b": "]"} ], "c" : 42, "d\"": "x" } "#;
+    let a = get(src, "a").unwrap();
+    let mut it = items(a);
+    assert_eq!(string(it.next().unwrap()), Some("1"));
+    let inner: [&str; 2] = {
+        let mut it = items(it.next().unwrap()).map(|e| string(e).unwrap());
+        [it.next().unwrap(), it.next().unwrap()]
+    };
+    assert_eq!(inner, ["2", "3"]);
+    assert_eq!(get(it.next().unwrap(), "b"), Some(r#""]""#));
+    assert_eq!(it.next(), None);
+    assert_eq!(get(src, "c"), Some("42"));
+    assert_eq!(get(src, "d\\\""), Some(r#""x""#));
+    assert_eq!(get(src, "e"), None);
+    assert_eq!(items("[]").next(), None);
+}
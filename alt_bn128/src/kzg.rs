@@ -0,0 +1,322 @@
+// KZG polynomial commitments over BN254 with a powers-of-tau SRS [tau^i]_1, [tau]_2.
+//
+// A commitment to p is [p(tau)]_1 and an opening at z is the commitment W to the quotient
+// (p(X) - p(z)) / (X - z), checked with
+//
+//   e(-W, [tau]_2) * e(C - y * G1 + z * W, G2) = 1
+//
+// Openings of several commitments at different points are folded with random scalars r_i
+// into the same two pairings: e(-sum(r_i * W_i), [tau]_2) * e(sum(r_i * (C_i - y_i * G1
+// + z_i * W_i)), G2) = 1.
+//
+// Only the verifier key is needed to verify, and it can be embedded at compile time:
+//
+//     const TAU_G2: G2Prepared = ...; // from codegen::g2_prepared
+//     const KEY: kzg::VerifierKey = kzg::VerifierKey::new(TAU_G2);
+use crate::{
+    domain::EvaluationDomain, groth16::ScalarSource, msm::msm, poly, Fr, G2Prepared, Group,
+    GroupError, Gt, PairingBatch, G1, G2,
+};
+#[cfg(any(feature = "std", test))]
+use crate::{AffineG1, AffineG2, Fq, Fq2};
+#[cfg(any(feature = "std", test))]
+use std::vec::Vec;
+
+#[derive(Debug)]
+pub enum Error {
+    PolynomialTooLarge,
+    InvalidLength,
+    InvalidEncoding,
+    Group(GroupError),
+}
+
+impl From<GroupError> for Error {
+    fn from(ge: GroupError) -> Self {
+        Error::Group(ge)
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct VerifierKey {
+    pub tau_g2: G2Prepared,
+}
+
+impl VerifierKey {
+    pub const fn new(tau_g2: G2Prepared) -> Self {
+        VerifierKey { tau_g2 }
+    }
+}
+
+/// A claim that the polynomial committed to by `commitment` takes `value` at `point`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Opening {
+    pub commitment: G1,
+    pub point: Fr,
+    pub value: Fr,
+    pub proof: G1,
+}
+
+/// Commits to the polynomial with coefficients `poly`, lowest degree first.
+pub fn commit(powers: &[G1], poly: &[Fr]) -> Result<G1, Error> {
+    if poly.len() > powers.len() {
+        return Err(Error::PolynomialTooLarge);
+    }
+    Ok(msm(&powers[..poly.len()], poly))
+}
+
+/// Commits to the polynomial taking the values `evals` over `domain`. The buffer is
+/// interpolated in place and holds the coefficients afterwards.
+pub fn commit_evaluations(
+    powers: &[G1],
+    domain: &EvaluationDomain,
+    evals: &mut [Fr],
+) -> Result<G1, Error> {
+    if evals.len() != domain.size() {
+        return Err(Error::InvalidLength);
+    }
+    domain.ifft(evals);
+    commit(powers, evals)
+}
+
+/// Opens `poly` at `z` and returns the value with its proof. The buffer is divided in
+/// place: it holds p(z) followed by the coefficients of the quotient afterwards.
+pub fn open(powers: &[G1], poly: &mut [Fr], z: Fr) -> Result<(Fr, G1), Error> {
+    if poly.len() > powers.len() {
+        return Err(Error::PolynomialTooLarge);
+    }
+    if poly.is_empty() {
+        return Ok((Fr::zero(), G1::zero()));
+    }
+    let value = poly::divide_by_linear(poly, z);
+    Ok((value, msm(&powers[..poly.len() - 1], &poly[1..])))
+}
+
+/// Writes `sum(gamma^i * polys[i])` to `out`, so several polynomials opened at the same
+/// point share one proof. Verify with `verify_same_point`.
+pub fn combine(polys: &[&[Fr]], gamma: Fr, out: &mut [Fr]) -> Result<(), Error> {
+    if polys.iter().any(|p| p.len() > out.len()) {
+        return Err(Error::InvalidLength);
+    }
+    for e in out.iter_mut() {
+        *e = Fr::zero();
+    }
+    let mut g = Fr::one();
+    for p in polys {
+        for (o, c) in out.iter_mut().zip(p.iter()) {
+            *o = *o + g * *c;
+        }
+        g = g * gamma;
+    }
+    Ok(())
+}
+
+pub fn verify(vk: &VerifierKey, opening: &Opening) -> bool {
+    let mut batch = PairingBatch::new();
+    batch.push_prepared(-opening.proof, &vk.tau_g2);
+    batch.push(
+        opening.commitment - G1::one() * opening.value + opening.proof * opening.point,
+        G2::one(),
+    );
+    batch.finalize() == Gt::one()
+}
+
+/// Verifies one proof for `values[i]` of every `commitments[i]` at `z`, made by opening
+/// the polynomials combined with `gamma`.
+pub fn verify_same_point(
+    vk: &VerifierKey,
+    commitments: &[G1],
+    values: &[Fr],
+    z: Fr,
+    gamma: Fr,
+    proof: G1,
+) -> Result<bool, Error> {
+    if commitments.len() != values.len() {
+        return Err(Error::InvalidLength);
+    }
+    let mut commitment = G1::zero();
+    let mut value = Fr::zero();
+    let mut g = Fr::one();
+    for (c, v) in commitments.iter().zip(values.iter()) {
+        commitment = commitment + *c * g;
+        value = value + g * *v;
+        g = g * gamma;
+    }
+    Ok(verify(
+        vk,
+        &Opening {
+            commitment,
+            point: z,
+            value,
+            proof,
+        },
+    ))
+}
+
+/// Verifies openings at any number of points with two pairings. The scalars must not be
+/// predictable by whoever produced the proofs.
+pub fn verify_batch<S: ScalarSource>(
+    vk: &VerifierKey,
+    openings: &[Opening],
+    source: &mut S,
+) -> bool {
+    let mut w = G1::zero();
+    let mut rest = G1::zero();
+    let mut value = Fr::zero();
+    for o in openings {
+        let r = source.next_scalar();
+        w = w + o.proof * r;
+        rest = rest + (o.commitment + o.proof * o.point) * r;
+        value = value + r * o.value;
+    }
+    let mut batch = PairingBatch::new();
+    batch.push_prepared(-w, &vk.tau_g2);
+    batch.push(rest - G1::one() * value, G2::one());
+    batch.finalize() == Gt::one()
+}
+
+/// Powers of tau in G1 and tau in G2.
+#[cfg(any(feature = "std", test))]
+#[derive(Clone, Debug)]
+pub struct Srs {
+    pub powers_g1: Vec<G1>,
+    pub tau_g2: G2,
+}
+
+#[cfg(any(feature = "std", test))]
+fn fq(buf: &[u8]) -> Result<Fq, Error> {
+    Fq::from_slice(buf).map_err(|_| Error::InvalidEncoding)
+}
+
+#[cfg(any(feature = "std", test))]
+impl Srs {
+    /// Reads `[tau^i]_1` as 64-byte points followed by `[tau]_2` as a 128-byte point,
+    /// big-endian as in the Ethereum precompiles, with the imaginary part of each G2
+    /// coordinate first.
+    pub fn from_bytes(data: &[u8]) -> Result<Self, Error> {
+        if data.len() < 128 || !(data.len() - 128).is_multiple_of(64) {
+            return Err(Error::InvalidLength);
+        }
+        let (g1s, g2) = data.split_at(data.len() - 128);
+        let mut powers_g1 = Vec::with_capacity(g1s.len() / 64);
+        for p in g1s.chunks(64) {
+            let (x, y) = (fq(&p[..32])?, fq(&p[32..])?);
+            powers_g1.push(if x.is_zero() && y.is_zero() {
+                G1::zero()
+            } else {
+                AffineG1::new(x, y)?.into()
+            });
+        }
+        let x = Fq2::new(fq(&g2[32..64])?, fq(&g2[..32])?);
+        let y = Fq2::new(fq(&g2[96..])?, fq(&g2[64..96])?);
+        Ok(Srs {
+            powers_g1,
+            tau_g2: AffineG2::new(x, y)?.into(),
+        })
+    }
+
+    pub fn verifier_key(&self) -> Option<VerifierKey> {
+        AffineG2::from_jacobian(self.tau_g2).map(|q| VerifierKey::new(G2Prepared::new(q)))
+    }
+}
+
+#[test]
+fn commit_open_verify() {
+    let tau = Fr::from_str("987654321987654321").unwrap();
+    let mut bytes = std::vec::Vec::new();
+    let mut t = Fr::one();
+    for _ in 0..8 {
+        let p = AffineG1::from_jacobian(G1::one() * t).unwrap();
+        let mut buf = [0u8; 64];
+        p.x().to_big_endian(&mut buf[..32]).unwrap();
+        p.y().to_big_endian(&mut buf[32..]).unwrap();
+        bytes.extend_from_slice(&buf);
+        t = t * tau;
+    }
+    let q = AffineG2::from_jacobian(G2::one() * tau).unwrap();
+    for c in [
+        q.x().imaginary(),
+        q.x().real(),
+        q.y().imaginary(),
+        q.y().real(),
+    ] {
+        let mut buf = [0u8; 32];
+        c.to_big_endian(&mut buf).unwrap();
+        bytes.extend_from_slice(&buf);
+    }
+    let srs = Srs::from_bytes(&bytes).unwrap();
+    let vk = srs.verifier_key().unwrap();
+    let powers = &srs.powers_g1;
+
+    let poly = [3u64, 1, 4, 1, 5, 9, 2, 6].map(|i| Fr::from_str(&std::format!("{}", i)).unwrap());
+    let c = commit(powers, &poly).unwrap();
+    let mut y = Fr::zero();
+    for e in poly.iter().rev() {
+        y = y * tau + *e;
+    }
+    assert_eq!(c, G1::one() * y);
+    assert!(matches!(
+        commit(&powers[..4], &poly),
+        Err(Error::PolynomialTooLarge)
+    ));
+
+    let domain = EvaluationDomain::new(8).unwrap();
+    let mut evals = poly;
+    domain.fft(&mut evals);
+    assert_eq!(commit_evaluations(powers, &domain, &mut evals).unwrap(), c);
+
+    let z = Fr::from_str("12345").unwrap();
+    let mut buf = poly;
+    let (value, proof) = open(powers, &mut buf, z).unwrap();
+    let mut expect = Fr::zero();
+    for e in poly.iter().rev() {
+        expect = expect * z + *e;
+    }
+    assert_eq!(value, expect);
+    let opening = Opening {
+        commitment: c,
+        point: z,
+        value,
+        proof,
+    };
+    assert!(verify(&vk, &opening));
+    assert!(!verify(
+        &vk,
+        &Opening {
+            value: value + Fr::one(),
+            ..opening
+        }
+    ));
+
+    // Two polynomials at one point.
+    let other = [7u64, 0, 0, 2].map(|i| Fr::from_str(&std::format!("{}", i)).unwrap());
+    let gamma = Fr::from_str("99").unwrap();
+    let mut combined = [Fr::zero(); 8];
+    combine(&[&poly, &other], gamma, &mut combined).unwrap();
+    let (_, proof2) = open(powers, &mut combined, z).unwrap();
+    let other_value = other[0] + other[3] * z * z * z;
+    let commitments = [c, commit(powers, &other).unwrap()];
+    assert!(verify_same_point(&vk, &commitments, &[value, other_value], z, gamma, proof2).unwrap());
+    assert!(!verify_same_point(&vk, &commitments, &[value, value], z, gamma, proof2).unwrap());
+
+    // Openings at different points.
+    let mut openings = [opening; 3];
+    for (i, o) in openings.iter_mut().enumerate().skip(1) {
+        let z = Fr::from_str(&std::format!("{}", i + 100)).unwrap();
+        let mut buf = poly;
+        let (value, proof) = open(powers, &mut buf, z).unwrap();
+        *o = Opening {
+            commitment: c,
+            point: z,
+            value,
+            proof,
+        };
+    }
+    let mut s = Fr::from_str("5").unwrap();
+    let mut source = || {
+        s = s * s + Fr::one();
+        s
+    };
+    assert!(verify_batch(&vk, &openings, &mut source));
+    openings[2].value = openings[1].value;
+    assert!(!verify_batch(&vk, &openings, &mut source));
+}
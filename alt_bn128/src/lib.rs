@@ -1,13 +1,49 @@
 #![no_std]
 
+#[cfg(any(feature = "alloc", test))]
+extern crate alloc;
+#[cfg(test)]
+extern crate self as alt_bn128;
+#[cfg(any(feature = "std", test))]
+extern crate std;
+
 pub mod arith;
+pub mod babyjubjub;
+#[cfg(any(feature = "std", test))]
+pub mod binfile;
+pub mod blake2b;
+pub mod bls12_381;
+#[cfg(any(feature = "bn462", test))]
+pub mod bn462;
+#[cfg(any(feature = "std", test))]
+pub mod codegen;
+pub mod domain;
 pub mod ethereum;
 mod fields;
+pub mod gnark;
+pub mod groth16;
 mod groups;
+pub mod grumpkin;
+mod json;
+pub mod keccak;
+pub mod kzg;
+pub mod mimc;
+pub mod modexp;
+pub mod msm;
+pub mod plonk;
+pub mod poly;
+pub mod poseidon;
+#[cfg(any(feature = "std", test))]
+pub mod ptau;
+#[cfg(any(feature = "std", test))]
+pub mod r1cs;
+pub mod secp256k1;
+#[cfg(any(feature = "std", test))]
+pub mod zkey;
 
 use crate::fields::FieldElement;
 use crate::groups::{G1Params, G2Params, GroupElement, GroupParams};
-use core::ops::{Add, Mul, Neg, Sub};
+use core::ops::{Add, Div, Mul, Neg, Sub};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 #[repr(C)]
@@ -23,11 +59,12 @@ impl Fr {
     pub fn pow(&self, exp: Fr) -> Self {
         Fr(self.0.pow(exp.0))
     }
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Option<Self> {
-        fields::Fr::from_str(s).map(|e| Fr(e))
+        fields::Fr::from_str(s).map(Fr)
     }
     pub fn inverse(&self) -> Option<Self> {
-        self.0.inverse().map(|e| Fr(e))
+        self.0.inverse().map(Fr)
     }
     pub fn is_zero(&self) -> bool {
         self.0.is_zero()
@@ -38,7 +75,7 @@ impl Fr {
     pub fn from_slice(slice: &[u8]) -> Result<Self, FieldError> {
         arith::U256::from_slice(slice)
             .map_err(|_| FieldError::InvalidSliceLength) // todo: maybe more sensful error handling
-            .map(|x| Fr::new_mul_factor(x))
+            .map(Fr::new_mul_factor)
     }
     pub fn to_big_endian(&self, slice: &mut [u8]) -> Result<(), FieldError> {
         self.0
@@ -47,7 +84,7 @@ impl Fr {
             .map_err(|_| FieldError::InvalidSliceLength)
     }
     pub fn new(val: arith::U256) -> Option<Self> {
-        fields::Fr::new(val).map(|x| Fr(x))
+        fields::Fr::new(val).map(Fr)
     }
     pub fn new_mul_factor(val: arith::U256) -> Self {
         Fr(fields::Fr::new_mul_factor(val))
@@ -55,6 +92,29 @@ impl Fr {
     pub fn into_u256(self) -> arith::U256 {
         (self.0).into()
     }
+    pub fn modulus() -> arith::U256 {
+        fields::Fr::modulus()
+    }
+    /// Builds an element from its Montgomery form, least significant limb first.
+    pub const fn from_montgomery(limbs: [u64; 4]) -> Self {
+        Fr(fields::const_fr(limbs))
+    }
+    pub fn sqrt(&self) -> Option<Self> {
+        self.0.sqrt().map(Fr)
+    }
+    /// 1 for a non-zero square, -1 for a non-square and 0 for zero.
+    pub fn legendre(&self) -> i8 {
+        self.0.legendre()
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.0.is_square()
+    }
+
+    /// `sgn0` of RFC 9380.
+    pub fn sgn0(&self) -> bool {
+        self.0.sgn0()
+    }
     pub fn set_bit(&mut self, bit: usize, to: bool) {
         self.0.set_bit(bit, to);
     }
@@ -129,11 +189,12 @@ impl Fq {
     pub fn pow(&self, exp: Fq) -> Self {
         Fq(self.0.pow(exp.0))
     }
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Option<Self> {
-        fields::Fq::from_str(s).map(|e| Fq(e))
+        fields::Fq::from_str(s).map(Fq)
     }
     pub fn inverse(&self) -> Option<Self> {
-        self.0.inverse().map(|e| Fq(e))
+        self.0.inverse().map(Fq)
     }
     pub fn is_zero(&self) -> bool {
         self.0.is_zero()
@@ -145,13 +206,13 @@ impl Fq {
         arith::U256::from_slice(slice)
             .map_err(|_| FieldError::InvalidSliceLength) // todo: maybe more sensful error handling
             .and_then(|x| fields::Fq::new(x).ok_or(FieldError::NotMember))
-            .map(|x| Fq(x))
+            .map(Fq)
     }
     pub fn to_big_endian(&self, slice: &mut [u8]) -> Result<(), FieldError> {
         let mut a: arith::U256 = self.0.into();
         // convert from Montgomery representation
         a.mul(
-            &fields::Fq::one().raw(),
+            fields::Fq::one().raw(),
             &fields::Fq::modulus(),
             self.0.inv(),
         );
@@ -171,6 +232,25 @@ impl Fq {
     pub fn sqrt(&self) -> Option<Self> {
         self.0.sqrt().map(Fq)
     }
+
+    /// 1 for a non-zero square, -1 for a non-square and 0 for zero.
+    pub fn legendre(&self) -> i8 {
+        self.0.legendre()
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.0.is_square()
+    }
+
+    /// `sgn0` of RFC 9380.
+    pub fn sgn0(&self) -> bool {
+        self.0.sgn0()
+    }
+
+    /// Builds an element from its Montgomery form, least significant limb first.
+    pub const fn from_montgomery(limbs: [u64; 4]) -> Self {
+        Fq(fields::const_fq(limbs))
+    }
 }
 
 impl Add<Fq> for Fq {
@@ -223,7 +303,7 @@ impl Fq2 {
     }
 
     /// Initalizes new F_q2(a + bi, a is real coeff, b is imaginary)
-    pub fn new(a: Fq, b: Fq) -> Fq2 {
+    pub const fn new(a: Fq, b: Fq) -> Fq2 {
         Fq2(fields::Fq2::new(a.0, b.0))
     }
 
@@ -247,6 +327,43 @@ impl Fq2 {
         self.0.sqrt().map(Fq2)
     }
 
+    /// 1 for a non-zero square, -1 for a non-square and 0 for zero.
+    pub fn legendre(&self) -> i8 {
+        self.0.legendre()
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.0.is_square()
+    }
+
+    /// `sgn0` of RFC 9380.
+    pub fn sgn0(&self) -> bool {
+        self.0.sgn0()
+    }
+
+    pub fn inverse(&self) -> Option<Self> {
+        self.0.inverse().map(Fq2)
+    }
+
+    pub fn squared(&self) -> Self {
+        Fq2(self.0.squared())
+    }
+
+    /// Multiplies by an element of Fq.
+    pub fn scale(&self, by: Fq) -> Self {
+        Fq2(self.0.scale(by.0))
+    }
+
+    /// Multiplies by xi = 9 + i, the non-residue that defines Fq6.
+    pub fn mul_by_nonresidue(&self) -> Self {
+        Fq2(self.0.mul_by_nonresidue())
+    }
+
+    /// The `power`-th iterate of x -> x^q, conjugation for odd powers.
+    pub fn frobenius_map(&self, power: usize) -> Self {
+        Fq2(self.0.frobenius_map(power))
+    }
+
     pub fn from_slice(bytes: &[u8]) -> Result<Self, FieldError> {
         let u512 = arith::U512::from_slice(bytes).map_err(|_| FieldError::InvalidU512Encoding)?;
         let (res, c0) = u512.divrem(&Fq::modulus());
@@ -289,6 +406,234 @@ impl Mul for Fq2 {
     }
 }
 
+/// An element c0 + c1 v + c2 v^2 of Fq6 = Fq2[v] / (v^3 - xi), xi = 9 + i.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(C)]
+pub struct Fq6(fields::Fq6);
+
+impl Fq6 {
+    pub fn zero() -> Self {
+        Fq6(fields::Fq6::zero())
+    }
+
+    pub fn one() -> Self {
+        Fq6(fields::Fq6::one())
+    }
+
+    pub fn new(c0: Fq2, c1: Fq2, c2: Fq2) -> Self {
+        Fq6(fields::Fq6::new(c0.0, c1.0, c2.0))
+    }
+
+    pub fn c0(&self) -> Fq2 {
+        Fq2(self.0.c0)
+    }
+
+    pub fn c1(&self) -> Fq2 {
+        Fq2(self.0.c1)
+    }
+
+    pub fn c2(&self) -> Fq2 {
+        Fq2(self.0.c2)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    pub fn inverse(&self) -> Option<Self> {
+        self.0.inverse().map(Fq6)
+    }
+
+    pub fn squared(&self) -> Self {
+        Fq6(self.0.squared())
+    }
+
+    pub fn pow(&self, exp: arith::U256) -> Self {
+        Fq6(self.0.pow(exp))
+    }
+
+    /// Multiplies by an element of Fq2.
+    pub fn scale(&self, by: Fq2) -> Self {
+        Fq6(self.0.scale(by.0))
+    }
+
+    /// Multiplies by v, the non-residue that defines Fq12.
+    pub fn mul_by_nonresidue(&self) -> Self {
+        Fq6(self.0.mul_by_nonresidue())
+    }
+
+    /// The `power`-th iterate of x -> x^q.
+    pub fn frobenius_map(&self, power: usize) -> Self {
+        Fq6(self.0.frobenius_map(power))
+    }
+}
+
+impl Add<Fq6> for Fq6 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Fq6(self.0 + other.0)
+    }
+}
+
+impl Sub<Fq6> for Fq6 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Fq6(self.0 - other.0)
+    }
+}
+
+impl Neg for Fq6 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Fq6(-self.0)
+    }
+}
+
+impl Mul for Fq6 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Fq6(self.0 * other.0)
+    }
+}
+
+/// An element c0 + c1 w of Fq12 = Fq6[w] / (w^2 - v), the field Gt lives in.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(C)]
+pub struct Fq12(fields::Fq12);
+
+impl Fq12 {
+    pub fn zero() -> Self {
+        Fq12(fields::Fq12::zero())
+    }
+
+    pub fn one() -> Self {
+        Fq12(fields::Fq12::one())
+    }
+
+    pub fn new(c0: Fq6, c1: Fq6) -> Self {
+        Fq12(fields::Fq12::new(c0.0, c1.0))
+    }
+
+    pub fn c0(&self) -> Fq6 {
+        Fq6(*self.0.c0())
+    }
+
+    pub fn c1(&self) -> Fq6 {
+        Fq6(*self.0.c1())
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    pub fn inverse(&self) -> Option<Self> {
+        self.0.inverse().map(Fq12)
+    }
+
+    pub fn squared(&self) -> Self {
+        Fq12(self.0.squared())
+    }
+
+    pub fn pow(&self, exp: arith::U256) -> Self {
+        Fq12(self.0.pow(exp))
+    }
+
+    /// The `power`-th iterate of x -> x^q.
+    pub fn frobenius_map(&self, power: usize) -> Self {
+        Fq12(self.0.frobenius_map(power))
+    }
+
+    /// c0 - c1 w, x^(q^6). It is the inverse on the cyclotomic subgroup, Gt included.
+    pub fn unitary_inverse(&self) -> Self {
+        Fq12(self.0.unitary_inverse())
+    }
+
+    /// Squaring of Granger and Scott, only correct on the cyclotomic subgroup.
+    pub fn cyclotomic_squared(&self) -> Self {
+        Fq12(self.0.cyclotomic_squared())
+    }
+
+    /// Exponentiation by cyclotomic squarings, only correct on the cyclotomic subgroup.
+    pub fn cyclotomic_pow(&self, exp: arith::U256) -> Self {
+        Fq12(self.0.cyclotomic_pow(exp))
+    }
+
+    /// Multiplies by the sparse element (ell_0 + ell_vv v^2) + ell_vw v w, the shape of the
+    /// lines of the Miller loop.
+    pub fn mul_by_024(&self, ell_0: Fq2, ell_vw: Fq2, ell_vv: Fq2) -> Self {
+        Fq12(self.0.mul_by_024(ell_0.0, ell_vw.0, ell_vv.0))
+    }
+
+    /// Raises to (q^12 - 1) / r, mapping a Miller loop output into Gt. None for zero.
+    pub fn final_exponentiation(&self) -> Option<Gt> {
+        self.0.final_exponentiation().map(Gt)
+    }
+
+    /// The T2 torus form m = (1 + c0) / c1 of a unitary element, half its size. None for
+    /// 1 and -1.
+    pub fn torus2_compress(&self) -> Option<Fq6> {
+        self.0.torus2_compress().map(Fq6)
+    }
+
+    /// (m + w) / (m - w), the inverse of `torus2_compress`.
+    pub fn torus2_decompress(m: Fq6) -> Self {
+        Fq12(fields::Fq12::torus2_decompress(m.0))
+    }
+
+    /// The T6 torus form (m1, m2) of a cyclotomic element, a third of its size: the T2
+    /// form m0 + m1 v + m2 v^2 with m0 recovered from 3 m0 m1 = 3 xi m2^2 + 1. None for 1.
+    pub fn torus6_compress(&self) -> Option<(Fq2, Fq2)> {
+        self.0.torus6_compress().map(|(m1, m2)| (Fq2(m1), Fq2(m2)))
+    }
+
+    /// The inverse of `torus6_compress`, None for m1 = 0.
+    pub fn torus6_decompress(m1: Fq2, m2: Fq2) -> Option<Self> {
+        fields::Fq12::torus6_decompress(m1.0, m2.0).map(Fq12)
+    }
+
+    /// Like `cyclotomic_pow` but with Karabina's compressed squarings, faster for
+    /// exponents with few set bits.
+    pub fn cyclotomic_pow_compressed(&self, exp: arith::U256) -> Self {
+        Fq12(self.0.cyclotomic_pow_compressed(exp))
+    }
+}
+
+impl Add<Fq12> for Fq12 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Fq12(self.0 + other.0)
+    }
+}
+
+impl Sub<Fq12> for Fq12 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Fq12(self.0 - other.0)
+    }
+}
+
+impl Neg for Fq12 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Fq12(-self.0)
+    }
+}
+
+impl Mul for Fq12 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Fq12(self.0 * other.0)
+    }
+}
+
 pub trait Group:
     Send
     + Sync
@@ -318,7 +663,7 @@ impl G1 {
     }
 
     pub fn x(&self) -> Fq {
-        Fq(self.0.x().clone())
+        Fq(*self.0.x())
     }
 
     pub fn set_x(&mut self, x: Fq) {
@@ -326,7 +671,7 @@ impl G1 {
     }
 
     pub fn y(&self) -> Fq {
-        Fq(self.0.y().clone())
+        Fq(*self.0.y())
     }
 
     pub fn set_y(&mut self, y: Fq) {
@@ -334,7 +679,7 @@ impl G1 {
     }
 
     pub fn z(&self) -> Fq {
-        Fq(self.0.z().clone())
+        Fq(*self.0.z())
     }
 
     pub fn set_z(&mut self, z: Fq) {
@@ -357,9 +702,8 @@ impl G1 {
 
         let mut y = y_squared.sqrt().ok_or(CurveError::NotMember)?;
 
-        if sign == 2 && y.into_u256().get_bit(0).expect("bit 0 always exist; qed") {
-            y = y.neg();
-        } else if sign == 3 && !y.into_u256().get_bit(0).expect("bit 0 always exist; qed") {
+        let odd = y.into_u256().get_bit(0).expect("bit 0 always exist; qed");
+        if (sign == 2 && odd) || (sign == 3 && !odd) {
             y = y.neg();
         } else if sign != 3 && sign != 2 {
             return Err(CurveError::InvalidEncoding);
@@ -431,8 +775,13 @@ impl AffineG1 {
         Ok(AffineG1(groups::AffineG1::new(x.0, y.0)?))
     }
 
+    /// Builds a point without the curve check, for constants known to be valid.
+    pub const fn new_unchecked(x: Fq, y: Fq) -> Self {
+        AffineG1(groups::AffineG1::new_unchecked(x.0, y.0))
+    }
+
     pub fn x(&self) -> Fq {
-        Fq(self.0.x().clone())
+        Fq(*self.0.x())
     }
 
     pub fn set_x(&mut self, x: Fq) {
@@ -440,7 +789,7 @@ impl AffineG1 {
     }
 
     pub fn y(&self) -> Fq {
-        Fq(self.0.y().clone())
+        Fq(*self.0.y())
     }
 
     pub fn set_y(&mut self, y: Fq) {
@@ -448,7 +797,7 @@ impl AffineG1 {
     }
 
     pub fn from_jacobian(g1: G1) -> Option<Self> {
-        g1.0.to_affine().map(|x| AffineG1(x))
+        g1.0.to_affine().map(AffineG1)
     }
 }
 
@@ -468,7 +817,7 @@ impl G2 {
     }
 
     pub fn x(&self) -> Fq2 {
-        Fq2(self.0.x().clone())
+        Fq2(*self.0.x())
     }
 
     pub fn set_x(&mut self, x: Fq2) {
@@ -476,7 +825,7 @@ impl G2 {
     }
 
     pub fn y(&self) -> Fq2 {
-        Fq2(self.0.y().clone())
+        Fq2(*self.0.y())
     }
 
     pub fn set_y(&mut self, y: Fq2) {
@@ -484,7 +833,7 @@ impl G2 {
     }
 
     pub fn z(&self) -> Fq2 {
-        Fq2(self.0.z().clone())
+        Fq2(*self.0.z())
     }
 
     pub fn set_z(&mut self, z: Fq2) {
@@ -583,7 +932,10 @@ impl Mul<Fr> for G2 {
     }
 }
 
-#[derive(Copy, Clone, PartialEq, Eq)]
+const GT_FLAG_MASK: u8 = 0b11 << 6;
+const GT_FLAG_IDENTITY: u8 = 0b01 << 6;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 #[repr(C)]
 pub struct Gt(fields::Fq12);
 
@@ -600,6 +952,116 @@ impl Gt {
     pub fn final_exponentiation(&self) -> Option<Self> {
         self.0.final_exponentiation().map(Gt)
     }
+    pub fn is_one(&self) -> bool {
+        self.0 == fields::Fq12::one()
+    }
+    /// Whether the value has order r, as every pairing does; a Miller loop output before
+    /// its final exponentiation usually doesn't.
+    pub fn is_in_subgroup(&self) -> bool {
+        self.0.is_in_subgroup()
+    }
+    /// The value as an element of Fq12.
+    pub fn to_fq12(&self) -> Fq12 {
+        Fq12(self.0)
+    }
+    /// An element of Fq12 of order r, None otherwise.
+    pub fn from_fq12(f: Fq12) -> Option<Self> {
+        let gt = Gt(f.0);
+        if gt.is_in_subgroup() {
+            Some(gt)
+        } else {
+            None
+        }
+    }
+
+    // The twelve Fq coefficients in tower order, c0.c0.c0, c0.c0.c1, c0.c1.c0, ...
+    fn coeffs(&self) -> [Fq; 12] {
+        let f = self.to_fq12();
+        let mut c = [Fq::zero(); 12];
+        for (i, e) in [f.c0(), f.c1()].iter().enumerate() {
+            for (j, a) in [e.c0(), e.c1(), e.c2()].iter().enumerate() {
+                c[6 * i + 2 * j] = a.real();
+                c[6 * i + 2 * j + 1] = a.imaginary();
+            }
+        }
+        c
+    }
+
+    fn from_coeffs(c: &[Fq; 12]) -> Result<Self, CurveError> {
+        let fq2 = |i: usize| Fq2::new(c[i], c[i + 1]);
+        let fq6 = |i: usize| Fq6::new(fq2(i), fq2(i + 2), fq2(i + 4));
+        Gt::from_fq12(Fq12::new(fq6(0), fq6(6))).ok_or(CurveError::NotMember)
+    }
+
+    /// The canonical 384-byte encoding: the twelve coefficients as 32-byte big-endian
+    /// integers from the last, c1.c2.c1, to the first, c0.c0.c0, as gnark-crypto writes
+    /// GT. It is the byte reversal of `to_bytes_le`.
+    pub fn to_bytes(&self) -> [u8; 384] {
+        let mut out = [0u8; 384];
+        for (c, o) in self.coeffs().iter().rev().zip(out.chunks_mut(32)) {
+            c.to_big_endian(o).unwrap();
+        }
+        out
+    }
+
+    /// Decodes `to_bytes`, rejecting coefficients not below q and values not of order r.
+    pub fn from_bytes(bytes: &[u8; 384]) -> Result<Self, CurveError> {
+        let mut c = [Fq::zero(); 12];
+        for (c, b) in c.iter_mut().rev().zip(bytes.chunks(32)) {
+            *c = Fq::from_slice(b)?;
+        }
+        Self::from_coeffs(&c)
+    }
+
+    /// The arkworks `CanonicalSerialize` encoding: the coefficients in tower order as
+    /// 32-byte little-endian integers.
+    pub fn to_bytes_le(&self) -> [u8; 384] {
+        let mut out = self.to_bytes();
+        out.reverse();
+        out
+    }
+
+    /// Decodes `to_bytes_le` with the same checks as `from_bytes`.
+    pub fn from_bytes_le(bytes: &[u8; 384]) -> Result<Self, CurveError> {
+        let mut be = *bytes;
+        be.reverse();
+        Self::from_bytes(&be)
+    }
+
+    /// The 128-byte torus encoding: m2 then m1 of `Fq12::torus6_compress`, each as two
+    /// 32-byte big-endian coefficients, imaginary part first. The identity has no torus
+    /// form and is 0x40 followed by zeros, the flag in the top two bits of the first byte.
+    pub fn to_compressed(&self) -> [u8; 128] {
+        let mut out = [0u8; 128];
+        match self.to_fq12().torus6_compress() {
+            Some((m1, m2)) => {
+                let coeffs = [m2.imaginary(), m2.real(), m1.imaginary(), m1.real()];
+                for (c, o) in coeffs.iter().zip(out.chunks_mut(32)) {
+                    c.to_big_endian(o).unwrap();
+                }
+            }
+            None => out[0] = GT_FLAG_IDENTITY,
+        }
+        out
+    }
+
+    /// Decodes `to_compressed`, rejecting non-canonical encodings and values not of order r.
+    pub fn from_compressed(bytes: &[u8; 128]) -> Result<Self, CurveError> {
+        let mut b = *bytes;
+        b[0] &= !GT_FLAG_MASK;
+        match bytes[0] & GT_FLAG_MASK {
+            0 => {}
+            GT_FLAG_IDENTITY if b.iter().all(|&e| e == 0) => return Ok(Gt::one()),
+            _ => return Err(CurveError::InvalidEncoding),
+        }
+        let mut c = [Fq::zero(); 4];
+        for (c, b) in c.iter_mut().zip(b.chunks(32)) {
+            *c = Fq::from_slice(b)?;
+        }
+        let f = Fq12::torus6_decompress(Fq2::new(c[3], c[2]), Fq2::new(c[1], c[0]))
+            .ok_or(CurveError::InvalidEncoding)?;
+        Gt::from_fq12(f).ok_or(CurveError::NotMember)
+    }
 }
 
 impl Mul<Gt> for Gt {
@@ -610,34 +1072,42 @@ impl Mul<Gt> for Gt {
     }
 }
 
+impl Div<Gt> for Gt {
+    type Output = Gt;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: Gt) -> Gt {
+        Gt(self.0
+            * other
+                .0
+                .inverse()
+                .expect("no pairing nor Miller loop output is zero"))
+    }
+}
+
 pub fn pairing(p: G1, q: G2) -> Gt {
     Gt(groups::pairing(&p.0, &q.0))
 }
 
 pub fn pairing_batch(pairs: &[(G1, G2)]) -> Gt {
-    let mut ps = [groups::G1::default(); 16];
-    let mut qs = [groups::G2::default(); 16];
-    for (i, (p, q)) in pairs.iter().enumerate() {
-        ps[i] = p.0;
-        qs[i] = q.0;
+    let mut batch = PairingBatch::new();
+    for (p, q) in pairs {
+        batch.push(*p, *q);
     }
-    Gt(groups::pairing_batch(
-        &ps[0..pairs.len()],
-        &qs[0..pairs.len()],
-    ))
+    batch.finalize()
 }
 
+/// The product of the Miller loops of the pairs, without the final exponentiation. Fails
+/// on points at infinity.
 pub fn miller_loop_batch(pairs: &[(G2, G1)]) -> Result<Gt, CurveError> {
-    let mut ps = [groups::G2Precomp::default(); 16];
-    let mut qs = [groups::AffineG::<groups::G1Params>::default(); 16];
-    for (i, (p, q)) in pairs.iter().enumerate() {
-        ps[i] =
-            p.0.to_affine()
-                .ok_or(CurveError::ToAffineConversion)?
-                .precompute();
-        qs[i] = q.0.to_affine().ok_or(CurveError::ToAffineConversion)?;
+    let mut batch = groups::PairingBatch::default();
+    for (q, p) in pairs {
+        if q.is_zero() || p.is_zero() {
+            return Err(CurveError::ToAffineConversion);
+        }
+        batch.push(&p.0, &q.0);
     }
-    Ok(Gt(groups::miller_loop_batch(&ps, &qs)))
+    Ok(Gt(batch.miller_loop()))
 }
 
 #[derive(Copy, Clone, PartialEq, Eq)]
@@ -649,8 +1119,13 @@ impl AffineG2 {
         Ok(AffineG2(groups::AffineG2::new(x.0, y.0)?))
     }
 
+    /// Builds a point without the curve and subgroup checks, for constants known to be valid.
+    pub const fn new_unchecked(x: Fq2, y: Fq2) -> Self {
+        AffineG2(groups::AffineG2::new_unchecked(x.0, y.0))
+    }
+
     pub fn x(&self) -> Fq2 {
-        Fq2(self.0.x().clone())
+        Fq2(*self.0.x())
     }
 
     pub fn set_x(&mut self, x: Fq2) {
@@ -658,7 +1133,7 @@ impl AffineG2 {
     }
 
     pub fn y(&self) -> Fq2 {
-        Fq2(self.0.y().clone())
+        Fq2(*self.0.y())
     }
 
     pub fn set_y(&mut self, y: Fq2) {
@@ -666,7 +1141,7 @@ impl AffineG2 {
     }
 
     pub fn from_jacobian(g2: G2) -> Option<Self> {
-        g2.0.to_affine().map(|x| AffineG2(x))
+        g2.0.to_affine().map(AffineG2)
     }
 }
 
@@ -675,3 +1150,212 @@ impl From<AffineG2> for G2 {
         G2(affine.0.to_jacobian())
     }
 }
+
+/// A G2 point with the line coefficients of the Miller loop computed ahead of time.
+#[derive(Copy, Clone, PartialEq, Eq)]
+#[repr(C)]
+pub struct G2Prepared(groups::G2Precomp);
+
+impl G2Prepared {
+    pub fn new(q: AffineG2) -> Self {
+        G2Prepared(q.0.precompute())
+    }
+
+    /// Builds a prepared point from the `[ell_0, ell_vw, ell_vv]` coefficients of every
+    /// Miller loop step, as emitted by `codegen`.
+    pub const fn from_coeffs(q: AffineG2, coeffs: [[Fq2; 3]; fields::BN254_LINES]) -> Self {
+        let zero = fields::Fq2::new(fields::const_fq([0; 4]), fields::const_fq([0; 4]));
+        let mut r = groups::G2Precomp {
+            q: q.0,
+            coeffs: [groups::EllCoeffs {
+                ell_0: zero,
+                ell_vw: zero,
+                ell_vv: zero,
+            }; fields::BN254_LINES],
+        };
+        let mut i = 0;
+        while i < fields::BN254_LINES {
+            r.coeffs[i] = groups::EllCoeffs {
+                ell_0: coeffs[i][0].0,
+                ell_vw: coeffs[i][1].0,
+                ell_vv: coeffs[i][2].0,
+            };
+            i += 1;
+        }
+        G2Prepared(r)
+    }
+}
+
+pub fn pairing_batch_prepared(pairs: &[(G1, &G2Prepared)]) -> Gt {
+    let mut batch = PairingBatch::new();
+    for (p, q) in pairs {
+        batch.push_prepared(*p, q);
+    }
+    batch.finalize()
+}
+
+/// Product of pairings over any number of pairs, with a single final exponentiation.
+/// Unlike `pairing_batch`, pairs are pushed one at a time, so the input never has to
+/// be held in memory at once. Prepared points are borrowed for the life of the batch and
+/// the lines of the other pairs are computed during the Miller loop, so the batch takes
+/// 296 bytes per pair, a little over 5 KB, and a flush puts 192 bytes per pair of affine
+/// points on the stack.
+pub struct PairingBatch<'a>(groups::PairingBatch<'a>);
+
+impl<'a> PairingBatch<'a> {
+    pub fn new() -> Self {
+        PairingBatch(groups::PairingBatch::default())
+    }
+
+    pub fn push(&mut self, p: G1, q: G2) {
+        self.0.push(&p.0, &q.0)
+    }
+
+    pub fn push_prepared(&mut self, p: G1, q: &'a G2Prepared) {
+        self.0.push_prepared(&p.0, &q.0)
+    }
+
+    pub fn finalize(self) -> Gt {
+        Gt(self.0.finalize())
+    }
+}
+
+impl<'a> Default for PairingBatch<'a> {
+    fn default() -> Self {
+        PairingBatch::new()
+    }
+}
+
+#[test]
+fn tower_and_gt() {
+    let fq2 = |a: &str, b: &str| Fq2::new(Fq::from_str(a).unwrap(), Fq::from_str(b).unwrap());
+    let f = Fq12::new(
+        Fq6::new(fq2("1", "2"), fq2("3", "4"), fq2("5", "6")),
+        Fq6::new(fq2("7", "8"), fq2("9", "10"), fq2("11", "12")),
+    );
+
+    let mut g = f;
+    for power in 1..13 {
+        g = g.frobenius_map(1);
+        assert_eq!(f.frobenius_map(power), g);
+        assert_eq!(f.c0().frobenius_map(power), g.c0().frobenius_map(0));
+    }
+    assert_eq!(g, f);
+    assert_eq!(f.frobenius_map(1), f.pow(Fq::modulus()));
+    assert_eq!(f.c1().frobenius_map(1), f.c1().pow(Fq::modulus()));
+    assert_eq!(f * f.inverse().unwrap(), Fq12::one());
+
+    let (a, b, c) = (fq2("13", "14"), fq2("15", "16"), fq2("17", "18"));
+    let sparse = Fq12::new(
+        Fq6::new(a, Fq2::zero(), c),
+        Fq6::new(Fq2::zero(), b, Fq2::zero()),
+    );
+    assert_eq!(f.mul_by_024(a, b, c), f * sparse);
+
+    let e = pairing(G1::one(), G2::one());
+    assert!(e.is_in_subgroup() && !e.is_one() && Gt::one().is_one());
+    assert_eq!((e * e) / e, e);
+    assert_eq!(e / e, Gt::one());
+    let h = e.to_fq12();
+    assert_eq!(h.unitary_inverse(), h.inverse().unwrap());
+    assert_eq!(h.cyclotomic_squared(), h.squared());
+    assert_eq!(h.cyclotomic_pow(12345.into()), h.pow(12345.into()));
+    assert_eq!(Gt::from_fq12(h), Some(e));
+    assert_eq!(Gt::from_fq12(f), None);
+    assert_eq!(Gt::from_fq12(Fq12::zero()), None);
+    let m = miller_loop_batch(&[(G2::one(), G1::one())]).unwrap();
+    assert!(!m.is_in_subgroup());
+    assert_eq!(m.to_fq12().final_exponentiation(), Some(e));
+}
+
+#[test]
+fn gt_encoding() {
+    let e = pairing(G1::one(), G2::one()).pow(Fr::from_str("1234567").unwrap());
+    for g in [Gt::one(), e, e.inverse().unwrap()].iter() {
+        let be = g.to_bytes();
+        let le = g.to_bytes_le();
+        assert_eq!(Gt::from_bytes(&be).unwrap(), *g);
+        assert_eq!(Gt::from_bytes_le(&le).unwrap(), *g);
+        let mut c0 = [0u8; 32];
+        g.to_fq12().c0().c0().real().to_big_endian(&mut c0).unwrap();
+        assert_eq!(be[352..], c0);
+        c0.reverse();
+        assert_eq!(le[..32], c0);
+    }
+    assert_eq!(Gt::one().to_bytes_le()[..33], {
+        let mut one = [0u8; 33];
+        one[0] = 1;
+        one
+    });
+
+    // A coefficient equal to q.
+    let mut bad = e.to_bytes();
+    Fq::modulus().to_big_endian(&mut bad[..32]).unwrap();
+    assert!(matches!(
+        Gt::from_bytes(&bad),
+        Err(CurveError::Field(FieldError::NotMember))
+    ));
+
+    // Not in the cyclotomic subgroup; in it but not of order r, as after the easy part
+    // of the final exponentiation only; a Miller loop output; zero.
+    let fq2 = |a: &str, b: &str| Fq2::new(Fq::from_str(a).unwrap(), Fq::from_str(b).unwrap());
+    let f = Fq12::new(
+        Fq6::new(fq2("1", "2"), fq2("3", "4"), fq2("5", "6")),
+        Fq6::new(fq2("7", "8"), fq2("9", "10"), fq2("11", "12")),
+    );
+    let easy = f.unitary_inverse() * f.inverse().unwrap();
+    let easy = easy.frobenius_map(2) * easy;
+    let m = miller_loop_batch(&[(G2::one(), G1::one())])
+        .unwrap()
+        .to_fq12();
+    for &f in [f, easy, m, Fq12::zero()].iter() {
+        let g = Gt(f.0);
+        assert!(!g.is_in_subgroup());
+        assert!(Gt::from_bytes(&g.to_bytes()).is_err());
+        assert!(Gt::from_bytes_le(&g.to_bytes_le()).is_err());
+        assert!(f.is_zero() || f.pow(Fr::modulus()) != Fq12::one());
+    }
+    assert_eq!(easy.unitary_inverse(), easy.inverse().unwrap());
+    assert!(e.to_fq12().pow(Fr::modulus()) == Fq12::one());
+}
+
+#[test]
+fn gt_compressed() {
+    let e = pairing(G1::one(), G2::one());
+    let mut g = Gt::one();
+    for _ in 0..4 {
+        assert_eq!(Gt::from_compressed(&g.to_compressed()).unwrap(), g);
+        g = g * e.pow(Fr::from_str("987654321").unwrap());
+    }
+    let mut one = [0u8; 128];
+    one[0] = 0x40;
+    assert_eq!(Gt::one().to_compressed(), one);
+
+    one[127] = 1;
+    assert!(Gt::from_compressed(&one).is_err());
+    let mut bad = e.to_compressed();
+    bad[0] |= 0x80;
+    assert!(Gt::from_compressed(&bad).is_err());
+    // m1 = 0.
+    let mut bad = e.to_compressed();
+    bad[64..].copy_from_slice(&[0; 64]);
+    assert!(matches!(
+        Gt::from_compressed(&bad),
+        Err(CurveError::InvalidEncoding)
+    ));
+    // Cyclotomic but not of order r.
+    let m = miller_loop_batch(&[(G2::one(), G1::one())])
+        .unwrap()
+        .to_fq12();
+    let easy = m.unitary_inverse() * m.inverse().unwrap();
+    let (m1, m2) = (easy.frobenius_map(2) * easy).torus6_compress().unwrap();
+    let mut bad = [0u8; 128];
+    let coeffs = [m2.imaginary(), m2.real(), m1.imaginary(), m1.real()];
+    for (c, o) in coeffs.iter().zip(bad.chunks_mut(32)) {
+        c.to_big_endian(o).unwrap();
+    }
+    assert!(matches!(
+        Gt::from_compressed(&bad),
+        Err(CurveError::NotMember)
+    ));
+}
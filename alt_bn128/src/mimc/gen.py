@@ -0,0 +1,34 @@
+# Writes constants.bin: the 91 round constants of circomlib's MiMC7, Montgomery form,
+# little-endian. c_0 = 0 and c_i = keccak256(c_(i - 1)) mod r with c_0 seeded from
+# keccak256("mimc").
+import os
+import sys
+
+sys.path.insert(0, os.path.join(os.path.dirname(__file__), "..", "..", "fixtures"))
+from keccak import keccak256  # noqa: E402
+
+R = 0x30644E72E131A029B85045B68181585D2833E84879B9709143E1F593F0000001
+ROUNDS = 91
+
+
+def constants():
+    c = keccak256(b"mimc")
+    out = [0]
+    for _ in range(1, ROUNDS):
+        c = keccak256(c)
+        out.append(int.from_bytes(c, "big") % R)
+    return out
+
+
+def hash(x, k):
+    r = 0
+    for i, c in enumerate(constants()):
+        t = (x + k) % R if i == 0 else (r + k + c) % R
+        r = pow(t, 7, R)
+    return (r + k) % R
+
+
+if __name__ == "__main__":
+    out = b"".join((x * 2**256 % R).to_bytes(32, "little") for x in constants())
+    with open(os.path.join(os.path.dirname(__file__), "constants.bin"), "wb") as f:
+        f.write(out)
@@ -0,0 +1,64 @@
+// MiMC7 of circomlib over Fr: 91 rounds of x -> (x + k + c_i)^7, the first round without
+// a constant, and the key added to the output. `multi_hash` is circomlibjs'
+// `mimc7.multiHash`, the Miyaguchi-Preneel chaining used by EdDSA-MiMC.
+//
+// The round constants are decoded from constants.bin (see gen.py) at compile time.
+use crate::Fr;
+
+pub const ROUNDS: usize = 91;
+
+const CONSTANTS: &[u8] = include_bytes!("constants.bin");
+
+const fn decode() -> [Fr; ROUNDS] {
+    let mut out = [Fr::from_montgomery([0; 4]); ROUNDS];
+    let mut i = 0;
+    while i < ROUNDS {
+        let mut limbs = [0u64; 4];
+        let mut j = 0;
+        while j < 32 {
+            limbs[j / 8] |= (CONSTANTS[i * 32 + j] as u64) << (8 * (j % 8));
+            j += 1;
+        }
+        out[i] = Fr::from_montgomery(limbs);
+        i += 1;
+    }
+    out
+}
+
+static C: [Fr; ROUNDS] = decode();
+
+/// `mimc7.hash(x, k)` of circomlibjs.
+pub fn hash(x: Fr, k: Fr) -> Fr {
+    let mut r = x;
+    for (i, c) in C.iter().enumerate() {
+        let t = if i == 0 { r + k } else { r + k + *c };
+        let t2 = t * t;
+        r = t2 * t2 * t2 * t;
+    }
+    r + k
+}
+
+/// `mimc7.multiHash(inputs, key)` of circomlibjs.
+pub fn multi_hash(inputs: &[Fr], key: Fr) -> Fr {
+    inputs.iter().fold(key, |r, x| r + *x + hash(*x, r))
+}
+
+#[test]
+fn circomlibjs_vectors() {
+    let n = |i: u64| Fr::new(crate::arith::U256::from(i)).unwrap();
+    assert_eq!(
+        hash(n(1), n(2)),
+        Fr::from_str(
+            "10594780656576967754230020536574539122676596303354946869887184401991294982664"
+        )
+        .unwrap()
+    );
+    assert_eq!(multi_hash(&[], n(7)), n(7));
+    assert_eq!(
+        multi_hash(&[n(1), n(2), n(3)], n(7)),
+        Fr::from_str(
+            "1968913490863472374141024045724945361792209046042142303678582202113329849479"
+        )
+        .unwrap()
+    );
+}
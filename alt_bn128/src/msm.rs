@@ -0,0 +1,91 @@
+// Multi-scalar multiplication sum(s_i * P_i) with Pippenger's bucket method: scalars are
+// cut into c-bit windows, each window sorts the points into 2^c - 1 buckets by digit and
+// sums them with about n + 2^(c + 1) additions instead of n * c doublings and additions.
+use crate::{arith::U256, Fr, Group};
+use core::ops::Add;
+
+const MAX_WINDOW: usize = 8;
+
+fn window_size(n: usize) -> usize {
+    if n < 32 {
+        3
+    } else {
+        // ln(n) + 2, capped so the buckets fit on the stack.
+        let ln = (usize::BITS - n.leading_zeros()) as usize * 69 / 100;
+        core::cmp::min(ln + 2, MAX_WINDOW)
+    }
+}
+
+pub fn msm<G: Group>(bases: &[G], scalars: &[Fr]) -> G {
+    assert_eq!(bases.len(), scalars.len());
+    pippenger(bases, scalars.iter().map(|s| s.into_u256()), G::zero())
+}
+
+// The bucket method over any group with 256-bit scalars, for curves whose scalars are not
+// Fr. `zero` is the identity of the group.
+pub(crate) fn pippenger<G, I>(bases: &[G], scalars: I, zero: G) -> G
+where
+    G: Copy + Add<Output = G>,
+    I: Iterator<Item = U256> + Clone,
+{
+    let c = window_size(bases.len());
+    let mut buckets = [zero; (1 << MAX_WINDOW) - 1];
+    let mut acc = zero;
+    let mut w = 256_usize.div_ceil(c);
+    while w > 0 {
+        w -= 1;
+        for _ in 0..c {
+            acc = acc + acc;
+        }
+        for b in buckets[..(1 << c) - 1].iter_mut() {
+            *b = zero;
+        }
+        for (p, s) in bases.iter().zip(scalars.clone()) {
+            let mut digit = 0;
+            for i in (w * c..(w + 1) * c).rev() {
+                digit = (digit << 1) | s.get_bit(i).unwrap_or(false) as usize;
+            }
+            if digit != 0 {
+                buckets[digit - 1] = buckets[digit - 1] + *p;
+            }
+        }
+        // sum(d * bucket_d) as a running sum from the highest digit down.
+        let mut running = zero;
+        let mut window = zero;
+        for b in buckets[..(1 << c) - 1].iter().rev() {
+            running = running + *b;
+            window = window + running;
+        }
+        acc = acc + window;
+    }
+    acc
+}
+
+#[test]
+fn msm_matches_naive() {
+    use crate::{G1, G2};
+
+    let mut bases = [G1::zero(); 40];
+    let mut scalars = [Fr::zero(); 40];
+    let mut s = Fr::from_str("123456789").unwrap();
+    for i in 0..40 {
+        s = s * s + Fr::one();
+        bases[i] = G1::one() * Fr::from_str("3").unwrap().pow(s);
+        scalars[i] = s;
+    }
+    scalars[7] = Fr::zero();
+    scalars[8] = -Fr::one();
+    for n in [0, 1, 5, 40] {
+        let expect = bases[..n]
+            .iter()
+            .zip(scalars[..n].iter())
+            .fold(G1::zero(), |acc, (p, s)| acc + *p * *s);
+        assert_eq!(msm(&bases[..n], &scalars[..n]), expect);
+    }
+
+    let bases = [G2::one(), G2::one() * scalars[0]];
+    assert_eq!(
+        msm(&bases, &scalars[1..3]),
+        bases[0] * scalars[1] + bases[1] * scalars[2]
+    );
+}
@@ -0,0 +1,476 @@
+// Dense univariate polynomials over Fr, coefficients lowest degree first.
+//
+// The slice functions work in place on caller buffers. `FixedPolynomial<N>` keeps up to
+// N coefficients on the stack for no_std, `DensePolynomial` and `Evaluations` use the heap
+// and need the `alloc` feature. Products go through the NTT once both factors have more
+// than `SCHOOLBOOK_THRESHOLD` coefficients.
+use crate::{domain::EvaluationDomain, Fr};
+#[cfg(any(feature = "alloc", test))]
+use alloc::{vec, vec::Vec};
+
+pub const SCHOOLBOOK_THRESHOLD: usize = 32;
+
+/// Horner evaluation of `p` at `x`.
+pub fn evaluate(p: &[Fr], x: Fr) -> Fr {
+    p.iter().rev().fold(Fr::zero(), |acc, c| acc * x + *c)
+}
+
+/// Divides by `X - z` in place and returns the remainder `p(z)`. `p[0]` holds the
+/// remainder afterwards and `p[1..]` the quotient.
+pub fn divide_by_linear(p: &mut [Fr], z: Fr) -> Fr {
+    if p.is_empty() {
+        return Fr::zero();
+    }
+    for i in (0..p.len() - 1).rev() {
+        p[i] = p[i] + z * p[i + 1];
+    }
+    p[0]
+}
+
+/// Divides by `X^n - 1` in place and returns whether the remainder is zero. `p[..n]`
+/// holds the remainder afterwards and `p[n..]` the quotient.
+pub fn divide_by_vanishing(p: &mut [Fr], n: usize) -> bool {
+    assert!(n > 0);
+    for i in (n..p.len()).rev() {
+        p[i - n] = p[i - n] + p[i];
+    }
+    p.iter().take(n).all(|c| c.is_zero())
+}
+
+/// Schoolbook product of `a` and `b` into `out[..a.len() + b.len() - 1]`.
+pub fn mul_schoolbook(a: &[Fr], b: &[Fr], out: &mut [Fr]) {
+    let n = product_len(a, b);
+    for e in out[..n].iter_mut() {
+        *e = Fr::zero();
+    }
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            out[i + j] = out[i + j] + *x * *y;
+        }
+    }
+}
+
+/// NTT product of the polynomials in `a` and `b`, both padded with zeros to the size of
+/// `domain`, which must exceed the degree of the product. The product ends up in `a`.
+pub fn mul_ntt(a: &mut [Fr], b: &mut [Fr], domain: &EvaluationDomain) {
+    domain.fft(a);
+    domain.fft(b);
+    for (x, y) in a.iter_mut().zip(b.iter()) {
+        *x = *x * *y;
+    }
+    domain.ifft(a);
+}
+
+fn product_len(a: &[Fr], b: &[Fr]) -> usize {
+    if a.is_empty() || b.is_empty() {
+        0
+    } else {
+        a.len() + b.len() - 1
+    }
+}
+
+fn trimmed(p: &[Fr]) -> &[Fr] {
+    let n = p.iter().rposition(|c| !c.is_zero()).map_or(0, |i| i + 1);
+    &p[..n]
+}
+
+/// Lagrange interpolation through `(xs[i], ys[i])` into `out`, using `m` and `q` of the
+/// same length as scratch. Returns false if two of the points coincide.
+fn interpolate_into(xs: &[Fr], ys: &[Fr], out: &mut [Fr], m: &mut [Fr], q: &mut [Fr]) -> bool {
+    let n = xs.len();
+    // m = prod(X - x_i), n + 1 coefficients.
+    m[0] = Fr::one();
+    for (k, x) in xs.iter().enumerate() {
+        m[k + 1] = m[k];
+        for i in (1..=k).rev() {
+            m[i] = m[i - 1] - *x * m[i];
+        }
+        m[0] = -*x * m[0];
+    }
+    for e in out[..n].iter_mut() {
+        *e = Fr::zero();
+    }
+    for (x, y) in xs.iter().zip(ys.iter()) {
+        q[..=n].copy_from_slice(&m[..=n]);
+        divide_by_linear(&mut q[..=n], *x);
+        let d = match evaluate(&q[1..=n], *x).inverse() {
+            Some(d) => d,
+            None => return false,
+        };
+        let s = *y * d;
+        for (o, c) in out[..n].iter_mut().zip(q[1..=n].iter()) {
+            *o = *o + s * *c;
+        }
+    }
+    true
+}
+
+/// A polynomial with at most `N` coefficients, stored inline.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FixedPolynomial<const N: usize> {
+    coeffs: [Fr; N],
+    len: usize,
+}
+
+impl<const N: usize> FixedPolynomial<N> {
+    pub fn zero() -> Self {
+        FixedPolynomial {
+            coeffs: [Fr::zero(); N],
+            len: 0,
+        }
+    }
+
+    /// `None` if `coeffs` has more than `N` coefficients once trailing zeros are dropped.
+    pub fn from_coefficients(coeffs: &[Fr]) -> Option<Self> {
+        let coeffs = trimmed(coeffs);
+        if coeffs.len() > N {
+            return None;
+        }
+        let mut p = Self::zero();
+        p.coeffs[..coeffs.len()].copy_from_slice(coeffs);
+        p.len = coeffs.len();
+        Some(p)
+    }
+
+    /// The polynomial taking the values `evals` over `domain`.
+    pub fn from_evaluations(domain: &EvaluationDomain, evals: &[Fr]) -> Option<Self> {
+        if evals.len() != domain.size() || evals.len() > N {
+            return None;
+        }
+        let mut buf = [Fr::zero(); N];
+        buf[..evals.len()].copy_from_slice(evals);
+        domain.ifft(&mut buf[..evals.len()]);
+        Self::from_coefficients(&buf[..evals.len()])
+    }
+
+    /// The polynomial of lowest degree through `(xs[i], ys[i])`, `None` if two points
+    /// coincide or there are more than `N - 1`.
+    pub fn interpolate(xs: &[Fr], ys: &[Fr]) -> Option<Self> {
+        assert_eq!(xs.len(), ys.len());
+        if xs.len() >= N {
+            return None;
+        }
+        let (mut out, mut m, mut q) = ([Fr::zero(); N], [Fr::zero(); N], [Fr::zero(); N]);
+        if !interpolate_into(xs, ys, &mut out, &mut m, &mut q) {
+            return None;
+        }
+        Self::from_coefficients(&out[..xs.len()])
+    }
+
+    pub fn coeffs(&self) -> &[Fr] {
+        &self.coeffs[..self.len]
+    }
+
+    /// `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.len.checked_sub(1)
+    }
+
+    pub fn evaluate(&self, x: Fr) -> Fr {
+        evaluate(self.coeffs(), x)
+    }
+
+    pub fn add(&self, other: &Self) -> Self {
+        let mut r = *self;
+        for (a, b) in r.coeffs.iter_mut().zip(other.coeffs.iter()) {
+            *a = *a + *b;
+        }
+        r.len = trimmed(&r.coeffs[..core::cmp::max(self.len, other.len)]).len();
+        r
+    }
+
+    pub fn sub(&self, other: &Self) -> Self {
+        let mut r = *self;
+        for (a, b) in r.coeffs.iter_mut().zip(other.coeffs.iter()) {
+            *a = *a - *b;
+        }
+        r.len = trimmed(&r.coeffs[..core::cmp::max(self.len, other.len)]).len();
+        r
+    }
+
+    /// `None` if the product has more than `N` coefficients.
+    pub fn mul(&self, other: &Self) -> Option<Self> {
+        let n = product_len(self.coeffs(), other.coeffs());
+        if n > N {
+            return None;
+        }
+        let mut r = Self::zero();
+        let domain = EvaluationDomain::new(n).filter(|d| d.size() <= N);
+        match domain {
+            Some(d) if core::cmp::min(self.len, other.len) > SCHOOLBOOK_THRESHOLD => {
+                let mut b = [Fr::zero(); N];
+                r.coeffs[..self.len].copy_from_slice(self.coeffs());
+                b[..other.len].copy_from_slice(other.coeffs());
+                mul_ntt(&mut r.coeffs[..d.size()], &mut b[..d.size()], &d);
+            }
+            _ => mul_schoolbook(self.coeffs(), other.coeffs(), &mut r.coeffs),
+        }
+        r.len = trimmed(&r.coeffs[..n]).len();
+        Some(r)
+    }
+
+    /// Quotient and remainder `self(z)` of the division by `X - z`.
+    pub fn divide_by_linear(&self, z: Fr) -> (Self, Fr) {
+        let mut buf = self.coeffs;
+        let r = divide_by_linear(&mut buf[..self.len], z);
+        let q = match self.len {
+            0 => Self::zero(),
+            n => Self::from_coefficients(&buf[1..n]).unwrap(),
+        };
+        (q, r)
+    }
+
+    /// The quotient by the vanishing polynomial of `domain`, `None` unless it divides.
+    pub fn divide_by_vanishing(&self, domain: &EvaluationDomain) -> Option<Self> {
+        let n = domain.size();
+        let mut buf = self.coeffs;
+        if !divide_by_vanishing(&mut buf[..self.len], n) {
+            return None;
+        }
+        Self::from_coefficients(buf.get(n..self.len).unwrap_or(&[]))
+    }
+}
+
+/// A polynomial in coefficient form on the heap.
+#[cfg(any(feature = "alloc", test))]
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct DensePolynomial {
+    coeffs: Vec<Fr>,
+}
+
+#[cfg(any(feature = "alloc", test))]
+impl DensePolynomial {
+    pub fn zero() -> Self {
+        DensePolynomial { coeffs: Vec::new() }
+    }
+
+    pub fn from_coefficients(mut coeffs: Vec<Fr>) -> Self {
+        let n = trimmed(&coeffs).len();
+        coeffs.truncate(n);
+        DensePolynomial { coeffs }
+    }
+
+    /// The polynomial of lowest degree through `(xs[i], ys[i])`, `None` if two points
+    /// coincide.
+    pub fn interpolate(xs: &[Fr], ys: &[Fr]) -> Option<Self> {
+        assert_eq!(xs.len(), ys.len());
+        let n = xs.len();
+        let (mut out, mut m, mut q) = (
+            vec![Fr::zero(); n],
+            vec![Fr::zero(); n + 1],
+            vec![Fr::zero(); n + 1],
+        );
+        if !interpolate_into(xs, ys, &mut out, &mut m, &mut q) {
+            return None;
+        }
+        Some(Self::from_coefficients(out))
+    }
+
+    pub fn coeffs(&self) -> &[Fr] {
+        &self.coeffs
+    }
+
+    pub fn into_coeffs(self) -> Vec<Fr> {
+        self.coeffs
+    }
+
+    /// `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.coeffs.len().checked_sub(1)
+    }
+
+    pub fn evaluate(&self, x: Fr) -> Fr {
+        evaluate(&self.coeffs, x)
+    }
+
+    pub fn add(&self, other: &Self) -> Self {
+        let mut r = self.coeffs.clone();
+        r.resize(core::cmp::max(r.len(), other.coeffs.len()), Fr::zero());
+        for (a, b) in r.iter_mut().zip(other.coeffs.iter()) {
+            *a = *a + *b;
+        }
+        Self::from_coefficients(r)
+    }
+
+    pub fn sub(&self, other: &Self) -> Self {
+        let mut r = self.coeffs.clone();
+        r.resize(core::cmp::max(r.len(), other.coeffs.len()), Fr::zero());
+        for (a, b) in r.iter_mut().zip(other.coeffs.iter()) {
+            *a = *a - *b;
+        }
+        Self::from_coefficients(r)
+    }
+
+    pub fn mul(&self, other: &Self) -> Self {
+        let n = product_len(&self.coeffs, &other.coeffs);
+        let small = core::cmp::min(self.coeffs.len(), other.coeffs.len()) <= SCHOOLBOOK_THRESHOLD;
+        match EvaluationDomain::new(n) {
+            Some(d) if !small => {
+                let mut a = self.coeffs.clone();
+                let mut b = other.coeffs.clone();
+                a.resize(d.size(), Fr::zero());
+                b.resize(d.size(), Fr::zero());
+                mul_ntt(&mut a, &mut b, &d);
+                Self::from_coefficients(a)
+            }
+            _ => {
+                let mut r = vec![Fr::zero(); n];
+                mul_schoolbook(&self.coeffs, &other.coeffs, &mut r);
+                Self::from_coefficients(r)
+            }
+        }
+    }
+
+    /// Quotient and remainder `self(z)` of the division by `X - z`.
+    pub fn divide_by_linear(&self, z: Fr) -> (Self, Fr) {
+        let mut buf = self.coeffs.clone();
+        let r = divide_by_linear(&mut buf, z);
+        if !buf.is_empty() {
+            buf.remove(0);
+        }
+        (Self::from_coefficients(buf), r)
+    }
+
+    /// The quotient by the vanishing polynomial of `domain`, `None` unless it divides.
+    pub fn divide_by_vanishing(&self, domain: &EvaluationDomain) -> Option<Self> {
+        let mut buf = self.coeffs.clone();
+        if !divide_by_vanishing(&mut buf, domain.size()) {
+            return None;
+        }
+        Some(Self::from_coefficients(
+            buf.split_off(core::cmp::min(domain.size(), buf.len())),
+        ))
+    }
+
+    /// Evaluations over `domain`, which must have at least as many points as coefficients.
+    pub fn evaluate_over_domain(&self, domain: EvaluationDomain) -> Evaluations {
+        assert!(self.coeffs.len() <= domain.size());
+        let mut evals = self.coeffs.clone();
+        evals.resize(domain.size(), Fr::zero());
+        domain.fft(&mut evals);
+        Evaluations { evals, domain }
+    }
+}
+
+/// A polynomial by its values over an evaluation domain.
+#[cfg(any(feature = "alloc", test))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Evaluations {
+    evals: Vec<Fr>,
+    domain: EvaluationDomain,
+}
+
+#[cfg(any(feature = "alloc", test))]
+impl Evaluations {
+    pub fn from_vec(evals: Vec<Fr>, domain: EvaluationDomain) -> Self {
+        assert_eq!(evals.len(), domain.size());
+        Evaluations { evals, domain }
+    }
+
+    pub fn evals(&self) -> &[Fr] {
+        &self.evals
+    }
+
+    pub fn domain(&self) -> &EvaluationDomain {
+        &self.domain
+    }
+
+    pub fn interpolate(&self) -> DensePolynomial {
+        let mut coeffs = self.evals.clone();
+        self.domain.ifft(&mut coeffs);
+        DensePolynomial::from_coefficients(coeffs)
+    }
+
+    /// Pointwise product, the evaluations of the product if its degree is below the size
+    /// of the domain.
+    pub fn mul(&self, other: &Self) -> Self {
+        assert_eq!(self.domain, other.domain);
+        let evals = self
+            .evals
+            .iter()
+            .zip(other.evals.iter())
+            .map(|(a, b)| *a * *b)
+            .collect();
+        Evaluations {
+            evals,
+            domain: self.domain,
+        }
+    }
+}
+
+#[test]
+fn polynomial_arithmetic() {
+    let mut s = Fr::from_str("42").unwrap();
+    let mut next = || {
+        s = s * s + Fr::one();
+        s
+    };
+    let a: Vec<Fr> = (0..50).map(|_| next()).collect();
+    let b: Vec<Fr> = (0..40).map(|_| next()).collect();
+    let pa = DensePolynomial::from_coefficients(a.clone());
+    let pb = DensePolynomial::from_coefficients(b.clone());
+
+    // NTT and schoolbook products agree, on the heap and on the stack.
+    let ab = pa.mul(&pb);
+    let mut expect = vec![Fr::zero(); 89];
+    mul_schoolbook(&a, &b, &mut expect);
+    assert_eq!(ab.coeffs(), &expect[..]);
+    let fa = FixedPolynomial::<128>::from_coefficients(&a).unwrap();
+    let fb = FixedPolynomial::<128>::from_coefficients(&b).unwrap();
+    assert_eq!(fa.mul(&fb).unwrap().coeffs(), &expect[..]);
+    assert!(FixedPolynomial::<64>::from_coefficients(&a)
+        .unwrap()
+        .mul(&FixedPolynomial::from_coefficients(&b).unwrap())
+        .is_none());
+    let x = next();
+    assert_eq!(ab.evaluate(x), pa.evaluate(x) * pb.evaluate(x));
+    assert_eq!(pa.add(&pb).sub(&pb), pa);
+    assert_eq!(fa.add(&fb).sub(&fb), fa);
+
+    // Division by X - z and by the vanishing polynomial.
+    let (q, r) = pa.divide_by_linear(x);
+    assert_eq!(r, pa.evaluate(x));
+    let linear = DensePolynomial::from_coefficients(vec![-x, Fr::one()]);
+    assert_eq!(
+        q.mul(&linear)
+            .add(&DensePolynomial::from_coefficients(vec![r])),
+        pa
+    );
+    assert_eq!(fa.divide_by_linear(x).0.coeffs(), q.coeffs());
+    let domain = EvaluationDomain::new(16).unwrap();
+    let mut zh = vec![Fr::zero(); 17];
+    zh[0] = -Fr::one();
+    zh[16] = Fr::one();
+    let multiple = pb.mul(&DensePolynomial::from_coefficients(zh));
+    assert_eq!(multiple.divide_by_vanishing(&domain), Some(pb.clone()));
+    assert_eq!(pa.divide_by_vanishing(&domain), None);
+    let fm = FixedPolynomial::<128>::from_coefficients(multiple.coeffs()).unwrap();
+    assert_eq!(fm.divide_by_vanishing(&domain), Some(fb));
+
+    // Interpolation through points and over a domain.
+    let xs: Vec<Fr> = (0..6).map(|_| next()).collect();
+    let ys: Vec<Fr> = xs.iter().map(|x| pa.evaluate(*x)).collect();
+    let p = DensePolynomial::interpolate(&xs, &ys).unwrap();
+    assert_eq!(p.degree(), Some(5));
+    for (x, y) in xs.iter().zip(ys.iter()) {
+        assert_eq!(p.evaluate(*x), *y);
+    }
+    assert_eq!(
+        FixedPolynomial::<8>::interpolate(&xs, &ys)
+            .unwrap()
+            .coeffs(),
+        p.coeffs()
+    );
+    assert!(DensePolynomial::interpolate(&[xs[0], xs[0]], &ys[..2]).is_none());
+    let domain = EvaluationDomain::new(128).unwrap();
+    let evals = pa
+        .evaluate_over_domain(domain)
+        .mul(&pb.evaluate_over_domain(domain));
+    assert_eq!(evals.interpolate(), ab);
+    assert_eq!(
+        FixedPolynomial::<128>::from_evaluations(&domain, evals.evals())
+            .unwrap()
+            .coeffs(),
+        ab.coeffs()
+    );
+}
@@ -0,0 +1,85 @@
+# Regenerates constants.bin, circomlib's Poseidon parameters for widths 2 to 17 over the
+# BN254 scalar field, with the Grain LFSR of the reference implementation
+# (generate_parameters_grain.sage, x^5 S-box, 8 full rounds).
+#
+# For each width t the file holds the (8 + R_P) * t round constants followed by the t * t
+# MDS matrix, row-major, every element in Montgomery form as 32 little-endian bytes.
+import os
+
+R = 21888242871839275222246405745257275088548364400416034343698204186575808495617
+N_BITS = 254
+R_F = 8
+R_P = [56, 57, 56, 60, 60, 63, 64, 63, 60, 66, 60, 65, 70, 60, 64, 68]
+
+
+def grain(t, r_p):
+    bits = []
+    for value, width in [(1, 2), (0, 4), (N_BITS, 12), (t, 12), (R_F, 10), (r_p, 10)]:
+        bits += [int(b) for b in bin(value)[2:].zfill(width)]
+    bits += [1] * 30
+
+    def step():
+        b = bits[62] ^ bits[51] ^ bits[38] ^ bits[23] ^ bits[13] ^ bits[0]
+        bits.pop(0)
+        bits.append(b)
+        return b
+
+    for _ in range(160):
+        step()
+    while True:
+        # Bits come in pairs, the second one is kept when the first one is set.
+        if step() == 1:
+            yield step()
+        else:
+            step()
+
+
+def field_elements(gen, count, reject):
+    out = []
+    while len(out) < count:
+        x = 0
+        for _ in range(N_BITS):
+            x = (x << 1) | next(gen)
+        if reject and x >= R:
+            continue
+        out.append(x % R)
+    return out
+
+
+def params(t):
+    r_p = R_P[t - 2]
+    gen = grain(t, r_p)
+    c = field_elements(gen, (R_F + r_p) * t, True)
+    xy = field_elements(gen, 2 * t, False)
+    assert len(set(xy)) == 2 * t
+    xs, ys = xy[:t], xy[t:]
+    m = [[pow(xs[i] + ys[j], R - 2, R) for j in range(t)] for i in range(t)]
+    return c, m
+
+
+def permute(state, c, m):
+    t = len(state)
+    r_p = len(c) // t - R_F
+    for r in range(R_F + r_p):
+        state = [(s + c[r * t + i]) % R for i, s in enumerate(state)]
+        if r < R_F // 2 or r >= R_F // 2 + r_p:
+            state = [pow(s, 5, R) for s in state]
+        else:
+            state[0] = pow(state[0], 5, R)
+        state = [sum(m[i][j] * state[j] for j in range(t)) % R for i in range(t)]
+    return state
+
+
+def poseidon(inputs):
+    c, m = params(len(inputs) + 1)
+    return permute([0] + inputs, c, m)[0]
+
+
+if __name__ == "__main__":
+    out = b""
+    for t in range(2, 18):
+        c, m = params(t)
+        for x in c + [e for row in m for e in row]:
+            out += (x * 2**256 % R).to_bytes(32, "little")
+    with open(os.path.join(os.path.dirname(__file__), "constants.bin"), "wb") as f:
+        f.write(out)
@@ -0,0 +1,103 @@
+// Reader for the `.r1cs` constraint systems written by circom.
+//
+// Wires are numbered with the constant one first, then the public outputs, the public
+// inputs and the private inputs, followed by the internal signals.
+use crate::binfile::{BinFile, Error, Reader};
+use crate::Fr;
+use std::vec::Vec;
+
+const SECTION_HEADER: u32 = 1;
+const SECTION_CONSTRAINTS: u32 = 2;
+
+/// `sum(coeff * witness[wire])`
+pub type LinearCombination = Vec<(usize, Fr)>;
+
+#[derive(Clone, Debug)]
+pub struct Constraint {
+    pub a: LinearCombination,
+    pub b: LinearCombination,
+    pub c: LinearCombination,
+}
+
+#[derive(Clone, Debug)]
+pub struct R1cs {
+    pub n_wires: usize,
+    pub n_pub_out: usize,
+    pub n_pub_in: usize,
+    pub n_prv_in: usize,
+    pub n_labels: u64,
+    pub constraints: Vec<Constraint>,
+}
+
+fn read_lc(r: &mut Reader, n_wires: usize) -> Result<LinearCombination, Error> {
+    let n = r.u32()? as usize;
+    let mut lc = Vec::with_capacity(n);
+    for _ in 0..n {
+        let wire = r.u32()? as usize;
+        if wire >= n_wires {
+            return Err(Error::InvalidEncoding);
+        }
+        lc.push((wire, r.fr()?));
+    }
+    Ok(lc)
+}
+
+fn eval(lc: &LinearCombination, witness: &[Fr]) -> Fr {
+    lc.iter().fold(Fr::zero(), |acc, (wire, coeff)| {
+        acc + *coeff * witness[*wire]
+    })
+}
+
+impl R1cs {
+    pub fn from_bytes(data: &[u8]) -> Result<Self, Error> {
+        let file = BinFile::new(data, b"r1cs", 1)?;
+
+        let mut r = file.section(SECTION_HEADER)?;
+        r.prime(Fr::modulus())?;
+        let n_wires = r.u32()? as usize;
+        let n_pub_out = r.u32()? as usize;
+        let n_pub_in = r.u32()? as usize;
+        let n_prv_in = r.u32()? as usize;
+        let n_labels = r.u64()?;
+        let n_constraints = r.u32()? as usize;
+        r.finish()?;
+        if n_wires == 0 || 1 + n_pub_out + n_pub_in + n_prv_in > n_wires {
+            return Err(Error::InvalidEncoding);
+        }
+
+        let mut r = file.section(SECTION_CONSTRAINTS)?;
+        let mut constraints = Vec::with_capacity(n_constraints);
+        for _ in 0..n_constraints {
+            constraints.push(Constraint {
+                a: read_lc(&mut r, n_wires)?,
+                b: read_lc(&mut r, n_wires)?,
+                c: read_lc(&mut r, n_wires)?,
+            });
+        }
+        r.finish()?;
+
+        Ok(R1cs {
+            n_wires,
+            n_pub_out,
+            n_pub_in,
+            n_prv_in,
+            n_labels,
+            constraints,
+        })
+    }
+
+    /// Number of public signals, outputs and inputs, not counting the constant one.
+    pub fn n_public(&self) -> usize {
+        self.n_pub_out + self.n_pub_in
+    }
+
+    /// Whether `witness`, starting with the constant one, satisfies every constraint.
+    pub fn is_satisfied(&self, witness: &[Fr]) -> bool {
+        witness.len() == self.n_wires
+            && witness[0] == Fr::one()
+            && self
+                .constraints
+                .iter()
+                .all(|c| eval(&c.a, witness) * eval(&c.b, witness) == eval(&c.c, witness))
+    }
+}
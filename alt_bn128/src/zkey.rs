@@ -0,0 +1,165 @@
+// Reader for the Groth16 proving keys written by `snarkjs groth16 setup` / `zkey new`.
+//
+// Besides the usual query points the key holds the A and B matrices of the circuit, with
+// one extra A row per public signal, and H points that are the Lagrange basis of the odd
+// powers of the 2n-th root of unity over delta, so the prover evaluates A * B - C on that
+// coset and never divides by the vanishing polynomial.
+use crate::binfile::{BinFile, Error, Reader};
+use crate::{groth16, AffineG2, Fq, Fr, G2Prepared, G1, G2};
+use std::vec::Vec;
+
+const PROTOCOL_GROTH16: u32 = 1;
+
+const SECTION_HEADER: u32 = 1;
+const SECTION_GROTH16_HEADER: u32 = 2;
+const SECTION_IC: u32 = 3;
+const SECTION_COEFFS: u32 = 4;
+const SECTION_A: u32 = 5;
+const SECTION_B_G1: u32 = 6;
+const SECTION_B_G2: u32 = 7;
+const SECTION_C: u32 = 8;
+const SECTION_H: u32 = 9;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Matrix {
+    A,
+    B,
+}
+
+/// An entry `value` of `matrix` at row `constraint`, column `signal`.
+#[derive(Copy, Clone, Debug)]
+pub struct Coefficient {
+    pub matrix: Matrix,
+    pub constraint: usize,
+    pub signal: usize,
+    pub value: Fr,
+}
+
+#[derive(Clone, Debug)]
+pub struct ProvingKey {
+    pub n_vars: usize,
+    pub n_public: usize,
+    pub domain_size: usize,
+    pub alpha_g1: G1,
+    pub beta_g1: G1,
+    pub beta_g2: G2,
+    pub gamma_g2: G2,
+    pub delta_g1: G1,
+    pub delta_g2: G2,
+    pub ic: Vec<G1>,
+    pub coeffs: Vec<Coefficient>,
+    pub a: Vec<G1>,
+    pub b_g1: Vec<G1>,
+    pub b_g2: Vec<G2>,
+    pub c: Vec<G1>,
+    pub h: Vec<G1>,
+}
+
+fn read_g1s(file: &BinFile, id: u32, n: usize) -> Result<Vec<G1>, Error> {
+    let mut r = file.section(id)?;
+    let mut v = Vec::with_capacity(n);
+    for _ in 0..n {
+        v.push(r.g1()?);
+    }
+    r.finish()?;
+    Ok(v)
+}
+
+// Coefficients are stored in Montgomery form of their Montgomery form, a * R^2.
+fn read_coeff_value(r: &mut Reader) -> Result<Fr, Error> {
+    const R_INV: Fr = Fr::from_montgomery([1, 0, 0, 0]);
+    Ok(r.fr_montgomery()? * R_INV)
+}
+
+impl ProvingKey {
+    pub fn from_bytes(data: &[u8]) -> Result<Self, Error> {
+        let file = BinFile::new(data, b"zkey", 1)?;
+
+        let mut r = file.section(SECTION_HEADER)?;
+        if r.u32()? != PROTOCOL_GROTH16 {
+            return Err(Error::UnsupportedProtocol);
+        }
+        r.finish()?;
+
+        let mut r = file.section(SECTION_GROTH16_HEADER)?;
+        r.prime(Fq::modulus())?;
+        r.prime(Fr::modulus())?;
+        let n_vars = r.u32()? as usize;
+        let n_public = r.u32()? as usize;
+        let domain_size = r.u32()? as usize;
+        if n_public >= n_vars || !domain_size.is_power_of_two() {
+            return Err(Error::InvalidEncoding);
+        }
+        let alpha_g1 = r.g1()?;
+        let beta_g1 = r.g1()?;
+        let beta_g2 = r.g2()?;
+        let gamma_g2 = r.g2()?;
+        let delta_g1 = r.g1()?;
+        let delta_g2 = r.g2()?;
+        r.finish()?;
+
+        let mut r = file.section(SECTION_COEFFS)?;
+        let n = r.u32()? as usize;
+        let mut coeffs = Vec::with_capacity(n);
+        for _ in 0..n {
+            let matrix = match r.u32()? {
+                0 => Matrix::A,
+                1 => Matrix::B,
+                _ => return Err(Error::InvalidEncoding),
+            };
+            let constraint = r.u32()? as usize;
+            let signal = r.u32()? as usize;
+            if constraint >= domain_size || signal >= n_vars {
+                return Err(Error::InvalidEncoding);
+            }
+            coeffs.push(Coefficient {
+                matrix,
+                constraint,
+                signal,
+                value: read_coeff_value(&mut r)?,
+            });
+        }
+        r.finish()?;
+
+        let mut r = file.section(SECTION_B_G2)?;
+        let mut b_g2 = Vec::with_capacity(n_vars);
+        for _ in 0..n_vars {
+            b_g2.push(r.g2()?);
+        }
+        r.finish()?;
+
+        Ok(ProvingKey {
+            n_vars,
+            n_public,
+            domain_size,
+            alpha_g1,
+            beta_g1,
+            beta_g2,
+            gamma_g2,
+            delta_g1,
+            delta_g2,
+            ic: read_g1s(&file, SECTION_IC, n_public + 1)?,
+            coeffs,
+            a: read_g1s(&file, SECTION_A, n_vars)?,
+            b_g1: read_g1s(&file, SECTION_B_G1, n_vars)?,
+            b_g2,
+            c: read_g1s(&file, SECTION_C, n_vars - n_public - 1)?,
+            h: read_g1s(&file, SECTION_H, domain_size)?,
+        })
+    }
+
+    pub fn verifying_key(&self) -> Result<groth16::VerifyingKey, groth16::Error> {
+        let prepare = |q: G2| {
+            AffineG2::from_jacobian(q)
+                .map(G2Prepared::new)
+                .ok_or(groth16::Error::InvalidKey)
+        };
+        groth16::VerifyingKey::new(
+            self.alpha_g1,
+            prepare(self.beta_g2)?,
+            prepare(self.gamma_g2)?,
+            prepare(self.delta_g2)?,
+            &self.ic,
+        )
+    }
+}
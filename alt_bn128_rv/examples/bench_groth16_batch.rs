@@ -0,0 +1,59 @@
+#![no_std]
+#![no_main]
+#![feature(lang_items)]
+
+use alt_bn128_rv::gnark;
+use alt_bn128_rv::groth16::{verify_batch, Instance, Proof, Transcript, VerifyingKey};
+use alt_bn128_rv::Fr;
+use core::arch::asm;
+
+fn exit(code: i8) -> ! {
+    unsafe {
+        asm!("mv a0, {0}",
+             "li a7, 93",
+             "ecall",
+             in(reg) code,
+        )
+    }
+    loop {}
+}
+
+#[panic_handler]
+fn panic_handler(_: &core::panic::PanicInfo) -> ! {
+    exit(-128);
+}
+
+#[lang = "eh_personality"]
+extern "C" fn eh_personality() {}
+
+#[no_mangle]
+fn abort() -> ! {
+    panic!("abort!")
+}
+
+#[no_mangle]
+fn _start() -> ! {
+    let vk = gnark::VerifyingKey::from_bytes(include_bytes!(
+        "../../alt_bn128/fixtures/gnark/vk.bin"
+    ))
+    .unwrap();
+    let vk = VerifyingKey::from_gnark(&vk).unwrap();
+    let proof = gnark::Proof::from_bytes(include_bytes!(
+        "../../alt_bn128/fixtures/gnark/proof_raw.bin"
+    ))
+    .unwrap();
+    // The public inputs of the SP1 proof, see fixtures/gnark/fetch.sh.
+    let inputs = [
+        Fr::from_str("406432647295647673015241033990011138953233847343093038612820599671924330193")
+            .unwrap(),
+        Fr::from_str("6265467995298269824615662043982564374977105610814771179903635896347370323221")
+            .unwrap(),
+    ];
+    let instances = [Instance {
+        proof: Proof::from(&proof),
+        inputs: &inputs,
+    }; 8];
+    let mut transcript = Transcript::new(&instances);
+    assert!(verify_batch(&vk, &instances, &mut transcript).unwrap());
+    exit(0)
+}
@@ -0,0 +1,58 @@
+#![no_std]
+#![no_main]
+#![feature(lang_items)]
+
+use alt_bn128_rv::arith::U256;
+use alt_bn128_rv::babyjubjub::{eddsa, AffinePoint};
+use alt_bn128_rv::Fr;
+use core::arch::asm;
+
+fn exit(code: i8) -> ! {
+    unsafe {
+        asm!("mv a0, {0}",
+             "li a7, 93",
+             "ecall",
+             in(reg) code,
+        )
+    }
+    loop {}
+}
+
+#[panic_handler]
+fn panic_handler(_: &core::panic::PanicInfo) -> ! {
+    exit(-128);
+}
+
+#[lang = "eh_personality"]
+extern "C" fn eh_personality() {}
+
+#[no_mangle]
+fn abort() -> ! {
+    panic!("abort!")
+}
+
+#[no_mangle]
+fn _start() -> ! {
+    let public = AffinePoint::new(
+        Fr::from_str(
+            "13277427435165878497778222415993513565335242147425444199013288855685581939618",
+        )
+        .unwrap(),
+        Fr::from_str(
+            "13622229784656158136036771217484571176836296686641868549125388198837476602820",
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    let packed: [u8; 64] = [
+        0xdf, 0xed, 0xb4, 0x31, 0x5d, 0x3f, 0x2e, 0xb4, 0xde, 0x2d, 0x3c, 0x51, 0x0d, 0x7a, 0x98,
+        0x7d, 0xca, 0xb6, 0x70, 0x89, 0xc8, 0xac, 0xe0, 0x63, 0x08, 0x82, 0x7b, 0xf5, 0xbc, 0xbe,
+        0x02, 0xa2, 0x9d, 0x04, 0x3e, 0xce, 0x56, 0x2a, 0x8f, 0x82, 0xbf, 0xc0, 0xad, 0xb6, 0x40,
+        0xc0, 0x10, 0x7a, 0x7d, 0x3a, 0x27, 0xc1, 0xc7, 0xc1, 0xa6, 0x17, 0x9a, 0x0d, 0xa7, 0x3d,
+        0xe5, 0xc1, 0xb2, 0x03,
+    ];
+    let sig = eddsa::Signature::from_compressed(&packed).unwrap();
+    let msg = Fr::new(U256([0x09080706050403020100, 0])).unwrap();
+    assert!(eddsa::verify_poseidon(&public, msg, &sig));
+    exit(0)
+}
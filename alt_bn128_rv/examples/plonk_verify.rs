@@ -0,0 +1,49 @@
+#![no_std]
+#![no_main]
+#![feature(lang_items)]
+
+use alt_bn128_rv::plonk::{public_signals, verify, Proof, VerifyingKey};
+use alt_bn128_rv::Fr;
+use core::arch::asm;
+
+fn exit(code: i8) -> ! {
+    unsafe {
+        asm!("mv a0, {0}",
+             "li a7, 93",
+             "ecall",
+             in(reg) code,
+        )
+    }
+    loop {}
+}
+
+#[panic_handler]
+fn panic_handler(_: &core::panic::PanicInfo) -> ! {
+    exit(-128);
+}
+
+#[lang = "eh_personality"]
+extern "C" fn eh_personality() {}
+
+#[no_mangle]
+fn abort() -> ! {
+    panic!("abort!")
+}
+
+#[no_mangle]
+fn _start() -> ! {
+    let vk = VerifyingKey::from_json(include_str!(
+        "../../alt_bn128/fixtures/plonk/verification_key.json"
+    ))
+    .unwrap();
+    let proof =
+        Proof::from_json(include_str!("../../alt_bn128/fixtures/plonk/proof.json")).unwrap();
+    let mut buf = [Fr::zero(); 2];
+    let public = public_signals(
+        include_str!("../../alt_bn128/fixtures/plonk/public.json"),
+        &mut buf,
+    )
+    .unwrap();
+    assert!(verify(&vk, &proof, public).unwrap());
+    exit(0)
+}
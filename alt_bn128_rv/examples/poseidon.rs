@@ -0,0 +1,42 @@
+#![no_std]
+#![no_main]
+#![feature(lang_items)]
+
+use alt_bn128_rv::poseidon::Poseidon;
+use alt_bn128_rv::Fr;
+use core::arch::asm;
+
+fn exit(code: i8) -> ! {
+    unsafe {
+        asm!("mv a0, {0}",
+             "li a7, 93",
+             "ecall",
+             in(reg) code,
+        )
+    }
+    loop {}
+}
+
+#[panic_handler]
+fn panic_handler(_: &core::panic::PanicInfo) -> ! {
+    exit(-128);
+}
+
+#[lang = "eh_personality"]
+extern "C" fn eh_personality() {}
+
+#[no_mangle]
+fn abort() -> ! {
+    panic!("abort!")
+}
+
+#[no_mangle]
+fn _start() -> ! {
+    let inputs = [Fr::from_str("1").unwrap(), Fr::from_str("2").unwrap()];
+    let expect = Fr::from_str(
+        "7853200120776062878684798364095072458815029376092732009249414926327459813530",
+    )
+    .unwrap();
+    assert!(Poseidon::<3>::hash(&inputs) == expect);
+    exit(0)
+}
@@ -0,0 +1,244 @@
+#![no_std]
+#![no_main]
+#![feature(lang_items)]
+
+use alt_bn128_rv::{
+    gnark, groth16, kzg, pairing_batch_prepared, plonk, AffineG2, Fr, G2Prepared, Group, Gt,
+    PairingBatch, G1, G2,
+};
+use core::arch::asm;
+
+// The stack below the entry frame is filled with a pattern before each pairing and scanned
+// after it, the lowest word overwritten marking the peak. PAINT must stay below the stack
+// CKB-VM gives the script, LIMIT is the bound every entry point is held to.
+const PAINT: usize = 256 * 1024;
+const LIMIT: usize = 64 * 1024;
+const PATTERN: u64 = 0xa5a5_a5a5_a5a5_a5a5;
+
+fn exit(code: i8) -> ! {
+    unsafe {
+        asm!("mv a0, {0}",
+             "li a7, 93",
+             "ecall",
+             in(reg) code,
+        )
+    }
+    loop {}
+}
+
+// Prints a NUL terminated message through the debug syscall of CKB-VM.
+fn debug(msg: &[u8]) {
+    unsafe {
+        asm!("mv a0, {0}",
+             "li a7, 2177",
+             "ecall",
+             in(reg) msg.as_ptr(),
+        )
+    }
+}
+
+#[panic_handler]
+fn panic_handler(_: &core::panic::PanicInfo) -> ! {
+    exit(-128);
+}
+
+#[lang = "eh_personality"]
+extern "C" fn eh_personality() {}
+
+#[no_mangle]
+fn abort() -> ! {
+    panic!("abort!")
+}
+
+fn sp() -> usize {
+    let sp: usize;
+    unsafe { asm!("mv {0}, sp", out(reg) sp) }
+    sp
+}
+
+#[inline(never)]
+fn paint(top: usize) {
+    let mut a = top - PAINT;
+    while a < top {
+        unsafe { core::ptr::write_volatile(a as *mut u64, PATTERN) };
+        a += 8;
+    }
+}
+
+#[inline(never)]
+fn peak(top: usize) -> usize {
+    let mut a = top - PAINT;
+    while a < top && unsafe { core::ptr::read_volatile(a as *const u64) } == PATTERN {
+        a += 8;
+    }
+    top - a
+}
+
+// "<name>: <used> bytes".
+fn report(name: &[u8], used: usize) {
+    let mut msg = [b' '; 64];
+    msg[..name.len()].copy_from_slice(name);
+    msg[name.len()] = b':';
+    let mut n = used;
+    let mut i = 36;
+    loop {
+        msg[i] = b'0' + (n % 10) as u8;
+        n /= 10;
+        if n == 0 {
+            break;
+        }
+        i -= 1;
+    }
+    msg[37..44].copy_from_slice(b" bytes\0");
+    debug(&msg);
+}
+
+// The keys and proofs, built before any measurement so that only the verification is
+// counted and not the 19 KB tables of the prepared points.
+struct Inputs {
+    prepared: G2Prepared,
+    groth16_vk: groth16::VerifyingKey,
+    groth16_proof: groth16::Proof,
+    plonk_vk: plonk::VerifyingKey,
+    plonk_proof: plonk::Proof,
+    kzg_vk: kzg::VerifierKey,
+    kzg_opening: kzg::Opening,
+}
+
+// The name a case is reported under and the entry point it runs.
+type Case = (&'static [u8], fn(&Inputs) -> bool);
+
+fn fr(s: &str) -> Fr {
+    Fr::from_str(s).unwrap()
+}
+
+// e(P, Q)^16 e(-16 P, Q) over 17 pairs, a full buffer and a second flush.
+#[inline(never)]
+fn on_the_fly(_: &Inputs) -> bool {
+    let (p, q) = (G1::one(), G2::one());
+    let mut batch = PairingBatch::new();
+    for _ in 0..16 {
+        batch.push(p, q);
+    }
+    batch.push(-(p * fr("16")), q);
+    batch.finalize() == Gt::one()
+}
+
+#[inline(never)]
+fn prepared(inputs: &Inputs) -> bool {
+    let p = G1::one();
+    let mut pairs = [(p, &inputs.prepared); 17];
+    pairs[16].0 = -(p * fr("16"));
+    pairs_prepared(&pairs)
+}
+
+#[inline(never)]
+fn pairs_prepared(pairs: &[(G1, &G2Prepared)]) -> bool {
+    pairing_batch_prepared(pairs) == Gt::one()
+}
+
+// Prepared and unprepared pairs in one batch over two flushes.
+#[inline(never)]
+fn mixed(inputs: &Inputs) -> bool {
+    let (p, q) = (G1::one(), G2::one());
+    let mut batch = PairingBatch::new();
+    for _ in 0..8 {
+        batch.push_prepared(p, &inputs.prepared);
+        batch.push(p, q);
+    }
+    batch.push_prepared(-(p * fr("16")), &inputs.prepared);
+    batch.finalize() == Gt::one()
+}
+
+#[inline(never)]
+fn groth16(inputs: &Inputs) -> bool {
+    // The public inputs of the SP1 proof, see fixtures/gnark/fetch.sh.
+    let public = [
+        fr("406432647295647673015241033990011138953233847343093038612820599671924330193"),
+        fr("6265467995298269824615662043982564374977105610814771179903635896347370323221"),
+    ];
+    groth16::verify(&inputs.groth16_vk, &inputs.groth16_proof, &public).unwrap()
+}
+
+#[inline(never)]
+fn plonk(inputs: &Inputs) -> bool {
+    let mut buf = [Fr::zero(); 2];
+    let public = plonk::public_signals(
+        include_str!("../../alt_bn128/fixtures/plonk/public.json"),
+        &mut buf,
+    )
+    .unwrap();
+    plonk::verify(&inputs.plonk_vk, &inputs.plonk_proof, public).unwrap()
+}
+
+#[inline(never)]
+fn kzg(inputs: &Inputs) -> bool {
+    kzg::verify(&inputs.kzg_vk, &inputs.kzg_opening)
+}
+
+// The SRS of tau = 5 and the opening of 1 + 2x + 3x^2 at 7.
+fn kzg_setup() -> (kzg::VerifierKey, kzg::Opening) {
+    let tau = fr("5");
+    let powers = [G1::one(), G1::one() * tau, G1::one() * tau * tau];
+    let mut poly = [fr("1"), fr("2"), fr("3")];
+    let commitment = kzg::commit(&powers, &poly).unwrap();
+    let (value, proof) = kzg::open(&powers, &mut poly, fr("7")).unwrap();
+    let tau_g2 = AffineG2::from_jacobian(G2::one() * tau).unwrap();
+    let opening = kzg::Opening {
+        commitment,
+        point: fr("7"),
+        value,
+        proof,
+    };
+    (kzg::VerifierKey::new(G2Prepared::new(tau_g2)), opening)
+}
+
+#[no_mangle]
+fn _start() -> ! {
+    let vk = gnark::VerifyingKey::from_bytes(include_bytes!(
+        "../../alt_bn128/fixtures/gnark/vk.bin"
+    ))
+    .unwrap();
+    let proof = gnark::Proof::from_bytes(include_bytes!(
+        "../../alt_bn128/fixtures/gnark/proof_raw.bin"
+    ))
+    .unwrap();
+    let (kzg_vk, kzg_opening) = kzg_setup();
+    let inputs = Inputs {
+        prepared: G2Prepared::new(AffineG2::from_jacobian(G2::one()).unwrap()),
+        groth16_vk: groth16::VerifyingKey::from_gnark(&vk).unwrap(),
+        groth16_proof: groth16::Proof::from(&proof),
+        plonk_vk: plonk::VerifyingKey::from_json(include_str!(
+            "../../alt_bn128/fixtures/plonk/verification_key.json"
+        ))
+        .unwrap(),
+        plonk_proof: plonk::Proof::from_json(include_str!(
+            "../../alt_bn128/fixtures/plonk/proof.json"
+        ))
+        .unwrap(),
+        kzg_vk,
+        kzg_opening,
+    };
+
+    let cases: [Case; 6] = [
+        (b"PairingBatch::push", on_the_fly),
+        (b"pairing_batch_prepared", prepared),
+        (b"PairingBatch::push_prepared", mixed),
+        (b"groth16::verify", groth16),
+        (b"plonk::verify", plonk),
+        (b"kzg::verify", kzg),
+    ];
+    // Leaves room for the frames of this function and of paint itself.
+    let top = sp() - 1024;
+    let mut code = 0;
+    for (name, case) in cases.iter() {
+        paint(top);
+        assert!(case(&inputs));
+        let used = peak(top);
+        report(name, used);
+        if used >= LIMIT {
+            code = 1;
+        }
+    }
+    exit(code)
+}
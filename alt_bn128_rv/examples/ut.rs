@@ -33,5 +33,15 @@ fn _start() -> ! {
     alt_bn128_rv::ethereum::ut::test_alt_bn128_add();
     alt_bn128_rv::ethereum::ut::test_alt_bn128_mul();
     alt_bn128_rv::ethereum::ut::test_alt_bn128_pairing();
+    alt_bn128_rv::ethereum::ut::test_ecrecover();
+    alt_bn128_rv::ethereum::ut::test_modexp();
+    alt_bn128_rv::ethereum::ut::test_blake2f();
+    alt_bn128_rv::ethereum::ut::test_bls12_g1add();
+    alt_bn128_rv::ethereum::ut::test_bls12_g1msm();
+    alt_bn128_rv::ethereum::ut::test_bls12_g2add();
+    alt_bn128_rv::ethereum::ut::test_bls12_g2msm();
+    alt_bn128_rv::ethereum::ut::test_bls12_pairing_check();
+    alt_bn128_rv::ethereum::ut::test_bls12_map_fp_to_g1();
+    alt_bn128_rv::ethereum::ut::test_bls12_map_fp2_to_g2();
     exit(0)
 }
@@ -26,7 +26,7 @@ impl From<u64> for U256 {
 
 /// 512-bit, stack allocated biginteger for use in extension
 /// field serialization and scalar interpretation.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
 #[repr(C)]
 pub struct U512(pub [u128; 4]);
 
@@ -49,7 +49,8 @@ impl U512 {
         debug_assert_eq!(c1.0.len(), 2);
         unroll! {
             for i in 0..2 {
-                mac_digit(i, &mut res, &modulo.0, c1.0[i]);
+                let carry = mac_digit(i, &mut res, &modulo.0, c1.0[i]);
+                debug_assert!(carry == 0);
             }
         }
 
@@ -112,11 +113,12 @@ impl U512 {
         let mut r = U256::zero();
 
         for i in (0..512).rev() {
-            // NB: modulo's first two bits are always unset
-            // so this will never destroy information
+            // r < modulo, so 2r + 1 < 2 * modulo and one subtraction
+            // brings it back, even when doubling drops the top bit.
+            let top = r.get_bit(255).unwrap();
             mul2(&mut r.0);
             assert!(r.set_bit(0, self.get_bit(i).unwrap()));
-            if &r >= modulo {
+            if top || &r >= modulo {
                 sub_noborrow(&mut r.0, &modulo.0);
                 if q.is_some() && !q.as_mut().unwrap().set_bit(i, true) {
                     q = None
@@ -131,6 +133,50 @@ impl U512 {
         }
     }
 
+    /// Add `other` to `self` modulo `modulo` * 2^256, a multiple of the modulus that
+    /// bounds the unreduced products of lazy reduction.
+    pub fn add(&mut self, other: &U512, modulo: &U256) {
+        let carry = add512(&mut self.0, &other.0);
+        let mut high = [self.0[2], self.0[3]];
+
+        if carry || U256(high) >= *modulo {
+            sub_noborrow(&mut high, &modulo.0);
+            self.0[2] = high[0];
+            self.0[3] = high[1];
+        }
+    }
+
+    /// Subtract `other` from `self` modulo `modulo` * 2^256.
+    pub fn sub(&mut self, other: &U512, modulo: &U256) {
+        if *self < *other {
+            let mut high = [self.0[2], self.0[3]];
+            add_nocarry(&mut high, &modulo.0);
+            self.0[2] = high[0];
+            self.0[3] = high[1];
+        }
+
+        sub512(&mut self.0, &other.0);
+    }
+
+    /// `self` / 2^256 (mod `modulo`) by Montgomery reduction, for `self` below
+    /// `modulo` * 2^256.
+    pub fn mont_reduce(&self, modulo: &U256, inv: u128) -> U256 {
+        let mut res = self.0;
+        let mut carry = 0;
+        unroll! {
+            for i in 0..2 {
+                let k = inv.wrapping_mul(res[i]);
+                carry += mac_digit(i, &mut res, &modulo.0, k);
+            }
+        }
+
+        let mut r = U256([res[2], res[3]]);
+        if carry != 0 || r >= *modulo {
+            sub_noborrow(&mut r.0, &modulo.0);
+        }
+        r
+    }
+
     pub fn interpret(buf: &[u8; 64]) -> U512 {
         let mut n = [0; 4];
         n[3] = BigEndian::read_u128(&buf[0..]);
@@ -144,6 +190,18 @@ impl U512 {
 impl Ord for U512 {
     #[inline]
     fn cmp(&self, other: &U512) -> Ordering {
+        if self.0[3] < other.0[3] {
+            return Ordering::Less;
+        }
+        if self.0[3] > other.0[3] {
+            return Ordering::Greater;
+        }
+        if self.0[2] < other.0[2] {
+            return Ordering::Less;
+        }
+        if self.0[2] > other.0[2] {
+            return Ordering::Greater;
+        }
         if self.0[1] < other.0[1] {
             return Ordering::Less;
         }
@@ -202,6 +260,35 @@ impl U256 {
         Ok(U256(n))
     }
 
+    /// Parses a decimal string, `None` on an invalid digit or overflow.
+    pub fn from_dec_str(s: &str) -> Option<U256> {
+        U256::from_str_radix(s, 10)
+    }
+
+    /// Parses a hex string without `0x` prefix, `None` on an invalid digit or overflow.
+    pub fn from_hex_str(s: &str) -> Option<U256> {
+        U256::from_str_radix(s, 16)
+    }
+
+    fn from_str_radix(s: &str, radix: u32) -> Option<U256> {
+        if s.is_empty() {
+            return None;
+        }
+        let mut n = [0u64; 4];
+        for c in s.chars() {
+            let mut carry = c.to_digit(radix)? as u128;
+            for limb in n.iter_mut() {
+                let t = (*limb as u128) * radix as u128 + carry;
+                *limb = t as u64;
+                carry = t >> 64;
+            }
+            if carry != 0 {
+                return None;
+            }
+        }
+        Some(U256::from(n))
+    }
+
     pub fn to_big_endian(&self, s: &mut [u8]) -> Result<(), Error> {
         if s.len() != 32 {
             return Err(Error::InvalidLength {
@@ -258,9 +345,9 @@ impl U256 {
 
     /// Add `other` to `self` (mod `modulo`)
     pub fn add(&mut self, other: &U256, modulo: &U256) {
-        add_nocarry(&mut self.0, &other.0);
+        let carry = add_nocarry(&mut self.0, &other.0);
 
-        if *self >= *modulo {
+        if carry || *self >= *modulo {
             sub_noborrow(&mut self.0, &modulo.0);
         }
     }
@@ -277,13 +364,24 @@ impl U256 {
     /// Multiply `self` by `other` (mod `modulo`) via the Montgomery
     /// multiplication method.
     pub fn mul(&mut self, other: &U256, modulo: &U256, inv: u128) {
-        mul_reduce(&mut self.0, &other.0, &modulo.0, inv);
+        let carry = mul_reduce(&mut self.0, &other.0, &modulo.0, inv);
 
-        if *self >= *modulo {
+        if carry || *self >= *modulo {
             sub_noborrow(&mut self.0, &modulo.0);
         }
     }
 
+    /// The 512-bit product of `self` and `other`, left unreduced.
+    pub fn mul_wide(&self, other: &U256) -> U512 {
+        let mut res = [0; 4];
+        unroll! {
+            for i in 0..2 {
+                mac_digit(i, &mut res, &other.0, self.0[i]);
+            }
+        }
+        U512(res)
+    }
+
     /// Turn `self` into its additive inverse (mod `modulo`)
     pub fn neg(&mut self, modulo: &U256) {
         if *self > Self::zero() {
@@ -317,8 +415,9 @@ impl U256 {
                 if b.is_even() {
                     div2(&mut b.0);
                 } else {
-                    add_nocarry(&mut b.0, &modulo.0);
+                    let carry = add_nocarry(&mut b.0, &modulo.0);
                     div2(&mut b.0);
+                    b.set_bit(255, carry);
                 }
             }
             while v.is_even() {
@@ -327,8 +426,9 @@ impl U256 {
                 if c.is_even() {
                     div2(&mut c.0);
                 } else {
-                    add_nocarry(&mut c.0, &modulo.0);
+                    let carry = add_nocarry(&mut c.0, &modulo.0);
                     div2(&mut c.0);
+                    c.set_bit(255, carry);
                 }
             }
 
@@ -350,8 +450,8 @@ impl U256 {
 
     /// Return an Iterator<Item=bool> over all bits from
     /// MSB to LSB.
-    pub fn bits(&self) -> BitIterator {
-        BitIterator { int: &self, n: 256 }
+    pub fn bits(&self) -> BitIterator<'_> {
+        BitIterator { int: self, n: 256 }
     }
 }
 
@@ -410,23 +510,53 @@ fn adc(a: u128, b: u128, carry: &mut u128) -> u128 {
     e
 }
 
+// Adds modulo 2^256 and returns the carry out, which only moduli above 2^255 (secp256k1)
+// ever produce.
 #[inline]
-fn add_nocarry(a: &mut [u128; 2], b: &[u128; 2]) {
+fn add_nocarry(a: &mut [u128; 2], b: &[u128; 2]) -> bool {
     let (c, d) = a[0].overflowing_add(b[0]);
+    let (e, f) = a[1].overflowing_add(b[1]);
+    let (g, h) = e.overflowing_add(d as u128);
     a[0] = c;
-    a[1] = a[1] + b[1] + d as u128;
+    a[1] = g;
+    f | h
 }
 
+// Subtracts modulo 2^256; callers either know `a >= b` or undo a carry they dropped.
 #[inline]
 fn sub_noborrow(a: &mut [u128; 2], b: &[u128; 2]) {
     let (c, d) = a[0].overflowing_sub(b[0]);
     a[0] = c;
-    a[1] = a[1] - b[1] - d as u128;
+    a[1] = a[1].wrapping_sub(b[1]).wrapping_sub(d as u128);
+}
+
+#[inline]
+fn add512(a: &mut [u128; 4], b: &[u128; 4]) -> bool {
+    let mut carry = 0;
+    unroll! {
+        for i in 0..4 {
+            a[i] = adc(a[i], b[i], &mut carry);
+        }
+    }
+    carry != 0
+}
+
+// Subtracts modulo 2^512, like `sub_noborrow`.
+#[inline]
+fn sub512(a: &mut [u128; 4], b: &[u128; 4]) {
+    let mut borrow = false;
+    for (a, b) in a.iter_mut().zip(b.iter()) {
+        let (c, d) = a.overflowing_sub(*b);
+        let (e, f) = c.overflowing_sub(borrow as u128);
+        *a = e;
+        borrow = d | f;
+    }
 }
 
 // TODO: Make `from_index` a const param
+// Returns the carry out of `acc`.
 #[inline(always)]
-fn mac_digit(from_index: usize, acc: &mut [u128; 4], b: &[u128; 2], c: u128) {
+fn mac_digit(from_index: usize, acc: &mut [u128; 4], b: &[u128; 2], c: u128) -> u128 {
     #[inline]
     fn mac_with_carry(a: u128, b: u128, c: u128, carry: &mut u128) -> u128 {
         let (b_hi, b_lo) = split_u128(b);
@@ -446,7 +576,7 @@ fn mac_digit(from_index: usize, acc: &mut [u128; 4], b: &[u128; 2], c: u128) {
     }
 
     if c == 0 {
-        return;
+        return 0;
     }
 
     let mut carry = 0;
@@ -469,11 +599,12 @@ fn mac_digit(from_index: usize, acc: &mut [u128; 4], b: &[u128; 2], c: u128) {
         }
     }
 
-    debug_assert!(carry == 0);
+    carry
 }
 
+// Returns whether the result overflowed 256 bits; it is below 2 * modulus either way.
 #[inline]
-fn mul_reduce(this: &mut [u128; 2], by: &[u128; 2], modulus: &[u128; 2], inv: u128) {
+fn mul_reduce(this: &mut [u128; 2], by: &[u128; 2], modulus: &[u128; 2], inv: u128) -> bool {
     // The Montgomery reduction here is based on Algorithm 14.32 in
     // Handbook of Applied Cryptography
     // <http://cacr.uwaterloo.ca/hac/about/chap14.pdf>.
@@ -485,12 +616,14 @@ fn mul_reduce(this: &mut [u128; 2], by: &[u128; 2], modulus: &[u128; 2], inv: u1
         }
     }
 
+    let mut carry = 0;
     unroll! {
         for i in 0..2 {
             let k = inv.wrapping_mul(res[i]);
-            mac_digit(i, &mut res, modulus, k);
+            carry += mac_digit(i, &mut res, modulus, k);
         }
     }
 
     this.copy_from_slice(&res[2..]);
+    carry != 0
 }
@@ -0,0 +1,199 @@
+// EdDSA over Baby Jubjub as in circomlib's `eddsa`: a signature (R8, S) of a field element
+// m under the public key A is valid when
+//
+//   S * B8 = R8 + 8 * H(R8, A, m) * A
+//
+// with H Poseidon of width 6 or MiMC7 `multiHash` of (R8.x, R8.y, A.x, A.y, m). Keys and
+// nonces are derived with BLAKE-512 like circomlibjs, so signatures are byte for byte
+// the same.
+use super::{blake512::blake512, AffinePoint, Error, Point, SUBGROUP_ORDER};
+use crate::{
+    arith::{U256, U512},
+    mimc,
+    poseidon::Poseidon,
+    Fr,
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Signature {
+    pub r8: AffinePoint,
+    pub s: U256,
+}
+
+impl Signature {
+    /// `packSignature` of circomlibjs: compressed R8 and S in 32 little-endian bytes.
+    pub fn to_compressed(&self) -> [u8; 64] {
+        let mut out = [0u8; 64];
+        out[..32].copy_from_slice(&self.r8.to_compressed());
+        self.s.to_big_endian(&mut out[32..]).unwrap();
+        out[32..].reverse();
+        out
+    }
+
+    pub fn from_compressed(bytes: &[u8; 64]) -> Result<Self, Error> {
+        let mut r8 = [0u8; 32];
+        r8.copy_from_slice(&bytes[..32]);
+        let mut s = [0u8; 32];
+        s.copy_from_slice(&bytes[32..]);
+        s.reverse();
+        let s = U256::from_slice(&s).unwrap();
+        if s >= SUBGROUP_ORDER {
+            return Err(Error::InvalidEncoding);
+        }
+        Ok(Signature {
+            r8: AffinePoint::from_compressed(&r8)?,
+            s,
+        })
+    }
+}
+
+// Interprets little-endian bytes as an integer reduced modulo l.
+fn reduce(bytes: &[u8; 64]) -> U256 {
+    let mut buf = *bytes;
+    buf.reverse();
+    U512::interpret(&buf).divrem(&SUBGROUP_ORDER).1
+}
+
+fn hash_poseidon(inputs: &[Fr; 5]) -> Fr {
+    Poseidon::<6>::hash(inputs)
+}
+
+fn hash_mimc(inputs: &[Fr; 5]) -> Fr {
+    mimc::multi_hash(inputs, Fr::zero())
+}
+
+/// A private key, the 32-byte seed of circomlibjs.
+#[derive(Clone)]
+pub struct PrivateKey([u8; 32]);
+
+impl PrivateKey {
+    pub fn new(seed: [u8; 32]) -> Self {
+        PrivateKey(seed)
+    }
+
+    // The pruned scalar s, with A = (s >> 3) * B8, and the nonce key.
+    fn expand(&self) -> (U256, [u8; 64]) {
+        let h = blake512(&self.0);
+        let mut s = [0u8; 32];
+        s.copy_from_slice(&h[..32]);
+        s[0] &= 0xf8;
+        s[31] &= 0x7f;
+        s[31] |= 0x40;
+        s.reverse();
+        (U256::from_slice(&s).unwrap(), h)
+    }
+
+    /// `prv2pub` of circomlibjs.
+    pub fn public(&self) -> AffinePoint {
+        let (s, _) = self.expand();
+        let s = U256([s.0[0] >> 3 | s.0[1] << 125, s.0[1] >> 3]);
+        Point::base8().mul(&s).to_affine()
+    }
+
+    fn sign(&self, msg: Fr, hash: fn(&[Fr; 5]) -> Fr) -> Signature {
+        let (s, h) = self.expand();
+        let a = self.public();
+        let mut buf = [0u8; 64];
+        buf[..32].copy_from_slice(&h[32..]);
+        msg.into_u256().to_big_endian(&mut buf[32..]).unwrap();
+        buf[32..].reverse();
+        let r = reduce(&blake512(&buf));
+        let r8 = Point::base8().mul(&r).to_affine();
+        let hm = hash(&[r8.x, r8.y, a.x, a.y, msg]).into_u256();
+        // r + hm * s < 2^512, reduced modulo l.
+        let s = U512::new(&s, &r, &hm).divrem(&SUBGROUP_ORDER).1;
+        Signature { r8, s }
+    }
+
+    /// `signPoseidon` of circomlibjs.
+    pub fn sign_poseidon(&self, msg: Fr) -> Signature {
+        self.sign(msg, hash_poseidon)
+    }
+
+    /// `signMiMC` of circomlibjs.
+    pub fn sign_mimc(&self, msg: Fr) -> Signature {
+        self.sign(msg, hash_mimc)
+    }
+}
+
+fn verify(public: &AffinePoint, msg: Fr, sig: &Signature, hash: fn(&[Fr; 5]) -> Fr) -> bool {
+    if sig.s >= SUBGROUP_ORDER {
+        return false;
+    }
+    let hm = hash(&[sig.r8.x, sig.r8.y, public.x, public.y, msg]).into_u256();
+    let left = Point::base8().mul(&sig.s);
+    let right = Point::from(sig.r8) + Point::from(*public).mul_by_cofactor().mul(&hm);
+    left == right
+}
+
+/// `verifyPoseidon` of circomlibjs, the `EdDSAPoseidonVerifier` circuit.
+pub fn verify_poseidon(public: &AffinePoint, msg: Fr, sig: &Signature) -> bool {
+    verify(public, msg, sig, hash_poseidon)
+}
+
+/// `verifyMiMC` of circomlibjs, the `EdDSAMiMCVerifier` circuit.
+pub fn verify_mimc(public: &AffinePoint, msg: Fr, sig: &Signature) -> bool {
+    verify(public, msg, sig, hash_mimc)
+}
+
+#[test]
+fn circomlibjs_vectors() {
+    let n = |s: &str| Fr::from_str(s).unwrap();
+    let mut seed = [0u8; 32];
+    for (i, b) in seed.iter_mut().enumerate() {
+        *b = (i % 10) as u8;
+    }
+    let key = PrivateKey::new(seed);
+    let public = key.public();
+    assert_eq!(
+        public,
+        AffinePoint::new(
+            n("13277427435165878497778222415993513565335242147425444199013288855685581939618"),
+            n("13622229784656158136036771217484571176836296686641868549125388198837476602820"),
+        )
+        .unwrap()
+    );
+
+    // The bytes 00 01 .. 09 00 00 read little-endian.
+    let msg = Fr::new(U256([0x09080706050403020100, 0])).unwrap();
+    let sig = key.sign_poseidon(msg);
+    let r8 = AffinePoint::new(
+        n("11384336176656855268977457483345535180380036354188103142384839473266348197733"),
+        n("15383486972088797283337779941324724402501462225528836549661220478783371668959"),
+    )
+    .unwrap();
+    assert_eq!(sig.r8, r8);
+    assert_eq!(
+        sig.s,
+        n("1672775540645840396591609181675628451599263765380031905495115170613215233181")
+            .into_u256()
+    );
+    let packed = sig.to_compressed();
+    let expect = [
+        0xdfedb4315d3f2eb4de2d3c510d7a987d_u128,
+        0xcab67089c8ace06308827bf5bcbe02a2,
+        0x9d043ece562a8f82bfc0adb640c0107a,
+        0x7d3a27c1c7c1a6179a0da73de5c1b203,
+    ];
+    for (chunk, e) in packed.chunks(16).zip(expect.iter()) {
+        assert_eq!(chunk, &e.to_be_bytes()[..]);
+    }
+    assert_eq!(Signature::from_compressed(&packed), Ok(sig));
+    assert!(verify_poseidon(&public, msg, &sig));
+    assert!(!verify_poseidon(&public, msg + Fr::one(), &sig));
+    assert!(!verify_mimc(&public, msg, &sig));
+
+    let sig = key.sign_mimc(msg);
+    assert_eq!(sig.r8, r8);
+    assert_eq!(
+        sig.s,
+        n("2523202440825208709475937830811065542425109372212752003460238913256192595070")
+            .into_u256()
+    );
+    assert!(verify_mimc(&public, msg, &sig));
+    let bad = Signature {
+        s: U256::from_slice(&[0xff; 32]).unwrap(),
+        ..sig
+    };
+    assert!(!verify_mimc(&public, msg, &bad));
+}
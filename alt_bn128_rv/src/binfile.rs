@@ -0,0 +1,177 @@
+// The sectioned binary container shared by iden3's tools (circom `.r1cs`, snarkjs `.zkey`,
+// `.ptau` and `.wtns`): a 4-byte magic, a u32 version, a u32 section count, then sections
+// of a u32 type and a u64 byte length followed by the data. Integers are little-endian.
+//
+// Field elements are 32 bytes little-endian. Curve points are stored with their
+// coordinates in Montgomery form, and a point whose bytes are all zero is the point at
+// infinity.
+use crate::{arith::U256, AffineG1, AffineG2, FieldError, Fq, Fq2, Fr, Group, GroupError, G1, G2};
+
+const MAX_SECTIONS: usize = 16;
+
+#[derive(Debug)]
+pub enum Error {
+    InvalidMagic,
+    UnsupportedVersion,
+    MissingSection(u32),
+    UnexpectedEof,
+    TrailingBytes,
+    UnsupportedField,
+    UnsupportedProtocol,
+    InvalidEncoding,
+    Field(FieldError),
+    Group(GroupError),
+}
+
+impl From<FieldError> for Error {
+    fn from(fe: FieldError) -> Self {
+        Error::Field(fe)
+    }
+}
+
+impl From<GroupError> for Error {
+    fn from(ge: GroupError) -> Self {
+        Error::Group(ge)
+    }
+}
+
+pub(crate) struct BinFile<'a> {
+    data: &'a [u8],
+    sections: [(u32, usize, usize); MAX_SECTIONS],
+    len: usize,
+}
+
+impl<'a> BinFile<'a> {
+    pub fn new(data: &'a [u8], magic: &[u8; 4], max_version: u32) -> Result<Self, Error> {
+        let mut r = Reader::new(data);
+        if r.take(4)? != magic {
+            return Err(Error::InvalidMagic);
+        }
+        let version = r.u32()?;
+        if version == 0 || version > max_version {
+            return Err(Error::UnsupportedVersion);
+        }
+        let n = r.u32()? as usize;
+        let mut file = BinFile {
+            data,
+            sections: [(0, 0, 0); MAX_SECTIONS],
+            len: 0,
+        };
+        for _ in 0..n {
+            let id = r.u32()?;
+            let size = r.u64()? as usize;
+            let start = r.pos;
+            r.take(size)?;
+            // Unknown extra sections beyond the table are never looked up.
+            if file.len < MAX_SECTIONS {
+                file.sections[file.len] = (id, start, start + size);
+                file.len += 1;
+            }
+        }
+        r.finish()?;
+        Ok(file)
+    }
+
+    pub fn section(&self, id: u32) -> Result<Reader<'a>, Error> {
+        self.sections[..self.len]
+            .iter()
+            .find(|s| s.0 == id)
+            .map(|s| Reader::new(&self.data[s.1..s.2]))
+            .ok_or(Error::MissingSection(id))
+    }
+}
+
+pub(crate) struct Reader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(buf: &'a [u8]) -> Self {
+        Reader { buf, pos: 0 }
+    }
+
+    pub fn take(&mut self, n: usize) -> Result<&'a [u8], Error> {
+        if self.buf.len() - self.pos < n {
+            return Err(Error::UnexpectedEof);
+        }
+        let r = &self.buf[self.pos..self.pos + n];
+        self.pos += n;
+        Ok(r)
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.buf.len() - self.pos
+    }
+
+    pub fn finish(&self) -> Result<(), Error> {
+        if self.pos != self.buf.len() {
+            return Err(Error::TrailingBytes);
+        }
+        Ok(())
+    }
+
+    pub fn u32(&mut self) -> Result<u32, Error> {
+        let mut b = [0u8; 4];
+        b.copy_from_slice(self.take(4)?);
+        Ok(u32::from_le_bytes(b))
+    }
+
+    pub fn u64(&mut self) -> Result<u64, Error> {
+        let mut b = [0u8; 8];
+        b.copy_from_slice(self.take(8)?);
+        Ok(u64::from_le_bytes(b))
+    }
+
+    pub fn limbs(&mut self) -> Result<[u64; 4], Error> {
+        let mut limbs = [0u64; 4];
+        for l in limbs.iter_mut() {
+            *l = self.u64()?;
+        }
+        Ok(limbs)
+    }
+
+    /// Checks a `n8, prime` pair against the expected modulus.
+    pub fn prime(&mut self, modulus: U256) -> Result<(), Error> {
+        if self.u32()? != 32 || U256::from(self.limbs()?) != modulus {
+            return Err(Error::UnsupportedField);
+        }
+        Ok(())
+    }
+
+    /// An element of Fr in standard form.
+    pub fn fr(&mut self) -> Result<Fr, Error> {
+        Ok(Fr::new(self.limbs()?.into()).ok_or(FieldError::NotMember)?)
+    }
+
+    /// An element of Fr in Montgomery form.
+    pub fn fr_montgomery(&mut self) -> Result<Fr, Error> {
+        let limbs = self.limbs()?;
+        Fr::new(limbs.into()).ok_or(FieldError::NotMember)?;
+        Ok(Fr::from_montgomery(limbs))
+    }
+
+    pub fn fq_montgomery(&mut self) -> Result<Fq, Error> {
+        let limbs = self.limbs()?;
+        Fq::from_u256(limbs.into())?;
+        Ok(Fq::from_montgomery(limbs))
+    }
+
+    pub fn g1(&mut self) -> Result<G1, Error> {
+        let x = self.fq_montgomery()?;
+        let y = self.fq_montgomery()?;
+        if x.is_zero() && y.is_zero() {
+            return Ok(G1::zero());
+        }
+        Ok(AffineG1::new(x, y)?.into())
+    }
+
+    pub fn g2(&mut self) -> Result<G2, Error> {
+        let x = Fq2::new(self.fq_montgomery()?, self.fq_montgomery()?);
+        let y = Fq2::new(self.fq_montgomery()?, self.fq_montgomery()?);
+        if x.is_zero() && y.is_zero() {
+            return Ok(G2::zero());
+        }
+        Ok(AffineG2::new(x, y)?.into())
+    }
+}
@@ -0,0 +1,246 @@
+use super::fp::{const_fp, Fp};
+use super::fp2::Fp2;
+use crate::fields::FieldElement;
+use core::ops::{Add, Mul, Neg, Sub};
+
+fn frobenius_coeffs_c1(power: usize) -> Fp2 {
+    match power % 6 {
+        0 => Fp2::one(),
+        1 => Fp2::new(
+            Fp::zero(),
+            const_fp([
+                0xcd03c9e48671f071,
+                0x5dab22461fcda5d2,
+                0x587042afd3851b95,
+                0x8eb60ebe01bacb9e,
+                0x03f97d6e83d050d2,
+                0x18f0206554638741,
+            ]),
+        ),
+        2 => Fp2::new(
+            const_fp([
+                0x30f1361b798a64e8,
+                0xf3b8ddab7ece5a2a,
+                0x16a8ca3ac61577f7,
+                0xc26a2ff874fd029b,
+                0x3636b76660701c6e,
+                0x051ba4ab241b6160,
+            ]),
+            Fp::zero(),
+        ),
+        3 => Fp2::new(
+            Fp::zero(),
+            const_fp([
+                0x760900000002fffd,
+                0xebf4000bc40c0002,
+                0x5f48985753c758ba,
+                0x77ce585370525745,
+                0x5c071a97a256ec6d,
+                0x15f65ec3fa80e493,
+            ]),
+        ),
+        _ => unimplemented!(),
+    }
+}
+fn frobenius_coeffs_c2(power: usize) -> Fp2 {
+    match power % 6 {
+        0 => Fp2::one(),
+        1 => Fp2::new(
+            const_fp([
+                0x890dc9e4867545c3,
+                0x2af322533285a5d5,
+                0x50880866309b7e2c,
+                0xa20d1b8c7e881024,
+                0x14e4f04fe2db9068,
+                0x14e56d3f1564853a,
+            ]),
+            Fp::zero(),
+        ),
+        2 => Fp2::new(
+            const_fp([
+                0xcd03c9e48671f071,
+                0x5dab22461fcda5d2,
+                0x587042afd3851b95,
+                0x8eb60ebe01bacb9e,
+                0x03f97d6e83d050d2,
+                0x18f0206554638741,
+            ]),
+            Fp::zero(),
+        ),
+        3 => Fp2::new(
+            const_fp([
+                0x43f5fffffffcaaae,
+                0x32b7fff2ed47fffd,
+                0x07e83a49a2e99d69,
+                0xeca8f3318332bb7a,
+                0xef148d1ea0f4c069,
+                0x040ab3263eff0206,
+            ]),
+            Fp::zero(),
+        ),
+        _ => unimplemented!(),
+    }
+}
+
+/// Fp2[v] / (v^3 - (u + 1)).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct Fp6 {
+    pub c0: Fp2,
+    pub c1: Fp2,
+    pub c2: Fp2,
+}
+
+impl Fp6 {
+    pub fn new(c0: Fp2, c1: Fp2, c2: Fp2) -> Self {
+        Fp6 { c0, c1, c2 }
+    }
+
+    pub fn mul_by_nonresidue(&self) -> Self {
+        Fp6 {
+            c0: self.c2.mul_by_nonresidue(),
+            c1: self.c0,
+            c2: self.c1,
+        }
+    }
+
+    pub fn scale(&self, by: Fp2) -> Self {
+        Fp6 {
+            c0: self.c0 * by,
+            c1: self.c1 * by,
+            c2: self.c2 * by,
+        }
+    }
+
+    pub fn frobenius_map(&self, power: usize) -> Self {
+        Fp6 {
+            c0: self.c0.frobenius_map(power),
+            c1: self.c1.frobenius_map(power) * frobenius_coeffs_c1(power),
+            c2: self.c2.frobenius_map(power) * frobenius_coeffs_c2(power),
+        }
+    }
+
+    /// Multiplies by c0 + c1 v.
+    pub fn mul_by_01(&self, c0: Fp2, c1: Fp2) -> Self {
+        let a_a = self.c0 * c0;
+        let b_b = self.c1 * c1;
+
+        Fp6 {
+            c0: ((self.c1 + self.c2) * c1 - b_b).mul_by_nonresidue() + a_a,
+            c1: (self.c0 + self.c1) * (c0 + c1) - a_a - b_b,
+            c2: (self.c0 + self.c2) * c0 - a_a + b_b,
+        }
+    }
+
+    /// Multiplies by c1 v.
+    pub fn mul_by_1(&self, c1: Fp2) -> Self {
+        Fp6 {
+            c0: (self.c2 * c1).mul_by_nonresidue(),
+            c1: self.c0 * c1,
+            c2: self.c1 * c1,
+        }
+    }
+}
+
+impl FieldElement for Fp6 {
+    fn zero() -> Self {
+        Fp6 {
+            c0: Fp2::zero(),
+            c1: Fp2::zero(),
+            c2: Fp2::zero(),
+        }
+    }
+
+    fn one() -> Self {
+        Fp6 {
+            c0: Fp2::one(),
+            c1: Fp2::zero(),
+            c2: Fp2::zero(),
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero() && self.c2.is_zero()
+    }
+
+    fn squared(&self) -> Self {
+        let s0 = self.c0.squared();
+        let ab = self.c0 * self.c1;
+        let s1 = ab + ab;
+        let s2 = (self.c0 - self.c1 + self.c2).squared();
+        let bc = self.c1 * self.c2;
+        let s3 = bc + bc;
+        let s4 = self.c2.squared();
+
+        Fp6 {
+            c0: s0 + s3.mul_by_nonresidue(),
+            c1: s1 + s4.mul_by_nonresidue(),
+            c2: s1 + s2 + s3 - s0 - s4,
+        }
+    }
+
+    fn inverse(self) -> Option<Self> {
+        let c0 = self.c0.squared() - self.c1 * self.c2.mul_by_nonresidue();
+        let c1 = self.c2.squared().mul_by_nonresidue() - self.c0 * self.c1;
+        let c2 = self.c1.squared() - self.c0 * self.c2;
+        ((self.c2 * c1 + self.c1 * c2).mul_by_nonresidue() + self.c0 * c0)
+            .inverse()
+            .map(|t| Fp6 {
+                c0: t * c0,
+                c1: t * c1,
+                c2: t * c2,
+            })
+    }
+}
+
+impl Mul for Fp6 {
+    type Output = Fp6;
+
+    fn mul(self, other: Fp6) -> Fp6 {
+        let a_a = self.c0 * other.c0;
+        let b_b = self.c1 * other.c1;
+        let c_c = self.c2 * other.c2;
+
+        Fp6 {
+            c0: ((self.c1 + self.c2) * (other.c1 + other.c2) - b_b - c_c).mul_by_nonresidue() + a_a,
+            c1: (self.c0 + self.c1) * (other.c0 + other.c1) - a_a - b_b + c_c.mul_by_nonresidue(),
+            c2: (self.c0 + self.c2) * (other.c0 + other.c2) - a_a + b_b - c_c,
+        }
+    }
+}
+
+impl Sub for Fp6 {
+    type Output = Fp6;
+
+    fn sub(self, other: Fp6) -> Fp6 {
+        Fp6 {
+            c0: self.c0 - other.c0,
+            c1: self.c1 - other.c1,
+            c2: self.c2 - other.c2,
+        }
+    }
+}
+
+impl Add for Fp6 {
+    type Output = Fp6;
+
+    fn add(self, other: Fp6) -> Fp6 {
+        Fp6 {
+            c0: self.c0 + other.c0,
+            c1: self.c1 + other.c1,
+            c2: self.c2 + other.c2,
+        }
+    }
+}
+
+impl Neg for Fp6 {
+    type Output = Fp6;
+
+    fn neg(self) -> Fp6 {
+        Fp6 {
+            c0: -self.c0,
+            c1: -self.c1,
+            c2: -self.c2,
+        }
+    }
+}
@@ -0,0 +1,365 @@
+// BLS12-381, y^2 = x^3 + 4 over a 381-bit prime field, with G2 on the M-type sextic twist
+// y^2 = x^3 + 4 (u + 1) over Fp2 = Fp[u] / (u^2 + 1) and the optimal ate pairing into
+// Fp12, whose Miller loop runs over the bits of the curve parameter x = -0xd201000000010000.
+// It is laid out like BN254: the same Fp2 / Fp6 / Fp12 tower, Jacobian points of
+// `groups::G` and precomputed line coefficients, on six-limb field elements.
+//
+// Points are x and y in 48 big-endian bytes each; `ethereum` has the padded encodings of
+// EIP-2537.
+use crate::arith::U256;
+use crate::fields::FieldElement;
+use crate::groups::{AffineG, GroupElement, GroupParams, G};
+use crate::{msm::pippenger, GroupError};
+use core::ops::{Add, Mul, Neg, Sub};
+
+mod fp;
+mod fp12;
+mod fp2;
+mod fp6;
+mod map;
+mod pairing;
+
+pub use self::fp::Fp;
+pub use self::fp2::Fp2;
+pub use self::map::{map_to_g1, map_to_g2};
+pub use self::pairing::{pairing, PairingBatch};
+
+use self::fp::const_fp;
+use self::fp12::Fp12;
+
+/// The order r of G1, G2 and Gt.
+pub const ORDER: U256 = U256([
+    0x53bda402fffe5bfeffffffff00000001,
+    0x73eda753299d7d483339d80809a1d805,
+]);
+
+#[derive(Debug, Default)]
+pub struct G1Params;
+
+impl GroupParams for G1Params {
+    type Base = Fp;
+
+    fn name() -> &'static str {
+        "BLS12-381 G1"
+    }
+
+    fn one() -> G<Self> {
+        G::new(
+            const_fp([
+                0x5cb38790fd530c16,
+                0x7817fc679976fff5,
+                0x154f95c7143ba1c1,
+                0xf0ae6acdf3d0e747,
+                0xedce6ecc21dbf440,
+                0x120177419e0bfb75,
+            ]),
+            const_fp([
+                0xbaac93d50ce72271,
+                0x8c22631a7918fd8e,
+                0xdd595f13570725ce,
+                0x51ac582950405194,
+                0x0e1c8c3fad0059c0,
+                0x0bbc3efc5008a26a,
+            ]),
+            Fp::one(),
+        )
+    }
+
+    fn coeff_b() -> Fp {
+        const_fp([
+            0xaa270000000cfff3,
+            0x53cc0032fc34000a,
+            0x478fe97a6b0a807f,
+            0xb1d37ebee6ba24d7,
+            0x8ec9733bbf78ab2f,
+            0x09d645513d83de7e,
+        ])
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct G2Params;
+
+impl GroupParams for G2Params {
+    type Base = Fp2;
+
+    fn name() -> &'static str {
+        "BLS12-381 G2"
+    }
+
+    fn one() -> G<Self> {
+        G::new(
+            Fp2::new(
+                const_fp([
+                    0xf5f28fa202940a10,
+                    0xb3f5fb2687b4961a,
+                    0xa1a893b53e2ae580,
+                    0x9894999d1a3caee9,
+                    0x6f67b7631863366b,
+                    0x058191924350bcd7,
+                ]),
+                const_fp([
+                    0xa5a9c0759e23f606,
+                    0xaaa0c59dbccd60c3,
+                    0x3bb17e18e2867806,
+                    0x1b1ab6cc8541b367,
+                    0xc2b6ed0ef2158547,
+                    0x11922a097360edf3,
+                ]),
+            ),
+            Fp2::new(
+                const_fp([
+                    0x4c730af860494c4a,
+                    0x597cfa1f5e369c5a,
+                    0xe7e6856caa0a635a,
+                    0xbbefb5e96e0d495f,
+                    0x07d3a975f0ef25a2,
+                    0x0083fd8e7e80dae5,
+                ]),
+                const_fp([
+                    0xadc0fc92df64b05d,
+                    0x18aa270a2b1461dc,
+                    0x86adac6a3be4eba0,
+                    0x79495c4ec93da33a,
+                    0xe7175850a43ccaed,
+                    0x0b2bc2a163de1bf2,
+                ]),
+            ),
+            Fp2::one(),
+        )
+    }
+
+    fn coeff_b() -> Fp2 {
+        Fp2::new(
+            const_fp([
+                0xaa270000000cfff3,
+                0x53cc0032fc34000a,
+                0x478fe97a6b0a807f,
+                0xb1d37ebee6ba24d7,
+                0x8ec9733bbf78ab2f,
+                0x09d645513d83de7e,
+            ]),
+            const_fp([
+                0xaa270000000cfff3,
+                0x53cc0032fc34000a,
+                0x478fe97a6b0a807f,
+                0xb1d37ebee6ba24d7,
+                0x8ec9733bbf78ab2f,
+                0x09d645513d83de7e,
+            ]),
+        )
+    }
+}
+
+macro_rules! group_wrapper {
+    ($point:ident, $affine:ident, $params:ty, $base:ty) => {
+        #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+        #[repr(C)]
+        pub struct $point(G<$params>);
+
+        impl $point {
+            pub fn new(x: $base, y: $base, z: $base) -> Self {
+                $point(G::new(x, y, z))
+            }
+
+            pub fn zero() -> Self {
+                $point(G::zero())
+            }
+
+            pub fn one() -> Self {
+                $point(G::one())
+            }
+
+            pub fn is_zero(&self) -> bool {
+                self.0.is_zero()
+            }
+
+            pub fn double(&self) -> Self {
+                $point(self.0.double())
+            }
+
+            /// sum(s_i * P_i) with scalars that aren't reduced modulo r.
+            pub fn msm(bases: &[Self], scalars: &[U256]) -> Self {
+                assert_eq!(bases.len(), scalars.len());
+                pippenger(bases, scalars.iter().copied(), $point::zero())
+            }
+
+            /// Whether r times the point is zero. Points on the curve outside the
+            /// subgroup exist as the cofactor isn't 1.
+            pub fn is_in_subgroup(&self) -> bool {
+                self.0.mul_u256(ORDER).is_zero()
+            }
+        }
+
+        impl Add<$point> for $point {
+            type Output = $point;
+
+            fn add(self, other: $point) -> $point {
+                $point(self.0 + other.0)
+            }
+        }
+
+        impl Sub<$point> for $point {
+            type Output = $point;
+
+            fn sub(self, other: $point) -> $point {
+                $point(self.0 - other.0)
+            }
+        }
+
+        impl Neg for $point {
+            type Output = $point;
+
+            fn neg(self) -> $point {
+                $point(-self.0)
+            }
+        }
+
+        /// Multiplication by any 256-bit scalar, not reduced modulo r.
+        impl Mul<U256> for $point {
+            type Output = $point;
+
+            fn mul(self, other: U256) -> $point {
+                $point(self.0.mul_u256(other))
+            }
+        }
+
+        #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+        #[repr(C)]
+        pub struct $affine(AffineG<$params>);
+
+        impl $affine {
+            /// Checks that the point is on the curve, not that it is in the subgroup.
+            pub fn new(x: $base, y: $base) -> Result<Self, GroupError> {
+                Ok($affine(AffineG::new(x, y)?))
+            }
+
+            pub fn x(&self) -> $base {
+                *self.0.x()
+            }
+
+            pub fn y(&self) -> $base {
+                *self.0.y()
+            }
+
+            pub fn from_jacobian(p: $point) -> Option<Self> {
+                p.0.to_affine().map($affine)
+            }
+        }
+
+        impl From<$affine> for $point {
+            fn from(affine: $affine) -> Self {
+                $point(affine.0.to_jacobian())
+            }
+        }
+    };
+}
+
+group_wrapper!(G1, AffineG1, G1Params, Fp);
+group_wrapper!(G2, AffineG2, G2Params, Fp2);
+
+/// An element of the target group, in Fp12.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(C)]
+pub struct Gt(Fp12);
+
+impl Gt {
+    pub fn one() -> Self {
+        Gt(Fp12::one())
+    }
+
+    pub fn is_one(&self) -> bool {
+        self.0 == Fp12::one()
+    }
+}
+
+impl Mul<Gt> for Gt {
+    type Output = Gt;
+
+    fn mul(self, other: Gt) -> Gt {
+        Gt(self.0 * other.0)
+    }
+}
+
+#[test]
+fn bls12_381_arithmetic() {
+    let fp = |s: &str| {
+        let mut bytes = [0u8; 48];
+        for (i, b) in bytes.iter_mut().enumerate() {
+            *b = u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap();
+        }
+        Fp::from_slice(&bytes).unwrap()
+    };
+
+    // Arithmetic close to the modulus and square roots in Fp and Fp2.
+    let a = -Fp::one();
+    assert_eq!(a * a, Fp::one());
+    assert_eq!(a + Fp::one(), Fp::zero());
+    assert_eq!(a * a.inverse().unwrap(), Fp::one());
+    assert!(!a.is_square());
+    let b = Fp2::new(Fp::from_u64(3), -Fp::from_u64(7));
+    assert_eq!((b * b).sqrt().map(|r| r == b || r == -b), Some(true));
+    assert_eq!(b * b.inverse().unwrap(), Fp2::one());
+    assert!(Fp2::new(a, Fp::zero()).is_square());
+
+    // The generators are on the curve and of order r.
+    let g1 = AffineG1::from_jacobian(G1::one()).unwrap();
+    let g2 = AffineG2::from_jacobian(G2::one()).unwrap();
+    assert!(AffineG1::new(g1.x(), g1.y()).is_ok());
+    assert!(AffineG2::new(g2.x(), g2.y()).is_ok());
+    assert!(AffineG1::new(g1.x(), g1.x()).is_err());
+    assert!(G1::one().is_in_subgroup() && G2::one().is_in_subgroup());
+    assert!((G1::one() * ORDER).is_zero() && (G2::one() * ORDER).is_zero());
+
+    // Bilinearity, and e(P, Q) e(-P, Q) = 1 in one batch.
+    let three = U256::from(3u64);
+    let e = pairing(&G1::one(), &G2::one());
+    assert!(!e.is_one());
+    assert_eq!(pairing(&(G1::one() * three), &G2::one()), e * e * e);
+    assert_eq!(
+        pairing(&(G1::one() * three), &G2::one()),
+        pairing(&G1::one(), &(G2::one() * three))
+    );
+    assert_eq!(pairing(&G1::zero(), &G2::one()), Gt::one());
+    let mut batch = PairingBatch::new();
+    for _ in 0..9 {
+        batch.push(&G1::one(), &(G2::one() * three));
+        batch.push(&-(G1::one() * three), &G2::one());
+    }
+    assert!(batch.finalize().is_one());
+
+    // BLS12381G1_XMD:SHA-256_SSWU_NU_ and BLS12381G2_XMD:SHA-256_SSWU_NU_ of RFC 9380,
+    // msg = "".
+    let p = AffineG1::from_jacobian(map_to_g1(fp(
+        "156c8a6a2c184569d69a76be144b5cdc5141d2d2ca4fe341f011e25e3969c55ad9e9b9ce2eb833c81a908e5fa4ac5f03",
+    )))
+    .unwrap();
+    assert_eq!(
+        p.x(),
+        fp("184bb665c37ff561a89ec2122dd343f20e0f4cbcaec84e3c3052ea81d1834e192c426074b02ed3dca4e7676ce4ce48ba")
+    );
+    assert_eq!(
+        p.y(),
+        fp("04407b8d35af4dacc809927071fc0405218f1401a6d15af775810e4e460064bcc9468beeba82fdc751be70476c888bf3")
+    );
+    let q = AffineG2::from_jacobian(map_to_g2(Fp2::new(
+        fp("07355d25caf6e7f2f0cb2812ca0e513bd026ed09dda65b177500fa31714e09ea0ded3a078b526bed3307f804d4b93b04"),
+        fp("02829ce3c021339ccb5caf3e187f6370e1e2a311dec9b75363117063ab2015603ff52c3d3b98f19c2f65575e99e8b78c"),
+    )))
+    .unwrap();
+    assert_eq!(
+        q.x(),
+        Fp2::new(
+            fp("00e7f4568a82b4b7dc1f14c6aaa055edf51502319c723c4dc2688c7fe5944c213f510328082396515734b6612c4e7bb7"),
+            fp("126b855e9e69b1f691f816e48ac6977664d24d99f8724868a184186469ddfd4617367e94527d4b74fc86413483afb35b"),
+        )
+    );
+    assert_eq!(
+        q.y(),
+        Fp2::new(
+            fp("0caead0fd7b6176c01436833c79d305c78be307da5f6af6c133c47311def6ff1e0babf57a0fb5539fce7ee12407b0a42"),
+            fp("1498aadcf7ae2b345243e281ae076df6de84455d766ab6fcdaad71fab60abb2e8b980a440043cd305db09d283c895e3d"),
+        )
+    );
+    assert!(G1::from(p).is_in_subgroup() && G2::from(q).is_in_subgroup());
+}
@@ -0,0 +1,204 @@
+use super::fp12::{Fp12, X_ABS};
+use super::fp2::Fp2;
+use super::{G1Params, G2Params, Gt, G1, G2};
+use crate::fields::FieldElement;
+use crate::groups::{AffineG, G};
+
+// One line per doubling and per addition over the bits of |x| after the leading one:
+// 63 doublings and 5 additions.
+const LINES: usize = 68;
+
+/// A line ell_0 + ell_v * x_P * v + ell_vw * y_P * v w, scaled by a factor in Fp2 that
+/// the final exponentiation removes.
+#[derive(Clone, Copy, Default, Eq, PartialEq)]
+pub struct EllCoeffs {
+    pub ell_0: Fp2,
+    pub ell_v: Fp2,
+    pub ell_vw: Fp2,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct G2Precomp {
+    pub q: AffineG<G2Params>,
+    pub coeffs: [EllCoeffs; LINES],
+}
+
+impl Default for G2Precomp {
+    fn default() -> Self {
+        G2Precomp {
+            q: AffineG::default(),
+            coeffs: [EllCoeffs::default(); LINES],
+        }
+    }
+}
+
+impl G2Precomp {
+    pub fn miller_loop(&self, g1: &AffineG<G1Params>) -> Fp12 {
+        miller_loop_batch(core::slice::from_ref(self), core::slice::from_ref(g1))
+    }
+}
+
+/// f_{|x|, Q}(P) for every pair with shared squarings, conjugated as x is negative.
+pub fn miller_loop_batch(g2_precomputes: &[G2Precomp], g1_vec: &[AffineG<G1Params>]) -> Fp12 {
+    let mut f = Fp12::one();
+
+    let mut idx = 0;
+    let ell = |f: Fp12, idx: usize| {
+        let mut f = f;
+        for (g2_precompute, g1) in g2_precomputes.iter().zip(g1_vec.iter()) {
+            let c = &g2_precompute.coeffs[idx];
+            f = f.mul_by_014(c.ell_0, c.ell_v.scale(*g1.x()), c.ell_vw.scale(*g1.y()));
+        }
+        f
+    };
+
+    for i in (0..63).rev() {
+        f = ell(f.squared(), idx);
+        idx += 1;
+        if X_ABS >> i & 1 == 1 {
+            f = ell(f, idx);
+            idx += 1;
+        }
+    }
+
+    f.unitary_inverse()
+}
+
+impl AffineG<G2Params> {
+    pub fn precompute(&self) -> G2Precomp {
+        let mut r = self.to_jacobian();
+
+        let mut coeffs = [EllCoeffs::default(); LINES];
+        let mut idx = 0;
+
+        for i in (0..63).rev() {
+            coeffs[idx] = r.doubling_step();
+            idx += 1;
+
+            if X_ABS >> i & 1 == 1 {
+                coeffs[idx] = r.mixed_addition_step(self);
+                idx += 1;
+            }
+        }
+
+        G2Precomp { q: *self, coeffs }
+    }
+}
+
+// Jacobian steps on the twist. With T = (X / Z^2, Y / Z^3) and slope l, the line through
+// T evaluated at P, after untwisting (x, y) -> (x / w^2, y / w^3) and multiplying by w^3,
+// is y_P v w - l x_P v + (l x_T - y_T); its coefficients are scaled here to clear the
+// denominators of l.
+impl G<G2Params> {
+    fn mixed_addition_step(&mut self, base: &AffineG<G2Params>) -> EllCoeffs {
+        let (x, y, z) = (*self.x(), *self.y(), *self.z());
+        let z2 = z.squared();
+        let h = *base.x() * z2 - x;
+        let r = *base.y() * z * z2 - y;
+        let hh = h.squared();
+        let hhh = h * hh;
+        let v = x * hh;
+        let x3 = r.squared() - hhh - (v + v);
+        let y3 = r * (v - x3) - y * hhh;
+        let z3 = z * h;
+
+        *self = G::new(x3, y3, z3);
+
+        // l = r / (Z h)
+        EllCoeffs {
+            ell_0: r * *base.x() - *base.y() * z3,
+            ell_v: -r,
+            ell_vw: z3,
+        }
+    }
+
+    fn doubling_step(&mut self) -> EllCoeffs {
+        let (x, y, z) = (*self.x(), *self.y(), *self.z());
+        let a = x.squared();
+        let b = y.squared();
+        let c = b.squared();
+        let mut d = (x + b).squared() - a - c;
+        d = d + d;
+        let e = a + a + a;
+        let x3 = e.squared() - (d + d);
+        let mut eight_c = c + c;
+        eight_c = eight_c + eight_c;
+        eight_c = eight_c + eight_c;
+        let yz = y * z;
+        let z3 = yz + yz;
+        let z2 = z.squared();
+
+        *self = G::new(x3, e * (d - x3) - eight_c, z3);
+
+        // l = 3 X^2 / (2 Y Z)
+        EllCoeffs {
+            ell_0: e * x - (b + b),
+            ell_v: -(e * z2),
+            ell_vw: z3 * z2,
+        }
+    }
+}
+
+pub fn pairing(p: &G1, q: &G2) -> Gt {
+    match (p.0.to_affine(), q.0.to_affine()) {
+        (None, _) | (_, None) => Gt::one(),
+        (Some(p), Some(q)) => Gt(q
+            .precompute()
+            .miller_loop(&p)
+            .final_exponentiation()
+            .expect("miller loop cannot produce zero")),
+    }
+}
+
+/// Multi-pairing over any number of pairs. Pairs are buffered and every 16 of them go
+/// through one shared Miller loop, the partial results are multiplied together and a
+/// single final exponentiation is done at the end.
+pub struct PairingBatch {
+    f: Fp12,
+    ps: [AffineG<G1Params>; 16],
+    qs: [G2Precomp; 16],
+    len: usize,
+}
+
+impl Default for PairingBatch {
+    fn default() -> Self {
+        PairingBatch {
+            f: Fp12::one(),
+            ps: [AffineG::default(); 16],
+            qs: [G2Precomp::default(); 16],
+            len: 0,
+        }
+    }
+}
+
+impl PairingBatch {
+    pub fn new() -> Self {
+        PairingBatch::default()
+    }
+
+    pub fn push(&mut self, p: &G1, q: &G2) {
+        if let (Some(p), Some(q)) = (p.0.to_affine(), q.0.to_affine()) {
+            if self.len == self.ps.len() {
+                self.flush();
+            }
+            self.ps[self.len] = p;
+            self.qs[self.len] = q.precompute();
+            self.len += 1;
+        }
+    }
+
+    fn flush(&mut self) {
+        if self.len != 0 {
+            self.f = self.f * miller_loop_batch(&self.qs[0..self.len], &self.ps[0..self.len]);
+            self.len = 0;
+        }
+    }
+
+    pub fn finalize(mut self) -> Gt {
+        self.flush();
+        Gt(self
+            .f
+            .final_exponentiation()
+            .expect("miller loop cannot produce zero"))
+    }
+}
@@ -0,0 +1,287 @@
+// Radix-2 evaluation domains over Fr: the multiplicative subgroups of order 2^k, k <= 28,
+// with their NTTs, the vanishing polynomial and the Lagrange basis. Everything works in
+// place on caller buffers and allocates nothing.
+use crate::{arith::U256, Fr};
+
+pub const TWO_ADICITY: u32 = 28;
+
+// 5^((r - 1) / 2^28), the same primitive 2^28-th root of unity as snarkjs and arkworks.
+const ROOT_OF_UNITY: Fr = Fr::from_montgomery([
+    0x636e735580d13d9c,
+    0xa22bf3742445ffd6,
+    0x56452ac01eb203d8,
+    0x1860ef942963f9e7,
+]);
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct EvaluationDomain {
+    size: usize,
+    log_size: u32,
+    omega: Fr,
+    omega_inv: Fr,
+    size_inv: Fr,
+}
+
+impl EvaluationDomain {
+    /// The smallest domain with at least `min_size` elements, `None` above 2^28.
+    pub fn new(min_size: usize) -> Option<Self> {
+        let size = min_size.checked_next_power_of_two()?;
+        let log_size = size.trailing_zeros();
+        if log_size > TWO_ADICITY {
+            return None;
+        }
+        let mut omega = ROOT_OF_UNITY;
+        for _ in log_size..TWO_ADICITY {
+            omega = omega * omega;
+        }
+        Some(EvaluationDomain {
+            size,
+            log_size,
+            omega,
+            omega_inv: omega.inverse().unwrap(),
+            size_inv: Fr::new(U256::from(size as u64)).unwrap().inverse().unwrap(),
+        })
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn log_size(&self) -> u32 {
+        self.log_size
+    }
+
+    /// The generator of the domain, a primitive `size`-th root of unity.
+    pub fn group_gen(&self) -> Fr {
+        self.omega
+    }
+
+    pub fn group_gen_inv(&self) -> Fr {
+        self.omega_inv
+    }
+
+    /// `omega^i`
+    pub fn element(&self, i: usize) -> Fr {
+        let mut r = Fr::one();
+        let mut base = self.omega;
+        let mut i = i % self.size;
+        while i > 0 {
+            if i & 1 == 1 {
+                r = r * base;
+            }
+            base = base * base;
+            i >>= 1;
+        }
+        r
+    }
+
+    /// `Z_H(tau) = tau^size - 1`, zero exactly on the domain.
+    pub fn evaluate_vanishing_polynomial(&self, tau: Fr) -> Fr {
+        let mut t = tau;
+        for _ in 0..self.log_size {
+            t = t * t;
+        }
+        t - Fr::one()
+    }
+
+    /// The `i`-th Lagrange basis polynomial at `tau`,
+    /// `L_i(tau) = omega^i * Z_H(tau) / (size * (tau - omega^i))`.
+    pub fn evaluate_lagrange(&self, i: usize, tau: Fr) -> Fr {
+        let w = self.element(i);
+        match (tau - w).inverse() {
+            Some(d) => w * self.evaluate_vanishing_polynomial(tau) * self.size_inv * d,
+            None => Fr::one(),
+        }
+    }
+
+    /// Every `L_i(tau)` into `out`, with a single inversion.
+    pub fn evaluate_all_lagrange_coefficients(&self, tau: Fr, out: &mut [Fr]) {
+        assert_eq!(out.len(), self.size);
+        let zh = self.evaluate_vanishing_polynomial(tau);
+        if zh.is_zero() {
+            // tau is omega^j for some j, where L_j = 1 and every other L_i = 0.
+            let mut w = Fr::one();
+            for e in out.iter_mut() {
+                *e = if w == tau { Fr::one() } else { Fr::zero() };
+                w = w * self.omega;
+            }
+            return;
+        }
+        // Batch inversion of tau - omega^i: prefix products going up, then the inverses
+        // coming down, recomputing each tau - omega^i rather than storing it.
+        let mut acc = Fr::one();
+        let mut w = Fr::one();
+        for e in out.iter_mut() {
+            *e = acc;
+            acc = acc * (tau - w);
+            w = w * self.omega;
+        }
+        let mut inv = acc.inverse().unwrap();
+        // w = omega^size = 1 here.
+        let factor = zh * self.size_inv;
+        for e in out.iter_mut().rev() {
+            w = w * self.omega_inv;
+            let d = tau - w;
+            *e = *e * inv * w * factor;
+            inv = inv * d;
+        }
+    }
+
+    /// Coefficients to evaluations at `omega^i`.
+    pub fn fft(&self, a: &mut [Fr]) {
+        assert_eq!(a.len(), self.size);
+        transform(a, self.omega, self.log_size);
+    }
+
+    /// Evaluations at `omega^i` to coefficients.
+    pub fn ifft(&self, a: &mut [Fr]) {
+        assert_eq!(a.len(), self.size);
+        transform(a, self.omega_inv, self.log_size);
+        for e in a.iter_mut() {
+            *e = *e * self.size_inv;
+        }
+    }
+
+    /// Coefficients to evaluations at `g * omega^i`.
+    pub fn coset_fft(&self, a: &mut [Fr], g: Fr) {
+        distribute_powers(a, g);
+        self.fft(a);
+    }
+
+    /// Evaluations at `g * omega^i` to coefficients.
+    pub fn coset_ifft(&self, a: &mut [Fr], g: Fr) {
+        self.ifft(a);
+        distribute_powers(a, g.inverse().expect("coset shift must not be zero"));
+    }
+}
+
+fn distribute_powers(a: &mut [Fr], g: Fr) {
+    let mut p = Fr::one();
+    for e in a.iter_mut() {
+        *e = *e * p;
+        p = p * g;
+    }
+}
+
+// Iterative Cooley-Tukey: bit-reversal permutation followed by log_n butterfly layers.
+fn transform(a: &mut [Fr], omega: Fr, log_n: u32) {
+    let n = a.len();
+    if n <= 1 {
+        return;
+    }
+    for k in 0..n {
+        let rk = k.reverse_bits() >> (usize::BITS - log_n);
+        if k < rk {
+            a.swap(k, rk);
+        }
+    }
+
+    let mut m = 1;
+    for _ in 0..log_n {
+        // omega^(n / 2m) is a primitive 2m-th root of unity.
+        let mut w_m = omega;
+        let mut t = n / (2 * m);
+        while t > 1 {
+            w_m = w_m * w_m;
+            t >>= 1;
+        }
+        let mut k = 0;
+        while k < n {
+            let mut w = Fr::one();
+            for j in 0..m {
+                let t = a[k + j + m] * w;
+                a[k + j + m] = a[k + j] - t;
+                a[k + j] = a[k + j] + t;
+                w = w * w_m;
+            }
+            k += 2 * m;
+        }
+        m *= 2;
+    }
+}
+
+#[cfg(test)]
+fn naive_dft(a: &[Fr], omega: Fr, shift: Fr) -> [Fr; 8] {
+    let mut out = [Fr::zero(); 8];
+    let mut x = shift;
+    for e in out.iter_mut() {
+        for c in a.iter().rev() {
+            *e = *e * x + *c;
+        }
+        x = x * omega;
+    }
+    out
+}
+
+#[test]
+fn fft_matches_naive_dft() {
+    let domain = EvaluationDomain::new(8).unwrap();
+    let coeffs = [9u64, 0, 7, 1, 1, 3, 5, 2].map(|i| Fr::new(U256::from(i)).unwrap());
+    let mut a = coeffs;
+    domain.fft(&mut a);
+    assert_eq!(a, naive_dft(&coeffs, domain.group_gen(), Fr::one()));
+    let g = Fr::from_str("5").unwrap();
+    let mut a = coeffs;
+    domain.coset_fft(&mut a, g);
+    assert_eq!(a, naive_dft(&coeffs, domain.group_gen(), g));
+    // The inverse transform is the DFT with omega^-1, scaled by 1/n.
+    let mut evals = naive_dft(&coeffs, domain.group_gen(), Fr::one());
+    let inverse = naive_dft(&evals, domain.group_gen_inv(), Fr::one());
+    domain.ifft(&mut evals);
+    for (e, i) in evals.iter().zip(inverse.iter()) {
+        assert_eq!(*e * Fr::from_str("8").unwrap(), *i);
+    }
+
+    // sum(p(omega^i) * L_i(tau)) = p(tau), inside and outside the domain.
+    let evals = naive_dft(&coeffs, domain.group_gen(), Fr::one());
+    for tau in [Fr::from_str("1234567").unwrap(), domain.element(3)] {
+        let mut l = [Fr::zero(); 8];
+        domain.evaluate_all_lagrange_coefficients(tau, &mut l);
+        let mut sum = Fr::zero();
+        for i in 0..8 {
+            assert_eq!(l[i], domain.evaluate_lagrange(i, tau));
+            sum = sum + l[i] * evals[i];
+        }
+        assert_eq!(sum, naive_dft(&coeffs, Fr::one(), tau)[0]);
+    }
+    assert!(domain
+        .evaluate_vanishing_polynomial(domain.element(5))
+        .is_zero());
+    let tau = Fr::from_str("3").unwrap();
+    assert_eq!(
+        domain.evaluate_vanishing_polynomial(tau),
+        Fr::from_str("6560").unwrap()
+    );
+}
+
+#[test]
+fn fft_roundtrip() {
+    let domain = EvaluationDomain::new(5).unwrap();
+    assert_eq!(domain.size(), 8);
+    assert_eq!(
+        domain.group_gen().pow(Fr::from_str("8").unwrap()),
+        Fr::one()
+    );
+    assert!(domain.group_gen().pow(Fr::from_str("4").unwrap()) != Fr::one());
+    assert!(EvaluationDomain::new((1 << 28) + 1).is_none());
+
+    let coeffs = [1u64, 2, 3, 4, 5, 6, 7, 8].map(|i| Fr::new(U256::from(i)).unwrap());
+    let mut a = coeffs;
+    domain.fft(&mut a);
+    // The sum of all evaluations over the subgroup is 8 times the constant term.
+    let sum = a.iter().fold(Fr::zero(), |s, e| s + *e);
+    assert_eq!(sum, Fr::from_str("8").unwrap());
+    domain.ifft(&mut a);
+    assert_eq!(a, coeffs);
+
+    let g = Fr::from_str("7").unwrap();
+    domain.coset_fft(&mut a, g);
+    // At g * omega^0 the polynomial is sum(c_i * g^i).
+    let mut x = Fr::zero();
+    for c in coeffs.iter().rev() {
+        x = x * g + *c;
+    }
+    assert_eq!(a[0], x);
+    domain.coset_ifft(&mut a, g);
+    assert_eq!(a, coeffs);
+}
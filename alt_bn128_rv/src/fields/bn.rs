@@ -0,0 +1,919 @@
+// Barreto-Naehrig curves y^2 = x^3 + b over Fq with a D-type sextic twist, a family
+// parameterized by one integer u:
+//     q = 36u^4 + 36u^3 + 24u^2 + 6u + 1,  r = 36u^4 + 36u^3 + 18u^2 + 6u + 1.
+// The tower is Fq2 = Fq[i] / (i^2 + 1), Fq6 = Fq2[v] / (v^3 - xi), Fq12 = Fq6[w] / (w^2 - v),
+// -1 being a non-residue as q = 3 mod 4 for every odd u. `BnCurveConfig` holds what the
+// tower, the groups and the pairing read. The `const fn`s here derive the moduli, the
+// Frobenius coefficients, the twist and the ate loop from u and xi when the crate is
+// compiled, so the generic code reads constants as it did tables.
+//
+// A new curve is a unit struct implementing `BnCurveConfig`, as `Bn462` at the end of this
+// file does. Pick u and xi = c0 + i, neither a square nor a cube in Fq2; `bn_q` and `bn_r`
+// give the moduli, `frobenius_gammas` the Frobenius coefficients, `twist_b` the b of the twist
+// and `ate_loop_count_naf` the loop, with `ate_loop_lines` of them for `Lines`. Fq and Fr are
+// `MontFp`s over the moduli, or `field_impl!` fields when they fit in 254 bits. Only the
+// generators are given by hand: a point of G1 and a point of the twist times its cofactor
+// 2q - r, which `AffineG::new` then checks to be in G2.
+
+use crate::fields::fp::{const_fq, Fq, Fr};
+use crate::fields::montgomery::{self as mont, add, add_mod, mul, small, sub, sub_mod};
+use crate::fields::{BnFq2, LazyReduction, PrimeField};
+use crate::groups::EllCoeffs;
+use core::fmt::Debug;
+
+pub trait BnCurveConfig: 'static + Copy + Clone + Default + Debug + PartialEq + Eq {
+    type Fq: LazyReduction + Default;
+    /// The scalars, whose modulus r is the order of G1 and G2.
+    type Fr: PrimeField;
+    /// Storage for the lines of a precomputed G2 point, at least `ate_loop_lines` long.
+    type Lines: Copy + Eq + AsRef<[EllCoeffs<Self>]> + AsMut<[EllCoeffs<Self>]>;
+
+    /// |u| and its sign. As q grows as 36 u^4, a u128 holds the u of any q below 2^500.
+    const U: u128;
+    const U_IS_NEGATIVE: bool;
+    /// The digits of 6u + 2 from `ate_loop_count_naf`.
+    const ATE_LOOP_COUNT_NAF: &'static [u8];
+
+    /// xi, the non-residue of Fq6.
+    const FQ6_NONRESIDUE: BnFq2<Self>;
+    /// The c0 of xi = c0 + i, which the tower multiplies by with additions.
+    const XI_C0: u64;
+    /// xi^((q^k - 1) / 3) and xi^(2 (q^k - 1) / 3) for k < 6, xi^((q^k - 1) / 6) for k < 12.
+    const FQ6_FROBENIUS_C1: [BnFq2<Self>; 6];
+    const FQ6_FROBENIUS_C2: [BnFq2<Self>; 6];
+    const FQ12_FROBENIUS_C1: [BnFq2<Self>; 12];
+    /// xi^((q - 1) / 3) and xi^((q - 1) / 2), the Frobenius of the twist.
+    const TWIST_MUL_BY_Q_X: BnFq2<Self>;
+    const TWIST_MUL_BY_Q_Y: BnFq2<Self>;
+    const TWO_INV: Self::Fq;
+
+    const G1_B: Self::Fq;
+    const G1_ONE: (Self::Fq, Self::Fq);
+    /// b / xi
+    const G2_B: BnFq2<Self>;
+    const G2_ONE: (BnFq2<Self>, BnFq2<Self>);
+
+    fn empty_lines() -> Self::Lines;
+}
+
+/// q(u)
+pub const fn bn_q<const N: usize>(u: u128, u_is_negative: bool) -> [u64; N] {
+    bn_poly(u, u_is_negative, 24)
+}
+
+/// r(u)
+pub const fn bn_r<const N: usize>(u: u128, u_is_negative: bool) -> [u64; N] {
+    bn_poly(u, u_is_negative, 18)
+}
+
+// Horner in |u|: for negative u the odd coefficients change sign and every partial sum
+// stays positive.
+const fn bn_poly<const N: usize>(u: u128, u_is_negative: bool, c2: u64) -> [u64; N] {
+    let coeffs = [36, 36, c2, 6, 1];
+    let mut acc = small::<N>(coeffs[0]);
+    let mut i = 1;
+    while i < 5 {
+        let lo = mont::mul_small(acc, u as u64, 0);
+        let hi = mont::mul_small(acc, (u >> 64) as u64, 0);
+        let mut shifted = [0; N];
+        let mut j = 1;
+        while j < N {
+            shifted[j] = hi[j - 1];
+            j += 1;
+        }
+        acc = add(lo, &shifted).0;
+        acc = if u_is_negative && i % 2 == 1 {
+            sub(acc, &small(coeffs[i]))
+        } else {
+            add(acc, &small(coeffs[i])).0
+        };
+        i += 1;
+    }
+    acc
+}
+
+// The non-adjacent form of 6u + 2, most significant digit first. A leading 1 0 -1 is folded
+// into 1 1, one doubling shorter.
+const fn ate_naf(u: u128, u_is_negative: bool) -> ([i8; 130], usize) {
+    let mut x = if u_is_negative { 6 * u - 2 } else { 6 * u + 2 };
+    let mut lsb = [0i8; 130];
+    let mut len = 0;
+    while x != 0 {
+        if x & 1 == 1 {
+            if x & 3 == 1 {
+                lsb[len] = 1;
+                x -= 1;
+            } else {
+                lsb[len] = -1;
+                x += 1;
+            }
+        }
+        x >>= 1;
+        len += 1;
+    }
+    let mut digits = [0i8; 130];
+    let mut i = 0;
+    while i < len {
+        digits[i] = lsb[len - 1 - i];
+        i += 1;
+    }
+    if len >= 3 && digits[1] == 0 && digits[2] == -1 {
+        digits[1] = 1;
+        let mut i = 2;
+        while i < len - 1 {
+            digits[i] = digits[i + 1];
+            i += 1;
+        }
+        len -= 1;
+    }
+    (digits, len)
+}
+
+pub const fn ate_loop_count_naf_len(u: u128, u_is_negative: bool) -> usize {
+    ate_naf(u, u_is_negative).1 - 1
+}
+
+/// The signed digits of |6u + 2| after the leading one, most significant first and -1
+/// written as 3; the Miller loop runs over them.
+pub const fn ate_loop_count_naf<const L: usize>(u: u128, u_is_negative: bool) -> [u8; L] {
+    let (digits, _) = ate_naf(u, u_is_negative);
+    let mut naf = [0; L];
+    let mut i = 0;
+    while i < L {
+        naf[i] = match digits[i + 1] {
+            1 => 1,
+            -1 => 3,
+            _ => 0,
+        };
+        i += 1;
+    }
+    naf
+}
+
+/// Lines of a precomputed G2 point: a doubling per digit, an addition per non-zero digit
+/// and the two additions of Frobenius images.
+pub const fn ate_loop_lines(naf: &[u8]) -> usize {
+    let mut lines = naf.len() + 2;
+    let mut i = 0;
+    while i < naf.len() {
+        if naf[i] != 0 {
+            lines += 1;
+        }
+        i += 1;
+    }
+    lines
+}
+
+// Fq2 in Montgomery limbs, for the derivations below.
+type Fq2Limbs<const N: usize> = [[u64; N]; 2];
+
+const fn fq2_mul<const N: usize>(a: &Fq2Limbs<N>, b: &Fq2Limbs<N>, q: &[u64; N]) -> Fq2Limbs<N> {
+    let inv = mont::inv64(q[0]);
+    let aa = mul(&a[0], &b[0], q, inv);
+    let bb = mul(&a[1], &b[1], q, inv);
+    let ab = mul(&a[0], &b[1], q, inv);
+    let ba = mul(&a[1], &b[0], q, inv);
+    [sub_mod(&aa, &bb, q), add_mod(&ab, &ba, q)]
+}
+
+const fn fq2_conjugate<const N: usize>(a: &Fq2Limbs<N>, q: &[u64; N]) -> Fq2Limbs<N> {
+    [a[0], sub_mod(&[0; N], &a[1], q)]
+}
+
+const fn fq2_pow<const N: usize>(a: &Fq2Limbs<N>, e: &[u64; N], q: &[u64; N]) -> Fq2Limbs<N> {
+    let mut res = [mont::r_pow(q, 1), [0; N]];
+    // From the top bit of e, as the small exponents of the tables would otherwise square
+    // one 64 N times.
+    let mut i = 64 * N;
+    while i > 0 && !mont::bit(e, i - 1) {
+        i -= 1;
+    }
+    while i > 0 {
+        i -= 1;
+        res = fq2_mul(&res, &res, q);
+        if mont::bit(e, i) {
+            res = fq2_mul(&res, a, q);
+        }
+    }
+    res
+}
+
+/// The Montgomery form of a canonical Fq2 element.
+pub const fn fq2_to_mont<const N: usize>(a: &Fq2Limbs<N>, q: &[u64; N]) -> Fq2Limbs<N> {
+    [mont::to_mont(&a[0], q), mont::to_mont(&a[1], q)]
+}
+
+/// gamma_k = xi^((q^k - 1) / 6) for k < 12, of which every Frobenius coefficient is a
+/// power. As the Frobenius of Fq2 is conjugation, gamma_k is the product of the conjugates
+/// sigma^j(gamma_1) for j < k, and only (q - 1) / 6 is ever an exponent.
+pub const fn frobenius_gammas<const N: usize>(xi: &Fq2Limbs<N>, q: &[u64; N]) -> [Fq2Limbs<N>; 12] {
+    // Every curve derives its Frobenius coefficients here, so none builds on a field where
+    // -1 is a square and Fq[i] / (i^2 + 1) is not a field.
+    assert!(q[0] & 3 == 3, "the tower needs q = 3 mod 4");
+    let e = mont::div_small(sub(*q, &small(1)), 6);
+    let gamma = fq2_pow(xi, &e, q);
+    let mut gammas = [[mont::r_pow(q, 1), [0; N]]; 12];
+    let mut conjugate = gamma;
+    let mut k = 1;
+    while k < 12 {
+        gammas[k] = fq2_mul(&gammas[k - 1], &conjugate, q);
+        conjugate = fq2_conjugate(&conjugate, q);
+        k += 1;
+    }
+    gammas
+}
+
+/// gamma_k^e for k < K.
+pub const fn fq2_table_pow<const N: usize, const K: usize>(
+    gammas: &[Fq2Limbs<N>; 12],
+    e: u64,
+    q: &[u64; N],
+) -> [Fq2Limbs<N>; K] {
+    let mut out = [gammas[0]; K];
+    let mut k = 0;
+    while k < K {
+        out[k] = fq2_pow(&gammas[k], &small(e), q);
+        k += 1;
+    }
+    out
+}
+
+/// b / xi in Montgomery form, from the canonical b.
+pub const fn twist_b<const N: usize>(b: &[u64; N], xi: &Fq2Limbs<N>, q: &[u64; N]) -> Fq2Limbs<N> {
+    let inv = mont::inv64(q[0]);
+    // 1 / (x0 + x1 i) = (x0 - x1 i) / (x0^2 + x1^2)
+    let norm = add_mod(
+        &mul(&xi[0], &xi[0], q, inv),
+        &mul(&xi[1], &xi[1], q, inv),
+        q,
+    );
+    let t = mul(&mont::inverse(&norm, q), &mont::to_mont(b, q), q, inv);
+    [
+        mul(&xi[0], &t, q, inv),
+        sub_mod(&[0; N], &mul(&xi[1], &t, q, inv), q),
+    ]
+}
+
+// BN254, the alt_bn128 of Ethereum: u = 0x44e992b44a6909f1, xi = 9 + i, b = 3.
+const BN254_U: u128 = 0x44e992b44a6909f1;
+const BN254_Q: [u64; 4] = bn_q(BN254_U, false);
+const BN254_XI_C0: u64 = 9;
+const BN254_XI: Fq2Limbs<4> = fq2_to_mont(&[small(BN254_XI_C0), small(1)], &BN254_Q);
+const BN254_GAMMAS: [Fq2Limbs<4>; 12] = frobenius_gammas(&BN254_XI, &BN254_Q);
+const BN254_NAF: [u8; ate_loop_count_naf_len(BN254_U, false)] = ate_loop_count_naf(BN254_U, false);
+/// The lines of a BN254 `G2Prepared`, which `from_coeffs` and `codegen` fix at more than the
+/// 87 the loop uses.
+pub const BN254_LINES: usize = 102;
+const _: () = assert!(ate_loop_lines(&BN254_NAF) <= BN254_LINES);
+
+const fn bn254_fq2(a: &Fq2Limbs<4>) -> BnFq2<Bn254> {
+    BnFq2::new(const_fq(a[0]), const_fq(a[1]))
+}
+
+const fn bn254_fq2_table<const K: usize>(t: &[Fq2Limbs<4>; K]) -> [BnFq2<Bn254>; K] {
+    let mut out = [bn254_fq2(&t[0]); K];
+    let mut k = 1;
+    while k < K {
+        out[k] = bn254_fq2(&t[k]);
+        k += 1;
+    }
+    out
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Bn254;
+
+impl BnCurveConfig for Bn254 {
+    type Fq = Fq;
+    type Fr = Fr;
+    type Lines = [EllCoeffs<Bn254>; BN254_LINES];
+
+    const U: u128 = BN254_U;
+    const U_IS_NEGATIVE: bool = false;
+    const ATE_LOOP_COUNT_NAF: &'static [u8] = &BN254_NAF;
+
+    const FQ6_NONRESIDUE: BnFq2<Bn254> = bn254_fq2(&BN254_XI);
+    const XI_C0: u64 = BN254_XI_C0;
+    const FQ6_FROBENIUS_C1: [BnFq2<Bn254>; 6] =
+        bn254_fq2_table(&fq2_table_pow(&BN254_GAMMAS, 2, &BN254_Q));
+    const FQ6_FROBENIUS_C2: [BnFq2<Bn254>; 6] =
+        bn254_fq2_table(&fq2_table_pow(&BN254_GAMMAS, 4, &BN254_Q));
+    const FQ12_FROBENIUS_C1: [BnFq2<Bn254>; 12] = bn254_fq2_table(&BN254_GAMMAS);
+    const TWIST_MUL_BY_Q_X: BnFq2<Bn254> = Self::FQ6_FROBENIUS_C1[1];
+    const TWIST_MUL_BY_Q_Y: BnFq2<Bn254> =
+        bn254_fq2(&fq2_pow(&BN254_GAMMAS[1], &small(3), &BN254_Q));
+    const TWO_INV: Fq = const_fq(mont::inverse(&mont::to_mont(&small(2), &BN254_Q), &BN254_Q));
+
+    const G1_B: Fq = const_fq(mont::to_mont(&small(3), &BN254_Q));
+    const G1_ONE: (Fq, Fq) = (
+        const_fq(mont::r_pow(&BN254_Q, 1)),
+        const_fq(mont::to_mont(&small(2), &BN254_Q)),
+    );
+    const G2_B: BnFq2<Bn254> = bn254_fq2(&twist_b(&small(3), &BN254_XI, &BN254_Q));
+    const G2_ONE: (BnFq2<Bn254>, BnFq2<Bn254>) = (
+        BnFq2::new(
+            const_fq([
+                0x8e83b5d102bc2026,
+                0xdceb1935497b0172,
+                0xfbb8264797811adf,
+                0x19573841af96503b,
+            ]),
+            const_fq([
+                0xafb4737da84c6140,
+                0x6043dd5a5802d8c4,
+                0x09e950fc52a02f86,
+                0x14fef0833aea7b6b,
+            ]),
+        ),
+        BnFq2::new(
+            const_fq([
+                0x619dfa9d886be9f6,
+                0xfe7fd297f59e9b78,
+                0xff9e1a62231b7dfe,
+                0x28fd7eebae9e4206,
+            ]),
+            const_fq([
+                0x64095b56c71856ee,
+                0xdc57f922327d3cbb,
+                0x55f935be33351076,
+                0x0da4a0e693fd6482,
+            ]),
+        ),
+    );
+
+    fn empty_lines() -> Self::Lines {
+        [EllCoeffs::default(); BN254_LINES]
+    }
+}
+
+#[test]
+#[should_panic(expected = "q = 3 mod 4")]
+fn tower_needs_nonresidue_minus_one() {
+    // -1 = 5^2 mod 13.
+    frobenius_gammas(&[small::<4>(2), small(1)], &small(13));
+}
+
+#[test]
+fn bn254_derived_constants() {
+    use crate::fields::FieldElement;
+
+    // The tables BN254 had written out before they were derived from u.
+    assert_eq!(
+        Bn254::FQ6_FROBENIUS_C1[..4],
+        [
+            BnFq2::one(),
+            BnFq2::new(
+                const_fq([
+                    13075984984163199792,
+                    3782902503040509012,
+                    8791150885551868305,
+                    1825854335138010348
+                ]),
+                const_fq([
+                    7963664994991228759,
+                    12257807996192067905,
+                    13179524609921305146,
+                    2767831111890561987
+                ])
+            ),
+            BnFq2::new(
+                const_fq([
+                    3697675806616062876,
+                    9065277094688085689,
+                    6918009208039626314,
+                    2775033306905974752
+                ]),
+                Fq::zero()
+            ),
+            BnFq2::new(
+                const_fq([
+                    14532872967180610477,
+                    12903226530429559474,
+                    1868623743233345524,
+                    2316889217940299650
+                ]),
+                const_fq([
+                    12447993766991532972,
+                    4121872836076202828,
+                    7630813605053367399,
+                    740282956577754197
+                ])
+            ),
+        ]
+    );
+    assert_eq!(
+        Bn254::FQ6_FROBENIUS_C2[..4],
+        [
+            BnFq2::one(),
+            BnFq2::new(
+                const_fq([
+                    8314163329781907090,
+                    11942187022798819835,
+                    11282677263046157209,
+                    1576150870752482284
+                ]),
+                const_fq([
+                    6763840483288992073,
+                    7118829427391486816,
+                    4016233444936635065,
+                    2630958277570195709
+                ])
+            ),
+            BnFq2::new(
+                const_fq([
+                    8183898218631979349,
+                    12014359695528440611,
+                    12263358156045030468,
+                    3187210487005268291
+                ]),
+                Fq::zero()
+            ),
+            BnFq2::new(
+                const_fq([
+                    4938922280314430175,
+                    13823286637238282975,
+                    15589480384090068090,
+                    481952561930628184
+                ]),
+                const_fq([
+                    3105754162722846417,
+                    11647802298615474591,
+                    13057042392041828081,
+                    1660844386505564338
+                ])
+            ),
+        ]
+    );
+    assert_eq!(
+        Bn254::FQ12_FROBENIUS_C1[..4],
+        [
+            BnFq2::one(),
+            BnFq2::new(
+                const_fq([
+                    12653890742059813127,
+                    14585784200204367754,
+                    1278438861261381767,
+                    212598772761311868
+                ]),
+                const_fq([
+                    11683091849979440498,
+                    14992204589386555739,
+                    15866167890766973222,
+                    1200023580730561873
+                ])
+            ),
+            BnFq2::new(
+                const_fq([
+                    14595462726357228530,
+                    17349508522658994025,
+                    1017833795229664280,
+                    299787779797702374
+                ]),
+                Fq::zero()
+            ),
+            BnFq2::new(
+                const_fq([
+                    3914496794763385213,
+                    790120733010914719,
+                    7322192392869644725,
+                    581366264293887267
+                ]),
+                const_fq([
+                    12817045492518885689,
+                    4440270538777280383,
+                    11178533038884588256,
+                    2767537931541304486
+                ])
+            ),
+        ]
+    );
+    assert_eq!(Bn254::TWIST_MUL_BY_Q_X, Bn254::FQ6_FROBENIUS_C1[1]);
+    assert_eq!(
+        Bn254::TWIST_MUL_BY_Q_Y,
+        BnFq2::new(
+            const_fq([
+                16482010305593259561,
+                13488546290961988299,
+                3578621962720924518,
+                2681173117283399901
+            ]),
+            const_fq([
+                11661927080404088775,
+                553939530661941723,
+                7860678177968807019,
+                3208568454732775116
+            ])
+        )
+    );
+    assert_eq!(
+        Bn254::TWO_INV,
+        const_fq([
+            9781510331150239090,
+            15059239858463337189,
+            10331104244869713732,
+            2249375503248834476
+        ])
+    );
+    assert_eq!(
+        Bn254::G1_B,
+        const_fq([
+            0x7a17caa950ad28d7,
+            0x1f6ac17ae15521b9,
+            0x334bea4e696bd284,
+            0x2a1f6744ce179d8e
+        ])
+    );
+    assert_eq!(
+        Bn254::G1_ONE,
+        (
+            Fq::one(),
+            const_fq([
+                0xa6ba871b8b1e1b3a,
+                0x14f1d651eb8e167b,
+                0xccdd46def0f28c58,
+                0x1c14ef83340fbe5e
+            ])
+        )
+    );
+    assert_eq!(
+        Bn254::G2_B,
+        BnFq2::new(
+            const_fq([
+                0x3bf938e377b802a8,
+                0x020b1b273633535d,
+                0x26b7edf049755260,
+                0x2514c6324384a86d
+            ]),
+            const_fq([
+                0x38e7ecccd1dcff67,
+                0x65f0b37d93ce0d3e,
+                0xd749d0dd22ac00aa,
+                0x0141b9ce4a688d4d
+            ])
+        )
+    );
+    assert_eq!(
+        Bn254::ATE_LOOP_COUNT_NAF,
+        &[
+            1, 0, 1, 0, 0, 0, 3, 0, 3, 0, 0, 0, 3, 0, 1, 0, 3, 0, 0, 3, 0, 0, 0, 0, 0, 1, 0, 0, 3,
+            0, 1, 0, 0, 3, 0, 0, 0, 0, 3, 0, 1, 0, 0, 0, 3, 0, 3, 0, 0, 1, 0, 0, 0, 3, 0, 0, 3, 0,
+            1, 0, 1, 0, 0, 0,
+        ]
+    );
+    assert_eq!(ate_loop_lines(Bn254::ATE_LOOP_COUNT_NAF), 87);
+}
+
+// Fp254BNb: u = -(2^62 + 2^55 + 1), xi = 1 + i, b = 2 and G1 generated by (-1, 1).
+#[cfg(test)]
+pub const FP254BNB_U: u128 = (1 << 62) + (1 << 55) + 1;
+
+#[cfg(test)]
+mod fp254bnb {
+    use super::*;
+    use crate::arith::U256;
+    use crate::fields::fp::fp254bnb::{const_fp254bnb, Fp254BnbFq};
+    use crate::fields::mont_fp::{MontFp, MontParams};
+    use crate::fields::{BnFq12, FieldElement};
+
+    const Q: [u64; 4] = bn_q(FP254BNB_U, true);
+    const XI_C0: u64 = 1;
+    const XI: Fq2Limbs<4> = fq2_to_mont(&[small(XI_C0), small(1)], &Q);
+    const GAMMAS: [Fq2Limbs<4>; 12] = frobenius_gammas(&XI, &Q);
+    const NAF: [u8; ate_loop_count_naf_len(FP254BNB_U, true)] =
+        ate_loop_count_naf(FP254BNB_U, true);
+
+    const fn fq2(a: &Fq2Limbs<4>) -> BnFq2<Fp254Bnb> {
+        BnFq2::new(const_fp254bnb(a[0]), const_fp254bnb(a[1]))
+    }
+
+    const fn fq2_table<const K: usize>(t: &[Fq2Limbs<4>; K]) -> [BnFq2<Fp254Bnb>; K] {
+        let mut out = [fq2(&t[0]); K];
+        let mut k = 1;
+        while k < K {
+            out[k] = fq2(&t[k]);
+            k += 1;
+        }
+        out
+    }
+
+    #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+    pub struct Fp254BnbR;
+
+    impl MontParams<4> for Fp254BnbR {
+        const MODULUS: [u64; 4] = bn_r(FP254BNB_U, true);
+    }
+
+    #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+    pub struct Fp254Bnb;
+
+    impl BnCurveConfig for Fp254Bnb {
+        type Fq = Fp254BnbFq;
+        type Fr = MontFp<Fp254BnbR, 4>;
+        type Lines = [EllCoeffs<Fp254Bnb>; ate_loop_lines(&NAF)];
+
+        const U: u128 = FP254BNB_U;
+        const U_IS_NEGATIVE: bool = true;
+        const ATE_LOOP_COUNT_NAF: &'static [u8] = &NAF;
+
+        const FQ6_NONRESIDUE: BnFq2<Fp254Bnb> = fq2(&XI);
+        const XI_C0: u64 = XI_C0;
+        const FQ6_FROBENIUS_C1: [BnFq2<Fp254Bnb>; 6] = fq2_table(&fq2_table_pow(&GAMMAS, 2, &Q));
+        const FQ6_FROBENIUS_C2: [BnFq2<Fp254Bnb>; 6] = fq2_table(&fq2_table_pow(&GAMMAS, 4, &Q));
+        const FQ12_FROBENIUS_C1: [BnFq2<Fp254Bnb>; 12] = fq2_table(&GAMMAS);
+        const TWIST_MUL_BY_Q_X: BnFq2<Fp254Bnb> = Self::FQ6_FROBENIUS_C1[1];
+        const TWIST_MUL_BY_Q_Y: BnFq2<Fp254Bnb> = fq2(&fq2_pow(&GAMMAS[1], &small(3), &Q));
+        const TWO_INV: Fp254BnbFq =
+            const_fp254bnb(mont::inverse(&mont::to_mont(&small(2), &Q), &Q));
+
+        const G1_B: Fp254BnbFq = const_fp254bnb(mont::to_mont(&small(2), &Q));
+        const G1_ONE: (Fp254BnbFq, Fp254BnbFq) = (
+            const_fp254bnb(mont::to_mont(&sub(Q, &small(1)), &Q)),
+            const_fp254bnb(mont::r_pow(&Q, 1)),
+        );
+        const G2_B: BnFq2<Fp254Bnb> = fq2(&twist_b(&small(2), &XI, &Q));
+        // A point of the twist times the cofactor 2q - r.
+        const G2_ONE: (BnFq2<Fp254Bnb>, BnFq2<Fp254Bnb>) = (
+            fq2(&fq2_to_mont(
+                &[
+                    [
+                        0xe1938b07d677f1de,
+                        0x3c281d9a853a5c75,
+                        0xa18b8f699e70e6ea,
+                        0x0709c6776299080b,
+                    ],
+                    [
+                        0xfb6a35bf403535fb,
+                        0x998398a2d278bad5,
+                        0xe081595f9795b931,
+                        0x2420e103b8df886d,
+                    ],
+                ],
+                &Q,
+            )),
+            fq2(&fq2_to_mont(
+                &[
+                    [
+                        0xd0fcf00e129f438d,
+                        0xc77e396c31ce327a,
+                        0x689b71e1ea69bb51,
+                        0x0b90d0421e0d646f,
+                    ],
+                    [
+                        0x7d67cc7f801cd05c,
+                        0x3c32ffa8509697c0,
+                        0x71721a7cd445d72a,
+                        0x089897a1498f1af5,
+                    ],
+                ],
+                &Q,
+            )),
+        );
+
+        fn empty_lines() -> Self::Lines {
+            [EllCoeffs::default(); ate_loop_lines(&NAF)]
+        }
+    }
+
+    #[test]
+    fn bn_negative_u() {
+        use crate::groups::BnPairingBatch;
+        use crate::groups::GroupElement;
+        use crate::groups::{pairing, AffineG, BnG1Params, BnG2Params, G};
+
+        let p = G::<BnG1Params<Fp254Bnb>>::one();
+        let q = G::<BnG2Params<Fp254Bnb>>::one();
+        let (px, py) = Fp254Bnb::G1_ONE;
+        let (qx, qy) = Fp254Bnb::G2_ONE;
+        assert!(AffineG::<BnG1Params<Fp254Bnb>>::new(px, py).is_ok());
+        assert!(AffineG::<BnG2Params<Fp254Bnb>>::new(qx, qy).is_ok());
+        let r = Fp254BnbR::MODULUS;
+        assert!(p.mul_limbs(&r).is_zero());
+
+        let e = pairing(&p, &q);
+        assert_ne!(e, BnFq12::one());
+        assert_eq!(e.pow(U256(mont::to_u128s(r))), BnFq12::one());
+        assert!(e.is_in_subgroup() && !(e + BnFq12::one()).is_in_subgroup());
+        assert_eq!(e.exp_by_neg_z(), e.cyclotomic_pow(U256([Fp254Bnb::U, 0])));
+        let (a, b) = (U256::from(6u64), U256::from(35u64));
+        assert_eq!(
+            pairing(&p.mul_u256(a), &q.mul_u256(b)),
+            e.pow(U256::from(210u64))
+        );
+
+        let mut batch = BnPairingBatch::default();
+        batch.push(&p.mul_u256(b), &q);
+        batch.push(&-p, &q.mul_u256(b));
+        assert_eq!(batch.finalize(), BnFq12::one());
+        let mut batch = BnPairingBatch::default();
+        batch.push(&p.mul_u256(a), &q.mul_u256(b));
+        let table = q.mul_u256(b).to_affine().unwrap().precompute();
+        let mut tables = BnPairingBatch::default();
+        tables.push_prepared(&p.mul_u256(a), &table);
+        assert_eq!(batch.miller_loop(), tables.miller_loop());
+    }
+}
+
+// BN462 of the pairing-friendly curves draft: u = 2^114 + 2^101 - 2^14 - 1, xi = 2 + i and
+// b = 5, with a q of 462 bits on eight limbs and G1 generated by (-1, 2). Behind the `bn462`
+// feature, which the `bn462` module of the crate wraps.
+#[cfg(any(feature = "bn462", test))]
+pub mod bn462 {
+    use super::*;
+    use crate::fields::mont_fp::{MontFp, MontParams};
+
+    const U: u128 = (1 << 114) + (1 << 101) - (1 << 14) - 1;
+    const Q: [u64; 8] = bn_q(U, false);
+    const XI_C0: u64 = 2;
+    const XI: Fq2Limbs<8> = fq2_to_mont(&[small(XI_C0), small(1)], &Q);
+    const GAMMAS: [Fq2Limbs<8>; 12] = frobenius_gammas(&XI, &Q);
+    const NAF: [u8; ate_loop_count_naf_len(U, false)] = ate_loop_count_naf(U, false);
+
+    #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+    pub struct Bn462Q;
+
+    impl MontParams<8> for Bn462Q {
+        const MODULUS: [u64; 8] = Q;
+    }
+
+    #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+    pub struct Bn462R;
+
+    impl MontParams<8> for Bn462R {
+        const MODULUS: [u64; 8] = bn_r(U, false);
+    }
+
+    pub type Fq = MontFp<Bn462Q, 8>;
+    pub type Fr = MontFp<Bn462R, 8>;
+
+    const fn fq2(a: &Fq2Limbs<8>) -> BnFq2<Bn462> {
+        BnFq2::new(Fq::from_mont(a[0]), Fq::from_mont(a[1]))
+    }
+
+    const fn fq2_table<const K: usize>(t: &[Fq2Limbs<8>; K]) -> [BnFq2<Bn462>; K] {
+        let mut out = [fq2(&t[0]); K];
+        let mut k = 1;
+        while k < K {
+            out[k] = fq2(&t[k]);
+            k += 1;
+        }
+        out
+    }
+
+    #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+    pub struct Bn462;
+
+    impl BnCurveConfig for Bn462 {
+        type Fq = Fq;
+        type Fr = Fr;
+        type Lines = [EllCoeffs<Bn462>; ate_loop_lines(&NAF)];
+
+        const U: u128 = U;
+        const U_IS_NEGATIVE: bool = false;
+        const ATE_LOOP_COUNT_NAF: &'static [u8] = &NAF;
+
+        const FQ6_NONRESIDUE: BnFq2<Bn462> = fq2(&XI);
+        const XI_C0: u64 = XI_C0;
+        const FQ6_FROBENIUS_C1: [BnFq2<Bn462>; 6] = fq2_table(&fq2_table_pow(&GAMMAS, 2, &Q));
+        const FQ6_FROBENIUS_C2: [BnFq2<Bn462>; 6] = fq2_table(&fq2_table_pow(&GAMMAS, 4, &Q));
+        const FQ12_FROBENIUS_C1: [BnFq2<Bn462>; 12] = fq2_table(&GAMMAS);
+        const TWIST_MUL_BY_Q_X: BnFq2<Bn462> = Self::FQ6_FROBENIUS_C1[1];
+        const TWIST_MUL_BY_Q_Y: BnFq2<Bn462> = fq2(&fq2_pow(&GAMMAS[1], &small(3), &Q));
+        const TWO_INV: Fq = Fq::from_mont(mont::inverse(&mont::to_mont(&small(2), &Q), &Q));
+
+        const G1_B: Fq = Fq::from_mont(mont::to_mont(&small(5), &Q));
+        const G1_ONE: (Fq, Fq) = (
+            Fq::from_mont(mont::to_mont(&sub(Q, &small(1)), &Q)),
+            Fq::from_mont(mont::to_mont(&small(2), &Q)),
+        );
+        const G2_B: BnFq2<Bn462> = fq2(&twist_b(&small(5), &XI, &Q));
+        // The point of the twist at x = 1 + i times the cofactor 2q - r.
+        const G2_ONE: (BnFq2<Bn462>, BnFq2<Bn462>) = (
+            fq2(&fq2_to_mont(
+                &[
+                    [
+                        0x1fbc582541faf553,
+                        0x76921728333af7b6,
+                        0xfcc0fbea7cf37ba2,
+                        0xd97311070894b5ce,
+                        0x067c462f93bd399c,
+                        0x35ea4210003902b9,
+                        0x4d0a7307bb1613bb,
+                        0x000000000000164d,
+                    ],
+                    [
+                        0xc15f63d1f3947aab,
+                        0x97bab061af559bdb,
+                        0x7fc076f97741ab61,
+                        0xa5d4f5a9da8e9862,
+                        0x13eff52c1c53f593,
+                        0x72fbc5f2ca1de316,
+                        0xc54c46d58ca1d413,
+                        0x00000000000022e0,
+                    ],
+                ],
+                &Q,
+            )),
+            fq2(&fq2_to_mont(
+                &[
+                    [
+                        0x9f93ff779494eed3,
+                        0xa3caac60afeeab6e,
+                        0x0069d88e9e2a3f9e,
+                        0x1162f15c7c07a5e9,
+                        0x4af7173fc787cc7e,
+                        0xc0f559cf53dd9897,
+                        0x692c471988963f6f,
+                        0x00000000000015cf,
+                    ],
+                    [
+                        0x7403caac95f87dfd,
+                        0xc8c435232221d689,
+                        0x26a3902f12436cd9,
+                        0x064283221535d8dc,
+                        0x8d833fdad2f78b13,
+                        0xfbe50a597b98b3f1,
+                        0x1fd380abb6c68779,
+                        0x00000000000021d2,
+                    ],
+                ],
+                &Q,
+            )),
+        );
+
+        fn empty_lines() -> Self::Lines {
+            [EllCoeffs::default(); ate_loop_lines(&NAF)]
+        }
+    }
+
+    #[test]
+    fn bn_462_bits() {
+        use crate::fields::{BnFq12, FieldElement};
+        use crate::groups::GroupElement;
+        use crate::groups::{pairing, AffineG, BnG1Params, BnG2Params, BnPairingBatch, Error, G};
+
+        assert_eq!(64 * 8 - Q[7].leading_zeros(), 462);
+        assert_eq!(64 * 8 - Bn462R::MODULUS[7].leading_zeros(), 462);
+
+        let p = G::<BnG1Params<Bn462>>::one();
+        let q = G::<BnG2Params<Bn462>>::one();
+        let (px, py) = Bn462::G1_ONE;
+        let (qx, qy) = Bn462::G2_ONE;
+        assert!(AffineG::<BnG1Params<Bn462>>::new(px, py).is_ok());
+        // The subgroup check multiplies by all 462 bits of r.
+        assert!(AffineG::<BnG2Params<Bn462>>::new(qx, qy).is_ok());
+        assert!(p.mul_limbs(&Bn462R::MODULUS).is_zero());
+        // The point at x = 1 + i before the cofactor is on the twist but not in G2.
+        let x = BnFq2::new(Fq::one(), Fq::one());
+        let y = BnFq2::new(
+            Fq::new([
+                0x1c39c6f5236facca,
+                0x51d3618e80d1eae2,
+                0x5d476037c990e779,
+                0x5c83b5639f2d584a,
+                0x8892ca9a96d405e9,
+                0xd9b12f41e0a29e76,
+                0x5c41c314c82f23a2,
+                0x00000000000006a6,
+            ])
+            .unwrap(),
+            Fq::new([
+                0x07c7e9131ca3d349,
+                0xa4b494b17f2e151e,
+                0xa2b89fbf35d614f6,
+                0xb60cd9de28d4b7b5,
+                0x37373565692bfaee,
+                0x264ec7bd2c541963,
+                0x23f43e0b3a10dc5d,
+                0x0000000000001d5e,
+            ])
+            .unwrap(),
+        );
+        assert!(matches!(
+            AffineG::<BnG2Params<Bn462>>::new(x, y),
+            Err(Error::NotInSubgroup)
+        ));
+
+        // r - 1, a scalar of the full width.
+        let minus_one = -Fr::one();
+        assert_eq!(p * minus_one, -p);
+        assert_eq!(q * minus_one, -q);
+
+        let e = pairing(&p, &q);
+        assert_ne!(e, BnFq12::one());
+        assert!(e.is_in_subgroup() && !(e + BnFq12::one()).is_in_subgroup());
+        let (a, b) = (Fr::new(small(6)).unwrap(), Fr::new(small(35)).unwrap());
+        assert_eq!(pairing(&(p * a), &(q * b)), pairing(&(p * (a * b)), &q));
+        assert_eq!(pairing(&(p * minus_one), &q), e.unitary_inverse());
+
+        let mut batch = BnPairingBatch::default();
+        batch.push(&(p * b), &q);
+        batch.push(&-p, &(q * b));
+        assert_eq!(batch.finalize(), BnFq12::one());
+        let mut batch = BnPairingBatch::default();
+        batch.push(&(p * a), &(q * b));
+        let table = (q * b).to_affine().unwrap().precompute();
+        let mut tables = BnPairingBatch::default();
+        tables.push_prepared(&(p * a), &table);
+        assert_eq!(batch.miller_loop(), tables.miller_loop());
+    }
+}
@@ -0,0 +1,320 @@
+// Compressed forms of the cyclotomic subgroup of Fq12, the elements f with
+// f^(q^4 - q^2 + 1) = 1 where pairings land.
+//
+// Over Fq6 the subgroup lies in the torus T2: f = c0 + c1 w with f conj(f) = 1, so
+// f = (m + w) / (m - w) for m = (1 + c0) / c1, half the size. It is also the torus T6 over
+// Fq2, and for m = m0 + m1 v + m2 v^2 the norm of f to Fq4 being one reads
+// 3 m0 m1 = 3 xi m2^2 + 1, which leaves (m1, m2), a third of the size. As xi is not a
+// square, m1 = 0 never satisfies it.
+//
+// Karabina's form keeps four of the six Fq2 coefficients, squares without the other two
+// and recovers them with an inversion, which exponentiation shares between many squares.
+use crate::arith::U256;
+use crate::fields::{BnCurveConfig, BnFq12, BnFq2, BnFq6, FieldElement};
+
+// Squares decompressed with one shared inversion in `cyclotomic_pow_compressed`.
+const KARABINA_BATCH: usize = 16;
+
+impl<C: BnCurveConfig> BnFq12<C> {
+    /// m = (1 + c0) / c1 of a unitary element, None for 1 and -1, where c1 = 0.
+    pub fn torus2_compress(&self) -> Option<BnFq6<C>> {
+        self.c1().inverse().map(|t| (*self.c0() + BnFq6::one()) * t)
+    }
+
+    /// (m + w) / (m - w) = ((m^2 + v) + 2 m w) / (m^2 - v), unitary for every m.
+    pub fn torus2_decompress(m: BnFq6<C>) -> Self {
+        let m2 = m.squared();
+        let v = BnFq6::one().mul_by_nonresidue();
+        let t = (m2 - v).inverse().expect("v is not a square in Fq6");
+        BnFq12::new((m2 + v) * t, (m + m) * t)
+    }
+
+    /// (m1, m2) of the T2 form of a cyclotomic element, None for 1.
+    pub fn torus6_compress(&self) -> Option<(BnFq2<C>, BnFq2<C>)> {
+        self.torus2_compress().map(|m| (m.c1, m.c2))
+    }
+
+    /// The cyclotomic element of `torus6_compress`, None for m1 = 0.
+    pub fn torus6_decompress(m1: BnFq2<C>, m2: BnFq2<C>) -> Option<Self> {
+        let m0 =
+            (triple(m2.squared()).mul_by_nonresidue() + BnFq2::one()) * triple(m1).inverse()?;
+        Some(Self::torus2_decompress(BnFq6::new(m0, m1, m2)))
+    }
+
+    /// self^by for a cyclotomic element, squaring in Karabina's form. The squares at the
+    /// set bits of `by` are decompressed in batches sharing one inversion.
+    pub fn cyclotomic_pow_compressed<I: Into<U256>>(&self, by: I) -> Self {
+        let by = by.into();
+        let mut digits = [0i8; 256];
+        for (i, d) in digits.iter_mut().enumerate() {
+            *d = by.get_bit(i).unwrap() as i8;
+        }
+        self.karabina_pow(&digits)
+    }
+
+    /// self^u for a cyclotomic element, over the non-adjacent form of u in Karabina's form.
+    /// Its squaring takes four Fq2 squarings and two products to the six products of
+    /// `cyclotomic_squared`, which pays for the decompressions even at the 24 non-zero
+    /// digits out of 64 of BN254's u: 16 of them share an inversion.
+    pub fn cyclotomic_pow_naf(&self, u: u128) -> Self {
+        let (digits, len) = naf(u);
+        self.karabina_pow(&digits[..len])
+    }
+
+    // self^e for the signed digits of e, least significant first, inverting by conjugation.
+    fn signed_pow(&self, digits: &[i8]) -> Self {
+        let inverse = self.unitary_inverse();
+        let mut res = Self::one();
+        let mut found_one = false;
+        for &d in digits.iter().rev() {
+            if found_one {
+                res = res.cyclotomic_squared();
+            }
+            if d != 0 {
+                found_one = true;
+                res = res * if d > 0 { *self } else { inverse };
+            }
+        }
+        res
+    }
+
+    fn karabina_pow(&self, digits: &[i8]) -> Self {
+        let top = match digits.iter().rposition(|&d| d != 0) {
+            Some(top) => top,
+            None => return Self::one(),
+        };
+
+        let mut res = Self::one();
+        let mut pending = [KarabinaFq12::new(self); KARABINA_BATCH];
+        let mut signs = [0i8; KARABINA_BATCH];
+        let mut n = 0;
+        let mut square = KarabinaFq12::new(self);
+        for (i, &d) in digits[..=top].iter().enumerate() {
+            if d != 0 {
+                pending[n] = square;
+                signs[n] = d;
+                n += 1;
+            }
+            if n == KARABINA_BATCH || (i == top && n > 0) {
+                let mut out = [Self::one(); KARABINA_BATCH];
+                if !KarabinaFq12::decompress_batch(&pending[..n], &mut out[..n]) {
+                    return self.signed_pow(digits);
+                }
+                for (f, &d) in out[..n].iter().zip(signs.iter()) {
+                    res = res * if d > 0 { *f } else { f.unitary_inverse() };
+                }
+                n = 0;
+            }
+            square = square.squared();
+        }
+        res
+    }
+}
+
+// The non-adjacent form of u, least significant digit first, and its length.
+fn naf(u: u128) -> ([i8; 129], usize) {
+    let mut digits = [0i8; 129];
+    // The value left to write is x + 2^128 top.
+    let (mut x, mut top) = (u, false);
+    let mut len = 0;
+    while x != 0 || top {
+        if x & 3 == 1 {
+            digits[len] = 1;
+            x -= 1;
+        } else if x & 3 == 3 {
+            digits[len] = -1;
+            let (y, carry) = x.overflowing_add(1);
+            x = y;
+            top |= carry;
+        }
+        x = x >> 1 | (top as u128) << 127;
+        top = false;
+        len += 1;
+    }
+    (digits, len)
+}
+
+/// Karabina's compressed cyclotomic element, writing
+/// f = g0 + g1 w^3 + g2 w + g3 w^4 + g4 w^2 + g5 w^5 and keeping (g2, g3, g4, g5).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct KarabinaFq12<C: BnCurveConfig> {
+    g2: BnFq2<C>,
+    g3: BnFq2<C>,
+    g4: BnFq2<C>,
+    g5: BnFq2<C>,
+}
+
+impl<C: BnCurveConfig> KarabinaFq12<C> {
+    pub fn new(f: &BnFq12<C>) -> Self {
+        KarabinaFq12 {
+            g2: f.c1().c0,
+            g3: f.c0().c2,
+            g4: f.c0().c1,
+            g5: f.c1().c2,
+        }
+    }
+
+    pub fn squared(&self) -> Self {
+        let g45 = self.g4 * self.g5;
+        let g23 = self.g2 * self.g3;
+        KarabinaFq12 {
+            g2: double(self.g2 + triple(g45.mul_by_nonresidue())),
+            g3: triple(self.g4.squared() + self.g5.squared().mul_by_nonresidue()) - double(self.g3),
+            g4: triple(self.g2.squared() + self.g3.squared().mul_by_nonresidue()) - double(self.g4),
+            g5: double(self.g5 + triple(g23)),
+        }
+    }
+
+    /// g1 = (xi g5^2 + 3 g4^2 - 2 g3) / (4 g2), or 2 g4 g5 / g3 when g2 = 0, as a fraction.
+    /// Zero over zero is the identity's g1 = 0 when g4 = g5 = 0 and None otherwise, where
+    /// the form loses the element.
+    fn g1_fraction(&self) -> Option<(BnFq2<C>, BnFq2<C>)> {
+        if !self.g2.is_zero() {
+            let num =
+                self.g5.squared().mul_by_nonresidue() + triple(self.g4.squared()) - double(self.g3);
+            Some((num, double(double(self.g2))))
+        } else if !self.g3.is_zero() {
+            Some((double(self.g4 * self.g5), self.g3))
+        } else if self.g4.is_zero() && self.g5.is_zero() {
+            Some((BnFq2::zero(), BnFq2::one()))
+        } else {
+            None
+        }
+    }
+
+    // g0 = xi (2 g1^2 + g2 g5 - 3 g3 g4) + 1.
+    fn with_g1(&self, g1: BnFq2<C>) -> BnFq12<C> {
+        let g0 = (double(g1.squared()) + self.g2 * self.g5 - triple(self.g3 * self.g4))
+            .mul_by_nonresidue()
+            + BnFq2::one();
+        BnFq12::new(
+            BnFq6::new(g0, self.g4, self.g3),
+            BnFq6::new(self.g2, g1, self.g5),
+        )
+    }
+
+    /// Decompresses `compressed` into `out` with one inversion, false if any element is lost.
+    pub fn decompress_batch(compressed: &[Self], out: &mut [BnFq12<C>]) -> bool {
+        assert!(compressed.len() <= KARABINA_BATCH && out.len() == compressed.len());
+        let mut nums = [BnFq2::zero(); KARABINA_BATCH];
+        let mut dens = [BnFq2::one(); KARABINA_BATCH];
+        let mut prefix = [BnFq2::one(); KARABINA_BATCH];
+        let mut acc = BnFq2::one();
+        for (i, c) in compressed.iter().enumerate() {
+            let (num, den) = match c.g1_fraction() {
+                Some(f) => f,
+                None => return false,
+            };
+            nums[i] = num;
+            dens[i] = den;
+            prefix[i] = acc;
+            acc = acc * den;
+        }
+        let mut inv = acc.inverse().expect("denominators are nonzero");
+        for (i, c) in compressed.iter().enumerate().rev() {
+            out[i] = c.with_g1(nums[i] * inv * prefix[i]);
+            inv = inv * dens[i];
+        }
+        true
+    }
+}
+
+fn double<C: BnCurveConfig>(a: BnFq2<C>) -> BnFq2<C> {
+    a + a
+}
+
+fn triple<C: BnCurveConfig>(a: BnFq2<C>) -> BnFq2<C> {
+    a + a + a
+}
+
+#[test]
+fn torus_and_karabina() {
+    use crate::fields::{Fq, Fq12, Fq2, Fq6};
+    use crate::groups::{pairing, GroupElement, G1, G2};
+
+    let fq2 = |a: &str, b: &str| Fq2::new(Fq::from_str(a).unwrap(), Fq::from_str(b).unwrap());
+    let f = Fq12::new(
+        Fq6::new(fq2("1", "2"), fq2("3", "4"), fq2("5", "6")),
+        Fq6::new(fq2("7", "8"), fq2("9", "10"), fq2("11", "12")),
+    );
+    // Cyclotomic but not of order r.
+    let easy = f.unitary_inverse() * f.inverse().unwrap();
+    let easy = easy.frobenius_map(2) * easy;
+    let e = pairing(&G1::one(), &G2::one());
+
+    let mut g = e;
+    for f in [easy, e, e.pow(U256::from(1234567u64)), e.unitary_inverse()].iter_mut() {
+        g = g * *f;
+        for f in [*f, g].iter() {
+            let m = f.torus2_compress().unwrap();
+            assert_eq!(Fq12::torus2_decompress(m), *f);
+            let (m1, m2) = f.torus6_compress().unwrap();
+            assert_eq!(Fq12::torus6_decompress(m1, m2), Some(*f));
+
+            let k = KarabinaFq12::new(f);
+            let mut out = [Fq12::zero(); 2];
+            assert!(KarabinaFq12::decompress_batch(
+                &[k, k.squared().squared()],
+                &mut out
+            ));
+            assert_eq!(out, [*f, f.cyclotomic_squared().cyclotomic_squared()]);
+
+            let by = U256::from(0x44E992B44A6909F1u64);
+            assert_eq!(f.cyclotomic_pow_compressed(by), f.cyclotomic_pow(by));
+            let by = crate::fields::Fr::modulus();
+            assert_eq!(f.cyclotomic_pow_compressed(by), f.cyclotomic_pow(by));
+        }
+    }
+
+    let one = Fq12::one();
+    assert_eq!(one.torus2_compress(), None);
+    assert_eq!(one.cyclotomic_pow_compressed(U256::from(5u64)), one);
+    assert_eq!(e.cyclotomic_pow_compressed(U256::zero()), one);
+    assert_eq!(Fq12::torus6_decompress(Fq2::zero(), Fq2::one()), None);
+
+    // m1 = 0 would need 3 xi m2^2 = -1, but xi is not a square.
+    let three = Fq2::one() + Fq2::one() + Fq2::one();
+    assert!((three.mul_by_nonresidue().inverse().unwrap() * -Fq2::one())
+        .sqrt()
+        .is_none());
+}
+
+#[test]
+fn cyclotomic_pow_naf() {
+    use crate::fields::{Bn254, Fq12};
+    use crate::groups::{pairing, GroupElement, G1, G2};
+
+    for &u in [0, 1, 3, 7, 0x5555, Bn254::U, u128::MAX].iter() {
+        let (digits, len) = naf(u);
+        let mut x = 0u128;
+        for i in (0..len).rev() {
+            x = x.wrapping_mul(2).wrapping_add(digits[i] as u128);
+            assert!(i == 0 || digits[i] == 0 || digits[i - 1] == 0);
+        }
+        assert_eq!(x, u);
+    }
+
+    let e = pairing(&G1::one(), &G2::one());
+    let u = U256([Bn254::U, 0]);
+    let (digits, len) = naf(Bn254::U);
+    for f in [e, e.cyclotomic_squared().unitary_inverse(), Fq12::one()].iter() {
+        let expected = f.cyclotomic_pow(u);
+        assert_eq!(f.cyclotomic_pow_naf(Bn254::U), expected);
+        assert_eq!(f.karabina_pow(&digits[..len]), expected);
+        assert_eq!(f.exp_by_neg_z(), expected.unitary_inverse());
+    }
+
+    let (p, q) = (G1::one(), G2::one());
+    for &(a, b) in [
+        (2u64, 3u64),
+        (0xdeadbeef, 0x1234567890abcdef),
+        (u64::MAX, 7),
+    ]
+    .iter()
+    {
+        let (a, b) = (U256::from(a), U256::from(b));
+        let ab = pairing(&p.mul_u256(a), &q).pow(b);
+        assert_eq!(pairing(&p, &q.mul_u256(b)).pow(a), ab);
+        assert_eq!(pairing(&p.mul_u256(b), &q.mul_u256(a)), ab);
+    }
+}
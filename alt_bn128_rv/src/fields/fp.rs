@@ -1,5 +1,7 @@
 use crate::arith::{U256, U512};
-use crate::fields::FieldElement;
+use crate::fields::bn::{self, Bn254, BnCurveConfig};
+use crate::fields::montgomery;
+use crate::fields::{FieldElement, LazyReduction, PrimeField};
 use core::ops::{Add, Mul, Neg, Sub};
 
 extern "C" {
@@ -7,15 +9,27 @@ extern "C" {
 }
 
 macro_rules! field_impl {
-    ($name:ident, $modulus:expr, $rsquared:expr, $rcubed:expr, $one:expr, $inv:expr) => {
+    ($name:ident, $modulus:expr) => {
         #[derive(Copy, Clone, Default, PartialEq, Eq, Debug)]
         #[repr(C)]
         pub struct $name(U256);
 
+        // R = 2^256, derived from the modulus when the crate is compiled.
+        impl $name {
+            const MODULUS: [u128; 2] = $modulus;
+            const ONE: [u128; 2] =
+                montgomery::to_u128s(montgomery::r_pow(&montgomery::to_u64s(Self::MODULUS), 1));
+            const R2: [u128; 2] =
+                montgomery::to_u128s(montgomery::r_pow(&montgomery::to_u64s(Self::MODULUS), 2));
+            const R3: [u128; 2] =
+                montgomery::to_u128s(montgomery::r_pow(&montgomery::to_u64s(Self::MODULUS), 3));
+            const INV: u128 = montgomery::inv128(Self::MODULUS[0]);
+        }
+
         impl From<$name> for U256 {
             #[inline]
             fn from(mut a: $name) -> Self {
-                a.0.mul(&U256::one(), &U256($modulus), $inv);
+                a.0.mul(&U256::one(), &U256($name::MODULUS), $name::INV);
 
                 a.0
             }
@@ -45,8 +59,8 @@ macro_rules! field_impl {
 
             /// Converts a U256 to an Fp so long as it's below the modulus.
             pub fn new(mut a: U256) -> Option<Self> {
-                if a < U256($modulus) {
-                    a.mul(&U256($rsquared), &U256($modulus), $inv);
+                if a < U256($name::MODULUS) {
+                    a.mul(&U256($name::R2), &U256($name::MODULUS), $name::INV);
 
                     Some($name(a))
                 } else {
@@ -56,25 +70,25 @@ macro_rules! field_impl {
 
             /// Converts a U256 to an Fr regardless of modulus.
             pub fn new_mul_factor(mut a: U256) -> Self {
-                a.mul(&U256($rsquared), &U256($modulus), $inv);
+                a.mul(&U256($name::R2), &U256($name::MODULUS), $name::INV);
                 $name(a)
             }
 
             pub fn interpret(buf: &[u8; 64]) -> Self {
-                $name::new(U512::interpret(buf).divrem(&U256($modulus)).1).unwrap()
+                $name::new(U512::interpret(buf).divrem(&U256($name::MODULUS)).1).unwrap()
             }
 
             /// Returns the modulus
             #[inline]
             #[allow(dead_code)]
             pub fn modulus() -> U256 {
-                U256($modulus)
+                U256($name::MODULUS)
             }
 
             #[inline]
             #[allow(dead_code)]
             pub fn inv(&self) -> u128 {
-                $inv
+                $name::INV
             }
 
             pub fn raw(&self) -> &U256 {
@@ -94,7 +108,7 @@ macro_rules! field_impl {
 
             #[inline]
             fn one() -> Self {
-                $name(U256($one))
+                $name(U256($name::ONE))
             }
 
             #[inline]
@@ -106,20 +120,61 @@ macro_rules! field_impl {
                 if self.is_zero() {
                     None
                 } else {
-                    self.0.invert(&U256($modulus));
-                    self.0.mul(&U256($rcubed), &U256($modulus), $inv);
+                    self.0.invert(&U256($name::MODULUS));
+                    self.0
+                        .mul(&U256($name::R3), &U256($name::MODULUS), $name::INV);
 
                     Some(self)
                 }
             }
         }
 
+        // CKB-VM spends more on the 512-bit additions of lazy reduction than the reductions
+        // it saves, so here a wide value is the reduced product from ll_u256_mont_mul and the
+        // wide sums are field sums. The tower gets the asm multiplication and keeps the rest
+        // of its savings, such as multiplying by xi with additions.
+        impl LazyReduction for $name {
+            type Wide = $name;
+
+            #[inline]
+            fn mul_wide(self, other: $name) -> $name {
+                self * other
+            }
+
+            #[inline]
+            fn add_wide(a: &mut $name, b: &$name) {
+                *a = *a + *b;
+            }
+
+            #[inline]
+            fn sub_wide(a: &mut $name, b: &$name) {
+                *a = *a - *b;
+            }
+
+            #[inline]
+            fn reduce_wide(a: &$name) -> $name {
+                *a
+            }
+        }
+
+        impl PrimeField for $name {
+            type Limbs = [u64; 4];
+
+            fn modulus_limbs() -> [u64; 4] {
+                montgomery::to_u64s($name::MODULUS)
+            }
+
+            fn to_limbs(&self) -> [u64; 4] {
+                montgomery::to_u64s(U256::from(*self).0)
+            }
+        }
+
         impl Add for $name {
             type Output = $name;
 
             #[inline]
             fn add(mut self, other: $name) -> $name {
-                self.0.add(&other.0, &U256($modulus));
+                self.0.add(&other.0, &U256($name::MODULUS));
 
                 self
             }
@@ -130,7 +185,7 @@ macro_rules! field_impl {
 
             #[inline]
             fn sub(mut self, other: $name) -> $name {
-                self.0.sub(&other.0, &U256($modulus));
+                self.0.sub(&other.0, &U256($name::MODULUS));
 
                 self
             }
@@ -143,8 +198,8 @@ macro_rules! field_impl {
             fn mul(mut self, other: $name) -> $name {
                 unsafe {
                     let mut ret = [0u128; 2];
-                    let n: [u128; 2] = $modulus;
-                    let k: u128 = $inv;
+                    let n: [u128; 2] = $name::MODULUS;
+                    let k: u128 = $name::INV;
                     ll_u256_mont_mul(
                         &mut ret as *mut u128 as *mut u64,
                         &self.0 .0 as *const u128 as *const u64,
@@ -163,84 +218,73 @@ macro_rules! field_impl {
 
             #[inline]
             fn neg(mut self) -> $name {
-                self.0.neg(&U256($modulus));
+                self.0.neg(&U256($name::MODULUS));
                 self
             }
         }
     };
 }
 
+// The BN254 moduli, q(u) and r(u) of the BN family at its u.
 field_impl!(
     Fr,
-    [
-        0x2833e84879b9709143e1f593f0000001,
-        0x30644e72e131a029b85045b68181585d
-    ],
-    [
-        0x53fe3ab1e35c59e31bb8e645ae216da7,
-        0x0216d0b17f4e44a58c49833d53bb8085
-    ],
-    [
-        0x2a489cbe1cfbb6b85e94d8e1b4bf0040,
-        0x0cf8594b7fcc657c893cc664a19fcfed
-    ],
-    [
-        0x36fc76959f60cd29ac96341c4ffffffb,
-        0x0e0a77c19a07df2f666ea36f7879462e
-    ],
-    0x6586864b4c6911b3c2e1f593efffffff
+    montgomery::to_u128s(bn::bn_r(Bn254::U, Bn254::U_IS_NEGATIVE))
 );
 
 field_impl!(
     Fq,
-    [
-        0x97816a916871ca8d3c208c16d87cfd47,
-        0x30644e72e131a029b85045b68181585d
-    ],
-    [
-        0xb5e71911d44501fbf32cfc5b538afa89,
-        0x06d89f71cab8351f47ab1eff0a417ff6
-    ],
-    [
-        0x62f210e6a7283db6b1cd6dafda1530df,
-        0x20fd6e902d592544ef7f0b0c0ada0afb
-    ],
-    [
-        0x0a78eb28f5c70b3dd35d438dc58f0d9d,
-        0x0e0a77c19a07df2f666ea36f7879462c
-    ],
-    0x9ede7d651eca6ac987d20782e4866389
+    montgomery::to_u128s(bn::bn_q(Bn254::U, Bn254::U_IS_NEGATIVE))
 );
 
-lazy_static::lazy_static! {
+// The base and scalar fields of secp256k1. Both moduli are above 2^255, which the
+// arithmetic in `arith` handles through its carries.
+field_impl!(
+    Secp256k1Fq,
+    [
+        0xfffffffffffffffffffffffefffffc2f,
+        0xffffffffffffffffffffffffffffffff
+    ]
+);
 
-    static ref FQ: U256 = U256([
-        0x97816a916871ca8d3c208c16d87cfd47,
-        0x30644e72e131a029b85045b68181585d
-    ]);
+field_impl!(
+    Secp256k1Fr,
+    [
+        0xbaaedce6af48a03bbfd25e8cd0364141,
+        0xfffffffffffffffffffffffffffffffe
+    ]
+);
 
-    pub static ref FQ_MINUS3_DIV4: Fq =
-        Fq::new(3.into()).expect("3 is a valid field element and static; qed").neg() *
-        Fq::new(4.into()).expect("4 is a valid field element and static; qed").inverse()
-            .expect("4 has inverse in Fq and is static; qed");
+// The base field of Fp254BNb from ISO/IEC 15946-5, a BN curve at a negative u, for the tests
+// of the generic BN code.
+#[cfg(test)]
+#[allow(dead_code)]
+pub mod fp254bnb {
+    use super::*;
 
-    static ref FQ_MINUS1_DIV2: Fq =
-        Fq::new(1.into()).expect("1 is a valid field element and static; qed").neg() *
-        Fq::new(2.into()).expect("2 is a valid field element and static; qed").inverse()
-            .expect("2 has inverse in Fq and is static; qed");
+    field_impl!(
+        Fp254BnbFq,
+        montgomery::to_u128s(bn::bn_q(bn::FP254BNB_U, true))
+    );
 
+    pub const fn const_fp254bnb(i: [u64; 4]) -> Fp254BnbFq {
+        Fp254BnbFq(U256(montgomery::to_u128s(i)))
+    }
 }
 
+// (q - 3) / 4, the exponent of the square root as q = 3 mod 4. A constant, as a lazily
+// initialised static would take atomics CKB-VM does not have.
+pub(crate) const FQ_SQRT_EXP: U256 = U256([
+    0x65e05aa45a1c72a34f082305b61f3f51,
+    0x0c19139cb84c680a6e14116da0605617,
+]);
+
 impl Fq {
     pub fn sqrt(&self) -> Option<Self> {
-        let a1 = self.pow(*FQ_MINUS3_DIV4);
+        let a1 = self.pow(FQ_SQRT_EXP);
         let a1a = a1 * *self;
-        let a0 = a1 * (a1a);
+        let a0 = a1 * a1a;
 
-        let mut am1 = *FQ;
-        am1.sub(&1.into(), &*FQ);
-
-        if a0 == Fq::new(am1).unwrap() {
+        if a0 == -Fq::one() {
             None
         } else {
             Some(a1a)
@@ -248,7 +292,271 @@ impl Fq {
     }
 }
 
+// (r - 1) / 2 and (p - 1) / 2, the exponents of Euler's criterion.
+const FR_EULER_EXP: U256 = U256([
+    0x9419f4243cdcb848a1f0fac9f8000000,
+    0x183227397098d014dc2822db40c0ac2e,
+]);
+pub(crate) const FQ_EULER_EXP: U256 = U256([
+    0xcbc0b548b438e5469e10460b6c3e7ea3,
+    0x183227397098d014dc2822db40c0ac2e,
+]);
+
+// Tonelli-Shanks constants for r - 1 = 2^28 * t: (t - 1) / 2 and 5^t, a primitive 2^28-th
+// root of unity.
+const FR_TWO_ADICITY: usize = 28;
+const FR_TS_EXP: U256 = U256([
+    0x0c0ac2e9419f4243cdcb848a1f0fac9f,
+    0x0000000183227397098d014dc2822db4,
+]);
+const FR_TS_ROOT: Fr = const_fr([
+    0x636e735580d13d9c,
+    0xa22bf3742445ffd6,
+    0x56452ac01eb203d8,
+    0x1860ef942963f9e7,
+]);
+
+fn legendre<F: FieldElement>(euler: F) -> i8 {
+    if euler.is_zero() {
+        0
+    } else if euler == F::one() {
+        1
+    } else {
+        -1
+    }
+}
+
+// Branch-free `if choice { b } else { a }`.
+fn select(a: Fr, b: Fr, choice: bool) -> Fr {
+    let mask = (choice as u128).wrapping_neg();
+    let (a, b) = ((a.0).0, (b.0).0);
+    Fr(U256([
+        a[0] ^ ((a[0] ^ b[0]) & mask),
+        a[1] ^ ((a[1] ^ b[1]) & mask),
+    ]))
+}
+
+impl Fr {
+    /// 1 for a non-zero square, -1 for a non-square and 0 for zero.
+    pub fn legendre(&self) -> i8 {
+        legendre(self.pow(FR_EULER_EXP))
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.legendre() >= 0
+    }
+
+    /// The parity of the canonical value, `sgn0` of RFC 9380.
+    pub fn sgn0(&self) -> bool {
+        !U256::from(*self).is_even()
+    }
+
+    /// Tonelli-Shanks in the constant-time form of RFC 9380, appendix I.4: the same
+    /// sequence of operations runs whatever the input.
+    pub fn sqrt(&self) -> Option<Self> {
+        let mut z = self.pow(FR_TS_EXP);
+        let mut t = z * z * *self;
+        z = z * *self;
+        let mut b = t;
+        let mut c = FR_TS_ROOT;
+        for i in (2..=FR_TWO_ADICITY).rev() {
+            for _ in 1..i - 1 {
+                b = b * b;
+            }
+            let e = b == Fr::one();
+            z = select(z * c, z, e);
+            c = c * c;
+            t = select(t * c, t, e);
+            b = t;
+        }
+        if z * z == *self {
+            Some(z)
+        } else {
+            None
+        }
+    }
+}
+
+impl Fq {
+    /// 1 for a non-zero square, -1 for a non-square and 0 for zero.
+    pub fn legendre(&self) -> i8 {
+        legendre(self.pow(FQ_EULER_EXP))
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.legendre() >= 0
+    }
+
+    /// The parity of the canonical value, `sgn0` of RFC 9380.
+    pub fn sgn0(&self) -> bool {
+        !U256::from(*self).is_even()
+    }
+}
+
+// (p + 1) / 4 for the secp256k1 base field, p = 3 mod 4.
+const SECP256K1_FQ_SQRT_EXP: U256 = U256([
+    0xffffffffffffffffffffffffbfffff0c,
+    0x3fffffffffffffffffffffffffffffff,
+]);
+
+impl Secp256k1Fq {
+    pub fn sqrt(&self) -> Option<Self> {
+        let a = self.pow(SECP256K1_FQ_SQRT_EXP);
+        if a * a == *self {
+            Some(a)
+        } else {
+            None
+        }
+    }
+}
+
 #[inline]
-pub fn const_fq(i: [u64; 4]) -> Fq {
-    Fq(U256::from(i))
+pub const fn const_fq(i: [u64; 4]) -> Fq {
+    Fq(U256([
+        (i[1] as u128) << 64 | i[0] as u128,
+        (i[3] as u128) << 64 | i[2] as u128,
+    ]))
+}
+
+#[inline]
+pub const fn const_fr(i: [u64; 4]) -> Fr {
+    Fr(U256([
+        (i[1] as u128) << 64 | i[0] as u128,
+        (i[3] as u128) << 64 | i[2] as u128,
+    ]))
+}
+
+#[inline]
+pub const fn const_secp256k1_fq(i: [u64; 4]) -> Secp256k1Fq {
+    Secp256k1Fq(U256([
+        (i[1] as u128) << 64 | i[0] as u128,
+        (i[3] as u128) << 64 | i[2] as u128,
+    ]))
+}
+
+#[test]
+fn sqrt_legendre_sgn0() {
+    let mut x = Fr::from_str("31415926535").unwrap();
+    let non_square = Fr::from_str("5").unwrap();
+    for _ in 0..32 {
+        x = x * x + Fr::one();
+        let sq = x * x;
+        let root = sq.sqrt().unwrap();
+        assert!(root == x || root == -x);
+        assert_eq!(sq.legendre(), 1);
+        assert!(sq.is_square());
+        assert_eq!((sq * non_square).legendre(), -1);
+        assert!((sq * non_square).sqrt().is_none());
+        assert_ne!(x.sgn0(), (-x).sgn0());
+
+        let y = Fq::new(U256::from(x)).unwrap();
+        assert_eq!((y * y).legendre(), 1);
+        assert_eq!((-(y * y)).legendre(), -1);
+        assert_ne!(y.sgn0(), (-y).sgn0());
+    }
+    assert_eq!(Fr::zero().sqrt(), Some(Fr::zero()));
+    assert_eq!(Fr::zero().legendre(), 0);
+    assert_eq!(Fq::zero().legendre(), 0);
+    assert!(Fr::one().sgn0() && !Fr::zero().sgn0() && !(-Fr::one()).sgn0());
+}
+
+#[test]
+fn derived_montgomery_constants() {
+    // R^2, R^3, R and -m^-1 mod 2^128 as they were written out by hand.
+    assert_eq!(
+        (Fr::R2, Fr::R3, Fr::ONE, Fr::INV),
+        (
+            [
+                0x53fe3ab1e35c59e31bb8e645ae216da7,
+                0x0216d0b17f4e44a58c49833d53bb8085
+            ],
+            [
+                0x2a489cbe1cfbb6b85e94d8e1b4bf0040,
+                0x0cf8594b7fcc657c893cc664a19fcfed
+            ],
+            [
+                0x36fc76959f60cd29ac96341c4ffffffb,
+                0x0e0a77c19a07df2f666ea36f7879462e
+            ],
+            0x6586864b4c6911b3c2e1f593efffffff
+        )
+    );
+    assert_eq!(
+        Fr::MODULUS,
+        [
+            0x2833e84879b9709143e1f593f0000001,
+            0x30644e72e131a029b85045b68181585d
+        ]
+    );
+    assert_eq!(
+        (Fq::R2, Fq::R3, Fq::ONE, Fq::INV),
+        (
+            [
+                0xb5e71911d44501fbf32cfc5b538afa89,
+                0x06d89f71cab8351f47ab1eff0a417ff6
+            ],
+            [
+                0x62f210e6a7283db6b1cd6dafda1530df,
+                0x20fd6e902d592544ef7f0b0c0ada0afb
+            ],
+            [
+                0x0a78eb28f5c70b3dd35d438dc58f0d9d,
+                0x0e0a77c19a07df2f666ea36f7879462c
+            ],
+            0x9ede7d651eca6ac987d20782e4866389
+        )
+    );
+    assert_eq!(
+        Fq::MODULUS,
+        [
+            0x97816a916871ca8d3c208c16d87cfd47,
+            0x30644e72e131a029b85045b68181585d
+        ]
+    );
+    assert_eq!(
+        (
+            Secp256k1Fq::R2,
+            Secp256k1Fq::R3,
+            Secp256k1Fq::ONE,
+            Secp256k1Fq::INV
+        ),
+        (
+            [
+                0x0000000000000001000007a2000e90a1,
+                0x00000000000000000000000000000000
+            ],
+            [
+                0x0000000100000b73002bb1e33795f671,
+                0x00000000000000000000000000000000
+            ],
+            [
+                0x000000000000000000000001000003d1,
+                0x00000000000000000000000000000000
+            ],
+            0xbcb223fedc24a059d838091dd2253531
+        )
+    );
+    assert_eq!(
+        (
+            Secp256k1Fr::R2,
+            Secp256k1Fr::R3,
+            Secp256k1Fr::ONE,
+            Secp256k1Fr::INV
+        ),
+        (
+            [
+                0x741496c20e7cf878896cf21467d7d140,
+                0x9d671cd581c69bc5e697f5e45bcd07c6
+            ],
+            [
+                0x0017648444d4322c7bc0cfe0e9ff41ed,
+                0x555d800c18ef116db1b31347f1d0b2da
+            ],
+            [
+                0x4551231950b75fc4402da1732fc9bebf,
+                0x00000000000000000000000000000001
+            ],
+            0x50a51ac834b9ec244b0dff665588b13f
+        )
+    );
 }
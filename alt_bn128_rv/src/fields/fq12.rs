@@ -1,77 +1,49 @@
 use crate::arith::U256;
-use crate::fields::{const_fq, FieldElement, Fq, Fq2, Fq6};
+use crate::fields::{Bn254, BnCurveConfig, BnFq2, BnFq6, FieldElement};
 use core::ops::{Add, Mul, Neg, Sub};
 
-fn frobenius_coeffs_c1(power: usize) -> Fq2 {
-    match power % 12 {
-        0 => Fq2::one(),
-        1 => Fq2::new(
-            const_fq([
-                12653890742059813127,
-                14585784200204367754,
-                1278438861261381767,
-                212598772761311868,
-            ]),
-            const_fq([
-                11683091849979440498,
-                14992204589386555739,
-                15866167890766973222,
-                1200023580730561873,
-            ]),
-        ),
-        2 => Fq2::new(
-            const_fq([
-                14595462726357228530,
-                17349508522658994025,
-                1017833795229664280,
-                299787779797702374,
-            ]),
-            Fq::zero(),
-        ),
-        3 => Fq2::new(
-            const_fq([
-                3914496794763385213,
-                790120733010914719,
-                7322192392869644725,
-                581366264293887267,
-            ]),
-            const_fq([
-                12817045492518885689,
-                4440270538777280383,
-                11178533038884588256,
-                2767537931541304486,
-            ]),
-        ),
-        _ => unimplemented!(),
-    }
-}
-
+/// Fq6[w] / (w^2 - v) of a BN curve.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 #[repr(C)]
-pub struct Fq12 {
-    c0: Fq6,
-    c1: Fq6,
+pub struct BnFq12<C: BnCurveConfig> {
+    c0: BnFq6<C>,
+    c1: BnFq6<C>,
 }
 
-impl Fq12 {
-    pub fn new(c0: Fq6, c1: Fq6) -> Self {
-        Fq12 { c0: c0, c1: c1 }
+pub type Fq12 = BnFq12<Bn254>;
+
+impl<C: BnCurveConfig> BnFq12<C> {
+    pub fn new(c0: BnFq6<C>, c1: BnFq6<C>) -> Self {
+        BnFq12 { c0, c1 }
     }
 
-    fn final_exponentiation_first_chunk(&self) -> Option<Fq12> {
-        match self.inverse() {
-            Some(b) => {
-                let a = self.unitary_inverse();
-                let c = a * b;
-                let d = c.frobenius_map(2);
+    pub fn c0(&self) -> &BnFq6<C> {
+        &self.c0
+    }
 
-                Some(d * c)
-            }
-            None => None,
-        }
+    pub fn c1(&self) -> &BnFq6<C> {
+        &self.c1
     }
 
-    fn final_exponentiation_last_chunk(&self) -> Fq12 {
+    // The easy part to (q^6 - 1) (q^2 + 1). f^(q^6) is the conjugate, and
+    // conj(f) / f = conj(f)^2 / (f conj(f)) where the norm f conj(f) = c0^2 - v c1^2 lies in
+    // Fq6, so an Fq6 inversion replaces the one of Fq12. The result is unitary.
+    fn final_exponentiation_first_chunk(&self) -> Option<Self> {
+        let norm = self.c0.squared() - self.c1.squared().mul_by_nonresidue();
+        let t = norm.inverse()?;
+        let a = self.unitary_inverse().squared();
+        let c = BnFq12::new(a.c0 * t, a.c1 * t);
+
+        Some(c.frobenius_map(2) * c)
+    }
+
+    // The hard part to the multiple 2u (6u^2 + 3u + 1) (q^4 - q^2 + 1) / r, the chain of
+    // Fuentes-Castaneda, Knapp and Rodriguez-Henriquez: three exponentiations by u, three
+    // cyclotomic squarings, ten products and three Frobenius maps. The exponent is of degree
+    // twelve in u, and spread over the four powers of q that Frobenius maps give for free it
+    // leaves coefficients of degree three, so no chain in q and u takes fewer than three
+    // exponentiations by u.
+    fn final_exponentiation_last_chunk(&self) -> Self {
         let a = self.exp_by_neg_z();
         let b = a.cyclotomic_squared();
         let c = b.cyclotomic_squared();
@@ -98,36 +70,58 @@ impl Fq12 {
         let s = self.unitary_inverse();
         let t = s * l;
         let u = t.frobenius_map(3);
-        let v = u * r;
 
-        v
+        u * r
     }
 
-    pub fn final_exponentiation(&self) -> Option<Fq12> {
+    pub fn final_exponentiation(&self) -> Option<Self> {
         self.final_exponentiation_first_chunk()
             .map(|a| a.final_exponentiation_last_chunk())
     }
 
     pub fn frobenius_map(&self, power: usize) -> Self {
-        Fq12 {
+        BnFq12 {
             c0: self.c0.frobenius_map(power),
             c1: self
                 .c1
                 .frobenius_map(power)
-                .scale(frobenius_coeffs_c1(power)),
+                .scale(C::FQ12_FROBENIUS_C1[power % 12]),
         }
     }
 
-    pub fn exp_by_neg_z(&self) -> Fq12 {
-        self.cyclotomic_pow(U256::from([4965661367192848881, 0, 0, 0]))
-            .unitary_inverse()
+    /// f^-u, for the final exponentiation written in u.
+    pub fn exp_by_neg_z(&self) -> Self {
+        let f = self.cyclotomic_pow_naf(C::U);
+        if C::U_IS_NEGATIVE {
+            f
+        } else {
+            f.unitary_inverse()
+        }
     }
 
-    pub fn unitary_inverse(&self) -> Fq12 {
-        Fq12::new(self.c0, -self.c1)
+    /// Whether the element has order r, without exponentiating by r. It must first be in
+    /// the cyclotomic subgroup, f^(q^6 + 1) = 1 and f^(q^4 + 1) = f^(q^2), where cyclotomic
+    /// squaring is valid. Since q - 6u^2 = r on every BN curve, f^r = 1 is then
+    /// f^q = f^(6u^2), two exponentiations by u.
+    pub fn is_in_subgroup(&self) -> bool {
+        if self.is_zero() || *self * self.unitary_inverse() != Self::one() {
+            return false;
+        }
+        if self.frobenius_map(4) * *self != self.frobenius_map(2) {
+            return false;
+        }
+        let a = self.exp_by_neg_z().exp_by_neg_z();
+        let b = a.cyclotomic_squared();
+        self.frobenius_map(1) == b * b.cyclotomic_squared()
+    }
+
+    pub fn unitary_inverse(&self) -> Self {
+        BnFq12::new(self.c0, -self.c1)
     }
 
-    pub fn mul_by_024(&self, ell_0: Fq2, ell_vw: Fq2, ell_vv: Fq2) -> Fq12 {
+    // The sparse product of Aranha et al. with every Fq2 product and sum left unreduced,
+    // one reduction per coefficient of Fq.
+    pub fn mul_by_024(&self, ell_0: BnFq2<C>, ell_vw: BnFq2<C>, ell_vv: BnFq2<C>) -> Self {
         let z0 = self.c0.c0;
         let z1 = self.c0.c1;
         let z2 = self.c0.c2;
@@ -139,30 +133,28 @@ impl Fq12 {
         let x2 = ell_vv;
         let x4 = ell_vw;
 
-        let d0 = z0 * x0;
-        let d2 = z2 * x2;
-        let d4 = z4 * x4;
+        let d0 = z0.mul_wide(&x0);
+        let d2 = z2.mul_wide(&x2);
+        let d4 = z4.mul_wide(&x4);
         let t2 = z0 + z4;
         let t1 = z0 + z2;
         let s0 = z1 + z3 + z5;
 
-        let s1 = z1 * x2;
+        let s1 = z1.mul_wide(&x2);
         let t3 = s1 + d4;
-        let t4 = t3.mul_by_nonresidue() + d0;
-        let z0 = t4;
+        let z0 = t3.mul_by_nonresidue() + d0;
 
-        let t3 = z5 * x4;
+        let t3 = z5.mul_wide(&x4);
         let s1 = s1 + t3;
         let t3 = t3 + d2;
         let t4 = t3.mul_by_nonresidue();
-        let t3 = z1 * x0;
+        let t3 = z1.mul_wide(&x0);
         let s1 = s1 + t3;
-        let t4 = t4 + t3;
-        let z1 = t4;
+        let z1 = t4 + t3;
 
         let t0 = x0 + x2;
-        let t3 = t1 * t0 - d0 - d2;
-        let t4 = z3 * x4;
+        let t3 = t1.mul_wide(&t0) - d0 - d2;
+        let t4 = z3.mul_wide(&x4);
         let s1 = s1 + t4;
         let t3 = t3 + t4;
 
@@ -170,28 +162,25 @@ impl Fq12 {
         let z2 = t3;
 
         let t1 = x2 + x4;
-        let t3 = t0 * t1 - d2 - d4;
+        let t3 = t0.mul_wide(&t1) - d2 - d4;
         let t4 = t3.mul_by_nonresidue();
-        let t3 = z3 * x0;
+        let t3 = z3.mul_wide(&x0);
         let s1 = s1 + t3;
-        let t4 = t4 + t3;
-        let z3 = t4;
+        let z3 = t4 + t3;
 
-        let t3 = z5 * x2;
+        let t3 = z5.mul_wide(&x2);
         let s1 = s1 + t3;
         let t4 = t3.mul_by_nonresidue();
         let t0 = x0 + x4;
-        let t3 = t2 * t0 - d0 - d4;
-        let t4 = t4 + t3;
-        let z4 = t4;
+        let t3 = t2.mul_wide(&t0) - d0 - d4;
+        let z4 = t4 + t3;
 
         let t0 = x0 + x2 + x4;
-        let t3 = s0 * t0 - s1;
-        let z5 = t3;
+        let z5 = s0.mul_wide(&t0) - s1;
 
-        Fq12 {
-            c0: Fq6::new(z0, z1, z2),
-            c1: Fq6::new(z3, z4, z5),
+        BnFq12 {
+            c0: BnFq6::new(z0.reduce(), z1.reduce(), z2.reduce()),
+            c1: BnFq6::new(z3.reduce(), z4.reduce(), z5.reduce()),
         }
     }
 
@@ -203,17 +192,17 @@ impl Fq12 {
         let z1 = self.c1.c1;
         let z5 = self.c1.c2;
 
-        let tmp = z0 * z1;
-        let t0 = (z0 + z1) * (z1.mul_by_nonresidue() + z0) - tmp - tmp.mul_by_nonresidue();
-        let t1 = tmp + tmp;
+        // (a + b s)^2 = (a^2 + b^2 xi) + 2 a b s over Fq4, with the products unreduced.
+        let fq4_square = |a: BnFq2<C>, b: BnFq2<C>| {
+            let tmp = a.mul_wide(&b);
+            let t = (a + b).mul_wide(&(b.mul_by_nonresidue() + a)) - tmp - tmp.mul_by_nonresidue();
+            let tmp = tmp.reduce();
+            (t.reduce(), tmp + tmp)
+        };
 
-        let tmp = z2 * z3;
-        let t2 = (z2 + z3) * (z3.mul_by_nonresidue() + z2) - tmp - tmp.mul_by_nonresidue();
-        let t3 = tmp + tmp;
-
-        let tmp = z4 * z5;
-        let t4 = (z4 + z5) * (z5.mul_by_nonresidue() + z4) - tmp - tmp.mul_by_nonresidue();
-        let t5 = tmp + tmp;
+        let (t0, t1) = fq4_square(z0, z1);
+        let (t2, t3) = fq4_square(z2, z3);
+        let (t4, t5) = fq4_square(z4, z5);
 
         let z0 = t0 - z0;
         let z0 = z0 + z0;
@@ -240,9 +229,9 @@ impl Fq12 {
         let z5 = z5 + z5;
         let z5 = z5 + t3;
 
-        Fq12 {
-            c0: Fq6::new(z0, z4, z3),
-            c1: Fq6::new(z2, z1, z5),
+        BnFq12 {
+            c0: BnFq6::new(z0, z4, z3),
+            c1: BnFq6::new(z2, z1, z5),
         }
     }
 
@@ -266,18 +255,18 @@ impl Fq12 {
     }
 }
 
-impl FieldElement for Fq12 {
+impl<C: BnCurveConfig> FieldElement for BnFq12<C> {
     fn zero() -> Self {
-        Fq12 {
-            c0: Fq6::zero(),
-            c1: Fq6::zero(),
+        BnFq12 {
+            c0: BnFq6::zero(),
+            c1: BnFq6::zero(),
         }
     }
 
     fn one() -> Self {
-        Fq12 {
-            c0: Fq6::one(),
-            c1: Fq6::zero(),
+        BnFq12 {
+            c0: BnFq6::one(),
+            c1: BnFq6::zero(),
         }
     }
 
@@ -288,7 +277,7 @@ impl FieldElement for Fq12 {
     fn squared(&self) -> Self {
         let ab = self.c0 * self.c1;
 
-        Fq12 {
+        BnFq12 {
             c0: (self.c1.mul_by_nonresidue() + self.c0) * (self.c0 + self.c1)
                 - ab
                 - ab.mul_by_nonresidue(),
@@ -297,59 +286,122 @@ impl FieldElement for Fq12 {
     }
 
     fn inverse(self) -> Option<Self> {
-        match (self.c0.squared() - (self.c1.squared().mul_by_nonresidue())).inverse() {
-            Some(t) => Some(Fq12 {
+        (self.c0.squared() - (self.c1.squared().mul_by_nonresidue()))
+            .inverse()
+            .map(|t| BnFq12 {
                 c0: self.c0 * t,
                 c1: -(self.c1 * t),
-            }),
-            None => None,
-        }
+            })
     }
 }
 
-impl Mul for Fq12 {
-    type Output = Fq12;
+impl<C: BnCurveConfig> Mul for BnFq12<C> {
+    type Output = Self;
 
-    fn mul(self, other: Fq12) -> Fq12 {
+    fn mul(self, other: Self) -> Self {
         let aa = self.c0 * other.c0;
         let bb = self.c1 * other.c1;
 
-        Fq12 {
+        BnFq12 {
             c0: bb.mul_by_nonresidue() + aa,
             c1: (self.c0 + self.c1) * (other.c0 + other.c1) - aa - bb,
         }
     }
 }
 
-impl Sub for Fq12 {
-    type Output = Fq12;
+impl<C: BnCurveConfig> Sub for BnFq12<C> {
+    type Output = Self;
 
-    fn sub(self, other: Fq12) -> Fq12 {
-        Fq12 {
+    fn sub(self, other: Self) -> Self {
+        BnFq12 {
             c0: self.c0 - other.c0,
             c1: self.c1 - other.c1,
         }
     }
 }
 
-impl Add for Fq12 {
-    type Output = Fq12;
+impl<C: BnCurveConfig> Add for BnFq12<C> {
+    type Output = Self;
 
-    fn add(self, other: Fq12) -> Fq12 {
-        Fq12 {
+    fn add(self, other: Self) -> Self {
+        BnFq12 {
             c0: self.c0 + other.c0,
             c1: self.c1 + other.c1,
         }
     }
 }
 
-impl Neg for Fq12 {
-    type Output = Fq12;
+impl<C: BnCurveConfig> Neg for BnFq12<C> {
+    type Output = Self;
 
-    fn neg(self) -> Fq12 {
-        Fq12 {
+    fn neg(self) -> Self {
+        BnFq12 {
             c0: -self.c0,
             c1: -self.c1,
         }
     }
 }
+
+#[test]
+fn lazy_reduction() {
+    use crate::fields::{Fq, Fq2, Fq6};
+
+    // Coefficients at and near q - 1 push the unreduced sums to their bounds.
+    let fq2 = |a: i64, b: i64| {
+        let fq = |x: i64| {
+            let f = Fq::new(U256::from(x.unsigned_abs())).unwrap();
+            if x < 0 {
+                -f
+            } else {
+                f
+            }
+        };
+        Fq2::new(fq(a), fq(b))
+    };
+    let fq2_schoolbook = |a: Fq2, b: Fq2| {
+        Fq2::new(
+            *a.real() * *b.real() - *a.imaginary() * *b.imaginary(),
+            *a.real() * *b.imaginary() + *a.imaginary() * *b.real(),
+        )
+    };
+    let fq6_schoolbook = |a: Fq6, b: Fq6| {
+        let m = |x, y| fq2_schoolbook(x, y);
+        let xi = |x: Fq2| m(x, Bn254::FQ6_NONRESIDUE);
+        Fq6::new(
+            m(a.c0, b.c0) + xi(m(a.c1, b.c2) + m(a.c2, b.c1)),
+            m(a.c0, b.c1) + m(a.c1, b.c0) + xi(m(a.c2, b.c2)),
+            m(a.c0, b.c2) + m(a.c1, b.c1) + m(a.c2, b.c0),
+        )
+    };
+
+    let mut f = Fq12::new(
+        Fq6::new(fq2(-1, -1), fq2(-2, -1), fq2(-1, 3)),
+        Fq6::new(fq2(-1, -2), fq2(5, -1), fq2(-3, -7)),
+    );
+    let g = f.final_exponentiation().unwrap();
+    for _ in 0..8 {
+        let (a, b) = (f.c0, f.c1);
+        for (x, y) in [(a.c0, b.c1), (a.c1, a.c1), (b.c2, a.c2)].iter() {
+            assert_eq!(*x * *y, fq2_schoolbook(*x, *y));
+            assert_eq!(
+                x.mul_by_nonresidue(),
+                fq2_schoolbook(*x, Bn254::FQ6_NONRESIDUE)
+            );
+        }
+        assert_eq!(a * b, fq6_schoolbook(a, b));
+        assert_eq!(
+            f.mul_by_024(b.c0, a.c1, b.c2),
+            f * Fq12::new(
+                Fq6::new(b.c0, Fq2::zero(), b.c2),
+                Fq6::new(Fq2::zero(), a.c1, Fq2::zero())
+            )
+        );
+        f = f * f + Fq12::one();
+    }
+
+    let mut g = g;
+    for _ in 0..8 {
+        assert_eq!(g.cyclotomic_squared(), g.squared());
+        g = g.cyclotomic_squared() * g;
+    }
+}
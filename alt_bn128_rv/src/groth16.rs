@@ -0,0 +1,415 @@
+// Groth16 verification, for one proof or for many proofs against the same key.
+//
+// A batch of n proofs is folded with random scalars r_i into one multi-pairing of n + 3
+// pairs, so it costs one final exponentiation instead of n:
+//
+//   prod e(r_i * A_i, B_i) * e(-sum(r_i) * alpha, beta)
+//       * e(-sum(r_i * vk_x_i), gamma) * e(-sum(r_i * C_i), delta) = 1
+//
+// An invalid proof makes the product differ from one except with probability 1/r over
+// the choice of scalars, which must not be known to whoever produced the proofs.
+use crate::{
+    gnark, keccak::Keccak256, msm::msm, AffineG1, AffineG2, Fq, Fr, G2Prepared, Group, Gt,
+    PairingBatch, G1, G2,
+};
+
+pub const MAX_INPUTS: usize = 31;
+
+#[derive(Debug)]
+pub enum Error {
+    TooManyInputs,
+    InvalidInputCount,
+    InvalidKey,
+    UnsupportedCommitments,
+}
+
+#[derive(Copy, Clone)]
+pub struct VerifyingKey {
+    pub alpha_g1: G1,
+    pub beta_g2: G2Prepared,
+    pub gamma_g2: G2Prepared,
+    pub delta_g2: G2Prepared,
+    ic: [G1; MAX_INPUTS + 1],
+    ic_len: usize,
+}
+
+impl VerifyingKey {
+    /// `ic` holds the point for the constant one followed by one point per public input.
+    pub fn new(
+        alpha_g1: G1,
+        beta_g2: G2Prepared,
+        gamma_g2: G2Prepared,
+        delta_g2: G2Prepared,
+        ic: &[G1],
+    ) -> Result<Self, Error> {
+        if ic.is_empty() {
+            return Err(Error::InvalidInputCount);
+        }
+        if ic.len() > MAX_INPUTS + 1 {
+            return Err(Error::TooManyInputs);
+        }
+        let mut vk = VerifyingKey {
+            alpha_g1,
+            beta_g2,
+            gamma_g2,
+            delta_g2,
+            ic: [G1::zero(); MAX_INPUTS + 1],
+            ic_len: ic.len(),
+        };
+        vk.ic[..ic.len()].copy_from_slice(ic);
+        Ok(vk)
+    }
+
+    /// Keys with the commitment extension are rejected, their proofs need an extra
+    /// Pedersen knowledge check that is not done here.
+    pub fn from_gnark(vk: &gnark::VerifyingKey) -> Result<Self, Error> {
+        if vk.nb_commitments() != 0 {
+            return Err(Error::UnsupportedCommitments);
+        }
+        let prepare = |q: G2| AffineG2::from_jacobian(q).map(G2Prepared::new);
+        match (
+            prepare(vk.beta_g2),
+            prepare(vk.gamma_g2),
+            prepare(vk.delta_g2),
+        ) {
+            (Some(beta), Some(gamma), Some(delta)) => {
+                Self::new(vk.alpha_g1, beta, gamma, delta, vk.k())
+            }
+            _ => Err(Error::InvalidKey),
+        }
+    }
+
+    pub fn ic(&self) -> &[G1] {
+        &self.ic[..self.ic_len]
+    }
+
+    fn vk_x(&self, inputs: &[Fr]) -> Result<G1, Error> {
+        if inputs.len() + 1 != self.ic_len {
+            return Err(Error::InvalidInputCount);
+        }
+        let mut acc = self.ic[0];
+        for (x, p) in inputs.iter().zip(self.ic[1..].iter()) {
+            acc = acc + *p * *x;
+        }
+        Ok(acc)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Proof {
+    pub a: G1,
+    pub b: G2,
+    pub c: G1,
+}
+
+impl From<&gnark::Proof> for Proof {
+    fn from(proof: &gnark::Proof) -> Self {
+        Proof {
+            a: proof.a,
+            b: proof.b,
+            c: proof.c,
+        }
+    }
+}
+
+/// A proof together with the public inputs it claims.
+#[derive(Copy, Clone, Debug)]
+pub struct Instance<'a> {
+    pub proof: Proof,
+    pub inputs: &'a [Fr],
+}
+
+/// Where the batching scalars come from: any `FnMut() -> Fr` backed by a random number
+/// generator, or a `Transcript` when the verifier has no randomness of its own.
+pub trait ScalarSource {
+    fn next_scalar(&mut self) -> Fr;
+}
+
+impl<F: FnMut() -> Fr> ScalarSource for F {
+    fn next_scalar(&mut self) -> Fr {
+        self()
+    }
+}
+
+/// Derives the batching scalars by hashing every proof and public input with Keccak-256,
+/// so they are fixed only once the whole batch is.
+pub struct Transcript {
+    seed: [u8; 32],
+    counter: u64,
+}
+
+impl Transcript {
+    pub fn new(instances: &[Instance]) -> Self {
+        let mut h = Keccak256::new();
+        h.update(b"alt_bn128/groth16/batch");
+        h.update(&(instances.len() as u64).to_be_bytes());
+        for instance in instances {
+            absorb_g1(&mut h, instance.proof.a);
+            absorb_g2(&mut h, instance.proof.b);
+            absorb_g1(&mut h, instance.proof.c);
+            h.update(&(instance.inputs.len() as u64).to_be_bytes());
+            for x in instance.inputs {
+                let mut buf = [0u8; 32];
+                x.to_big_endian(&mut buf).unwrap();
+                h.update(&buf);
+            }
+        }
+        Transcript {
+            seed: h.finalize(),
+            counter: 0,
+        }
+    }
+}
+
+impl ScalarSource for Transcript {
+    fn next_scalar(&mut self) -> Fr {
+        let mut wide = [0u8; 64];
+        for (i, half) in wide.chunks_mut(32).enumerate() {
+            let mut h = Keccak256::new();
+            h.update(&self.seed);
+            h.update(&self.counter.to_be_bytes());
+            h.update(&[i as u8]);
+            half.copy_from_slice(&h.finalize());
+        }
+        self.counter += 1;
+        Fr::interpret(&wide)
+    }
+}
+
+fn absorb_fq(h: &mut Keccak256, e: Fq) {
+    let mut buf = [0u8; 32];
+    e.to_big_endian(&mut buf).unwrap();
+    h.update(&buf);
+}
+
+fn absorb_g1(h: &mut Keccak256, p: G1) {
+    match AffineG1::from_jacobian(p) {
+        Some(p) => {
+            absorb_fq(h, p.x());
+            absorb_fq(h, p.y());
+        }
+        None => h.update(&[0u8; 64]),
+    }
+}
+
+fn absorb_g2(h: &mut Keccak256, p: G2) {
+    match AffineG2::from_jacobian(p) {
+        Some(p) => {
+            absorb_fq(h, p.x().real());
+            absorb_fq(h, p.x().imaginary());
+            absorb_fq(h, p.y().real());
+            absorb_fq(h, p.y().imaginary());
+        }
+        None => h.update(&[0u8; 128]),
+    }
+}
+
+pub fn verify(vk: &VerifyingKey, proof: &Proof, inputs: &[Fr]) -> Result<bool, Error> {
+    let vk_x = vk.vk_x(inputs)?;
+    let mut batch = PairingBatch::new();
+    batch.push(proof.a, proof.b);
+    batch.push_prepared(-vk.alpha_g1, &vk.beta_g2);
+    batch.push_prepared(-vk_x, &vk.gamma_g2);
+    batch.push_prepared(-proof.c, &vk.delta_g2);
+    Ok(batch.finalize() == Gt::one())
+}
+
+/// Checks all proofs at once. `Ok(false)` means at least one of them is invalid, see
+/// `find_invalid` to tell which.
+pub fn verify_batch<S: ScalarSource>(
+    vk: &VerifyingKey,
+    instances: &[Instance],
+    source: &mut S,
+) -> Result<bool, Error> {
+    // sum(r_i vk_x_i) as one multi-scalar multiplication over ic, the scalar of ic[0]
+    // being sum(r_i) and that of ic[j] sum(r_i x_ij).
+    let mut scalars = [Fr::zero(); MAX_INPUTS + 1];
+    let scalars = &mut scalars[..vk.ic_len];
+    let mut c = G1::zero();
+    let mut batch = PairingBatch::new();
+    for instance in instances {
+        if instance.inputs.len() + 1 != vk.ic_len {
+            return Err(Error::InvalidInputCount);
+        }
+        let r = source.next_scalar();
+        scalars[0] = scalars[0] + r;
+        for (s, x) in scalars[1..].iter_mut().zip(instance.inputs.iter()) {
+            *s = *s + r * *x;
+        }
+        c = c + instance.proof.c * r;
+        batch.push(instance.proof.a * r, instance.proof.b);
+    }
+    batch.push_prepared(-(vk.alpha_g1 * scalars[0]), &vk.beta_g2);
+    batch.push_prepared(-msm(vk.ic(), scalars), &vk.gamma_g2);
+    batch.push_prepared(-c, &vk.delta_g2);
+    Ok(batch.finalize() == Gt::one())
+}
+
+/// Reports the index of every invalid proof in a batch that failed `verify_batch`, by
+/// splitting it in halves and batch checking each half until single proofs are left.
+/// Costs about 2 * k * log2(n) batch checks for k invalid proofs out of n.
+pub fn find_invalid<S: ScalarSource, F: FnMut(usize)>(
+    vk: &VerifyingKey,
+    instances: &[Instance],
+    source: &mut S,
+    mut report: F,
+) -> Result<(), Error> {
+    bisect(vk, instances, 0, source, &mut report)
+}
+
+// `instances` is known to hold at least one invalid proof.
+fn bisect<S: ScalarSource, F: FnMut(usize)>(
+    vk: &VerifyingKey,
+    instances: &[Instance],
+    offset: usize,
+    source: &mut S,
+    report: &mut F,
+) -> Result<(), Error> {
+    if instances.len() == 1 {
+        if !verify(vk, &instances[0].proof, instances[0].inputs)? {
+            report(offset);
+        }
+        return Ok(());
+    }
+    if instances.is_empty() {
+        return Ok(());
+    }
+    let (l, r) = instances.split_at(instances.len() / 2);
+    if verify_batch(vk, l, source)? {
+        // A batch of valid proofs always passes, so the invalid ones are on the right.
+        return bisect(vk, r, offset + l.len(), source, report);
+    }
+    bisect(vk, l, offset, source, report)?;
+    if r.len() == 1 || !verify_batch(vk, r, source)? {
+        bisect(vk, r, offset + l.len(), source, report)?;
+    }
+    Ok(())
+}
+
+/// Creates a proof for `witness`, the full assignment starting with the constant one,
+/// blinded with `r` and `s`. The witness is not checked against the circuit, an
+/// unsatisfying one gives a proof that does not verify.
+#[cfg(any(feature = "std", test))]
+pub fn prove(pk: &crate::zkey::ProvingKey, witness: &[Fr], r: Fr, s: Fr) -> Result<Proof, Error> {
+    use crate::{domain::EvaluationDomain, msm::msm, zkey::Matrix};
+    use std::vec;
+
+    if witness.len() != pk.n_vars {
+        return Err(Error::InvalidInputCount);
+    }
+    let n = pk.domain_size;
+    let domain = EvaluationDomain::new(n).ok_or(Error::InvalidKey)?;
+    let shift = EvaluationDomain::new(2 * n)
+        .ok_or(Error::InvalidKey)?
+        .group_gen();
+
+    let mut a = vec![Fr::zero(); n];
+    let mut b = vec![Fr::zero(); n];
+    for coeff in pk.coeffs.iter() {
+        let v = match coeff.matrix {
+            Matrix::A => &mut a[coeff.constraint],
+            Matrix::B => &mut b[coeff.constraint],
+        };
+        *v = *v + coeff.value * witness[coeff.signal];
+    }
+    let mut c: vec::Vec<Fr> = a.iter().zip(b.iter()).map(|(a, b)| *a * *b).collect();
+    // A * B - C vanishes on the domain, evaluate it on the coset of odd 2n-th roots.
+    for e in [&mut a, &mut b, &mut c] {
+        domain.ifft(e);
+        domain.coset_fft(e, shift);
+    }
+    let h: vec::Vec<Fr> = (0..n).map(|i| a[i] * b[i] - c[i]).collect();
+
+    let pi_a = pk.alpha_g1 + msm(&pk.a, witness) + pk.delta_g1 * r;
+    let pi_b = pk.beta_g2 + msm(&pk.b_g2, witness) + pk.delta_g2 * s;
+    let b_g1 = pk.beta_g1 + msm(&pk.b_g1, witness) + pk.delta_g1 * s;
+    let pi_c = msm(&pk.c, &witness[pk.n_public + 1..]) + msm(&pk.h, &h) + pi_a * s + b_g1 * r
+        - pk.delta_g1 * (r * s);
+    Ok(Proof {
+        a: pi_a,
+        b: pi_b,
+        c: pi_c,
+    })
+}
+
+#[test]
+fn verify_batch_and_find_invalid() {
+    let vk = gnark::VerifyingKey::from_bytes(include_bytes!("../fixtures/gnark/vk.bin")).unwrap();
+    let vk = VerifyingKey::from_gnark(&vk).unwrap();
+    let proof =
+        gnark::Proof::from_bytes(include_bytes!("../fixtures/gnark/proof_raw.bin")).unwrap();
+    let proof = Proof::from(&proof);
+    let good = [
+        Fr::from_str(gnark::SP1_PUBLIC[0]).unwrap(),
+        Fr::from_str(gnark::SP1_PUBLIC[1]).unwrap(),
+    ];
+    let bad = [good[0], good[1] + Fr::one()];
+    assert!(verify(&vk, &proof, &good).unwrap());
+    assert!(!verify(&vk, &proof, &bad).unwrap());
+
+    // More than 16 pairs, so the multi-pairing runs over several chunks.
+    let mut instances = [Instance {
+        proof,
+        inputs: &good,
+    }; 20];
+    assert!(verify_batch(&vk, &instances, &mut Transcript::new(&instances)).unwrap());
+
+    instances[3].inputs = &bad;
+    instances[17].proof.c = -proof.c;
+    let mut transcript = Transcript::new(&instances);
+    assert!(!verify_batch(&vk, &instances, &mut transcript).unwrap());
+    let mut found = [0usize; 2];
+    let mut n = 0;
+    find_invalid(&vk, &instances, &mut transcript, |i| {
+        found[n] = i;
+        n += 1;
+    })
+    .unwrap();
+    assert_eq!(&found[..n], &[3, 17]);
+
+    // The same with scalars from a caller supplied generator.
+    let mut state = Fr::from_str("7").unwrap();
+    let mut rng = || {
+        state = state * state + Fr::one();
+        state
+    };
+    assert!(!verify_batch(&vk, &instances, &mut rng).unwrap());
+
+    match verify_batch(
+        &vk,
+        &[Instance {
+            proof,
+            inputs: &good[..1],
+        }],
+        &mut rng,
+    ) {
+        Err(Error::InvalidInputCount) => {}
+        r => panic!("unexpected {:?}", r),
+    }
+}
+
+#[test]
+fn prove_circom_circuit() {
+    use crate::{r1cs::R1cs, zkey::ProvingKey};
+
+    let r1cs = R1cs::from_bytes(include_bytes!("../fixtures/circom/mycircuit.r1cs")).unwrap();
+    let pk = ProvingKey::from_bytes(include_bytes!("../fixtures/circom/mycircuit.zkey")).unwrap();
+    assert_eq!(r1cs.n_public(), pk.n_public);
+    assert_eq!(r1cs.n_wires, pk.n_vars);
+
+    // [one, c, a, b] with c = a * b.
+    let witness = ["1", "33", "3", "11"].map(|x| Fr::from_str(x).unwrap());
+    assert!(r1cs.is_satisfied(&witness));
+    let vk = pk.verifying_key().unwrap();
+    let r = Fr::from_str("1234").unwrap();
+    let s = Fr::from_str("5678").unwrap();
+    let proof = prove(&pk, &witness, r, s).unwrap();
+    assert!(verify(&vk, &proof, &witness[1..2]).unwrap());
+    assert!(!verify(&vk, &proof, &witness[2..3]).unwrap());
+
+    let mut bad = witness;
+    bad[1] = Fr::from_str("34").unwrap();
+    assert!(!r1cs.is_satisfied(&bad));
+    let proof = prove(&pk, &bad, r, s).unwrap();
+    assert!(!verify(&vk, &proof, &bad[1..2]).unwrap());
+}
@@ -0,0 +1,235 @@
+// BLAKE3 with 32 bytes of output, the `blake3s` Barretenberg hashes to the curve with.
+// Chunks are compressed as the input arrives and merged on a stack of chaining values.
+const IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+const PERMUTATION: [usize; 16] = [2, 6, 3, 10, 7, 0, 4, 13, 1, 11, 12, 5, 9, 14, 15, 8];
+
+const CHUNK_START: u32 = 1;
+const CHUNK_END: u32 = 2;
+const PARENT: u32 = 4;
+const ROOT: u32 = 8;
+
+const BLOCK: usize = 64;
+const CHUNK: usize = 1024;
+// Enough for 2^54 chunks.
+const MAX_DEPTH: usize = 54;
+
+fn compress(cv: &[u32; 8], block: &[u8; BLOCK], counter: u64, len: usize, flags: u32) -> [u32; 8] {
+    let mut m = [0u32; 16];
+    for (w, b) in m.iter_mut().zip(block.chunks(4)) {
+        *w = u32::from_le_bytes([b[0], b[1], b[2], b[3]]);
+    }
+    let mut v = [0u32; 16];
+    v[..8].copy_from_slice(cv);
+    v[8..12].copy_from_slice(&IV[..4]);
+    v[12] = counter as u32;
+    v[13] = (counter >> 32) as u32;
+    v[14] = len as u32;
+    v[15] = flags;
+
+    for _ in 0..7 {
+        let mut g = |a: usize, b: usize, c: usize, d: usize, x: u32, y: u32| {
+            v[a] = v[a].wrapping_add(v[b]).wrapping_add(x);
+            v[d] = (v[d] ^ v[a]).rotate_right(16);
+            v[c] = v[c].wrapping_add(v[d]);
+            v[b] = (v[b] ^ v[c]).rotate_right(12);
+            v[a] = v[a].wrapping_add(v[b]).wrapping_add(y);
+            v[d] = (v[d] ^ v[a]).rotate_right(8);
+            v[c] = v[c].wrapping_add(v[d]);
+            v[b] = (v[b] ^ v[c]).rotate_right(7);
+        };
+        g(0, 4, 8, 12, m[0], m[1]);
+        g(1, 5, 9, 13, m[2], m[3]);
+        g(2, 6, 10, 14, m[4], m[5]);
+        g(3, 7, 11, 15, m[6], m[7]);
+        g(0, 5, 10, 15, m[8], m[9]);
+        g(1, 6, 11, 12, m[10], m[11]);
+        g(2, 7, 8, 13, m[12], m[13]);
+        g(3, 4, 9, 14, m[14], m[15]);
+        let prev = m;
+        for (w, i) in m.iter_mut().zip(PERMUTATION.iter()) {
+            *w = prev[*i];
+        }
+    }
+
+    let mut out = [0u32; 8];
+    for (i, o) in out.iter_mut().enumerate() {
+        *o = v[i] ^ v[i + 8];
+    }
+    out
+}
+
+// The last compression of a chunk or parent node, kept back until it is known whether it
+// is the root.
+struct Output {
+    cv: [u32; 8],
+    block: [u8; BLOCK],
+    counter: u64,
+    len: usize,
+    flags: u32,
+}
+
+impl Output {
+    fn chaining_value(&self) -> [u32; 8] {
+        compress(&self.cv, &self.block, self.counter, self.len, self.flags)
+    }
+
+    fn root(&self) -> [u32; 8] {
+        compress(&self.cv, &self.block, 0, self.len, self.flags | ROOT)
+    }
+}
+
+fn parent(left: &[u32; 8], right: &[u32; 8]) -> Output {
+    let mut block = [0u8; BLOCK];
+    for (b, w) in block.chunks_mut(4).zip(left.iter().chain(right.iter())) {
+        b.copy_from_slice(&w.to_le_bytes());
+    }
+    Output {
+        cv: IV,
+        block,
+        counter: 0,
+        len: BLOCK,
+        flags: PARENT,
+    }
+}
+
+pub struct Blake3 {
+    // The chunk being read: its chaining value, compressed blocks and buffered block.
+    cv: [u32; 8],
+    chunk: u64,
+    blocks: usize,
+    buf: [u8; BLOCK],
+    len: usize,
+    // Chaining values of completed subtrees, one for every set bit of `chunk`.
+    stack: [[u32; 8]; MAX_DEPTH],
+    depth: usize,
+}
+
+impl Blake3 {
+    pub fn new() -> Self {
+        Blake3 {
+            cv: IV,
+            chunk: 0,
+            blocks: 0,
+            buf: [0; BLOCK],
+            len: 0,
+            stack: [[0; 8]; MAX_DEPTH],
+            depth: 0,
+        }
+    }
+
+    fn flags(&self) -> u32 {
+        if self.blocks == 0 {
+            CHUNK_START
+        } else {
+            0
+        }
+    }
+
+    fn output(&self) -> Output {
+        Output {
+            cv: self.cv,
+            block: self.buf,
+            counter: self.chunk,
+            len: self.len,
+            flags: self.flags() | CHUNK_END,
+        }
+    }
+
+    pub fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            // A full chunk is only finished once more input shows it is not the last.
+            if self.blocks * BLOCK + self.len == CHUNK {
+                let mut cv = self.output().chaining_value();
+                let mut total = self.chunk + 1;
+                while total & 1 == 0 {
+                    self.depth -= 1;
+                    cv = parent(&self.stack[self.depth], &cv).chaining_value();
+                    total >>= 1;
+                }
+                self.stack[self.depth] = cv;
+                self.depth += 1;
+                self.cv = IV;
+                self.chunk += 1;
+                self.blocks = 0;
+                self.buf = [0; BLOCK];
+                self.len = 0;
+            }
+            if self.len == BLOCK {
+                self.cv = compress(&self.cv, &self.buf, self.chunk, BLOCK, self.flags());
+                self.blocks += 1;
+                self.buf = [0; BLOCK];
+                self.len = 0;
+            }
+            let n = core::cmp::min(BLOCK - self.len, data.len());
+            self.buf[self.len..self.len + n].copy_from_slice(&data[..n]);
+            self.len += n;
+            data = &data[n..];
+        }
+    }
+
+    pub fn finalize(self) -> [u8; 32] {
+        let mut out = self.output();
+        for cv in self.stack[..self.depth].iter().rev() {
+            out = parent(cv, &out.chaining_value());
+        }
+        let mut hash = [0u8; 32];
+        for (b, w) in hash.chunks_mut(4).zip(out.root().iter()) {
+            b.copy_from_slice(&w.to_le_bytes());
+        }
+        hash
+    }
+}
+
+pub fn blake3(data: &[u8]) -> [u8; 32] {
+    let mut h = Blake3::new();
+    h.update(data);
+    h.finalize()
+}
+
+#[test]
+fn official_vectors() {
+    let input: [u8; 3072] = core::array::from_fn(|i| (i % 251) as u8);
+    let hex = |s: &str| -> [u8; 32] {
+        core::array::from_fn(|i| u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap())
+    };
+    for (n, h) in [
+        (
+            0,
+            "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262",
+        ),
+        (
+            1,
+            "2d3adedff11b61f14c886e35afa036736dcd87a74d27b5c1510225d0f592e213",
+        ),
+        (
+            1023,
+            "10108970eeda3eb932baac1428c7a2163b0e924c9a9e25b35bba72b28f70bd11",
+        ),
+        (
+            1024,
+            "42214739f095a406f3fc83deb889744ac00df831c10daa55189b5d121c855af7",
+        ),
+        (
+            1025,
+            "d00278ae47eb27b34faecf67b4fe263f82d5412916c1ffd97c8cb7fb814b8444",
+        ),
+        (
+            2048,
+            "e776b6028c7cd22a4d0ba182a8bf62205d2ef576467e838ed6f2529b85fba24a",
+        ),
+        (
+            3072,
+            "b98cb0ff3623be03326b373de6b9095218513e64f1ee2edd2525c7ad1e5cffd2",
+        ),
+    ] {
+        assert_eq!(blake3(&input[..n]), hex(h));
+        let mut split = Blake3::new();
+        for part in input[..n].chunks(100) {
+            split.update(part);
+        }
+        assert_eq!(split.finalize(), hex(h));
+    }
+}
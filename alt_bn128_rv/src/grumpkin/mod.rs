@@ -0,0 +1,292 @@
+// Grumpkin, y^2 = x^3 - 17 over Fr with a group of prime order q, the modulus of Fq. With
+// G1 it forms a cycle: points of either curve are scalars of the other, which is what
+// Noir and Aztec use for recursion and Pedersen hashing. The generator (1, sqrt(-16)) and
+// the hash to curve follow Barretenberg.
+//
+// Encodings: uncompressed points are x and y in 32 big-endian bytes each and compressed
+// points x with the top bit set for an odd y, as `affine_element::compress` of
+// Barretenberg. The point at infinity is all zeros in both, which is never a valid x as
+// -17 is not a square in Fr.
+use crate::arith::{U256, U512};
+use crate::groups::{self, GroupElement, GroupParams, GrumpkinParams};
+use crate::{msm::pippenger, CurveError, Fq, Fr, GroupError};
+use core::ops::{Add, Mul, Neg, Sub};
+
+mod blake3;
+
+use self::blake3::Blake3;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct Point(groups::Grumpkin);
+
+impl Point {
+    pub fn new(x: Fr, y: Fr, z: Fr) -> Self {
+        Point(groups::Grumpkin::new(x.0, y.0, z.0))
+    }
+
+    pub fn zero() -> Self {
+        Point(groups::Grumpkin::zero())
+    }
+
+    pub fn one() -> Self {
+        Point(groups::Grumpkin::one())
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    pub fn double(&self) -> Self {
+        Point(self.0.double())
+    }
+
+    pub fn b() -> Fr {
+        Fr(GrumpkinParams::coeff_b())
+    }
+
+    pub fn normalize(&mut self) {
+        if let Some(a) = self.0.to_affine() {
+            self.0 = a.to_jacobian();
+        }
+    }
+
+    pub fn to_uncompressed(&self) -> [u8; 64] {
+        let mut out = [0u8; 64];
+        if let Some(p) = AffinePoint::from_jacobian(*self) {
+            p.x().into_u256().to_big_endian(&mut out[..32]).unwrap();
+            p.y().into_u256().to_big_endian(&mut out[32..]).unwrap();
+        }
+        out
+    }
+
+    pub fn from_uncompressed(bytes: &[u8; 64]) -> Result<Self, CurveError> {
+        if bytes.iter().all(|b| *b == 0) {
+            return Ok(Point::zero());
+        }
+        let (x, y) = (fr(&bytes[..32])?, fr(&bytes[32..])?);
+        AffinePoint::new(x, y)
+            .map_err(|_| CurveError::NotMember)
+            .map(Into::into)
+    }
+
+    pub fn to_compressed(&self) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        if let Some(p) = AffinePoint::from_jacobian(*self) {
+            p.x().into_u256().to_big_endian(&mut out).unwrap();
+            if p.y().into_u256().get_bit(0).unwrap() {
+                out[0] |= 0x80;
+            }
+        }
+        out
+    }
+
+    pub fn from_compressed(bytes: &[u8; 32]) -> Result<Self, CurveError> {
+        if bytes.iter().all(|b| *b == 0) {
+            return Ok(Point::zero());
+        }
+        let mut buf = *bytes;
+        let odd = buf[0] & 0x80 != 0;
+        buf[0] &= 0x7f;
+        let x = fr(&buf)?;
+        AffinePoint::from_x(x, odd)
+            .ok_or(CurveError::NotMember)
+            .map(Into::into)
+    }
+}
+
+// A canonical big-endian element.
+fn fr(bytes: &[u8]) -> Result<Fr, CurveError> {
+    U256::from_slice(bytes)
+        .ok()
+        .and_then(Fr::new)
+        .ok_or(CurveError::InvalidEncoding)
+}
+
+impl Add<Point> for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point(self.0 + other.0)
+    }
+}
+
+impl Sub<Point> for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point(self.0 - other.0)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point(-self.0)
+    }
+}
+
+impl Mul<Fq> for Point {
+    type Output = Point;
+
+    fn mul(self, other: Fq) -> Point {
+        Point(self.0 * other.0)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(C)]
+pub struct AffinePoint(groups::AffineGrumpkin);
+
+impl AffinePoint {
+    pub fn new(x: Fr, y: Fr) -> Result<Self, GroupError> {
+        Ok(AffinePoint(groups::AffineGrumpkin::new(x.0, y.0)?))
+    }
+
+    /// Builds a point without the curve check, for constants known to be valid.
+    pub const fn new_unchecked(x: Fr, y: Fr) -> Self {
+        AffinePoint(groups::AffineGrumpkin::new_unchecked(x.0, y.0))
+    }
+
+    /// The point with abscissa `x` and a y of the given parity, if there is one.
+    pub fn from_x(x: Fr, odd: bool) -> Option<Self> {
+        let mut y = (x * x * x + Point::b()).sqrt()?;
+        if y.into_u256().get_bit(0).unwrap() != odd {
+            y = -y;
+        }
+        Some(AffinePoint::new_unchecked(x, y))
+    }
+
+    pub fn x(&self) -> Fr {
+        Fr(*self.0.x())
+    }
+
+    pub fn y(&self) -> Fr {
+        Fr(*self.0.y())
+    }
+
+    pub fn from_jacobian(p: Point) -> Option<Self> {
+        p.0.to_affine().map(AffinePoint)
+    }
+}
+
+impl From<AffinePoint> for Point {
+    fn from(affine: AffinePoint) -> Self {
+        Point(affine.0.to_jacobian())
+    }
+}
+
+pub fn msm(bases: &[Point], scalars: &[Fq]) -> Point {
+    assert_eq!(bases.len(), scalars.len());
+    pippenger(bases, scalars.iter().map(|s| s.into_u256()), Point::zero())
+}
+
+/// `affine_element::hash_to_curve` of Barretenberg: x is the 512-bit BLAKE3 hash of
+/// `seed || attempt || 0` followed by that of `seed || attempt || 1`, reduced modulo r,
+/// and the top bit of the first hash picks the parity of y. The attempt counter starts at
+/// 0 and is incremented until x is on the curve.
+pub fn hash_to_curve(seed: &[u8]) -> AffinePoint {
+    for attempt in 0..=255u8 {
+        let hash = |i: u8| {
+            let mut h = Blake3::new();
+            h.update(seed);
+            h.update(&[attempt, i]);
+            h.finalize()
+        };
+        let mut buf = [0u8; 64];
+        buf[..32].copy_from_slice(&hash(0));
+        buf[32..].copy_from_slice(&hash(1));
+        let x = Fr::new(U512::interpret(&buf).divrem(&Fr::modulus()).1).unwrap();
+        if let Some(p) = AffinePoint::from_x(x, buf[0] > 127) {
+            return p;
+        }
+    }
+    // Each attempt fails with probability about 1/2.
+    unreachable!()
+}
+
+/// `derive_generators` of Barretenberg: the points `hash_to_curve(blake3(domain) || i)` for
+/// `start <= i < start + out.len()`, with i in 4 big-endian bytes. Pedersen commitments
+/// in Noir use the domain "DEFAULT_DOMAIN_SEPARATOR".
+pub fn derive_generators(domain: &[u8], start: u32, out: &mut [AffinePoint]) {
+    let mut preimage = [0u8; 36];
+    preimage[..32].copy_from_slice(&blake3::blake3(domain));
+    for (i, g) in out.iter_mut().enumerate() {
+        preimage[32..].copy_from_slice(&(start + i as u32).to_be_bytes());
+        *g = hash_to_curve(&preimage);
+    }
+}
+
+#[test]
+fn grumpkin_arithmetic() {
+    let hex = |s: &str| Fr::new(U256::from_hex_str(s).unwrap()).unwrap();
+
+    // The generator of Barretenberg's grumpkin::g1.
+    let g = AffinePoint::from_jacobian(Point::one()).unwrap();
+    assert_eq!(g.x(), Fr::one());
+    assert_eq!(
+        g.y(),
+        hex("0000000000000002cf135e7506a45d632d270d45f1181294833fc48d823f272c")
+    );
+    assert!(AffinePoint::new(g.x(), g.y()).is_ok());
+    assert!(AffinePoint::new(g.x(), g.x()).is_err());
+
+    // The group order is q.
+    let minus_one = -Fq::one();
+    assert_eq!(Point::one() * minus_one + Point::one(), Point::zero());
+    assert_eq!(Point::one() * minus_one, -Point::one());
+
+    let a = Fq::from_str("123456789").unwrap();
+    let b = Fq::from_str("987654321").unwrap();
+    let p = Point::one() * a;
+    let q = Point::one() * b;
+    assert_eq!(p + q, Point::one() * (a + b));
+    assert_eq!(p - q, Point::one() * (a - b));
+    assert_eq!(p.double(), p + p);
+
+    let mut bases = [Point::zero(); 10];
+    let mut scalars = [Fq::zero(); 10];
+    let mut s = Fq::from_str("5").unwrap();
+    for i in 0..10 {
+        s = s * s + Fq::one();
+        bases[i] = p * s;
+        scalars[i] = s * a;
+    }
+    let expect = bases
+        .iter()
+        .zip(scalars.iter())
+        .fold(Point::zero(), |acc, (p, s)| acc + *p * *s);
+    assert_eq!(msm(&bases, &scalars), expect);
+
+    for p in [Point::zero(), Point::one(), p, -p, q] {
+        assert_eq!(Point::from_uncompressed(&p.to_uncompressed()).unwrap(), p);
+        assert_eq!(Point::from_compressed(&p.to_compressed()).unwrap(), p);
+    }
+    let mut bad = Point::one().to_compressed();
+    bad[0] |= 0x40;
+    assert!(Point::from_compressed(&bad).is_err());
+
+    // From fixtures/grumpkin/gen.py, a transcription of Barretenberg's derive_generators.
+    let mut gens = [g; 2];
+    derive_generators(b"DEFAULT_DOMAIN_SEPARATOR", 0, &mut gens);
+    assert_eq!(
+        gens[0],
+        AffinePoint::new(
+            hex("090f0e7be6065116fed5a40ef6dcb8b21cdcf61fee39385aacd0f77ed77bc2cb"),
+            hex("2a8cd707e93d8e00c13de277fb2357107d10997af166f26171c3d6dc186858df"),
+        )
+        .unwrap()
+    );
+    assert_eq!(
+        gens[1],
+        AffinePoint::new(
+            hex("094ddaa183922b3255350c912bd5c50dfd3500cc9cc8be258741a2286580773c"),
+            hex("12d6552a5f617b04f933e088af8667950a5883540284fc7ee7534bc995a39444"),
+        )
+        .unwrap()
+    );
+    let mut next = [g; 1];
+    derive_generators(b"DEFAULT_DOMAIN_SEPARATOR", 1, &mut next);
+    assert_eq!(next[0], gens[1]);
+}
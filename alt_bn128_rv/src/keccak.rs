@@ -0,0 +1,166 @@
+// Keccak-256 as used by Ethereum, that is with the original 0x01 padding rather than the
+// 0x06 of SHA3-256.
+
+const RATE: usize = 136;
+
+const RC: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808a,
+    0x8000000080008000,
+    0x000000000000808b,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008a,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000a,
+    0x000000008000808b,
+    0x800000000000008b,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800a,
+    0x800000008000000a,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+const RHO: [u32; 24] = [
+    1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44,
+];
+
+const PI: [usize; 24] = [
+    10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1,
+];
+
+fn keccak_f(a: &mut [u64; 25]) {
+    for rc in RC.iter() {
+        let mut c = [0u64; 5];
+        for x in 0..5 {
+            c[x] = a[x] ^ a[x + 5] ^ a[x + 10] ^ a[x + 15] ^ a[x + 20];
+        }
+        for x in 0..5 {
+            let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+            for y in 0..5 {
+                a[x + 5 * y] ^= d;
+            }
+        }
+
+        let mut last = a[1];
+        for i in 0..24 {
+            let t = a[PI[i]];
+            a[PI[i]] = last.rotate_left(RHO[i]);
+            last = t;
+        }
+
+        for y in 0..5 {
+            let row = [
+                a[5 * y],
+                a[5 * y + 1],
+                a[5 * y + 2],
+                a[5 * y + 3],
+                a[5 * y + 4],
+            ];
+            for x in 0..5 {
+                a[5 * y + x] = row[x] ^ (!row[(x + 1) % 5] & row[(x + 2) % 5]);
+            }
+        }
+
+        a[0] ^= rc;
+    }
+}
+
+#[derive(Clone)]
+pub struct Keccak256 {
+    state: [u64; 25],
+    buf: [u8; RATE],
+    len: usize,
+}
+
+impl Default for Keccak256 {
+    fn default() -> Self {
+        Keccak256::new()
+    }
+}
+
+impl Keccak256 {
+    pub fn new() -> Self {
+        Keccak256 {
+            state: [0; 25],
+            buf: [0; RATE],
+            len: 0,
+        }
+    }
+
+    fn absorb_block(&mut self) {
+        for (s, b) in self.state.iter_mut().zip(self.buf.chunks(8)) {
+            let mut w = [0u8; 8];
+            w.copy_from_slice(b);
+            *s ^= u64::from_le_bytes(w);
+        }
+        keccak_f(&mut self.state);
+        self.len = 0;
+    }
+
+    pub fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            let n = core::cmp::min(RATE - self.len, data.len());
+            self.buf[self.len..self.len + n].copy_from_slice(&data[..n]);
+            self.len += n;
+            data = &data[n..];
+            if self.len == RATE {
+                self.absorb_block();
+            }
+        }
+    }
+
+    pub fn finalize(mut self) -> [u8; 32] {
+        for b in self.buf[self.len..].iter_mut() {
+            *b = 0;
+        }
+        self.buf[self.len] ^= 0x01;
+        self.buf[RATE - 1] ^= 0x80;
+        self.absorb_block();
+        let mut out = [0u8; 32];
+        for (o, s) in out.chunks_mut(8).zip(self.state.iter()) {
+            o.copy_from_slice(&s.to_le_bytes());
+        }
+        out
+    }
+}
+
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    let mut h = Keccak256::new();
+    h.update(data);
+    h.finalize()
+}
+
+#[test]
+fn keccak256_vectors() {
+    use crate::ethereum::ut::hex2bin;
+
+    let mut expect = [0u8; 32];
+    hex2bin(
+        "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470",
+        &mut expect,
+    );
+    assert_eq!(keccak256(b""), expect);
+    hex2bin(
+        "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45",
+        &mut expect,
+    );
+    assert_eq!(keccak256(b"abc"), expect);
+
+    // Crosses the 136 byte block boundary in uneven pieces.
+    let data = [0x61u8; 300];
+    let mut h = Keccak256::new();
+    for chunk in data.chunks(7) {
+        h.update(chunk);
+    }
+    assert_eq!(h.finalize(), keccak256(&data));
+}
@@ -0,0 +1,353 @@
+// Modular exponentiation of big-endian numbers of any length, for the MODEXP precompile.
+// Moduli are limited to MAX_LEN bytes so everything lives on the stack; base and exponent
+// are read as byte streams and may be as long as the caller likes.
+//
+// Odd moduli use Montgomery multiplication, the same reduction as `arith::mul_reduce` for
+// any number of 64-bit limbs. An even modulus m = q * 2^t is split: the power is taken
+// modulo the odd q with Montgomery and modulo 2^t by truncated products, then the two are
+// recombined with the CRT.
+pub const MAX_LEN: usize = 1024;
+
+const LIMBS: usize = MAX_LEN / 8;
+
+type Limbs = [u64; LIMBS];
+
+#[inline(always)]
+fn mac(a: u64, b: u64, c: u64, carry: &mut u64) -> u64 {
+    let t = a as u128 + b as u128 * c as u128 + *carry as u128;
+    *carry = (t >> 64) as u64;
+    t as u64
+}
+
+fn from_be(bytes: &[u8]) -> Limbs {
+    let mut out = [0u64; LIMBS];
+    for (i, b) in bytes.iter().rev().enumerate() {
+        out[i / 8] |= (*b as u64) << (8 * (i % 8));
+    }
+    out
+}
+
+fn to_be(a: &Limbs, out: &mut [u8]) {
+    let len = out.len();
+    for (i, b) in out.iter_mut().enumerate() {
+        let j = len - 1 - i;
+        *b = (a[j / 8] >> (8 * (j % 8))) as u8;
+    }
+}
+
+// Number of limbs up to the most significant non-zero one.
+fn limbs_len(a: &Limbs) -> usize {
+    a.iter().rposition(|l| *l != 0).map_or(0, |i| i + 1)
+}
+
+fn is_one(a: &Limbs, n: usize) -> bool {
+    n > 0 && a[0] == 1 && a[1..n].iter().all(|l| *l == 0)
+}
+
+fn geq(a: &Limbs, b: &Limbs, n: usize) -> bool {
+    for i in (0..n).rev() {
+        if a[i] != b[i] {
+            return a[i] > b[i];
+        }
+    }
+    true
+}
+
+fn sub_assign(a: &mut Limbs, b: &Limbs, n: usize) {
+    let mut borrow = false;
+    for i in 0..n {
+        let (x, c0) = a[i].overflowing_sub(b[i]);
+        let (y, c1) = x.overflowing_sub(borrow as u64);
+        a[i] = y;
+        borrow = c0 | c1;
+    }
+}
+
+// Shifts in one bit on the right and returns the bit shifted out of n limbs.
+fn shl1(a: &mut Limbs, n: usize, bit: bool) -> bool {
+    let mut carry = bit as u64;
+    for l in a[..n].iter_mut() {
+        let top = *l >> 63;
+        *l = *l << 1 | carry;
+        carry = top;
+    }
+    carry != 0
+}
+
+// Keeps the low t bits.
+fn truncate(a: &mut Limbs, t: usize) {
+    for (i, l) in a.iter_mut().enumerate() {
+        if 64 * i >= t {
+            *l = 0;
+        } else if 64 * (i + 1) > t {
+            *l &= (1 << (t % 64)) - 1;
+        }
+    }
+}
+
+// The product modulo 2^t.
+fn mul_lo(a: &Limbs, b: &Limbs, t: usize) -> Limbs {
+    let n = t.div_ceil(64);
+    let mut out = [0u64; LIMBS];
+    for i in 0..n {
+        let mut carry = 0;
+        for j in 0..n - i {
+            out[i + j] = mac(out[i + j], a[i], b[j], &mut carry);
+        }
+    }
+    truncate(&mut out, t);
+    out
+}
+
+// A modulus above 1 with n significant limbs.
+struct Modulus {
+    m: Limbs,
+    n: usize,
+}
+
+impl Modulus {
+    // r = 2r + bit mod m, for r < m.
+    fn double(&self, r: &mut Limbs, bit: bool) {
+        if shl1(r, self.n, bit) || geq(r, &self.m, self.n) {
+            sub_assign(r, &self.m, self.n);
+        }
+    }
+
+    // A big-endian byte stream reduced modulo m, one bit at a time.
+    fn reduce<I: Iterator<Item = u8>>(&self, bytes: I) -> Limbs {
+        let mut r = [0u64; LIMBS];
+        for b in bytes {
+            for i in (0..8).rev() {
+                self.double(&mut r, b >> i & 1 != 0);
+            }
+        }
+        r
+    }
+}
+
+// Montgomery arithmetic modulo an odd m with R = 2^(64n).
+struct Montgomery {
+    modulus: Modulus,
+    inv: u64,
+    // R mod m and R^2 mod m.
+    r: Limbs,
+    r2: Limbs,
+}
+
+impl Montgomery {
+    fn new(modulus: Modulus) -> Self {
+        // -m^-1 mod 2^64 by Newton's iteration, each step doubling the correct bits.
+        let mut x = 1u64;
+        for _ in 0..6 {
+            x = x.wrapping_mul(2u64.wrapping_sub(modulus.m[0].wrapping_mul(x)));
+        }
+        let mut r = [0u64; LIMBS];
+        r[0] = 1;
+        for _ in 0..64 * modulus.n {
+            modulus.double(&mut r, false);
+        }
+        let mut r2 = r;
+        for _ in 0..64 * modulus.n {
+            modulus.double(&mut r2, false);
+        }
+        Montgomery {
+            modulus,
+            inv: x.wrapping_neg(),
+            r,
+            r2,
+        }
+    }
+
+    // a * b / R mod m, coarsely integrated operand scanning.
+    fn mul(&self, a: &Limbs, b: &Limbs) -> Limbs {
+        let (m, n) = (&self.modulus.m, self.modulus.n);
+        let mut t = [0u64; LIMBS + 2];
+        for bi in &b[..n] {
+            let mut carry = 0;
+            for j in 0..n {
+                t[j] = mac(t[j], a[j], *bi, &mut carry);
+            }
+            let (s, c) = t[n].overflowing_add(carry);
+            t[n] = s;
+            t[n + 1] = c as u64;
+
+            let u = t[0].wrapping_mul(self.inv);
+            let mut carry = 0;
+            mac(t[0], u, m[0], &mut carry);
+            for j in 1..n {
+                t[j - 1] = mac(t[j], u, m[j], &mut carry);
+            }
+            let (s, c) = t[n].overflowing_add(carry);
+            t[n - 1] = s;
+            t[n] = t[n + 1] + c as u64;
+        }
+        let mut out = [0u64; LIMBS];
+        out[..n].copy_from_slice(&t[..n]);
+        if t[n] != 0 || geq(&out, m, n) {
+            sub_assign(&mut out, m, n);
+        }
+        out
+    }
+
+    fn pow<B, E>(&self, base: B, exp: E) -> Limbs
+    where
+        B: Iterator<Item = u8>,
+        E: Iterator<Item = u8>,
+    {
+        let b = self.mul(&self.modulus.reduce(base), &self.r2);
+        let mut x = self.r;
+        let mut started = false;
+        for e in exp {
+            for i in (0..8).rev() {
+                if started {
+                    x = self.mul(&x, &x);
+                }
+                if e >> i & 1 != 0 {
+                    x = self.mul(&x, &b);
+                    started = true;
+                }
+            }
+        }
+        let mut one = [0u64; LIMBS];
+        one[0] = 1;
+        self.mul(&x, &one)
+    }
+}
+
+// base^exp mod 2^t for t > 0.
+fn pow_pow2<B, E>(base: B, exp: E, t: usize) -> Limbs
+where
+    B: Iterator<Item = u8>,
+    E: Iterator<Item = u8>,
+{
+    let n = t.div_ceil(64);
+    let mut b = [0u64; LIMBS];
+    for byte in base {
+        for i in (0..8).rev() {
+            shl1(&mut b, n, byte >> i & 1 != 0);
+        }
+        truncate(&mut b, t);
+    }
+    let mut x = [0u64; LIMBS];
+    x[0] = 1;
+    for e in exp {
+        for i in (0..8).rev() {
+            x = mul_lo(&x, &x, t);
+            if e >> i & 1 != 0 {
+                x = mul_lo(&x, &b, t);
+            }
+        }
+    }
+    x
+}
+
+/// Writes `base^exp mod modulus` in `output`, which has the length of `modulus`, with
+/// 0^0 = 1 and a zero result for a zero modulus.
+pub fn modexp<B, E>(base: B, exp: E, modulus: &[u8], output: &mut [u8])
+where
+    B: Iterator<Item = u8> + Clone,
+    E: Iterator<Item = u8> + Clone,
+{
+    assert!(modulus.len() <= MAX_LEN);
+    assert_eq!(modulus.len(), output.len());
+    let m = from_be(modulus);
+    let n = limbs_len(&m);
+    if n == 0 || is_one(&m, n) {
+        output.iter_mut().for_each(|b| *b = 0);
+        return;
+    }
+
+    let t = m.iter().position(|l| *l != 0).unwrap();
+    let t = 64 * t + m[t].trailing_zeros() as usize;
+    if t == 0 {
+        let mont = Montgomery::new(Modulus { m, n });
+        to_be(&mont.pow(base, exp), output);
+        return;
+    }
+
+    // m = q * 2^t with q odd.
+    let mut q = [0u64; LIMBS];
+    for (i, l) in q.iter_mut().enumerate() {
+        let j = i + t / 64;
+        if j < LIMBS {
+            *l = m[j] >> (t % 64);
+            if !t.is_multiple_of(64) && j + 1 < LIMBS {
+                *l |= m[j + 1] << (64 - t % 64);
+            }
+        }
+    }
+    let qn = limbs_len(&q);
+    let lo = pow_pow2(base.clone(), exp.clone(), t);
+    if is_one(&q, qn) {
+        to_be(&lo, output);
+        return;
+    }
+    let hi = Montgomery::new(Modulus { m: q, n: qn }).pow(base, exp);
+
+    // x = hi + q * ((lo - hi) * q^-1 mod 2^t), below m.
+    let mut q_inv = [0u64; LIMBS];
+    q_inv[0] = 1;
+    let mut two = [0u64; LIMBS];
+    two[0] = 2;
+    let mut bits = 1;
+    while bits < t {
+        // q_inv = q_inv * (2 - q * q_inv) mod 2^t
+        let mut d = two;
+        sub_assign(&mut d, &mul_lo(&q, &q_inv, t), LIMBS);
+        q_inv = mul_lo(&q_inv, &d, t);
+        bits *= 2;
+    }
+    let mut d = lo;
+    sub_assign(&mut d, &hi, LIMBS);
+    truncate(&mut d, t);
+    let h = mul_lo(&d, &q_inv, t);
+
+    let mut x = hi;
+    let hn = limbs_len(&h);
+    for i in 0..hn {
+        let mut carry = 0;
+        for j in 0..qn {
+            if i + j < n {
+                x[i + j] = mac(x[i + j], h[i], q[j], &mut carry);
+            }
+        }
+        let mut k = i + qn;
+        while carry != 0 && k < n {
+            let (s, c) = x[k].overflowing_add(carry);
+            x[k] = s;
+            carry = c as u64;
+            k += 1;
+        }
+    }
+    to_be(&x, output);
+}
+
+#[test]
+fn small_moduli() {
+    let mut out = [0u8; 2];
+    for m in 0..600u64 {
+        for (base, exp) in [(0u64, 0u8), (0, 5), (3, 0), (7, 13), (255, 255), (1000, 77)] {
+            let expect = if m == 0 {
+                0
+            } else {
+                (0..exp).fold(1 % m, |acc, _| acc * (base % m) % m)
+            };
+            let modulus = (m as u16).to_be_bytes();
+            let base = (base as u16).to_be_bytes();
+            modexp(
+                base.iter().copied(),
+                [exp].iter().copied(),
+                &modulus,
+                &mut out,
+            );
+            assert_eq!(
+                u16::from_be_bytes(out) as u64,
+                expect,
+                "{} {} {}",
+                m,
+                base[1],
+                exp
+            );
+        }
+    }
+}
@@ -0,0 +1,317 @@
+// Verifier for the PLONK proofs of `snarkjs plonk prove`, reading the verification key,
+// proof and public signals in the JSON that snarkjs exports.
+//
+// Challenges come from the snarkjs Keccak-256 transcript: points are hashed as uncompressed
+// big-endian affine coordinates and scalars as 32 big-endian bytes. The linearization
+// follows snarkjs, so both openings are checked with a single two-pair pairing:
+//
+//   e(-(Wxi + u * Wxiw), X_2) * e(xi * Wxi + u * xi * w * Wxiw + F - E, G2) = 1
+use crate::{
+    arith::U256, domain::EvaluationDomain, json, keccak::Keccak256, AffineG1, Fr, G2Prepared,
+    Group, GroupError, Gt, PairingBatch, G1, G2,
+};
+
+#[derive(Debug)]
+pub enum Error {
+    InvalidJson(&'static str),
+    InvalidNumber,
+    Group(GroupError),
+    InvalidKey,
+    InvalidInputCount,
+}
+
+impl From<GroupError> for Error {
+    fn from(ge: GroupError) -> Self {
+        Error::Group(ge)
+    }
+}
+
+impl From<json::Error> for Error {
+    fn from(e: json::Error) -> Self {
+        match e {
+            json::Error::InvalidJson(msg) => Error::InvalidJson(msg),
+            json::Error::InvalidNumber => Error::InvalidNumber,
+            json::Error::Group(ge) => Error::Group(ge),
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct VerifyingKey {
+    pub n_public: usize,
+    pub domain: EvaluationDomain,
+    pub k1: Fr,
+    pub k2: Fr,
+    pub qm: G1,
+    pub ql: G1,
+    pub qr: G1,
+    pub qo: G1,
+    pub qc: G1,
+    pub s1: G1,
+    pub s2: G1,
+    pub s3: G1,
+    pub x_2: G2Prepared,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Proof {
+    pub a: G1,
+    pub b: G1,
+    pub c: G1,
+    pub z: G1,
+    pub t1: G1,
+    pub t2: G1,
+    pub t3: G1,
+    pub wxi: G1,
+    pub wxiw: G1,
+    pub eval_a: Fr,
+    pub eval_b: Fr,
+    pub eval_c: Fr,
+    pub eval_s1: Fr,
+    pub eval_s2: Fr,
+    pub eval_zw: Fr,
+}
+
+fn check_header(src: &str) -> Result<(), Error> {
+    if json::str(json::get(src, "protocol"))? != "plonk" {
+        return Err(Error::InvalidJson("protocol is not plonk"));
+    }
+    if json::str(json::get(src, "curve"))? != "bn128" {
+        return Err(Error::InvalidJson("curve is not bn128"));
+    }
+    Ok(())
+}
+
+fn field<'a>(src: &'a str, key: &'static str) -> Result<&'a str, Error> {
+    json::get(src, key).ok_or(Error::InvalidJson(key))
+}
+
+fn g1(src: &str, key: &'static str) -> Result<G1, Error> {
+    Ok(json::g1(field(src, key)?)?)
+}
+
+fn scalar(src: &str, key: &'static str) -> Result<Fr, Error> {
+    Ok(json::parse_fr(json::str(Some(field(src, key)?))?)?)
+}
+
+fn integer(src: &str, key: &'static str) -> Result<usize, Error> {
+    field(src, key)?
+        .parse()
+        .map_err(|_| Error::InvalidJson(key))
+}
+
+impl VerifyingKey {
+    /// Parses the key written by `snarkjs zkey export verificationkey`.
+    pub fn from_json(src: &str) -> Result<Self, Error> {
+        check_header(src)?;
+        let power = integer(src, "power")?;
+        let domain = EvaluationDomain::new(1 << power.min(63)).ok_or(Error::InvalidKey)?;
+        if domain.log_size() as usize != power || scalar(src, "w")? != domain.group_gen() {
+            return Err(Error::InvalidKey);
+        }
+        Ok(VerifyingKey {
+            n_public: integer(src, "nPublic")?,
+            domain,
+            k1: scalar(src, "k1")?,
+            k2: scalar(src, "k2")?,
+            qm: g1(src, "Qm")?,
+            ql: g1(src, "Ql")?,
+            qr: g1(src, "Qr")?,
+            qo: g1(src, "Qo")?,
+            qc: g1(src, "Qc")?,
+            s1: g1(src, "S1")?,
+            s2: g1(src, "S2")?,
+            s3: g1(src, "S3")?,
+            x_2: G2Prepared::new(json::affine_g2(field(src, "X_2")?)?),
+        })
+    }
+}
+
+impl Proof {
+    /// Parses the proof written by `snarkjs plonk prove`.
+    pub fn from_json(src: &str) -> Result<Self, Error> {
+        check_header(src)?;
+        Ok(Proof {
+            a: g1(src, "A")?,
+            b: g1(src, "B")?,
+            c: g1(src, "C")?,
+            z: g1(src, "Z")?,
+            t1: g1(src, "T1")?,
+            t2: g1(src, "T2")?,
+            t3: g1(src, "T3")?,
+            wxi: g1(src, "Wxi")?,
+            wxiw: g1(src, "Wxiw")?,
+            eval_a: scalar(src, "eval_a")?,
+            eval_b: scalar(src, "eval_b")?,
+            eval_c: scalar(src, "eval_c")?,
+            eval_s1: scalar(src, "eval_s1")?,
+            eval_s2: scalar(src, "eval_s2")?,
+            eval_zw: scalar(src, "eval_zw")?,
+        })
+    }
+}
+
+/// Parses the array of decimal strings in `public.json` into `out`, returning the filled
+/// prefix.
+pub fn public_signals<'a>(src: &str, out: &'a mut [Fr]) -> Result<&'a [Fr], Error> {
+    let mut n = 0;
+    for raw in json::items(src) {
+        let slot = out.get_mut(n).ok_or(Error::InvalidInputCount)?;
+        *slot = json::parse_fr(json::str(Some(raw))?)?;
+        n += 1;
+    }
+    Ok(&out[..n])
+}
+
+struct Transcript(Keccak256);
+
+impl Transcript {
+    fn new() -> Self {
+        Transcript(Keccak256::new())
+    }
+
+    fn g1(&mut self, p: G1) {
+        let mut buf = [0u8; 64];
+        match AffineG1::from_jacobian(p) {
+            Some(p) => {
+                p.x().to_big_endian(&mut buf[..32]).unwrap();
+                p.y().to_big_endian(&mut buf[32..]).unwrap();
+            }
+            None => buf[0] = 0x40,
+        }
+        self.0.update(&buf);
+    }
+
+    fn fr(&mut self, e: Fr) {
+        let mut buf = [0u8; 32];
+        e.into_u256().to_big_endian(&mut buf).unwrap();
+        self.0.update(&buf);
+    }
+
+    fn challenge(self) -> Fr {
+        let h = self.0.finalize();
+        Fr::new_mul_factor(U256::from_slice(&h).unwrap())
+    }
+}
+
+pub fn verify(vk: &VerifyingKey, proof: &Proof, public: &[Fr]) -> Result<bool, Error> {
+    if public.len() != vk.n_public {
+        return Err(Error::InvalidInputCount);
+    }
+
+    let mut t = Transcript::new();
+    for p in [vk.qm, vk.ql, vk.qr, vk.qo, vk.qc, vk.s1, vk.s2, vk.s3] {
+        t.g1(p);
+    }
+    for x in public {
+        t.fr(*x);
+    }
+    for p in [proof.a, proof.b, proof.c] {
+        t.g1(p);
+    }
+    let beta = t.challenge();
+
+    let mut t = Transcript::new();
+    t.fr(beta);
+    let gamma = t.challenge();
+
+    let mut t = Transcript::new();
+    t.fr(beta);
+    t.fr(gamma);
+    t.g1(proof.z);
+    let alpha = t.challenge();
+
+    let mut t = Transcript::new();
+    t.fr(alpha);
+    for p in [proof.t1, proof.t2, proof.t3] {
+        t.g1(p);
+    }
+    let xi = t.challenge();
+
+    let mut t = Transcript::new();
+    t.fr(xi);
+    for e in [
+        proof.eval_a,
+        proof.eval_b,
+        proof.eval_c,
+        proof.eval_s1,
+        proof.eval_s2,
+        proof.eval_zw,
+    ] {
+        t.fr(e);
+    }
+    let v1 = t.challenge();
+    let mut v = [v1; 5];
+    for i in 1..5 {
+        v[i] = v[i - 1] * v1;
+    }
+
+    let mut t = Transcript::new();
+    t.g1(proof.wxi);
+    t.g1(proof.wxiw);
+    let u = t.challenge();
+
+    let zh = vk.domain.evaluate_vanishing_polynomial(xi);
+    let xin = zh + Fr::one();
+    let l1 = vk.domain.evaluate_lagrange(0, xi);
+    let mut pi = Fr::zero();
+    for (i, x) in public.iter().enumerate() {
+        pi = pi - *x * vk.domain.evaluate_lagrange(i, xi);
+    }
+
+    let (a, b, c) = (proof.eval_a, proof.eval_b, proof.eval_c);
+    let alpha2 = alpha * alpha;
+    let e1 = (a + beta * proof.eval_s1 + gamma) * (b + beta * proof.eval_s2 + gamma);
+    let r0 = pi - l1 * alpha2 - alpha * proof.eval_zw * e1 * (c + gamma);
+
+    // The commitment D to the linearization polynomial.
+    let betaxi = beta * xi;
+    let d2 =
+        alpha * (a + betaxi + gamma) * (b + betaxi * vk.k1 + gamma) * (c + betaxi * vk.k2 + gamma)
+            + l1 * alpha2
+            + u;
+    let d3 = alpha * beta * proof.eval_zw * e1;
+    let d = vk.qm * (a * b) + vk.ql * a + vk.qr * b + vk.qo * c + vk.qc + proof.z * d2
+        - vk.s3 * d3
+        - (proof.t1 + proof.t2 * xin + proof.t3 * (xin * xin)) * zh;
+
+    let f = d + proof.a * v[0] + proof.b * v[1] + proof.c * v[2] + vk.s1 * v[3] + vk.s2 * v[4];
+    let e = G1::one()
+        * (-r0
+            + v[0] * a
+            + v[1] * b
+            + v[2] * c
+            + v[3] * proof.eval_s1
+            + v[4] * proof.eval_s2
+            + u * proof.eval_zw);
+
+    let a1 = proof.wxi + proof.wxiw * u;
+    let b1 = proof.wxi * xi + proof.wxiw * (u * xi * vk.domain.group_gen()) + f - e;
+
+    let mut batch = PairingBatch::new();
+    batch.push_prepared(-a1, &vk.x_2);
+    batch.push(b1, G2::one());
+    Ok(batch.finalize() == Gt::one())
+}
+
+#[test]
+fn verify_snarkjs_proof() {
+    let vk =
+        VerifyingKey::from_json(include_str!("../fixtures/plonk/verification_key.json")).unwrap();
+    let proof = Proof::from_json(include_str!("../fixtures/plonk/proof.json")).unwrap();
+    let mut buf = [Fr::zero(); 4];
+    let public = public_signals(include_str!("../fixtures/plonk/public.json"), &mut buf).unwrap();
+    // [out, x] of input.json, outputs first as snarkjs orders them.
+    assert_eq!(
+        public,
+        [Fr::from_str("38").unwrap(), Fr::from_str("5").unwrap()]
+    );
+    assert!(verify(&vk, &proof, public).unwrap());
+
+    let wrong = [public[0] + Fr::one(), public[1]];
+    assert!(!verify(&vk, &proof, &wrong).unwrap());
+    let mut bad = proof;
+    bad.eval_zw = bad.eval_zw + Fr::one();
+    assert!(!verify(&vk, &bad, public).unwrap());
+    assert!(verify(&vk, &proof, &public[..1]).is_err());
+}
@@ -0,0 +1,265 @@
+// The Poseidon hash of circomlib over Fr: x^5 S-box, 8 full rounds and the partial round
+// counts of circomlib for widths 2 to 17, that is 1 to 16 inputs. The state starts with
+// the capacity element, zero for a plain hash, followed by the inputs, and the hash is
+// the first element of the permuted state.
+//
+// The round constants and MDS matrices are decoded from constants.bin (see gen.py) at
+// compile time into one static per width, already in Montgomery form. `Poseidon<T>`
+// refers to the statics of width T only, so a program that hashes with a single width
+// does not link the others.
+use crate::Fr;
+
+const R_F: usize = 8;
+const R_P: [usize; 16] = [
+    56, 57, 56, 60, 60, 63, 64, 63, 60, 66, 60, 65, 70, 60, 64, 68,
+];
+pub const MAX_WIDTH: usize = 17;
+
+const CONSTANTS: &[u8] = include_bytes!("constants.bin");
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    InvalidInputCount,
+    InvalidOutputCount,
+}
+
+const fn rounds(t: usize) -> usize {
+    R_F + R_P[t - 2]
+}
+
+// Byte offset of the constants of width t.
+const fn offset(t: usize) -> usize {
+    let mut off = 0;
+    let mut w = 2;
+    while w < t {
+        off += (rounds(w) * w + w * w) * 32;
+        w += 1;
+    }
+    off
+}
+
+const fn decode<const N: usize>(start: usize) -> [Fr; N] {
+    let mut out = [Fr::from_montgomery([0; 4]); N];
+    let mut i = 0;
+    while i < N {
+        let mut limbs = [0u64; 4];
+        let mut j = 0;
+        while j < 32 {
+            limbs[j / 8] |= (CONSTANTS[start + i * 32 + j] as u64) << (8 * (j % 8));
+            j += 1;
+        }
+        out[i] = Fr::from_montgomery(limbs);
+        i += 1;
+    }
+    out
+}
+
+macro_rules! params {
+    ($($t:literal => $c:ident, $m:ident;)*) => {
+        $(
+            static $c: [Fr; rounds($t) * $t] = decode(offset($t));
+            static $m: [Fr; $t * $t] = decode(offset($t) + rounds($t) * $t * 32);
+        )*
+
+        /// Round constants and row-major MDS matrix of width `t`.
+        #[inline(always)]
+        fn params(t: usize) -> Option<(&'static [Fr], &'static [Fr])> {
+            match t {
+                $($t => Some((&$c, &$m)),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+params! {
+    2 => C2, M2;
+    3 => C3, M3;
+    4 => C4, M4;
+    5 => C5, M5;
+    6 => C6, M6;
+    7 => C7, M7;
+    8 => C8, M8;
+    9 => C9, M9;
+    10 => C10, M10;
+    11 => C11, M11;
+    12 => C12, M12;
+    13 => C13, M13;
+    14 => C14, M14;
+    15 => C15, M15;
+    16 => C16, M16;
+    17 => C17, M17;
+}
+
+fn sbox(x: Fr) -> Fr {
+    let x2 = x * x;
+    x2 * x2 * x
+}
+
+fn permute_with(state: &mut [Fr], c: &[Fr], m: &[Fr]) {
+    let t = state.len();
+    let mut tmp = [Fr::zero(); MAX_WIDTH];
+    let full = R_F / 2;
+    let n = c.len() / t;
+    for (r, rc) in c.chunks(t).enumerate() {
+        for (s, k) in state.iter_mut().zip(rc.iter()) {
+            *s = *s + *k;
+        }
+        if r < full || r >= n - full {
+            for s in state.iter_mut() {
+                *s = sbox(*s);
+            }
+        } else {
+            state[0] = sbox(state[0]);
+        }
+        for (i, row) in m.chunks(t).enumerate() {
+            tmp[i] = row
+                .iter()
+                .zip(state.iter())
+                .fold(Fr::zero(), |acc, (a, b)| acc + *a * *b);
+        }
+        state.copy_from_slice(&tmp[..t]);
+    }
+}
+
+/// The Poseidon permutation of width `T`, 2 to 17.
+pub struct Poseidon<const T: usize>;
+
+impl<const T: usize> Poseidon<T> {
+    pub fn permute(state: &mut [Fr; T]) {
+        let (c, m) = params(T).expect("Poseidon width must be 2 to 17");
+        permute_with(state, c, m);
+    }
+
+    /// The hash of `T - 1` inputs.
+    pub fn hash(inputs: &[Fr]) -> Fr {
+        assert_eq!(inputs.len() + 1, T);
+        let mut state = [Fr::zero(); T];
+        state[1..].copy_from_slice(inputs);
+        Self::permute(&mut state);
+        state[0]
+    }
+}
+
+/// The permutation of width `state.len()`.
+pub fn permute(state: &mut [Fr]) -> Result<(), Error> {
+    let (c, m) = params(state.len()).ok_or(Error::InvalidInputCount)?;
+    permute_with(state, c, m);
+    Ok(())
+}
+
+/// `poseidon(inputs)` of circomlibjs, for 1 to 16 inputs.
+pub fn hash(inputs: &[Fr]) -> Result<Fr, Error> {
+    let mut out = [Fr::zero()];
+    hash_ex(inputs, Fr::zero(), &mut out)?;
+    Ok(out[0])
+}
+
+/// `poseidon(inputs, init_state, out.len())` of circomlibjs, circomlib's `PoseidonEx`: the
+/// first `out.len()` elements of the permuted state `[init_state, inputs..]`.
+pub fn hash_ex(inputs: &[Fr], init_state: Fr, out: &mut [Fr]) -> Result<(), Error> {
+    let t = inputs.len() + 1;
+    if !(2..=MAX_WIDTH).contains(&t) {
+        return Err(Error::InvalidInputCount);
+    }
+    if out.len() > t {
+        return Err(Error::InvalidOutputCount);
+    }
+    let mut state = [Fr::zero(); MAX_WIDTH];
+    state[0] = init_state;
+    state[1..t].copy_from_slice(inputs);
+    permute(&mut state[..t])?;
+    out.copy_from_slice(&state[..out.len()]);
+    Ok(())
+}
+
+/// A sponge over the permutation of width `width`, absorbing `width - 1` elements per
+/// permutation. Squeezing after at most `width - 1` elements gives the same result as
+/// `hash` of those elements padded with zeros.
+#[derive(Clone)]
+pub struct Sponge {
+    state: [Fr; MAX_WIDTH],
+    width: usize,
+    pos: usize,
+}
+
+impl Sponge {
+    pub fn new(width: usize) -> Result<Self, Error> {
+        if !(2..=MAX_WIDTH).contains(&width) {
+            return Err(Error::InvalidInputCount);
+        }
+        Ok(Sponge {
+            state: [Fr::zero(); MAX_WIDTH],
+            width,
+            pos: 0,
+        })
+    }
+
+    pub fn absorb(&mut self, x: Fr) {
+        if self.pos == self.width - 1 {
+            permute(&mut self.state[..self.width]).unwrap();
+            self.pos = 0;
+        }
+        self.state[1 + self.pos] = self.state[1 + self.pos] + x;
+        self.pos += 1;
+    }
+
+    pub fn squeeze(&mut self) -> Fr {
+        permute(&mut self.state[..self.width]).unwrap();
+        self.pos = 0;
+        self.state[0]
+    }
+}
+
+#[test]
+fn circomlibjs_vectors() {
+    let n = |i: u64| Fr::new(crate::arith::U256::from(i)).unwrap();
+    let hex = |s: &str| Fr::new(crate::arith::U256::from_hex_str(s).unwrap()).unwrap();
+
+    let h12 = hex("115cc0f5e7d690413df64c6b9662e9cf2a3617f2743245519e19607a4417189a");
+    assert_eq!(hash(&[n(1), n(2)]), Ok(h12));
+    assert_eq!(Poseidon::<3>::hash(&[n(1), n(2)]), h12);
+    assert_eq!(
+        hash(&[n(1)]),
+        Ok(hex(
+            "29176100eaa962bdc1fe6c654d6a3c130e96a4d1168b33848b897dc502820133"
+        ))
+    );
+    assert_eq!(
+        hash(&[n(0), n(0)]),
+        Ok(hex(
+            "2098f5fb9e239eab3ceac3f27b81e481dc3124d55ffed523a839ee8446b64864"
+        ))
+    );
+    assert_eq!(
+        hash(&[n(1), n(2), n(3), n(4)]),
+        Ok(hex(
+            "299c867db6c1fdd79dcefa40e4510b9837e60ebb1ce0663dbaa525df65250465"
+        ))
+    );
+    assert_eq!(
+        hash(&[n(1), n(2), n(3), n(4), n(5), n(6)]),
+        Ok(Fr::from_str(
+            "20400040500897583745843009878988256314335038853985262692600694741116813247201"
+        )
+        .unwrap())
+    );
+    let inputs: [Fr; 16] = core::array::from_fn(|i| n(i as u64 + 1));
+    assert_eq!(
+        hash(&inputs),
+        Ok(hex(
+            "16159a551cbb66108281a48099fff949ae08afd7f1f2ec06de2ffb96b919b765"
+        ))
+    );
+    assert_eq!(hash(&[]), Err(Error::InvalidInputCount));
+    assert_eq!(hash(&[n(0); 17]), Err(Error::InvalidInputCount));
+
+    let mut out = [Fr::zero(); 3];
+    hash_ex(&[n(1), n(2)], Fr::zero(), &mut out).unwrap();
+    assert_eq!(out[0], h12);
+
+    let mut sponge = Sponge::new(3).unwrap();
+    sponge.absorb(n(1));
+    sponge.absorb(n(2));
+    assert_eq!(sponge.squeeze(), h12);
+}
@@ -0,0 +1,230 @@
+// Reader for the powers of tau files of `snarkjs powersoftau`, the format the Perpetual
+// Powers of Tau ceremony is distributed in. Points are decoded one at a time while
+// iterating, so nothing but the caller's own output is held in memory.
+//
+// Sections: 1 the header (base field, power, ceremony power), 2 [tau^i]_1 for
+// i < 2^(power + 1) - 1, 3 [tau^i]_2, 4 [alpha * tau^i]_1, 5 [beta * tau^i]_1 for i < 2^power,
+// 6 [beta]_2 and 7 the contributions. The Lagrange forms `prepare phase2` adds in 12 to 15
+// are not read.
+use crate::binfile::{BinFile, Error, Reader};
+use crate::{
+    groth16::ScalarSource, kzg, AffineG2, Fq, G2Prepared, Group, Gt, PairingBatch, G1, G2,
+};
+use std::vec::Vec;
+
+const SECTION_HEADER: u32 = 1;
+const SECTION_TAU_G1: u32 = 2;
+const SECTION_TAU_G2: u32 = 3;
+const SECTION_ALPHA_TAU_G1: u32 = 4;
+const SECTION_BETA_TAU_G1: u32 = 5;
+const SECTION_BETA_G2: u32 = 6;
+
+/// The points of one section, decoded as they are iterated.
+pub struct Points<'a, G> {
+    reader: Reader<'a>,
+    remaining: usize,
+    read: fn(&mut Reader<'a>) -> Result<G, Error>,
+}
+
+impl<G> Iterator for Points<'_, G> {
+    type Item = Result<G, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        Some((self.read)(&mut self.reader))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+pub struct PowersOfTau<'a> {
+    file: BinFile<'a>,
+    power: u32,
+    ceremony_power: u32,
+}
+
+impl<'a> PowersOfTau<'a> {
+    pub fn from_bytes(data: &'a [u8]) -> Result<Self, Error> {
+        let file = BinFile::new(data, b"ptau", 1)?;
+        let mut r = file.section(SECTION_HEADER)?;
+        r.prime(Fq::modulus())?;
+        let power = r.u32()?;
+        let ceremony_power = r.u32()?;
+        r.finish()?;
+        if power == 0 || power > 28 || ceremony_power < power {
+            return Err(Error::InvalidEncoding);
+        }
+        let ptau = PowersOfTau {
+            file,
+            power,
+            ceremony_power,
+        };
+        // Check every section length up front so iteration only fails on a bad point.
+        ptau.tau_g1()?;
+        ptau.tau_g2()?;
+        ptau.alpha_tau_g1()?;
+        ptau.beta_tau_g1()?;
+        ptau.beta_g2()?;
+        Ok(ptau)
+    }
+
+    /// log2 of the number of G2 powers, 2^power.
+    pub fn power(&self) -> u32 {
+        self.power
+    }
+
+    /// The power of the ceremony the file was truncated from.
+    pub fn ceremony_power(&self) -> u32 {
+        self.ceremony_power
+    }
+
+    fn points<G>(
+        &self,
+        id: u32,
+        n: usize,
+        size: usize,
+        read: fn(&mut Reader<'a>) -> Result<G, Error>,
+    ) -> Result<Points<'a, G>, Error> {
+        let reader = self.file.section(id)?;
+        if reader.remaining() != n * size {
+            return Err(Error::InvalidEncoding);
+        }
+        Ok(Points {
+            reader,
+            remaining: n,
+            read,
+        })
+    }
+
+    /// `[tau^i]_1` for i < 2^(power + 1) - 1.
+    pub fn tau_g1(&self) -> Result<Points<'a, G1>, Error> {
+        self.points(SECTION_TAU_G1, (2 << self.power) - 1, 64, Reader::g1)
+    }
+
+    /// `[tau^i]_2` for i < 2^power.
+    pub fn tau_g2(&self) -> Result<Points<'a, G2>, Error> {
+        self.points(SECTION_TAU_G2, 1 << self.power, 128, Reader::g2)
+    }
+
+    /// `[alpha * tau^i]_1` for i < 2^power.
+    pub fn alpha_tau_g1(&self) -> Result<Points<'a, G1>, Error> {
+        self.points(SECTION_ALPHA_TAU_G1, 1 << self.power, 64, Reader::g1)
+    }
+
+    /// `[beta * tau^i]_1` for i < 2^power.
+    pub fn beta_tau_g1(&self) -> Result<Points<'a, G1>, Error> {
+        self.points(SECTION_BETA_TAU_G1, 1 << self.power, 64, Reader::g1)
+    }
+
+    pub fn beta_g2(&self) -> Result<G2, Error> {
+        let mut r = self.file.section(SECTION_BETA_G2)?;
+        let p = r.g2()?;
+        r.finish()?;
+        Ok(p)
+    }
+
+    /// A KZG setup with the first `n` powers in G1.
+    pub fn srs(&self, n: usize) -> Result<kzg::Srs, Error> {
+        let powers_g1 = self.tau_g1()?.take(n).collect::<Result<Vec<_>, _>>()?;
+        if powers_g1.len() != n {
+            return Err(Error::UnexpectedEof);
+        }
+        Ok(kzg::Srs {
+            powers_g1,
+            tau_g2: self.tau_g2()?.nth(1).unwrap()?,
+        })
+    }
+
+    /// Checks that every section holds successive powers of the same tau, starting from
+    /// the generators, and that alpha and beta are used consistently. Each section is
+    /// folded with random scalars into
+    ///
+    ///   e(sum(r_i * P_i), [tau]_2) = e(sum(r_i * P_(i + 1)), G2)
+    ///
+    /// and all of them, with e([beta]_1, G2) = e(G1, [beta]_2), are checked with one
+    /// multi-pairing.
+    pub fn validate<S: ScalarSource>(&self, source: &mut S) -> Result<bool, Error> {
+        let mut tau_g1 = self.tau_g1()?;
+        let mut tau_g2 = self.tau_g2()?;
+        if tau_g1.next().unwrap()? != G1::one() || tau_g2.next().unwrap()? != G2::one() {
+            return Ok(false);
+        }
+        let tau = self.tau_g1()?.nth(1).unwrap()?;
+        let tau_2 = match AffineG2::from_jacobian(self.tau_g2()?.nth(1).unwrap()?) {
+            Some(p) => G2Prepared::new(p),
+            None => return Ok(false),
+        };
+
+        let (mut lo_g1, mut hi_g1) = (G1::zero(), G1::zero());
+        for points in [self.tau_g1()?, self.alpha_tau_g1()?, self.beta_tau_g1()?] {
+            let (lo, hi) = fold(points, source)?;
+            lo_g1 = lo_g1 + lo;
+            hi_g1 = hi_g1 + hi;
+        }
+        let (lo_g2, hi_g2) = fold(self.tau_g2()?, source)?;
+
+        let r = source.next_scalar();
+        let beta = self.beta_tau_g1()?.next().unwrap()?;
+        let mut batch = PairingBatch::new();
+        batch.push_prepared(-lo_g1, &tau_2);
+        batch.push(hi_g1 + beta * r, G2::one());
+        batch.push(tau, lo_g2);
+        batch.push(-G1::one(), hi_g2);
+        batch.push(-G1::one() * r, self.beta_g2()?);
+        Ok(batch.finalize() == Gt::one())
+    }
+}
+
+// (sum(r_i * P_i), sum(r_i * P_(i + 1))) over successive pairs of points.
+fn fold<G: Group, S: ScalarSource>(mut points: Points<G>, source: &mut S) -> Result<(G, G), Error> {
+    let (mut lo, mut hi) = (G::zero(), G::zero());
+    let mut prev = points.next().unwrap()?;
+    for p in points {
+        let p = p?;
+        let r = source.next_scalar();
+        lo = lo + prev * r;
+        hi = hi + p * r;
+        prev = p;
+    }
+    Ok((lo, hi))
+}
+
+#[test]
+fn read_and_validate_ptau() {
+    use crate::{pairing, Fr};
+
+    let data = include_bytes!("../fixtures/ptau/powersOfTau28_hez_final_08.ptau");
+    let ptau = PowersOfTau::from_bytes(data).unwrap();
+    assert_eq!((ptau.power(), ptau.ceremony_power()), (8, 28));
+    assert_eq!(ptau.tau_g1().unwrap().count(), 511);
+    assert_eq!(ptau.tau_g2().unwrap().count(), 256);
+
+    let srs = ptau.srs(4).unwrap();
+    assert_eq!(srs.powers_g1[0], G1::one());
+    assert_eq!(
+        pairing(srs.powers_g1[3], G2::one()),
+        pairing(srs.powers_g1[2], srs.tau_g2)
+    );
+    assert!(ptau.srs(512).is_err());
+
+    let mut s = Fr::from_str("17").unwrap();
+    let mut source = || {
+        s = s * s + Fr::one();
+        s
+    };
+    assert!(ptau.validate(&mut source).unwrap());
+
+    // Swapping tau^2 and tau^3 in G1 keeps every point valid but breaks the powers.
+    let mut bad = data.to_vec();
+    let start = 12 + 12 + 44 + 12 + 2 * 64;
+    let (a, b) = bad[start..start + 128].split_at_mut(64);
+    a.swap_with_slice(b);
+    let ptau = PowersOfTau::from_bytes(&bad).unwrap();
+    assert!(!ptau.validate(&mut source).unwrap());
+    assert!(PowersOfTau::from_bytes(&data[..data.len() - 1]).is_err());
+}
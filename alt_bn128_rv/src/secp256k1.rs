@@ -0,0 +1,339 @@
+// secp256k1, y^2 = x^3 + 7 over the field of p = 2^256 - 2^32 - 977, with a group of prime
+// order n. It runs on the field and group code of BN254; both moduli are above 2^255,
+// which `arith` handles with its carries. Ethereum uses it for transaction signatures and
+// the ecrecover precompile.
+//
+// Encodings: uncompressed points are x and y in 32 big-endian bytes each, the form
+// Ethereum addresses hash, and compressed points the 33-byte SEC1 form, 0x02 or 0x03 for
+// the parity of y followed by x.
+use crate::arith::U256;
+use crate::fields::{self, FieldElement};
+use crate::groups::{self, GroupElement, GroupParams, Secp256k1Params};
+use crate::{CurveError, FieldError, GroupError};
+use core::ops::{Add, Mul, Neg, Sub};
+
+macro_rules! field_wrapper {
+    ($name:ident, $inner:ty) => {
+        #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+        #[repr(C)]
+        pub struct $name($inner);
+
+        impl $name {
+            pub fn zero() -> Self {
+                $name(<$inner>::zero())
+            }
+            pub fn one() -> Self {
+                $name(<$inner>::one())
+            }
+            pub fn pow(&self, exp: U256) -> Self {
+                $name(self.0.pow(exp))
+            }
+            pub fn inverse(&self) -> Option<Self> {
+                self.0.inverse().map($name)
+            }
+            pub fn is_zero(&self) -> bool {
+                self.0.is_zero()
+            }
+            /// Converts a U256 so long as it's below the modulus.
+            pub fn new(val: U256) -> Option<Self> {
+                <$inner>::new(val).map($name)
+            }
+            /// Converts a U256 reduced modulo the modulus.
+            pub fn new_mul_factor(val: U256) -> Self {
+                $name(<$inner>::new_mul_factor(val))
+            }
+            /// Reads 32 big-endian bytes of a value below the modulus.
+            pub fn from_slice(slice: &[u8]) -> Result<Self, FieldError> {
+                let val = U256::from_slice(slice).map_err(|_| FieldError::InvalidSliceLength)?;
+                $name::new(val).ok_or(FieldError::NotMember)
+            }
+            /// Writes the value in 32 big-endian bytes.
+            pub fn to_big_endian(&self, slice: &mut [u8]) -> Result<(), FieldError> {
+                self.into_u256()
+                    .to_big_endian(slice)
+                    .map_err(|_| FieldError::InvalidSliceLength)
+            }
+            pub fn into_u256(self) -> U256 {
+                (self.0).into()
+            }
+            pub fn modulus() -> U256 {
+                <$inner>::modulus()
+            }
+        }
+
+        impl Add<$name> for $name {
+            type Output = $name;
+
+            fn add(self, other: $name) -> $name {
+                $name(self.0 + other.0)
+            }
+        }
+
+        impl Sub<$name> for $name {
+            type Output = $name;
+
+            fn sub(self, other: $name) -> $name {
+                $name(self.0 - other.0)
+            }
+        }
+
+        impl Neg for $name {
+            type Output = $name;
+
+            fn neg(self) -> $name {
+                $name(-self.0)
+            }
+        }
+
+        impl Mul for $name {
+            type Output = $name;
+
+            fn mul(self, other: $name) -> $name {
+                $name(self.0 * other.0)
+            }
+        }
+    };
+}
+
+field_wrapper!(Fp, fields::Secp256k1Fq);
+field_wrapper!(Fn, fields::Secp256k1Fr);
+
+impl Fp {
+    pub fn sqrt(&self) -> Option<Self> {
+        self.0.sqrt().map(Fp)
+    }
+}
+
+// p - n: an x-coordinate r + n of recovery ids 2 and 3 must be below p.
+const P_MINUS_N: U256 = U256([0x4551231950b75fc4402da1722fc9baee, 0x1]);
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct Point(groups::Secp256k1);
+
+impl Point {
+    pub fn new(x: Fp, y: Fp, z: Fp) -> Self {
+        Point(groups::Secp256k1::new(x.0, y.0, z.0))
+    }
+
+    pub fn zero() -> Self {
+        Point(groups::Secp256k1::zero())
+    }
+
+    pub fn one() -> Self {
+        Point(groups::Secp256k1::one())
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    pub fn double(&self) -> Self {
+        Point(self.0.double())
+    }
+
+    pub fn b() -> Fp {
+        Fp(Secp256k1Params::coeff_b())
+    }
+}
+
+impl Add<Point> for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point(self.0 + other.0)
+    }
+}
+
+impl Sub<Point> for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point(self.0 - other.0)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point(-self.0)
+    }
+}
+
+impl Mul<Fn> for Point {
+    type Output = Point;
+
+    fn mul(self, other: Fn) -> Point {
+        Point(self.0 * other.0)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(C)]
+pub struct AffinePoint(groups::AffineSecp256k1);
+
+impl AffinePoint {
+    pub fn new(x: Fp, y: Fp) -> Result<Self, GroupError> {
+        Ok(AffinePoint(groups::AffineSecp256k1::new(x.0, y.0)?))
+    }
+
+    /// The point with abscissa `x` and a y of the given parity, if there is one.
+    pub fn from_x(x: Fp, odd: bool) -> Option<Self> {
+        let mut y = (x * x * x + Point::b()).sqrt()?;
+        if y.into_u256().get_bit(0).unwrap() != odd {
+            y = -y;
+        }
+        Some(AffinePoint(groups::AffineSecp256k1::new_unchecked(
+            x.0, y.0,
+        )))
+    }
+
+    pub fn x(&self) -> Fp {
+        Fp(*self.0.x())
+    }
+
+    pub fn y(&self) -> Fp {
+        Fp(*self.0.y())
+    }
+
+    pub fn from_jacobian(p: Point) -> Option<Self> {
+        p.0.to_affine().map(AffinePoint)
+    }
+
+    pub fn to_uncompressed(&self) -> [u8; 64] {
+        let mut out = [0u8; 64];
+        self.x().to_big_endian(&mut out[..32]).unwrap();
+        self.y().to_big_endian(&mut out[32..]).unwrap();
+        out
+    }
+
+    pub fn from_uncompressed(bytes: &[u8; 64]) -> Result<Self, CurveError> {
+        let x = Fp::from_slice(&bytes[..32]).map_err(|_| CurveError::InvalidEncoding)?;
+        let y = Fp::from_slice(&bytes[32..]).map_err(|_| CurveError::InvalidEncoding)?;
+        AffinePoint::new(x, y).map_err(|_| CurveError::NotMember)
+    }
+
+    pub fn to_compressed(&self) -> [u8; 33] {
+        let mut out = [0u8; 33];
+        out[0] = if self.y().into_u256().is_even() { 2 } else { 3 };
+        self.x().to_big_endian(&mut out[1..]).unwrap();
+        out
+    }
+
+    pub fn from_compressed(bytes: &[u8; 33]) -> Result<Self, CurveError> {
+        let odd = match bytes[0] {
+            2 => false,
+            3 => true,
+            _ => return Err(CurveError::InvalidEncoding),
+        };
+        let x = Fp::from_slice(&bytes[1..]).map_err(|_| CurveError::InvalidEncoding)?;
+        AffinePoint::from_x(x, odd).ok_or(CurveError::NotMember)
+    }
+}
+
+impl From<AffinePoint> for Point {
+    fn from(affine: AffinePoint) -> Self {
+        Point(affine.0.to_jacobian())
+    }
+}
+
+/// Recovers the public key of an ECDSA signature (r, s) of a 32-byte message hash.
+/// Bit 0 of `recid` is the parity of the y of R = k * G and bit 1 is set when its x is
+/// r + n rather than r. Any s in [1, n) is accepted, low or high.
+pub fn recover(hash: &[u8; 32], r: &U256, s: &U256, recid: u8) -> Option<AffinePoint> {
+    let (r, s) = (Fn::new(*r)?, Fn::new(*s)?);
+    if r.is_zero() || s.is_zero() || recid > 3 {
+        return None;
+    }
+    let mut x = Fp::new(r.into_u256()).unwrap();
+    if recid & 2 != 0 {
+        if r.into_u256() >= P_MINUS_N {
+            return None;
+        }
+        x = x + Fp::new(Fn::modulus()).unwrap();
+    }
+    let big_r = AffinePoint::from_x(x, recid & 1 != 0)?;
+
+    // Q = r^-1 * (s * R - e * G) with e the hash reduced modulo n.
+    let e = Fn::new_mul_factor(U256::from_slice(hash).unwrap());
+    let r_inv = r.inverse().unwrap();
+    let q = Point::from(big_r) * (s * r_inv) - Point::one() * (e * r_inv);
+    AffinePoint::from_jacobian(q)
+}
+
+#[test]
+fn secp256k1_arithmetic() {
+    let hex = |s: &str| U256::from_hex_str(s).unwrap();
+
+    // Arithmetic close to the moduli, where additions and products carry out of 256 bits.
+    let a = -Fp::one();
+    let b = Fp::new(hex(
+        "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc00",
+    ))
+    .unwrap();
+    assert_eq!(a + a, -Fp::one() - Fp::one());
+    assert_eq!(a * a, Fp::one());
+    assert_eq!((a + b) - b, a);
+    assert_eq!(b * b.inverse().unwrap(), Fp::one());
+    assert_eq!((b * b).sqrt().map(|r| r == b || r == -b), Some(true));
+    assert_eq!(
+        b.into_u256(),
+        hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc00")
+    );
+    let c = -Fn::one();
+    assert_eq!(c * c, Fn::one());
+    assert_eq!(c.inverse(), Some(c));
+    assert_eq!(
+        Fn::new_mul_factor(U256([u128::MAX, u128::MAX])).into_u256(),
+        hex("000000000000000000000000000000014551231950b75fc4402da1732fc9bebe")
+    );
+
+    // The group order is n.
+    let g = Point::one();
+    assert_eq!(g * c + g, Point::zero());
+    assert_eq!(g * c, -g);
+    let p = g * Fn::new(hex("123456789")).unwrap();
+    let q = g * Fn::new(hex("987654321")).unwrap();
+    assert_eq!(p + q, g * Fn::new(hex("aaaaaaaaa")).unwrap());
+    assert_eq!(p.double(), p + p);
+
+    // 2 * G, from SEC 2.
+    let g2 = AffinePoint::from_jacobian(g.double()).unwrap();
+    assert_eq!(
+        g2.x().into_u256(),
+        hex("c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5")
+    );
+    assert_eq!(
+        g2.y().into_u256(),
+        hex("1ae168fea63dc339a3c58419466ceaeef7f632653266d0e1236431a950cfe52a")
+    );
+
+    let p = AffinePoint::from_jacobian(p).unwrap();
+    for p in [g2, p] {
+        assert_eq!(
+            AffinePoint::from_uncompressed(&p.to_uncompressed()).unwrap(),
+            p
+        );
+        assert_eq!(AffinePoint::from_compressed(&p.to_compressed()).unwrap(), p);
+    }
+    let mut bad = g2.to_uncompressed();
+    bad[63] ^= 1;
+    assert!(AffinePoint::from_uncompressed(&bad).is_err());
+
+    // A signature by the key 1 with nonce 2, from fixtures/secp256k1/gen.py.
+    let mut hash = [0u8; 32];
+    hex("1c8aff950685c2ed4bc3174f3472287b56d9517b9c948127319a09a7a36deac8")
+        .to_big_endian(&mut hash)
+        .unwrap();
+    let r = g2.x().into_u256();
+    let s = hex("f147bf94a439a02d3e042bdee51952a936ffde56ec662f054e8c38f6e80a6577");
+    let g1 = AffinePoint::from_jacobian(g).unwrap();
+    assert_eq!(recover(&hash, &r, &s, 0), Some(g1));
+    assert_ne!(recover(&hash, &r, &s, 1), Some(g1));
+    assert_eq!(recover(&hash, &r, &s, 2), None);
+    let high = (-Fn::new(s).unwrap()).into_u256();
+    assert_eq!(recover(&hash, &r, &high, 1), Some(g1));
+    assert_eq!(recover(&hash, &r, &U256::zero(), 0), None);
+    assert_eq!(recover(&hash, &Fn::modulus(), &s, 0), None);
+}
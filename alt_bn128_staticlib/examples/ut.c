@@ -5,6 +5,21 @@
 extern uint32_t alt_bn128_add(const char* data, uint32_t data_len, char* output);
 extern uint32_t alt_bn128_mul(const char* data, uint32_t data_len, char* output);
 extern uint32_t alt_bn128_pairing(const char* data, uint32_t data_len, char* output);
+extern uint32_t ecrecover(const char* data, uint32_t data_len, char* output);
+extern uint32_t modexp(const char* data, uint32_t data_len, char* output, uint32_t* output_len);
+extern uint64_t modexp_gas(const char* data, uint32_t data_len);
+extern uint32_t blake2f(const char* data, uint32_t data_len, char* output);
+extern uint64_t blake2f_gas(const char* data, uint32_t data_len);
+extern uint32_t bls12_g1add(const char* data, uint32_t data_len, char* output);
+extern uint32_t bls12_g1msm(const char* data, uint32_t data_len, char* output);
+extern uint64_t bls12_g1msm_gas(const char* data, uint32_t data_len);
+extern uint32_t bls12_g2add(const char* data, uint32_t data_len, char* output);
+extern uint32_t bls12_g2msm(const char* data, uint32_t data_len, char* output);
+extern uint64_t bls12_g2msm_gas(const char* data, uint32_t data_len);
+extern uint32_t bls12_pairing_check(const char* data, uint32_t data_len, char* output);
+extern uint64_t bls12_pairing_check_gas(const char* data, uint32_t data_len);
+extern uint32_t bls12_map_fp_to_g1(const char* data, uint32_t data_len, char* output);
+extern uint32_t bls12_map_fp2_to_g2(const char* data, uint32_t data_len, char* output);
 
 int hex2bin(const char *s, char *buf)
 {
@@ -82,6 +97,150 @@ const char* alt_bn128_pairing_case[alt_bn128_pairing_case_count][2] = {
     {"105456a333e6d636854f987ea7bb713dfd0ae8371a72aea313ae0c32c0bf10160cf031d41b41557f3e7e3ba0c51bebe5da8e6ecd855ec50fc87efcdeac168bcc0476be093a6d2b4bbf907172049874af11e1b6267606e00804d3ff0037ec57fd3010c68cb50161b7d1d96bb71edfec9880171954e56871abf3d93cc94d745fa114c059d74e5b6c4ec14ae5864ebe23a71781d86c29fb8fb6cce94f70d3de7a2101b33461f39d9e887dbb100f170a2345dde3c07e256d1dfa2b657ba5cd030427000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000021a2c3013d2ea92e13c800cde68ef56a294b883f6ac35d25f587c09b1b3c635f7290158a80cd3d66530f74dc94c94adb88f5cdb481acca997b6e60071f08a115f2f997f3dbd66a7afe07fe7862ce239edba9e05c5afff7f8a1259c9733b2dfbb929d1691530ca701b4a106054688728c9972c8512e9789e9567aae23e302ccd75", "0000000000000000000000000000000000000000000000000000000000000001"},
 };
 
+#define ecrecover_case_count 11
+const char* ecrecover_case[ecrecover_case_count][2] = {
+    {"18c547e4f7b0f325ad1e56f57e26c745b09a3e503d86e00e5255ff7f715d3d1c000000000000000000000000000000000000000000000000000000000000001c73b1693892219d736caba55bdb67216e485557ea6b6af75f37096c9aa6a5a75feeb940b1d03b21e36b0e47e79769f095fe2ab855bd91e3a38756b7d75a9c4549", "000000000000000000000000a94f5374fce5edbc8e2a8697c15331677e6ebf0b"},
+    {"456e9aea5e197a1f1af7a3e85a3212fa4049a3ba34c2289b4c860fc0b0c64ef3000000000000000000000000000000000000000000000000000000000000001c9242685bf161793cc25603c231bc2f568eb630ea16aa137d2664ac80388256084f8ae3bd7535248d0bd448298cc2e2071e56992d0774dc340c368ae950852ada", "0000000000000000000000007156526fbd7a3c72969b54f64e42c10fbb768c8a"},
+    {"1c8aff950685c2ed4bc3174f3472287b56d9517b9c948127319a09a7a36deac8000000000000000000000000000000000000000000000000000000000000001bc6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5f147bf94a439a02d3e042bdee51952a936ffde56ec662f054e8c38f6e80a6577", "0000000000000000000000007e5f4552091a69125d5dfcb7b8c2659029395bdf"},
+    {"1c8aff950685c2ed4bc3174f3472287b56d9517b9c948127319a09a7a36deac8000000000000000000000000000000000000000000000000000000000000001cc6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee50eb8406b5bc65fd2c1fbd4211ae6ad5583aefe8fc2e2713671462595e82bdbca", "0000000000000000000000007e5f4552091a69125d5dfcb7b8c2659029395bdf"},
+    {"1c8aff950685c2ed4bc3174f3472287b56d9517b9c948127319a09a7a36deac8000000000000000000000000000000000000000000000000000000000000001b5cbdf0646e5db4eaa398f365f2ea7a0e3d419b7e0330e39ce92bddedcac4f9bc8765b9adc3f29556c0be853cdb941c6322bcb52b29be18b949a53688376912e8", "000000000000000000000000a94f5374fce5edbc8e2a8697c15331677e6ebf0b"},
+    {"1c8aff950685c2ed4bc3174f3472287b56d9517b9c948127319a09a7a36deac8000000000000000000000000000000000000000000000000000000000000001c5cbdf0646e5db4eaa398f365f2ea7a0e3d419b7e0330e39ce92bddedcac4f9bc789a46523c0d6aa93f417ac3246be39b97f227bb858a8782762d280498cd2e59", "000000000000000000000000a94f5374fce5edbc8e2a8697c15331677e6ebf0b"},
+    {"18c547e4f7b0f325ad1e56f57e26c745b09a3e503d86e00e5255ff7f715d3d1c100000000000000000000000000000000000000000000000000000000000001c73b1693892219d736caba55bdb67216e485557ea6b6af75f37096c9aa6a5a75feeb940b1d03b21e36b0e47e79769f095fe2ab855bd91e3a38756b7d75a9c4549", ""},
+    {"18c547e4f7b0f325ad1e56f57e26c745b09a3e503d86e00e5255ff7f715d3d1c000000000000000000000000000000000000000000000000000000000000001d73b1693892219d736caba55bdb67216e485557ea6b6af75f37096c9aa6a5a75feeb940b1d03b21e36b0e47e79769f095fe2ab855bd91e3a38756b7d75a9c4549", ""},
+    {"18c547e4f7b0f325ad1e56f57e26c745b09a3e503d86e00e5255ff7f715d3d1c000000000000000000000000000000000000000000000000000000000000001c0000000000000000000000000000000000000000000000000000000000000000eeb940b1d03b21e36b0e47e79769f095fe2ab855bd91e3a38756b7d75a9c4549", ""},
+    {"18c547e4f7b0f325ad1e56f57e26c745b09a3e503d86e00e5255ff7f715d3d1c000000000000000000000000000000000000000000000000000000000000001c73b1693892219d736caba55bdb67216e485557ea6b6af75f37096c9aa6a5a75ffffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141", ""},
+    {"18c547e4f7b0f325ad1e56f57e26c745b09a3e503d86e00e5255ff7f715d3d1c", ""},
+};
+
+#define modexp_case_count 21
+const char* modexp_case[modexp_case_count][2] = {
+    {"00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000002003fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2efffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f", "0000000000000000000000000000000000000000000000000000000000000001"},
+    {"000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000020fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2efffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f", "0000000000000000000000000000000000000000000000000000000000000000"},
+    {"0000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000408d4880b60510229ac27b55f5e0522de3dfdd2af55fcb80442b42ec24e48d04697e51cbcd5c06d4d254c2bbfaf36105ffd75eb16ec60598b06c0b8a4baf5a47a902e2abc411e5551d641aee6874fb1b4509be4bf20f0d99ea5ffd809a54a5c689de98c9fa9f8b9e51ddaf77cc0ad136d8966a3d4e537e3591edd11a7f891e27918e", "313c311e1919ef2ef6e162ea1e89564b5eccaa1da7de16081fc4681bd2f9d788bdf7ce6c8e29f647d3ef6212a8804825433e274d47b76215f20ee248e487544f"},
+    {"000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000040d433c653b32543825c243173f538ce958f11e77138c607d7c60620938866870f2902cfecc30dc45f23be2e65b62d64f8718b26300989dc1118901a1e77a2bbf00343c9748a8c7545ed3555fa30d39aa4da561c487c6aec66081c779a3fc4c444516bca436367e426f72f0066d6bd387cf512b7dfe652f4772194df22e5f1a81dbe", "33766aee06d3c61997fbc0d6d688b9335abbe3132a526f54a4ca02885f84605aa1622bbfe29a81b5b3dbe375e112ce2e3250ef8855ea056c2dfe100e1c8ef1d6"},
+    {"000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000000000000000000000000000000000030000000000000000000000000000000000000000000000000000000000000040d0f34355de9a626c1dce92c3dbcc014425bee224853bb2c7ca12f0f2395d604a2cde4c54295f14fb4981ace427542a68146d231eb393ea8e82436a2ed0589761010001a9f9d37f01143bf5fbe2400ae0f8dd517ae1c3bd1599e34beb7f3a7465499be3d9b22f976b86a00d0d97b6ae07fee653780eb2bc095e5f7f643df7656078830a", "724b3dfb787290d34454db2e4873cae723d42dd1898389c2fd92443284d85f470993fd07853cd7506b378b0235ddf75227ceafd9093421d197b8eb3426d5f4c1"},
+    {"000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000030000000000000000000000000000000000000000000000000000000000000080ae73ea3170f2e8ec10ce0c174234517f72ba27935ea129d34b0533fd748fc9208383cc74fa26f2c08868a2a104c03ad138c7ba1d7848579dd5d3073c3a957146215281e1000035bf3e4fc1f91298af50fbbb2b9d78b7f88d2b98ed0db1da26922ff7b7b925c1d5f78285cd86eb362789500f224c82ad8ddd86df2a5f8cc0c51b010001fae3b5123034e6a09dc9153844c8cc56954aa18826b2319da76ece70ca87cf0b796e9870bd8b6966802e6791b24dcfefacd68849d822cc064a26c45577d84cb0c74ffa0b3b8c94b65c43f4f6948c7d4ef0090c41a0deafc6794beb0b2e9926920484951da5dc73a9f18ea33433a385cf046369c39b4fe6e9043e25a468cb9573", "e26954021af928802a116fefab3d8cb35ed0f1cb16e3fe2ee3699d57a5fee674eb2ba71037c9730140815cb6dcf84dfafdcabfc7f1f4cf79942291757310a51829977138e14b154aed1d0429ba0966aaea01117771253785107094a265eef4fa80d6eaa1273e8d5c9341556bd5a714f41c3949bad9dd16012e563269a8a49395"},
+    {"000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000100359483dbb026745b5a825d33439c5a30476735289396f020c8d9517e589c6ee27cd8b120336309bfe197431635f805e5efc385ffdd3cee4061c90c8737154ee675871c9c48fee7132522bb280e7791915b7eb685d9981aaca915375d5b78fb12b891c3a8a1251c52330d3e8ccee9164f27abbd25d2dbda8635cd46262019e79f39dfb2e9ea0eb89cd2b3122d6f22bc7556d496e22b78162c5854a18a8794adeda14eb118adaf93ffebb90e86737855903e54fd8436a832150ed4f1deeba846f9030dbf2ae0a84a60e6cc28389c658840a3bc74c8d47d6170fa952e2cbf11ebd0602e8d00907cfd4b9d1f59f374d5f2bd00df94bc91e8fa96d752bf76a1db5246025cd6b354a3ddfe7170623398a0a5c04788a52a39b52dc9d7a22d938a60d2a0865d93b09e8a20d0fc9f844fdb08ea3c790716b8fa5b13ea0ffdd2a9b5831ad38603f20021d785852817c0a6ec981e0d77ba836ba0e7f553e05f90a8b6dec6bc523b21b5586c6a1cc09d5bd0925c03ce5563cbe7e58c05e58c0f02fe09358ea1dbe3708b0070e2d023a7698927ff1d5c0afb157246ef7437c3f4eb7b79176020b5a1c165f73ca0eabc701ad816fe5be850daf902e3c70a921f2dfc7494b6e5eaf7c6b29424775513ff4c00e3eda22c00a11e39d9103f87c66235caffa04c2679f85c7a9f9e2d2a744e4c6e9e8b09c6a027d5df7a78e9536210a7e791c69e3eff7c", "3fac1e2462bc8b43b6f6f84eb127a688c7f81d76250b0c15dd5b0592004609df8fb43f8758e213306c5de9ffbc20b6ca07b837c228633e11fbfc8a679b404154a7f214c85bf824267b4bb17f322ef83b8a8c8952d548c364f560028c9fb1c749c7ac5c275395949ee74838003d821843dbcdd3abb0de9a23eb8c055bc72819fb4219abd4d36cf8b735b5a44f991fc66b825681610fe06aea4085e798af6a76f24d3867936773744bcb5dd8e4b54eaa797fa586be45610c9f8f6e103200862fad3ccc0ca95aded5fdb76bef3cbd53d6135866691f12472c72a2a75f5baa35165c9c3cc290a783c55ba01d9f3315896953b404bfb0370c9a6e9ce30b58a46b51e8"},
+    {"00000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000020074b7bc7be13347beb7475ce04fc90ad058fc3e19e533f62d2fa4af81c82ebe495bf8841a60a4fd13cf92214ed389296197e74ce87d380a25a172aebb11a81b48dc7cfba8b8d2d3d4b5b99536100d4015f81a431dd5534374263374eecd5ac8e3cd99fe280f305f45e5cd7faed2521b073d191aa1b6482273214ef0a7c05c0d0b13cd1a768d297d01a105aff71bd54f235b2f516140a5ebb2688145cb904c17426b74d3da190564c30562536b5ad706e30f4a30a33cc3db23e4896409369b6a075ed23657545c7f23af6e00568384e81c1ddfa1e36202a24f07c44eb30adaa91db53633c1bd5d0d9b760ff42730fe233309e0149b2563e6dda01415f669bda3143368d5b7cf930056e9fb32d65dd00fb012d1a292b0de5c4ca94cfaf1c692def927c9ddcfcddb78738ff41a1dbff6fe16dc8870cfc725e6dd95c87c3f94eab2305347e712b665ce2dd49f728a550c54bdeebf5cae34e911154d680cf94e06523e4892a221bba51d5049560947d6ee1147ffb68ebf4c9e99033066c2087e6d74f657d43610f86463bfd511cb004e2b7397722ff20a56b19dd0e59c39bacc209c72df2eba9cf83bc55d06549a267a2df0e3ed4c3175e405daae1d86d0f0539c3b9a0328a36d369befb83c56a9938fbfe66465a37f822952b84bfd3258f8e64e13bec4b16e67a2483ebabf8afff2e25eee3216d8cfbca65d21c9e502d042b385aa2703374227e999bbb96d470cbf1ed82969216a8bac20d2c63a3f7bce294eca9a7fe52de852751e258d7470c43d11fe853d4a1f861df69f22e0e6637a75e5f6ce967ec7159e7ecdbea8ca64427feedbcdaef1760f10c7ad05f971ae8dd7070bb9612b23871c4488fb0870b2d254bbad46892d77801db10a4e1720a28dae387d793b34e4ecd6df5149729cc30b61583cf1555c2c4f4c1b9fac6536f83f1785ef1e79150ffb9de2ce835fd2b6400cb49804963c66dcf4ed2de0639f5950a7d22e1bb9b8437ecd1e072a679847f16872679872ef85676b52f71f88cb76ea00a0f846a6c01ddad01a755bc0468c7cd2194297209f046416c9b247c85da419ad84c0faeae512ed4367f80c2f8383d1c7bd7788b78dfd300f5ef8c8ad6209183d9243e8c0afe6c07b3e042fc910d6f84894f6d4716a4cb774607de693a30a19592ef7a355377ebadcb563cd088c990c9acbc57bfac79745b02ff6eee22f77b367839140f90f834874989db965580ced247c3018d134c5128336d51549af775f2674634b05c705f823f3af6be7cb80dfd5bf0f3680b5b4950f0d6778227e9abb0b8a46ab765d2233b1107b1e15eabb9a2ee1d650696838a88420a7625a313573cbd901385ed2f3bb688b80136467dcf05f3d173a5b6bdfd1bd750816baf7a881f4e07c65c72103ae07947714b38a770f20d2859c94f83efb0b384f1fce33adbee6a47fa362eb", "2fb4fda85b3cdf508cd35654f7f4ae557bce8d805263c3a3564cca2a4175361c4ee87aed396ef456e4c4dfe8514491390bb75cfef95e06c4cb2920cfe6bf495513b1ff8b9cc141de717e276b508de1e2ab5c616ff03603385694c4a451f3e0614fae49275e7b6f453ef7a6a76583150629b31c71564d6c41ff564a1ab6564d97d49d25493f994f66db8892497dea464dd6dbf99efcc2ce002d32800bb607e8280d0ade494632df2e3aade587761db1fc9f3e0ee69d4fee6f901e70771a1a86ad0c43deef3b457244c522888056622f46bfae30ab84f4cb1b869d65bcd920b43932c7255d1c99bffbf2bf540e7c80a19d9158dfb5725a11e8658204753f3ea4000a3994d828ce00d23667b24769d037224100fd62e172c76bd61f81a488c0b707c5daee4e4465b424a1d96f313ff4565343075e3d93825831ce5dc61ece880ce6b39fe80a296c314ce735d12a7ee4153bc970099f4b3f2cb7c1d485910d257f862fa0d78dd3ce171a00c286d869df42db13d7869930cb464ed5e81b6ffb3e5b3b43f031c39887a70a025a2c19ce63cb769d7f97f428d6943697ca2824c0d3d7128401735c16ad7081a0700cbed0bac25870294ba5bf989d219613204d3ff329a0f87ecebb3a2c2473b83f6af70705824a857e419d0a14404ccadf337e6f5b07833f0a5c9793d6bc218abf3607afc080f380dc7184fdbcb5a7a4245cce0c0e2c0d"},
+    {"000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000000000000000000000030000000000000000000000000000000000000000000000000000000000000400e8fd403da9fd10895c6b471800c00d79d43145c1296ebc12cbd7400c4d3a611e9fec684dfe411dff2008b54b353e22018767f17319f10da2936cb6f6fc09b3cc29377a9604eb89ad10656a675fcf6156ffd8a286656be5114b6e1d4db6d37792bd30f375dbfbe9c9081e4c3691129d41fa11b9546b2757ab154690d5fda23fd2fc1295b793dc1e5b3274aa228cd12a1d3ffa2749f28cbe1ef57ba3f016535d66f8aeaccc1203ce317a653ac70c3eeaebb81b4e9c11b07aae812653fd4eef49240edfb62a5dc0a1219751d1f992644ef6a12f149896aad2a8e9ddc752a96c29fa0df0fd611194b88dc34d5fe3dd148273f7b9739c2f9283bd6d3ba579f0f208acb57566c2f4bcabf74a9146d3a3318862b7b62e88096f8197e29711b395c257366ed3419680a8defe8aa640c36f222ffa7d143742c41e9eaf114b665e4f80840e2e702d254b047ea10c343d87e0b042edf5c36a730b283145e5bfda66a8a74465dc45cee15c6a00b4f90aec136a5f373d05785a28d6fe7c5b3921d1785858defdf49b9c0f7918d6de8a6ecedbe7e80aa734010b30bb9c7f28f5003c1e7f4a3b37f6fa09e4d37179f92653cbad0c797c1f5d27b94797a4555a601324b413890ffdd7677d94435a9506bb85833a5650f3eb5762f3205d9218bf86f60e61acf2124af4186e7fd37ab7a7eaf9d5a8523ddcd70e86cc2ece8da92701c238263aee6e34042b3eb510ed033a3d981f16e88dd845255f5695cedf1fad82aff26c3c3386716a3330923b209f9341d0ebbb18c832e22853c01a6471f751e077ba9405edeb32c9233877cdcf8a6c988ce3377291970a22c0209b3f5d6371dd23485fe7c771e5fb84f787fd015de1fdd8587c1808b5f01d72853fe5e056c393c9236aaa2bcf882e97db1a51cf55c3b45748e4f7d47f8bb1d6460f5a583bfa17f1847b881b45bb3604de3ad17d57c4cc66875a7e3730cc842b35cac6928a54c8f1f82ce48f823f775740934dd0e0e76f228e79214e1cbe34fa584131c4b97ea3a71f992ec20328ae52b6b44bbf44de19fabbe2ffdd01bfeec6eb19af0da16173d0112f89b4b8fddfd58b3f85c6793ebedd9baa18dd800dbad712726b8b8797639f49319875589d18f33c3697df093ea4c6dba97a2d6d3f764dc0e9f5d843ffdf0b571d68981d567e8ddcaf559065979fe7a7d61ae643af91b64eae58195cdd5293b5d4238fe54d957a53f06b635d016a811df02adec5c502d01ae61f80451ed373acfb06847eabf282c54d012585c7c7b3027795ff894b91272018f86b72757a2df568cd03f14ffe88aeafecc468cfcc61de170cf14c8419fb50d5981e2a702d667a0264800bd603c3e63af6fc70646be4af226f220f1b0f8cce10c700dea98e6f80f55fb500123008563c6b14d59c7d14540d81df325860b53febf487cdcce7d3ccfe9d2592a60100013f0a35604e2d87ba718d188fbc20e5943627c467a5588649443e447d485c8d38899663fa1e1a7495e551ed805ce0dec5b14c5e3d9caaa9501c9f184d18e1509c0b3ee87877031eefc8af55bc3cad9d28e877266e4c01f80e6dfecd076f2fbe4a2581ecbbb05e4cb30f7d567e9e7818c7dadb56792a9ba5f84df6ed5f9dc6febfb6213ef1d05dcfff0336fa9e7758aecdf11741ffa12903a9eecd02496e910e71060a233ae5e626aecd29de494143e53ea1ceab54278a67fc8eccd79da0eefd30ec74d984a756930063fe846c4d017dd54f936b67544e8f3f0471e7cbd19980e8605e43cb1047936df2f161e35e355edac88aa1ec4544cb991e2d0917a2cef16ac2a57e99418a09bbce2d87dca89cf6913b5154b426ba0a7d7ce31d78eb4514f1903fab2ca525b51d54f762b56ea31fbc97771f328fb5dd1622793828b18472ced5f87bb9d678a7c97264ead0a242364e767159521f7f335a07103bb3dab3bcf0ba5aad66e77e8e5631053d40933b109b5dfe6fbbdcf64d605b3db10b22b48aa91b6fcb7907167d689cadca1cf3b20e18b056e8d25b832a776b0149247aa30539eb598f3035baa621b0ab115d1f1e5551bff37ac29d43e94db0b9d6ee8808c56331d9c6f1e45abab7ee35ccf7a523d682123d8b2f64496ac9d361eaf5112870a3768ef1078207a82093c4ac8b3fe2d65078b5c7147aec9f868a8f9bea56146c30c560805d71346c1966b99891bd13221365d8b6e80c1a1d7213582bfe095152f3c9a6249f738b18d00962645ba6349671564208bbb837f680a8befc026e892bd2dc8e5fc2adb64643b1ce6bb54a533d219952eb277ab93424030275bfcab914a02cc1fbd34109a26def5fc5b80adb8ff3c8f8699a5adef7fe4231920f5bea4600e373cf4c608fee056bcfe89d67133570280948e827cc8b71c259309aab37a70bbe8a77fdf1d3fa4164b7b1f9ccb7e5aa3be8ac4351ec81e705017d8e28e3675affe07bc7fbbef680f090c00166b23818079e339258eba24ef54079db4f10b83ebbbde1dec8b61889f4ee97f96cea7c007ff35acd67d00e2d0b2b670e6300228abd700e1975eb8aec6cd71c343cf4d14ecb7fe59a45df3249b793298f58a345404efc6dd5cce8af43075505d4b2fe69d9a31f3fd84bc557c2ca7af9371bb144e3c55255651fef5480e23c57b50dcaef5c9bab194c441ab4a71a475da3d834d97fc13d6e647a6f389a7caa5615a51f1b00052c92c6c5b8a9cee3fe419381d5a0c3c09e158eba996c3f2b367510b4e2a43639e3cbfd5877f2246204f87a931e6e464235f6e6a73b6de06a89784c37eee1754dc4807f678ab786516b716071885aaf607a8ee7ffe8c8ec49dc14507e5529979ce90a7faf865d46ceb8b7aec72e9bfbf75970a099542c2e8caae3a2b3ca781b2b7ab4c747160b893c69e051048a7b33", "365fecfd5038b54f901d1d2654bbd9310567794652de50eb9cee4afc81307903a21e44b8ed887d0af13a228313a4865693bf2b0d2c6b3612b5c03d019ba54b4a76a657a912bdffe29cfcbd262d422c30b6a8e6c6c6bd7a80db9137164b2edaae9711b64a692d64fbd03433e21f78a88dbd35b6dea8df9064712ebad53be13e1d1ccfc0716b7b67ff503997459969144be0abf77fb0f5b3131f2a01afe4c0b65e041f7e74d6d5706209d71ee26e4c04c53573b07427b5183b55a8ea75339b2e9b7397c0773e1180829d191ad85ae26addf36ab576ed83928d07e315c52afbbb6e40427d190ead1d657f94bbf0da44b6f92279684a782425bc73030ddc67d217858c1362e0bdd590a45161ed91dc042f2176b80c935aba94464b75e496501aa7384083fa70e78e3972a076e1725d8441555228001cd9686553e60e8456491888a518ea9592d54287a42092de69ff2d9ba7377172e95794e6cc3bb1e490f8962850f325f092125277e0901ddc9c27f4579dbde0c3a1437f424f2192ec73443320b9e45859e54f1444df58c89c0432229732c4cebb222e07ef82188e74680ad19625e533d3e67a642e0ad6060bed512d66ccafd1900448bb0d96491ec86d8e91a0eb2820bc04435d6ffac14561748f7aa08d95ca15a462da0616b506f3889b844280b13e566aa99e4b025b36d742d55d6699b2511df13fe179410c31b1ac1b8e84201ef09a239c7cb5191312e4df7a3b8523bd3d20160a42c0922b46b10f244097946ffd37b21cd8f99d36a3078acf54395d3f0966789c2c2bad73a888bddf73f99bfa69a0f055bce6a448003c47310a1b50bc8252e97764be1c5cc54c7244c49823bc17d2aad750783a23649d4cc58668c2c17fe5d7f58ae8ba0b908504fdf5e4f7197f006c263c79c8a9149873a8fecaeabe7184d2a73ab84451fe3e841bb71f5884de3628b3fe875bbcf6639d8ba3c6e69b5989c280b9455d8a873aa4fc78e9dbabc29831a66fddc694bb283f2f7d7fcbdd80961af987d281f97e5eaed86cd1fee600f3fc3623fa9131f1e7a51bd68e801fc5f56e19e8bc41bb919d46b3bf92e420e6355c54ef7afbeb507d83d45242e65315d17644c001e6ba07d7dcdbe94c69ccead13e27a04bfe618962f7748a18fb4e676debe832aa016401c9e307e4a19e63d7dd44ab1259eacc9a43370cd591c31b9e506e6b88a7d9fc531f47ff514080698cd3af312b3196838725295eed9822ba651ab872f736c26ff9e0bb0b7c25dccb3cdd60997e62ef4032a601c7c047b2634974bb2c519a02a90d311382b33d64d93adf9088c533d6c7808382a3a2c6e5d2a50a1b164d8ba029961d98f788e2b8d71c30281d98015ae5b3ad65b6db21b1c87e2b60dba3f7f199ecb6ac6d0e445b876c3ca8951d57251b44bab4e443beb971229c092aa9d0c894b3743c149e2596"},
+    {"000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000280000000000000000000000000000000000000000000000000000000000000020b2fca3dc946fa5a029d50adc687db5a644534cc109efd63944850c142311564d8a25e786e58113ab6fa9c0e57dcec4c74921ab8fbdfb6d48d0afd7330b289b4cc2891e4dab40d43aabc015e52acc98cc2111e3b2a1668dbff5196f5872f3074e33b0b635e26d5001", "551068ad7579104ec8135ec2de6ef520720598722382864ae3ae89f79947abfb"},
+    {"000000000000000000000000000000000000000000000000000000000000003000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000030e2dff2335b4935382ff02e82ee71bf95e9f4e57757e1be320b2a43ac29b09f0b0b95103da15724450b1a47149ea4cc4de151beb96cc1e7280034df34a6fcd50d74804b80ba4488b792cbc07a84321db6b4daadec345e2ab2ff7498c2a8f3f164405317506eba4f5285ce1e0de82a1340", "04a83ef9efdba3a902900def86840bbce498f43c44e4a43d2004c8c0b1239f54927ddd030b6b788ba8c715d7f1f1ef7d"},
+    {"0000000000000000000000000000000000000000000000000000000000000048000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000480cd77fdcce6a48b4afa1e7024f59866b722437851208f35c840efc1c4d3256629fe361994c1b37c0e25583d3c8634ae1969c4620b0e8193d464be2f4f47bec58c8b5cc5837c1c29536ec868cfdbf369a7dbbdd1e77e1618e451d2bab668738090f35700c1a9638e02ad47742cd1c3d44035aa12b59268527de8ad76ee3a01849299e46748949d040419b43dad68cc2250000000000000000", "084f9aae2920f742ca72504f52af29d8aa470c766eee9021399406349de7c83cf794281c42b3c7f05c447fd681d4eef507f915db1eacb6fb02bb36c651f97c969bb89529300808d9"},
+    {"000000000000000000000000000000000000000000000000000000000000002800000000000000000000000000000000000000000000000000000000000000140000000000000000000000000000000000000000000000000000000000000028896f99125bc85e8a5438bc53c0ace45b39cfc96240f16f624d4f1e5f3139e91904b4caa46f3f4bb591aed06a6c9517521a221593c0e17bf5220a4bb410000000000000000000000000000000000000000000000000000000000000000000000000000000", "0cb48ee9a13cb728cb656531366a230ef7f7f96cab9546ff47ab6bc64c48fddeb53d9d03b7d2e671"},
+    {"000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000030000000000000000000000000000000000000000000000000000000000000020a7565979f47f0e89de81aa61c51823d4ee796b2cfc6ea1970299bab64d5ef249010001000000000000000000000000000000000000000006a5f7aa8cd80a9aca5a4222", "000000000000000000000000000000000000000000caa5e6a5b7b90de2999021"},
+    {"0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000020007", "0001"},
+    {"00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000000205090001", "0000"},
+    {"00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000000205090000", "0000"},
+    {"00000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000002097530b1005404a61d6dfc1b503994439ebffc9bd61e6c7f7051cb01fcc84eb4b0000000000000000000000000000000000000000000000000000000000000000e189bfec4e9ec26a43b6fa27d47b449b89633ea173c144521dd4a99548ec4e5f", "0000000000000000000000000000000000000000000000000000000000000001"},
+    {"0000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000000509", ""},
+    {"000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000040863403db07396bddec8e86cbc2369c1c16a5a3d02dfcb51d7eefb7d2400f58070f9ec505c6c49683e7ace39d64597557fdb4ea54601430110c4acdc6c88e372c03e60e169f00a0300f42d8abd7a5362715fab4eef301d4d5df62e0fe827300", "9d372beba18407c5940eb3ef25dd3d51f60c5848a21899512ff5586b06c9fd7d924b5365dda84d7e2f9587b9d177bc1fab0d25ccf2b231d48943428629451cc0"},
+    {"0000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000080203", "0000000000000000"},
+};
+const uint64_t modexp_case_gas[modexp_case_count] = {1360, 1360, 200, 200, 341, 1365, 341, 1365, 87381, 1701, 1524, 1647, 1325, 200, 200, 200, 200, 200, 200, 200, 200};
+
+#define blake2f_case_count 8
+const char* blake2f_case[blake2f_case_count][2] = {
+    {"", ""},
+    {"00000c48c9bdf267e6096a3ba7ca8485ae67bb2bf894fe72f36e3cf1361d5f3af54fa5d182e6ad7f520e511f6c3e2b8c68059b6bbd41fbabd9831f79217e1319cde05b61626300000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000300000000000000000000000000000001", ""},
+    {"0000000c48c9bdf267e6096a3ba7ca8485ae67bb2bf894fe72f36e3cf1361d5f3af54fa5d182e6ad7f520e511f6c3e2b8c68059b6bbd41fbabd9831f79217e1319cde05b6162630000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000030000000000000000000000000000000102", ""},
+    {"0000000c48c9bdf267e6096a3ba7ca8485ae67bb2bf894fe72f36e3cf1361d5f3af54fa5d182e6ad7f520e511f6c3e2b8c68059b6bbd41fbabd9831f79217e1319cde05b61626300000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000300000000000000000000000000000002", ""},
+    {"0000000048c9bdf267e6096a3ba7ca8485ae67bb2bf894fe72f36e3cf1361d5f3af54fa5d182e6ad7f520e511f6c3e2b8c68059b6bbd41fbabd9831f79217e1319cde05b61626300000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000300000000000000000000000000000001", "08c9bcf367e6096a3ba7ca8485ae67bb2bf894fe72f36e3cf1361d5f3af54fa5d282e6ad7f520e511f6c3e2b8c68059b9442be0454267ce079217e1319cde05b"},
+    {"0000000c48c9bdf267e6096a3ba7ca8485ae67bb2bf894fe72f36e3cf1361d5f3af54fa5d182e6ad7f520e511f6c3e2b8c68059b6bbd41fbabd9831f79217e1319cde05b61626300000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000300000000000000000000000000000001", "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d17d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923"},
+    {"0000000c48c9bdf267e6096a3ba7ca8485ae67bb2bf894fe72f36e3cf1361d5f3af54fa5d182e6ad7f520e511f6c3e2b8c68059b6bbd41fbabd9831f79217e1319cde05b61626300000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000300000000000000000000000000000000", "75ab69d3190a562c51aef8d88f1c2775876944407270c42c9844252c26d2875298743e7f6d5ea2f2d3e8d226039cd31b4e426ac4f2d3d666a610c2116fde4735"},
+    {"0000000148c9bdf267e6096a3ba7ca8485ae67bb2bf894fe72f36e3cf1361d5f3af54fa5d182e6ad7f520e511f6c3e2b8c68059b6bbd41fbabd9831f79217e1319cde05b61626300000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000300000000000000000000000000000001", "b63a380cb2897d521994a85234ee2c181b5f844d2c624c002677e9703449d2fba551b3a8333bcdf5f2f7e08993d53923de3d64fcc68c034e717b9293fed7a421"},
+};
+
+#define bls12_g1add_case_count 11
+const char* bls12_g1add_case[bls12_g1add_case_count][2] = {
+    {"0000000000000000000000000000000017f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb0000000000000000000000000000000008b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1000000000000000000000000000000000572cbea904d67468808c8eb50a9450c9721db309128012543902d0ac358a62ae28f75bb8f1c7c42c39a8c5529bf0f4e00000000000000000000000000000000166a9d8cabc673a322fda673779d8e3822ba3ecb8670e461f73bb9021d5fd76a4c56d9d4cd16bd1bba86881979749d28", "0000000000000000000000000000000009ece308f9d1f0131765212deca99697b112d61f9be9a5f1f3780a51335b3ff981747a0b2ca2179b96d2c0c9024e522400000000000000000000000000000000032b80d3a6f5b09f8a84623389c5f80ca69a0cddabc3097f9d9c27310fd43be6e745256c634af45ca3473b0590ae30d1"},
+    {"000000000000000000000000000000000572cbea904d67468808c8eb50a9450c9721db309128012543902d0ac358a62ae28f75bb8f1c7c42c39a8c5529bf0f4e00000000000000000000000000000000166a9d8cabc673a322fda673779d8e3822ba3ecb8670e461f73bb9021d5fd76a4c56d9d4cd16bd1bba86881979749d28000000000000000000000000000000000572cbea904d67468808c8eb50a9450c9721db309128012543902d0ac358a62ae28f75bb8f1c7c42c39a8c5529bf0f4e00000000000000000000000000000000166a9d8cabc673a322fda673779d8e3822ba3ecb8670e461f73bb9021d5fd76a4c56d9d4cd16bd1bba86881979749d28", "000000000000000000000000000000000c9b60d5afcbd5663a8a44b7c5a02f19e9a77ab0a35bd65809bb5c67ec582c897feb04decc694b13e08587f3ff9b5b6000000000000000000000000000000000143be6d078c2b79a7d4f1d1b21486a030ec93f56aa54e1de880db5a66dd833a652a95bee27c824084006cb5644cbd43f"},
+    {"0000000000000000000000000000000017f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb0000000000000000000000000000000008b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e10000000000000000000000000000000017f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb00000000000000000000000000000000114d1d6855d545a8aa7d76c8cf2e21f267816aef1db507c96655b9d5caac42364e6f38ba0ecb751bad54dcd6b939c2ca", "0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"},
+    {"00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000009ece308f9d1f0131765212deca99697b112d61f9be9a5f1f3780a51335b3ff981747a0b2ca2179b96d2c0c9024e522400000000000000000000000000000000032b80d3a6f5b09f8a84623389c5f80ca69a0cddabc3097f9d9c27310fd43be6e745256c634af45ca3473b0590ae30d1", "0000000000000000000000000000000009ece308f9d1f0131765212deca99697b112d61f9be9a5f1f3780a51335b3ff981747a0b2ca2179b96d2c0c9024e522400000000000000000000000000000000032b80d3a6f5b09f8a84623389c5f80ca69a0cddabc3097f9d9c27310fd43be6e745256c634af45ca3473b0590ae30d1"},
+    {"00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000", "0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"},
+    {"00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000a989badd40d6212b33cffc3f3763e9bc760f988c9926b26da9dd85e928483446346b8ed00e1de5d5ea93e354abe706c0000000000000000000000000000000017f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb0000000000000000000000000000000008b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1", "0000000000000000000000000000000017bcbbfdd2442c328150f65465bd7b9c4ff36e35261ad3549222e532758a1cf0945ba133ec513517b4ea9de098a037f90000000000000000000000000000000006d1d4f6580f49b4e0a98509ffd18f24afcada36fd0d44e9fc9e5f0c19df3ec01474eefc659d57d149b97ca899010a5d"},
+    {"", ""},
+    {"0000000000000000000000000000000017f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb0000000000000000000000000000000008b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1000000000000000000000000000000000572cbea904d67468808c8eb50a9450c9721db309128012543902d0ac358a62ae28f75bb8f1c7c42c39a8c5529bf0f4e00000000000000000000000000000000166a9d8cabc673a322fda673779d8e3822ba3ecb8670e461f73bb9021d5fd76a4c56d9d4cd16bd1bba86881979749d", ""},
+    {"0000000000000000000000000000000017f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb0000000000000000000000000000000008b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e10100000000000000000000000000000017f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb0000000000000000000000000000000008b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1", ""},
+    {"0000000000000000000000000000000017f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb0000000000000000000000000000000008b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e10000000000000000000000000000000031f2e5916b17be2e71b10b4292f558e727dfd7d48af9cbc5087f0ce00dcca27c8b01e83eaace1aefb539f00adb2271660000000000000000000000000000000008b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1", ""},
+    {"0000000000000000000000000000000017f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb0000000000000000000000000000000008b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e10000000000000000000000000000000017f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb0000000000000000000000000000000017f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb", ""},
+};
+
+#define bls12_g1msm_case_count 11
+const char* bls12_g1msm_case[bls12_g1msm_case_count][2] = {
+    {"0000000000000000000000000000000017f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb0000000000000000000000000000000008b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e10000000000000000000000000000000000000000000000000000000000000005", "0000000000000000000000000000000010e7791fb972fe014159aa33a98622da3cdc98ff707965e536d8636b5fcc5ac7a91a8c46e59a00dca575af0f18fb13dc0000000000000000000000000000000016ba437edcc6551e30c10512367494bfb6b01cc6681e8a4c3cd2501832ab5c4abc40b4578b85cbaffbf0bcd70d67c6e2"},
+    {"0000000000000000000000000000000017f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb0000000000000000000000000000000008b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e10000000000000000000000000000000000000000000000000000000000000000", "0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"},
+    {"0000000000000000000000000000000017f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb0000000000000000000000000000000008b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e173eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001", "0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"},
+    {"0000000000000000000000000000000017f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb0000000000000000000000000000000008b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff", "0000000000000000000000000000000016ea601ca88f7d3489479129b258960b4c1df37194d30803627c30c34252679a0ada1a51bc7a4006a4f0564050d3174600000000000000000000000000000000039e394a6f95c4a2f27bf38f950b2af8d2aa8e0c4a1ffbe9ca518d1bedb573e310fba8f436aec3a3c8f2655fad5e2013"},
+    {"00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000007", "0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"},
+    {"0000000000000000000000000000000017f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb0000000000000000000000000000000008b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e10000000000000000000000000000000000000000000000000000000000000003000000000000000000000000000000000572cbea904d67468808c8eb50a9450c9721db309128012543902d0ac358a62ae28f75bb8f1c7c42c39a8c5529bf0f4e00000000000000000000000000000000166a9d8cabc673a322fda673779d8e3822ba3ecb8670e461f73bb9021d5fd76a4c56d9d4cd16bd1bba86881979749d2873eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000009", "0000000000000000000000000000000017f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb0000000000000000000000000000000008b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1"},
+    {"0000000000000000000000000000000009ece308f9d1f0131765212deca99697b112d61f9be9a5f1f3780a51335b3ff981747a0b2ca2179b96d2c0c9024e522400000000000000000000000000000000032b80d3a6f5b09f8a84623389c5f80ca69a0cddabc3097f9d9c27310fd43be6e745256c634af45ca3473b0590ae30d180000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000010e7791fb972fe014159aa33a98622da3cdc98ff707965e536d8636b5fcc5ac7a91a8c46e59a00dca575af0f18fb13dc0000000000000000000000000000000016ba437edcc6551e30c10512367494bfb6b01cc6681e8a4c3cd2501832ab5c4abc40b4578b85cbaffbf0bcd70d67c6e20000000000000000000000000000000000000000000000000000000000003039", "000000000000000000000000000000000ffa44a0b75f121cfd20169943028d27de3f391be8fae49b3157573c7c9b2f1fca34fb197952cac17ccaadbe1794e3ac00000000000000000000000000000000194882625666ce6578db262b398abc9c37c9abaf3c5c043c9d8c7611dd6de2ebb2e92b24d6c965fb87d0beb03c7d31a0"},
+    {"", ""},
+    {"0000000000000000000000000000000017f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb0000000000000000000000000000000008b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e100000000000000000000000000000000000000000000000000000000000000", ""},
+    {"00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000a989badd40d6212b33cffc3f3763e9bc760f988c9926b26da9dd85e928483446346b8ed00e1de5d5ea93e354abe706c0000000000000000000000000000000000000000000000000000000000000001", ""},
+    {"0000000000000000000000000000000017f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb0000000000000000000000000000000017f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb0000000000000000000000000000000000000000000000000000000000000001", ""},
+};
+const uint64_t bls12_g1msm_case_gas[bls12_g1msm_case_count] = {12000, 12000, 12000, 12000, 12000, 30528, 22776, 0, 0, 12000, 12000};
+
+#define bls12_g2add_case_count 8
+const char* bls12_g2add_case[bls12_g2add_case_count][2] = {
+    {"00000000000000000000000000000000024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb80000000000000000000000000000000013e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e000000000000000000000000000000000ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801000000000000000000000000000000000606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be000000000000000000000000000000001638533957d540a9d2370f17cc7ed5863bc0b995b8825e0ee1ea1e1e4d00dbae81f14b0bf3611b78c952aacab827a053000000000000000000000000000000000a4edef9c1ed7f729f520e47730a124fd70662a904ba1074728114d1031e1572c6c886f6b57ec72a6178288c47c33577000000000000000000000000000000000468fb440d82b0630aeb8dca2b5256789a66da69bf91009cbfe6bd221e47aa8ae88dece9764bf3bd999d95d71e4c9899000000000000000000000000000000000f6d4552fa65dd2638b361543f887136a43253d9c66c411697003f7a13c308f5422e1aa0a59c8967acdefd8b6e36ccf3", "00000000000000000000000000000000122915c824a0857e2ee414a3dccb23ae691ae54329781315a0c75df1c04d6d7a50a030fc866f09d516020ef82324afae0000000000000000000000000000000009380275bbc8e5dcea7dc4dd7e0550ff2ac480905396eda55062650f8d251c96eb480673937cc6d9d6a44aaa56ca66dc000000000000000000000000000000000b21da7955969e61010c7a1abc1a6f0136961d1e3b20b1a7326ac738fef5c721479dfd948b52fdf2455e44813ecfd8920000000000000000000000000000000008f239ba329b3967fe48d718a36cfe5f62a7e42e0bf1c1ed714150a166bfbd6bcf6b3b58b975b9edea56d53f23a0e849"},
+    {"000000000000000000000000000000001638533957d540a9d2370f17cc7ed5863bc0b995b8825e0ee1ea1e1e4d00dbae81f14b0bf3611b78c952aacab827a053000000000000000000000000000000000a4edef9c1ed7f729f520e47730a124fd70662a904ba1074728114d1031e1572c6c886f6b57ec72a6178288c47c33577000000000000000000000000000000000468fb440d82b0630aeb8dca2b5256789a66da69bf91009cbfe6bd221e47aa8ae88dece9764bf3bd999d95d71e4c9899000000000000000000000000000000000f6d4552fa65dd2638b361543f887136a43253d9c66c411697003f7a13c308f5422e1aa0a59c8967acdefd8b6e36ccf3000000000000000000000000000000001638533957d540a9d2370f17cc7ed5863bc0b995b8825e0ee1ea1e1e4d00dbae81f14b0bf3611b78c952aacab827a053000000000000000000000000000000000a4edef9c1ed7f729f520e47730a124fd70662a904ba1074728114d1031e1572c6c886f6b57ec72a6178288c47c33577000000000000000000000000000000000468fb440d82b0630aeb8dca2b5256789a66da69bf91009cbfe6bd221e47aa8ae88dece9764bf3bd999d95d71e4c9899000000000000000000000000000000000f6d4552fa65dd2638b361543f887136a43253d9c66c411697003f7a13c308f5422e1aa0a59c8967acdefd8b6e36ccf3", "000000000000000000000000000000000e7a30979a8853a077454eb63b8dcee75f106221b262886bb8e01b0abb043368da82f60899cc1412e33e4120195fc55700000000000000000000000000000000070227d3f13684fdb7ce31b8065ba3acb35f7bde6fe2ddfefa359f8b35d08a9ab9537b43e24f4ffb720b5a0bda2a82f2000000000000000000000000000000000701377cb7da22789d032737eabcea2b2eee6bb4634c4365864511a43c2caad50422993ccd3e99636eb8a5f189454b18000000000000000000000000000000000782c14e2c4ee61cbe7be6e462a66b2e3509f42d53ff333efc9bfe9a00307cd2f68b007606446d98a75fb808a405d8b9"},
+    {"00000000000000000000000000000000024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb80000000000000000000000000000000013e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e000000000000000000000000000000000ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801000000000000000000000000000000000606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be00000000000000000000000000000000024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb80000000000000000000000000000000013e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e000000000000000000000000000000000d1b3cc2c7027888be51d9ef691d77bcb679afda66c73f17f9ee3837a55024f78c71363275a75d75d86bab79f74782aa0000000000000000000000000000000013fa4d4a0ad8b1ce186ed5061789213d993923066dddaf1040bc3ff59f825c78df74f2d75467e25e0f55f8a00fa030ed", "00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"},
+    {"0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000", "00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"},
+    {"00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000017faa6201231304f270b858dad9462089f2a5b83388e4b10773abc1eef6d193b9fce4e8ea2d9d28e3c3a315aa7de14ca0000000000000000000000000000000000cc12449be6ac4e7f367e7242250427c4fb4c39325d3164ad397c1837a90f0ea1a534757df374dd6569345eb41ed76e00000000000000000000000000000000024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb80000000000000000000000000000000013e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e000000000000000000000000000000000ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801000000000000000000000000000000000606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be", "000000000000000000000000000000001852c4c7fd27d91c598d3638b5a58481d53b078c1f38a267c42fed57910a8f93dc4bffc23ef95f371995f0ee9555908a00000000000000000000000000000000042dd9d74ed49068d10bea59e8853dc0868e1939070547ee48403ece51043461ae1cb5e61823080904866dc9f4fa2b300000000000000000000000000000000002561b0bd20285ca3f761f9c8af159b267c63591bcbf4333df8d1a68c63c9ad90d7415c22b9f1bcb640321214d73821f000000000000000000000000000000000b29960a0f513de6d2bb9e197a61d4f0ebb82e54828b55acb2fb761100085a2a4868813449cf91181b7b47a8bf8866e8"},
+    {"", ""},
+    {"00000000000000000000000000000000024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb80000000000000000000000000000000013e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e000000000000000000000000000000000ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801000000000000000000000000000000000606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be010000000000000000000000000000001638533957d540a9d2370f17cc7ed5863bc0b995b8825e0ee1ea1e1e4d00dbae81f14b0bf3611b78c952aacab827a053000000000000000000000000000000000a4edef9c1ed7f729f520e47730a124fd70662a904ba1074728114d1031e1572c6c886f6b57ec72a6178288c47c33577000000000000000000000000000000000468fb440d82b0630aeb8dca2b5256789a66da69bf91009cbfe6bd221e47aa8ae88dece9764bf3bd999d95d71e4c9899000000000000000000000000000000000f6d4552fa65dd2638b361543f887136a43253d9c66c411697003f7a13c308f5422e1aa0a59c8967acdefd8b6e36ccf3", ""},
+    {"00000000000000000000000000000000024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb80000000000000000000000000000000013e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e000000000000000000000000000000000ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801000000000000000000000000000000000606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be00000000000000000000000000000000024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb80000000000000000000000000000000013e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e00000000000000000000000000000000024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb80000000000000000000000000000000013e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e", ""},
+};
+
+#define bls12_g2msm_case_count 6
+const char* bls12_g2msm_case[bls12_g2msm_case_count][2] = {
+    {"00000000000000000000000000000000024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb80000000000000000000000000000000013e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e000000000000000000000000000000000ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801000000000000000000000000000000000606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be0000000000000000000000000000000000000000000000000000000000000005", "000000000000000000000000000000000411a5de6730ffece671a9f21d65028cc0f1102378de124562cb1ff49db6f004fcd14d683024b0548eff3d1468df26880000000000000000000000000000000000fb837804dba8213329db46608b6c121d973363c1234a86dd183baff112709cf97096c5e9a1a770ee9d7dc641a894d60000000000000000000000000000000019b5e8f5d4a72f2b75811ac084a7f814317360bac52f6aab15eed416b4ef9938e0bdc4865cc2c4d0fd947e7c6925fd1400000000000000000000000000000000093567b4228be17ee62d11a254edd041ee4b953bffb8b8c7f925bd6662b4298bac2822b446f5b5de3b893e1be5aa4986"},
+    {"00000000000000000000000000000000024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb80000000000000000000000000000000013e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e000000000000000000000000000000000ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801000000000000000000000000000000000606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001", "00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"},
+    {"00000000000000000000000000000000024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb80000000000000000000000000000000013e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e000000000000000000000000000000000ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801000000000000000000000000000000000606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79beffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff000000000000000000000000000000001638533957d540a9d2370f17cc7ed5863bc0b995b8825e0ee1ea1e1e4d00dbae81f14b0bf3611b78c952aacab827a053000000000000000000000000000000000a4edef9c1ed7f729f520e47730a124fd70662a904ba1074728114d1031e1572c6c886f6b57ec72a6178288c47c33577000000000000000000000000000000000468fb440d82b0630aeb8dca2b5256789a66da69bf91009cbfe6bd221e47aa8ae88dece9764bf3bd999d95d71e4c9899000000000000000000000000000000000f6d4552fa65dd2638b361543f887136a43253d9c66c411697003f7a13c308f5422e1aa0a59c8967acdefd8b6e36ccf30000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001", "00000000000000000000000000000000136c169e9ae7c4ba427ff4ccfbdbfe04faa1a1441d02d2a66009bb011a40cfe25ef38e6f0f7231f668b5b1bc40dc08fe0000000000000000000000000000000018b1ce898bd11efd56f35a51114c8940e2d2c599892a3d8e4c6b38b85425c8af8023045bc6cdd3908f1e4f9b31b927bf00000000000000000000000000000000190d3d371d944188a79c9c3faa06f731e4c4921e7ced45ad1fa7058243aaa323f161f9cad7a51f627516c2cbccd34ce10000000000000000000000000000000000a85dbac3365606449059c268b5fc19c73edc74fbecb0146dc6ad705f1f4bb97a1f501524db907e979950ef38216298"},
+    {"", ""},
+    {"00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000017faa6201231304f270b858dad9462089f2a5b83388e4b10773abc1eef6d193b9fce4e8ea2d9d28e3c3a315aa7de14ca0000000000000000000000000000000000cc12449be6ac4e7f367e7242250427c4fb4c39325d3164ad397c1837a90f0ea1a534757df374dd6569345eb41ed76e0000000000000000000000000000000000000000000000000000000000000001", ""},
+    {"00000000000000000000000000000000024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb80000000000000000000000000000000013e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e00000000000000000000000000000000024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb80000000000000000000000000000000013e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e0000000000000000000000000000000000000000000000000000000000000001", ""},
+};
+const uint64_t bls12_g2msm_case_gas[bls12_g2msm_case_count] = {22500, 22500, 62302, 0, 22500, 22500};
+
+#define bls12_pairing_check_case_count 10
+const char* bls12_pairing_check_case[bls12_pairing_check_case_count][2] = {
+    {"0000000000000000000000000000000017f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb0000000000000000000000000000000008b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e100000000000000000000000000000000024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb80000000000000000000000000000000013e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e000000000000000000000000000000000ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801000000000000000000000000000000000606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be0000000000000000000000000000000017f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb00000000000000000000000000000000114d1d6855d545a8aa7d76c8cf2e21f267816aef1db507c96655b9d5caac42364e6f38ba0ecb751bad54dcd6b939c2ca00000000000000000000000000000000024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb80000000000000000000000000000000013e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e000000000000000000000000000000000ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801000000000000000000000000000000000606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be", "0000000000000000000000000000000000000000000000000000000000000001"},
+    {"0000000000000000000000000000000017f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb0000000000000000000000000000000008b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e100000000000000000000000000000000024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb80000000000000000000000000000000013e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e000000000000000000000000000000000ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801000000000000000000000000000000000606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be", "0000000000000000000000000000000000000000000000000000000000000000"},
+    {"000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb80000000000000000000000000000000013e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e000000000000000000000000000000000ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801000000000000000000000000000000000606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be", "0000000000000000000000000000000000000000000000000000000000000001"},
+    {"000000000000000000000000000000000572cbea904d67468808c8eb50a9450c9721db309128012543902d0ac358a62ae28f75bb8f1c7c42c39a8c5529bf0f4e00000000000000000000000000000000166a9d8cabc673a322fda673779d8e3822ba3ecb8670e461f73bb9021d5fd76a4c56d9d4cd16bd1bba86881979749d2800000000000000000000000000000000122915c824a0857e2ee414a3dccb23ae691ae54329781315a0c75df1c04d6d7a50a030fc866f09d516020ef82324afae0000000000000000000000000000000009380275bbc8e5dcea7dc4dd7e0550ff2ac480905396eda55062650f8d251c96eb480673937cc6d9d6a44aaa56ca66dc000000000000000000000000000000000b21da7955969e61010c7a1abc1a6f0136961d1e3b20b1a7326ac738fef5c721479dfd948b52fdf2455e44813ecfd8920000000000000000000000000000000008f239ba329b3967fe48d718a36cfe5f62a7e42e0bf1c1ed714150a166bfbd6bcf6b3b58b975b9edea56d53f23a0e8490000000000000000000000000000000006e82f6da4520f85c5d27d8f329eccfa05944fd1096b20734c894966d12a9e2a9a9744529d7212d33883113a0cadb90900000000000000000000000000000000022901b141a9daabba0acdf56c7a9ca7819db2bb9b92848d7b0885e0b57c1695d6c307cebda4d19f13259775ba9c632f00000000000000000000000000000000024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb80000000000000000000000000000000013e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e000000000000000000000000000000000ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801000000000000000000000000000000000606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be", "0000000000000000000000000000000000000000000000000000000000000001"},
+    {"000000000000000000000000000000000572cbea904d67468808c8eb50a9450c9721db309128012543902d0ac358a62ae28f75bb8f1c7c42c39a8c5529bf0f4e00000000000000000000000000000000166a9d8cabc673a322fda673779d8e3822ba3ecb8670e461f73bb9021d5fd76a4c56d9d4cd16bd1bba86881979749d2800000000000000000000000000000000122915c824a0857e2ee414a3dccb23ae691ae54329781315a0c75df1c04d6d7a50a030fc866f09d516020ef82324afae0000000000000000000000000000000009380275bbc8e5dcea7dc4dd7e0550ff2ac480905396eda55062650f8d251c96eb480673937cc6d9d6a44aaa56ca66dc000000000000000000000000000000000b21da7955969e61010c7a1abc1a6f0136961d1e3b20b1a7326ac738fef5c721479dfd948b52fdf2455e44813ecfd8920000000000000000000000000000000008f239ba329b3967fe48d718a36cfe5f62a7e42e0bf1c1ed714150a166bfbd6bcf6b3b58b975b9edea56d53f23a0e8490000000000000000000000000000000010e7791fb972fe014159aa33a98622da3cdc98ff707965e536d8636b5fcc5ac7a91a8c46e59a00dca575af0f18fb13dc000000000000000000000000000000000346ce6b5cb9917c1a5aa2a40cd71817adc72ebe8b6688732a5e8288c40599d9626b4ba725ce344fbe0e4328f297e3c900000000000000000000000000000000024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb80000000000000000000000000000000013e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e000000000000000000000000000000000ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801000000000000000000000000000000000606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be", "0000000000000000000000000000000000000000000000000000000000000000"},
+    {"0000000000000000000000000000000017f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb0000000000000000000000000000000008b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000009ece308f9d1f0131765212deca99697b112d61f9be9a5f1f3780a51335b3ff981747a0b2ca2179b96d2c0c9024e522400000000000000000000000000000000032b80d3a6f5b09f8a84623389c5f80ca69a0cddabc3097f9d9c27310fd43be6e745256c634af45ca3473b0590ae30d1000000000000000000000000000000001638533957d540a9d2370f17cc7ed5863bc0b995b8825e0ee1ea1e1e4d00dbae81f14b0bf3611b78c952aacab827a053000000000000000000000000000000000a4edef9c1ed7f729f520e47730a124fd70662a904ba1074728114d1031e1572c6c886f6b57ec72a6178288c47c33577000000000000000000000000000000000468fb440d82b0630aeb8dca2b5256789a66da69bf91009cbfe6bd221e47aa8ae88dece9764bf3bd999d95d71e4c9899000000000000000000000000000000000f6d4552fa65dd2638b361543f887136a43253d9c66c411697003f7a13c308f5422e1aa0a59c8967acdefd8b6e36ccf30000000000000000000000000000000017f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb00000000000000000000000000000000114d1d6855d545a8aa7d76c8cf2e21f267816aef1db507c96655b9d5caac42364e6f38ba0ecb751bad54dcd6b939c2ca0000000000000000000000000000000019e384121b7d70927c49e6d044fd8517c36bc6ed2813a8956dd64f049869e8a77f7e46930240e6984abe26fa6a89658f0000000000000000000000000000000003f4b4e761936d90fd5f55f99087138a07a69755ad4a46e4dd1c2cfe6d11371e1cc033111a0595e3bba98d0f538db4510000000000000000000000000000000017a31a4fccfb5f768a2157517c77a4f8aaf0dee8f260d96e02e1175a8754d09600923beae02a019afc327b65a2fdbbfc00000000000000000000000000000000088bb5832f4a4a452edda646ebaa2853a54205d56329960b44b2450070734724a74daaa401879bad142132316e9b3401", "0000000000000000000000000000000000000000000000000000000000000001"},
+    {"", ""},
+    {"00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000a989badd40d6212b33cffc3f3763e9bc760f988c9926b26da9dd85e928483446346b8ed00e1de5d5ea93e354abe706c00000000000000000000000000000000024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb80000000000000000000000000000000013e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e000000000000000000000000000000000ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801000000000000000000000000000000000606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be", ""},
+    {"0000000000000000000000000000000017f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb0000000000000000000000000000000008b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000017faa6201231304f270b858dad9462089f2a5b83388e4b10773abc1eef6d193b9fce4e8ea2d9d28e3c3a315aa7de14ca0000000000000000000000000000000000cc12449be6ac4e7f367e7242250427c4fb4c39325d3164ad397c1837a90f0ea1a534757df374dd6569345eb41ed76e", ""},
+    {"0000000000000000000000000000000017f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb0000000000000000000000000000000008b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e100000000000000000000000000000000024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb80000000000000000000000000000000013e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e000000000000000000000000000000000ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801000000000000000000000000000000000606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79", ""},
+};
+const uint64_t bls12_pairing_check_case_gas[bls12_pairing_check_case_count] = {102900, 70300, 70300, 102900, 102900, 135500, 37700, 70300, 70300, 37700};
+
+#define bls12_map_fp_to_g1_case_count 8
+const char* bls12_map_fp_to_g1_case[bls12_map_fp_to_g1_case_count][2] = {
+    {"00000000000000000000000000000000156c8a6a2c184569d69a76be144b5cdc5141d2d2ca4fe341f011e25e3969c55ad9e9b9ce2eb833c81a908e5fa4ac5f03", "00000000000000000000000000000000184bb665c37ff561a89ec2122dd343f20e0f4cbcaec84e3c3052ea81d1834e192c426074b02ed3dca4e7676ce4ce48ba0000000000000000000000000000000004407b8d35af4dacc809927071fc0405218f1401a6d15af775810e4e460064bcc9468beeba82fdc751be70476c888bf3"},
+    {"00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000", "0000000000000000000000000000000011a9a0372b8f332d5c30de9ad14e50372a73fa4c45d5f2fa5097f2d6fb93bcac592f2e1711ac43db0519870c7d0ea41500000000000000000000000000000000092c0f994164a0719f51c24ba3788de240ff926b55f58c445116e8bc6a47cd63392fd4e8e22bdf9feaa96ee773222133"},
+    {"00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001", "000000000000000000000000000000001073311196f8ef19477219ccee3a48035ff432295aa9419eed45d186027d88b90832e14c4f0e2aa4d15f54d1c3ed0f9300000000000000000000000000000000034d6e3755a2073039d609db4cf3aef548283b5cc92f1021cbdb276414bcd8072b112d80a2b0a7dbf22bdaf17e006d45"},
+    {"000000000000000000000000000000001a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaaa", "000000000000000000000000000000001073311196f8ef19477219ccee3a48035ff432295aa9419eed45d186027d88b90832e14c4f0e2aa4d15f54d1c3ed0f930000000000000000000000000000000016b3a3b2e3dddf6a11459ddaf657fde21c4f10282a56029d9b55ab3ce1f41e1cf39ad27e0ea35823c7d3250e81ff3d66"},
+    {"", ""},
+    {"000000000000000000000000000000001a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab", ""},
+    {"01000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001", ""},
+    {"000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001", ""},
+};
+
+#define bls12_map_fp2_to_g2_case_count 6
+const char* bls12_map_fp2_to_g2_case[bls12_map_fp2_to_g2_case_count][2] = {
+    {"0000000000000000000000000000000007355d25caf6e7f2f0cb2812ca0e513bd026ed09dda65b177500fa31714e09ea0ded3a078b526bed3307f804d4b93b040000000000000000000000000000000002829ce3c021339ccb5caf3e187f6370e1e2a311dec9b75363117063ab2015603ff52c3d3b98f19c2f65575e99e8b78c", "0000000000000000000000000000000000e7f4568a82b4b7dc1f14c6aaa055edf51502319c723c4dc2688c7fe5944c213f510328082396515734b6612c4e7bb700000000000000000000000000000000126b855e9e69b1f691f816e48ac6977664d24d99f8724868a184186469ddfd4617367e94527d4b74fc86413483afb35b000000000000000000000000000000000caead0fd7b6176c01436833c79d305c78be307da5f6af6c133c47311def6ff1e0babf57a0fb5539fce7ee12407b0a42000000000000000000000000000000001498aadcf7ae2b345243e281ae076df6de84455d766ab6fcdaad71fab60abb2e8b980a440043cd305db09d283c895e3d"},
+    {"0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000", "00000000000000000000000000000000018320896ec9eef9d5e619848dc29ce266f413d02dd31d9b9d44ec0c79cd61f18b075ddba6d7bd20b7ff27a4b324bfce000000000000000000000000000000000a67d12118b5a35bb02d2e86b3ebfa7e23410db93de39fb06d7025fa95e96ffa428a7a27c3ae4dd4b40bd251ac658892000000000000000000000000000000000260e03644d1a2c321256b3246bad2b895cad13890cbe6f85df55106a0d334604fb143c7a042d878006271865bc359410000000000000000000000000000000004c69777a43f0bda07679d5805e63f18cf4e0e7c6112ac7f70266d199b4f76ae27c6269a3ceebdae30806e9a76aadf5c"},
+    {"00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000001a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaaa", "000000000000000000000000000000001192902dd01ecb3aabbbef02287fb2139a8d87dd87bbd5507ba8fd6ccb828cd3780b5d8b998171f85976e42b5bc0a976000000000000000000000000000000000607ac8f6ee4aae172e48db3b35504818e20b09a5d44b30bc2b52526be1ba1ce9d3f70eeb5f95f8605e24fff59fd69c0000000000000000000000000000000000a3234171e8d4724c896911c3e0888ff11a14d2b08c72ad32549c4bd63d29d8cbd5739fa3bf2874696a084856ebac4c80000000000000000000000000000000003892abb5c45ed21e28ed2dceaae7366880d395ee7e3e42d6ef4ea4996d832d65c8d564c266ef612b6a77d1ea438a640"},
+    {"", ""},
+    {"00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab", ""},
+    {"00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001", ""},
+};
+
 int main() {
     for (int i = 0; i < alt_bn128_add_case_count; i++) {
         char buf0[1024] = {};
@@ -134,5 +293,239 @@ int main() {
         }
     }
 
+    for (int i = 0; i < ecrecover_case_count; i++) {
+        char buf0[128] = {};
+        char buf1[32] = {};
+        const char *inputs = ecrecover_case[i][0];
+        const char *expect = ecrecover_case[i][1];
+        hex2bin(inputs, buf0);
+        // An empty expectation is an empty output.
+        if (ecrecover(buf0, strlen(inputs) / 2, buf1) != (strlen(expect) == 0)) {
+            return 1;
+        }
+        hex2bin(expect, buf0);
+        for (int i = 0; i < strlen(expect) / 2; i++) {
+            if (buf0[i] != buf1[i]) {
+                return 1;
+            }
+        }
+    }
+
+    // No input may come as NULL.
+    if (ecrecover(NULL, 0, (char[32]){}) != 1) {
+        return 1;
+    }
+
+    for (int i = 0; i < modexp_case_count; i++) {
+        char buf0[4096] = {};
+        char buf1[1024] = {};
+        uint32_t len = 0;
+        const char *inputs = modexp_case[i][0];
+        const char *expect = modexp_case[i][1];
+        hex2bin(inputs, buf0);
+        if (modexp_gas(buf0, strlen(inputs) / 2) != modexp_case_gas[i]) {
+            return 1;
+        }
+        if (modexp(buf0, strlen(inputs) / 2, buf1, &len) != 0 || len != strlen(expect) / 2) {
+            return 1;
+        }
+        hex2bin(expect, buf0);
+        for (int i = 0; i < len; i++) {
+            if (buf0[i] != buf1[i]) {
+                return 1;
+            }
+        }
+    }
+
+    {
+        char buf1[1024] = {};
+        uint32_t len = 1;
+        if (modexp_gas(NULL, 0) != 200 || modexp(NULL, 0, buf1, &len) != 0 || len != 0) {
+            return 1;
+        }
+    }
+
+    for (int i = 0; i < blake2f_case_count; i++) {
+        char buf0[256] = {};
+        char buf1[64] = {};
+        const char *inputs = blake2f_case[i][0];
+        const char *expect = blake2f_case[i][1];
+        uint32_t len = strlen(inputs) / 2;
+        hex2bin(inputs, buf0);
+        // An empty expectation is an error.
+        if (blake2f(buf0, len, buf1) != (strlen(expect) == 0)) {
+            return 1;
+        }
+        if (strlen(expect) == 0) {
+            continue;
+        }
+        if (blake2f_gas(buf0, len) != ((uint8_t)buf0[2] << 8 | (uint8_t)buf0[3])) {
+            return 1;
+        }
+        hex2bin(expect, buf0);
+        for (int i = 0; i < 64; i++) {
+            if (buf0[i] != buf1[i]) {
+                return 1;
+            }
+        }
+    }
+
+    if (blake2f(NULL, 0, (char[64]){}) != 1 || blake2f_gas(NULL, 0) != 0) {
+        return 1;
+    }
+
+    for (int i = 0; i < bls12_g1add_case_count; i++) {
+        char buf0[512] = {};
+        char buf1[128] = {};
+        const char *inputs = bls12_g1add_case[i][0];
+        const char *expect = bls12_g1add_case[i][1];
+        uint32_t len = strlen(inputs) / 2;
+        hex2bin(inputs, buf0);
+        // An empty expectation is an error.
+        if (bls12_g1add(buf0, len, buf1) != (strlen(expect) == 0)) {
+            return 1;
+        }
+        hex2bin(expect, buf0);
+        for (int i = 0; i < strlen(expect) / 2; i++) {
+            if (buf0[i] != buf1[i]) {
+                return 1;
+            }
+        }
+    }
+
+    for (int i = 0; i < bls12_g1msm_case_count; i++) {
+        char buf0[2048] = {};
+        char buf1[128] = {};
+        const char *inputs = bls12_g1msm_case[i][0];
+        const char *expect = bls12_g1msm_case[i][1];
+        uint32_t len = strlen(inputs) / 2;
+        hex2bin(inputs, buf0);
+        if (bls12_g1msm_gas(buf0, len) != bls12_g1msm_case_gas[i]) {
+            return 1;
+        }
+        // An empty expectation is an error.
+        if (bls12_g1msm(buf0, len, buf1) != (strlen(expect) == 0)) {
+            return 1;
+        }
+        hex2bin(expect, buf0);
+        for (int i = 0; i < strlen(expect) / 2; i++) {
+            if (buf0[i] != buf1[i]) {
+                return 1;
+            }
+        }
+    }
+
+    for (int i = 0; i < bls12_g2add_case_count; i++) {
+        char buf0[1024] = {};
+        char buf1[256] = {};
+        const char *inputs = bls12_g2add_case[i][0];
+        const char *expect = bls12_g2add_case[i][1];
+        uint32_t len = strlen(inputs) / 2;
+        hex2bin(inputs, buf0);
+        // An empty expectation is an error.
+        if (bls12_g2add(buf0, len, buf1) != (strlen(expect) == 0)) {
+            return 1;
+        }
+        hex2bin(expect, buf0);
+        for (int i = 0; i < strlen(expect) / 2; i++) {
+            if (buf0[i] != buf1[i]) {
+                return 1;
+            }
+        }
+    }
+
+    for (int i = 0; i < bls12_g2msm_case_count; i++) {
+        char buf0[2048] = {};
+        char buf1[256] = {};
+        const char *inputs = bls12_g2msm_case[i][0];
+        const char *expect = bls12_g2msm_case[i][1];
+        uint32_t len = strlen(inputs) / 2;
+        hex2bin(inputs, buf0);
+        if (bls12_g2msm_gas(buf0, len) != bls12_g2msm_case_gas[i]) {
+            return 1;
+        }
+        // An empty expectation is an error.
+        if (bls12_g2msm(buf0, len, buf1) != (strlen(expect) == 0)) {
+            return 1;
+        }
+        hex2bin(expect, buf0);
+        for (int i = 0; i < strlen(expect) / 2; i++) {
+            if (buf0[i] != buf1[i]) {
+                return 1;
+            }
+        }
+    }
+
+    for (int i = 0; i < bls12_pairing_check_case_count; i++) {
+        char buf0[2048] = {};
+        char buf1[32] = {};
+        const char *inputs = bls12_pairing_check_case[i][0];
+        const char *expect = bls12_pairing_check_case[i][1];
+        uint32_t len = strlen(inputs) / 2;
+        hex2bin(inputs, buf0);
+        if (bls12_pairing_check_gas(buf0, len) != bls12_pairing_check_case_gas[i]) {
+            return 1;
+        }
+        // An empty expectation is an error.
+        if (bls12_pairing_check(buf0, len, buf1) != (strlen(expect) == 0)) {
+            return 1;
+        }
+        hex2bin(expect, buf0);
+        for (int i = 0; i < strlen(expect) / 2; i++) {
+            if (buf0[i] != buf1[i]) {
+                return 1;
+            }
+        }
+    }
+
+    for (int i = 0; i < bls12_map_fp_to_g1_case_count; i++) {
+        char buf0[128] = {};
+        char buf1[128] = {};
+        const char *inputs = bls12_map_fp_to_g1_case[i][0];
+        const char *expect = bls12_map_fp_to_g1_case[i][1];
+        uint32_t len = strlen(inputs) / 2;
+        hex2bin(inputs, buf0);
+        // An empty expectation is an error.
+        if (bls12_map_fp_to_g1(buf0, len, buf1) != (strlen(expect) == 0)) {
+            return 1;
+        }
+        hex2bin(expect, buf0);
+        for (int i = 0; i < strlen(expect) / 2; i++) {
+            if (buf0[i] != buf1[i]) {
+                return 1;
+            }
+        }
+    }
+
+    for (int i = 0; i < bls12_map_fp2_to_g2_case_count; i++) {
+        char buf0[256] = {};
+        char buf1[256] = {};
+        const char *inputs = bls12_map_fp2_to_g2_case[i][0];
+        const char *expect = bls12_map_fp2_to_g2_case[i][1];
+        uint32_t len = strlen(inputs) / 2;
+        hex2bin(inputs, buf0);
+        // An empty expectation is an error.
+        if (bls12_map_fp2_to_g2(buf0, len, buf1) != (strlen(expect) == 0)) {
+            return 1;
+        }
+        hex2bin(expect, buf0);
+        for (int i = 0; i < strlen(expect) / 2; i++) {
+            if (buf0[i] != buf1[i]) {
+                return 1;
+            }
+        }
+    }
+
+    {
+        char buf1[256] = {};
+        if (bls12_g1add(NULL, 0, buf1) != 1 || bls12_g1msm(NULL, 0, buf1) != 1 ||
+            bls12_g1msm_gas(NULL, 0) != 0 || bls12_g2add(NULL, 0, buf1) != 1 ||
+            bls12_g2msm(NULL, 0, buf1) != 1 || bls12_g2msm_gas(NULL, 0) != 0 ||
+            bls12_pairing_check(NULL, 0, buf1) != 1 || bls12_pairing_check_gas(NULL, 0) != 37700 ||
+            bls12_map_fp_to_g1(NULL, 0, buf1) != 1 || bls12_map_fp2_to_g2(NULL, 0, buf1) != 1) {
+            return 1;
+        }
+    }
+
     return 0;
 }
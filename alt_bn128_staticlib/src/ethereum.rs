@@ -1,3 +1,13 @@
+// The input of an export. `from_raw_parts` wants a non-null pointer even for no bytes, so a
+// zero length is an empty input whatever `data` holds, NULL included.
+unsafe fn input<'a>(data: *const u8, data_len: u32) -> &'a [u8] {
+    if data_len == 0 {
+        &[]
+    } else {
+        core::slice::from_raw_parts(data, data_len as usize)
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn alt_bn128_add(data: *mut u8, data_len: u32, output: *mut u8) -> u32 {
     unsafe {
@@ -51,3 +61,191 @@ pub extern "C" fn alt_bn128_pairing(data: *mut u8, data_len: u32, output: *mut u
         return 0;
     }
 }
+
+// Returns 1 when the precompile returns no data.
+#[no_mangle]
+pub extern "C" fn ecrecover(data: *mut u8, data_len: u32, output: *mut u8) -> u32 {
+    unsafe {
+        let data = input(data, data_len);
+        let mut buf0 = [0u8; 128];
+        let len = core::cmp::min(data.len(), 128);
+        buf0[..len].copy_from_slice(&data[..len]);
+        let mut buf1 = [0u8; 32];
+        if let Err(_) = alt_bn128::ethereum::ecrecover(&buf0, &mut buf1) {
+            return 1;
+        }
+        for i in 0..32 {
+            output.offset(i as isize).write(buf1[i as usize]);
+        }
+        return 0;
+    }
+}
+
+// The output holds up to 1024 bytes, the longest modulus; its length is stored in
+// `output_len`.
+#[no_mangle]
+pub extern "C" fn modexp(
+    data: *mut u8,
+    data_len: u32,
+    output: *mut u8,
+    output_len: *mut u32,
+) -> u32 {
+    unsafe {
+        let buf0 = input(data, data_len);
+        let mut buf1 = [0u8; alt_bn128::modexp::MAX_LEN];
+        let len = match alt_bn128::ethereum::modexp(buf0, &mut buf1) {
+            Ok(len) => len,
+            Err(_) => return 1,
+        };
+        for i in 0..len {
+            output.offset(i as isize).write(buf1[i as usize]);
+        }
+        output_len.write(len as u32);
+        return 0;
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn modexp_gas(data: *mut u8, data_len: u32) -> u64 {
+    unsafe { alt_bn128::ethereum::modexp_gas(input(data, data_len)) }
+}
+
+#[no_mangle]
+pub extern "C" fn blake2f(data: *mut u8, data_len: u32, output: *mut u8) -> u32 {
+    unsafe {
+        let buf0 = input(data, data_len);
+        let mut buf1 = [0u8; 64];
+        if let Err(_) = alt_bn128::ethereum::blake2f(buf0, &mut buf1) {
+            return 1;
+        }
+        for i in 0..64 {
+            output.offset(i as isize).write(buf1[i as usize]);
+        }
+        return 0;
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn blake2f_gas(data: *mut u8, data_len: u32) -> u64 {
+    unsafe { alt_bn128::ethereum::blake2f_gas(input(data, data_len)) }
+}
+
+#[no_mangle]
+pub extern "C" fn bls12_g1add(data: *mut u8, data_len: u32, output: *mut u8) -> u32 {
+    unsafe {
+        let buf0 = input(data, data_len);
+        let mut buf1 = [0u8; 128];
+        if let Err(_) = alt_bn128::ethereum::bls12_g1add(buf0, &mut buf1) {
+            return 1;
+        }
+        for i in 0..128 {
+            output.offset(i as isize).write(buf1[i as usize]);
+        }
+        return 0;
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn bls12_g1msm(data: *mut u8, data_len: u32, output: *mut u8) -> u32 {
+    unsafe {
+        let buf0 = input(data, data_len);
+        let mut buf1 = [0u8; 128];
+        if let Err(_) = alt_bn128::ethereum::bls12_g1msm(buf0, &mut buf1) {
+            return 1;
+        }
+        for i in 0..128 {
+            output.offset(i as isize).write(buf1[i as usize]);
+        }
+        return 0;
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn bls12_g1msm_gas(data: *mut u8, data_len: u32) -> u64 {
+    unsafe { alt_bn128::ethereum::bls12_g1msm_gas(input(data, data_len)) }
+}
+
+#[no_mangle]
+pub extern "C" fn bls12_g2add(data: *mut u8, data_len: u32, output: *mut u8) -> u32 {
+    unsafe {
+        let buf0 = input(data, data_len);
+        let mut buf1 = [0u8; 256];
+        if let Err(_) = alt_bn128::ethereum::bls12_g2add(buf0, &mut buf1) {
+            return 1;
+        }
+        for i in 0..256 {
+            output.offset(i as isize).write(buf1[i as usize]);
+        }
+        return 0;
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn bls12_g2msm(data: *mut u8, data_len: u32, output: *mut u8) -> u32 {
+    unsafe {
+        let buf0 = input(data, data_len);
+        let mut buf1 = [0u8; 256];
+        if let Err(_) = alt_bn128::ethereum::bls12_g2msm(buf0, &mut buf1) {
+            return 1;
+        }
+        for i in 0..256 {
+            output.offset(i as isize).write(buf1[i as usize]);
+        }
+        return 0;
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn bls12_g2msm_gas(data: *mut u8, data_len: u32) -> u64 {
+    unsafe { alt_bn128::ethereum::bls12_g2msm_gas(input(data, data_len)) }
+}
+
+#[no_mangle]
+pub extern "C" fn bls12_pairing_check(data: *mut u8, data_len: u32, output: *mut u8) -> u32 {
+    unsafe {
+        let buf0 = input(data, data_len);
+        let mut buf1 = [0u8; 32];
+        if let Err(_) = alt_bn128::ethereum::bls12_pairing_check(buf0, &mut buf1) {
+            return 1;
+        }
+        for i in 0..32 {
+            output.offset(i as isize).write(buf1[i as usize]);
+        }
+        return 0;
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn bls12_pairing_check_gas(data: *mut u8, data_len: u32) -> u64 {
+    unsafe { alt_bn128::ethereum::bls12_pairing_check_gas(input(data, data_len)) }
+}
+
+#[no_mangle]
+pub extern "C" fn bls12_map_fp_to_g1(data: *mut u8, data_len: u32, output: *mut u8) -> u32 {
+    unsafe {
+        let buf0 = input(data, data_len);
+        let mut buf1 = [0u8; 128];
+        if let Err(_) = alt_bn128::ethereum::bls12_map_fp_to_g1(buf0, &mut buf1) {
+            return 1;
+        }
+        for i in 0..128 {
+            output.offset(i as isize).write(buf1[i as usize]);
+        }
+        return 0;
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn bls12_map_fp2_to_g2(data: *mut u8, data_len: u32, output: *mut u8) -> u32 {
+    unsafe {
+        let buf0 = input(data, data_len);
+        let mut buf1 = [0u8; 256];
+        if let Err(_) = alt_bn128::ethereum::bls12_map_fp2_to_g2(buf0, &mut buf1) {
+            return 1;
+        }
+        for i in 0..256 {
+            output.offset(i as isize).write(buf1[i as usize]);
+        }
+        return 0;
+    }
+}